@@ -32,7 +32,7 @@ impl RidStore<Msg> for Store {
             }
             Msg::Reset => {
                 let current_elapsed = format!("Elapsed: {}", self.elapsed_secs);
-                self.elapsed_secs = 0;
+                self.set_elapsed_secs(0);
                 rid::post(Reply::Reset(req_id, current_elapsed));
             }
         }
@@ -45,7 +45,7 @@ impl Store {
             self.running = true;
             thread::spawn(move || {
                 while store::read().running {
-                    store::write().elapsed_secs += 1;
+                    *store::write().elapsed_secs_mut() += 1;
                     rid::post(Reply::Tick);
                     thread::sleep(time::Duration::from_secs(1));
                 }
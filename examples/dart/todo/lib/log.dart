@@ -1,6 +1,8 @@
 import 'dart:io';
 import 'package:logger/logger.dart';
 
+import 'generated/rid_api.dart';
+
 final LOG_LEVEL = _levelFromString(Platform.environment['DART_LOG']);
 final LOG_VERBOSE = LOG_LEVEL.index <= Level.debug.index;
 
@@ -33,3 +35,33 @@ Level _levelFromString(String? level) {
       return Level.nothing;
   }
 }
+
+/// Forwards messages posted by Rust, including records logged via the `log` crate, to [log].
+void logRidMessages() {
+  rid.messageChannel.stream.listen((RidMessage msg) {
+    final origin = msg.isLogRecord ? '[${msg.target}] ' : '';
+    final text = '$origin${msg.message}';
+    switch (msg.type) {
+      case RidMessageType.Severe:
+        log.wtf(text, msg.details);
+        break;
+      case RidMessageType.Error:
+        log.e(text, msg.details);
+        break;
+      case RidMessageType.LogWarn:
+      case RidMessageType.MsgWarn:
+        log.w(text);
+        break;
+      case RidMessageType.LogInfo:
+      case RidMessageType.MsgInfo:
+        log.i(text);
+        break;
+      case RidMessageType.LogDebug:
+        log.d(text);
+        break;
+      case RidMessageType.LogTrace:
+        log.v(text);
+        break;
+    }
+  });
+}
@@ -5,6 +5,7 @@ import 'log.dart';
 
 messages() async {
   rid.debugReply = (reply) => log.d('$reply');
+  logRidMessages();
 
   final store = Store.instance;
   await store.msgAddTodo("Hello");
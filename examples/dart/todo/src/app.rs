@@ -21,6 +21,12 @@ pub struct Store {
 
 impl RidStore<Msg> for Store {
     fn create() -> Self {
+        // Forward records logged via `info!`, `warn!`, etc. to the Dart message channel
+        rid::RidLogger::new()
+            .max_level(log::LevelFilter::Debug)
+            .target("todo")
+            .init()
+            .ok();
         Self {
             last_added_id: 0,
             todos: vec![],
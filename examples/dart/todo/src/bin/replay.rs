@@ -0,0 +1,3 @@
+fn main() {
+    rid::replay::run_from_args::<todo::Store, todo::Msg>();
+}
@@ -0,0 +1,65 @@
+use std::{env, ffi::CString, fs, os::raw::c_char, process::Command};
+
+use rid::{
+    replay::{read_message_log, RidLogEntry, RidReplay},
+    testing::RidTestHarness,
+};
+use todo::{Msg, Reply, Store};
+
+// The functions Dart calls to send messages and to free the store when the app exits
+extern "C" {
+    fn rid_msg_AddTodo(req_id: u64, arg0: *mut c_char);
+    fn rid_msg_CompleteTodo(req_id: u64, arg0: u32);
+    fn rid_msg_RemoveCompleted(req_id: u64);
+    fn rid_store_free();
+}
+
+fn dart_string(s: &str) -> *mut c_char {
+    CString::new(s).unwrap().into_raw()
+}
+
+#[test]
+fn records_and_replays_todo_session() {
+    let path = env::temp_dir()
+        .join(format!("rid_todo_session_{}.ridlog", std::process::id()));
+    let harness = RidTestHarness::<Store, Msg, Reply>::new();
+
+    rid::start_recording(&path).unwrap();
+    unsafe {
+        rid_msg_AddTodo(1, dart_string("write tests"));
+        rid_msg_AddTodo(2, dart_string("run tests"));
+        rid_msg_CompleteTodo(3, 1);
+        rid_msg_RemoveCompleted(4);
+        rid_store_free();
+    }
+    assert!(!rid::is_recording(), "freeing the store stops recording");
+    drop(harness);
+
+    let entries = read_message_log(&path).unwrap();
+    assert_eq!(entries.len(), 5, "messages and final snapshot");
+    let final_snapshot = match entries.last().unwrap() {
+        RidLogEntry::Snapshot { store, .. } => store.clone(),
+        entry => panic!("not a snapshot {:?}", entry),
+    };
+    assert!(final_snapshot.contains("run tests"), "{}", final_snapshot);
+    assert!(!final_snapshot.contains("write tests"), "{}", final_snapshot);
+
+    let store = RidReplay::new(&path).run::<Store, Msg>().unwrap();
+    assert_eq!(format!("{:#?}", store), final_snapshot, "replayed store");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_replay"))
+        .arg(&path)
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(
+        output.status.success(),
+        "replay binary: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim_end(),
+        final_snapshot,
+        "replay binary prints replayed store"
+    );
+}
@@ -1,11 +1,13 @@
 import 'dart:async';
+import 'dart:developer' show Timeline, TimelineTask;
 import 'dart:ffi';
 import 'dart:isolate';
+import 'dart:typed_data';
 
 import '_isolate_binding.dart' show initIsolate;
+import '_rid_frame.dart' show RidFrame, RidWireReader, RID_TRACE_VARIANT;
 
-const String _MSG_SEPARATOR = '^';
-
+// NOTE: the order needs to match `rid::_RidMessageType` as messages are posted with its index
 enum RidMessageType {
   Severe,
   Error,
@@ -13,29 +15,12 @@ enum RidMessageType {
   LogInfo,
   LogDebug,
   MsgInfo,
-  MsgWarn
+  MsgWarn,
+  LogTrace
 }
 
-RidMessageType _ridMsgTypeFromString(String s) {
-  switch (s.toLowerCase()) {
-    case "err_severe":
-      return RidMessageType.Severe;
-    case "err_error":
-      return RidMessageType.Error;
-    case "log_warn":
-      return RidMessageType.LogWarn;
-    case "log_info":
-      return RidMessageType.LogInfo;
-    case "log_debug":
-      return RidMessageType.LogDebug;
-    case "msg_warn":
-      return RidMessageType.MsgWarn;
-    case "msg_info":
-      return RidMessageType.MsgInfo;
-    default:
-      throw ArgumentError.value(s);
-  }
-}
+// NOTE: the order needs to match `log::Level` as Rust posts its index starting at 0
+enum RidLogLevel { Error, Warn, Info, Debug, Trace }
 
 final _REMOVE_QUOTE_RX = RegExp(r'(^"|"$)');
 
@@ -44,15 +29,49 @@ class RidMessage {
   late final String message;
   late final String? details;
 
-  RidMessage._(this.type, String message, String? details) {
+  /// Level of the `log` crate record this message was posted for via `rid::RidLogger`,
+  /// `null` for messages posted via rid's macros, i.e. `rid::log_info!`.
+  final RidLogLevel? level;
+
+  /// Target of the log record, by default the module path of the code that logged it.
+  final String? target;
+
+  /// Module path of the code that logged the record, i.e. `todo::app`.
+  final String? modulePath;
+
+  /// Source file and line of the code that logged the record.
+  final String? file;
+  final int? line;
+
+  /// Time at which the record was logged.
+  final DateTime? timestamp;
+
+  RidMessage._(
+    this.type,
+    String message,
+    String? details, {
+    this.level,
+    this.target,
+    this.modulePath,
+    this.file,
+    this.line,
+    this.timestamp,
+  }) {
     this.message = message.replaceAll(_REMOVE_QUOTE_RX, '');
     this.details = details?.replaceAll(_REMOVE_QUOTE_RX, '');
   }
 
+  /// `true` if this message was posted for a record logged via the `log` crate.
+  bool get isLogRecord => level != null;
+
   @override
   String toString() {
     final detailsString = details == null ? '' : ', details: "$details"';
-    return 'RidMessage{ type: $type, message: "$message"$detailsString }';
+    final locationString = line == null ? '' : ':$line';
+    final recordString = isLogRecord
+        ? ', level: $level, target: "$target", file: "$file$locationString"'
+        : '';
+    return 'RidMessage{ type: $type, message: "$message"$detailsString$recordString }';
   }
 
   @override
@@ -62,25 +81,168 @@ class RidMessage {
           runtimeType == other.runtimeType &&
           type == other.type &&
           message == other.message &&
-          details == other.details;
+          details == other.details &&
+          level == other.level &&
+          target == other.target &&
+          modulePath == other.modulePath &&
+          file == other.file &&
+          line == other.line &&
+          timestamp == other.timestamp;
+
+  @override
+  int get hashCode =>
+      type.hashCode ^
+      message.hashCode ^
+      details.hashCode ^
+      level.hashCode ^
+      target.hashCode ^
+      modulePath.hashCode ^
+      file.hashCode ^
+      line.hashCode ^
+      timestamp.hashCode;
+}
+
+// -----------------
+// Tracing
+// -----------------
+// Spans and events of the Rust `tracing` crate are posted on the message channel once
+// `rid::RidTracingLayer` is part of the subscriber, which requires the `tracing` feature of rid.
+
+// NOTE: the order needs to match `TraceKind` in `rid-ffi/src/tracing_layer.rs`
+enum RidTraceKind { SpanEnter, SpanExit, Event }
+
+class RidTraceEvent {
+  final RidTraceKind kind;
+
+  /// Name of the span or of the event, the latter includes its location by default.
+  final String name;
+
+  /// Target of the span or event, by default the module path of the code that created it.
+  final String target;
+  final RidLogLevel level;
+
+  /// Id of the span that was entered or exited, `null` for events.
+  final int? spanId;
+
+  /// Id of the parent of the span or of the span the event occurred in.
+  final int? parentId;
+
+  /// Field values formatted via `Debug`, except for strings which are provided as is.
+  final Map<String, String> fields;
+
+  /// Source file and line of the code that created the span or event.
+  final String? file;
+  final int? line;
+
+  /// Time at which the span was entered or exited or the event occurred.
+  final DateTime timestamp;
+
+  /// How long the span was entered, only provided when it is exited.
+  final Duration? duration;
+
+  RidTraceEvent._(
+    this.kind,
+    this.name,
+    this.target,
+    this.level,
+    this.spanId,
+    this.parentId,
+    this.fields,
+    this.file,
+    this.line,
+    this.timestamp,
+    this.duration,
+  );
+
+  /// Message of an event, `null` for spans.
+  String? get message => fields['message'];
 
   @override
-  int get hashCode => type.hashCode ^ message.hashCode ^ details.hashCode;
+  String toString() {
+    final durationString =
+        duration == null ? '' : ', duration: ${duration!.inMicroseconds}us';
+    return 'RidTraceEvent{ kind: $kind, name: "$name", target: "$target", '
+        'level: $level, fields: $fields$durationString }';
+  }
+
+  static RidTraceEvent _decode(RidWireReader r) {
+    final kind = RidTraceKind.values[r.readU8()];
+    final name = r.readString();
+    final target = r.readString();
+    final level = RidLogLevel.values[r.readU8()];
+    final spanId = r.readBool() ? r.readU64() : null;
+    final parentId = r.readBool() ? r.readU64() : null;
+    final fields = <String, String>{};
+    final fieldsLen = r.readLen();
+    for (var i = 0; i < fieldsLen; i++) {
+      fields[r.readString()] = r.readString();
+    }
+    final file = r.readBool() ? r.readString() : null;
+    final line = r.readBool() ? r.readU32() : null;
+    final timestamp = DateTime.fromMicrosecondsSinceEpoch(r.readU64());
+    final duration =
+        r.readBool() ? Duration(microseconds: r.readU64()) : null;
+    return RidTraceEvent._(kind, name, target, level, spanId, parentId,
+        fields, file, line, timestamp, duration);
+  }
+}
+
+/// Converts [traces] into `dart:developer` [Timeline] events which show up in the timeline of
+/// Dart DevTools alongside the frame timings.
+///
+/// Each span becomes a [TimelineTask] that starts when the span is entered and finishes when it
+/// is exited, nested inside the task of its parent span. Events become instant events.
+/// The timeline records when Dart received them, therefore the time Rust measured is added to
+/// the arguments of each.
+///
+/// ```dart
+/// ridTracesToTimeline(rid.messageChannel.traces);
+/// ```
+StreamSubscription<RidTraceEvent> ridTracesToTimeline(
+    Stream<RidTraceEvent> traces) {
+  final tasks = <int, TimelineTask>{};
+  return traces.listen((trace) {
+    final arguments = <String, String>{
+      ...trace.fields,
+      'target': trace.target,
+      'timestamp': trace.timestamp.toIso8601String(),
+    };
+    if (trace.kind == RidTraceKind.SpanEnter) {
+      final task = TimelineTask(
+          parent: trace.parentId == null ? null : tasks[trace.parentId],
+          filterKey: trace.target);
+      task.start(trace.name, arguments: arguments);
+      tasks[trace.spanId!] = task;
+    } else if (trace.kind == RidTraceKind.SpanExit) {
+      final duration = trace.duration;
+      if (duration != null) {
+        arguments['durationUs'] = '${duration.inMicroseconds}';
+      }
+      tasks.remove(trace.spanId)?.finish(arguments: arguments);
+    } else {
+      Timeline.instantSync(trace.message ?? trace.name, arguments: arguments);
+    }
+  });
 }
 
 abstract class RidMessageChannel {
   Stream<RidMessage> get stream;
+
+  /// Spans and events posted by `rid::RidTracingLayer`, see [ridTracesToTimeline].
+  Stream<RidTraceEvent> get traces;
 }
 
 class RidMessageChannelInternal implements RidMessageChannel {
   final _zone = Zone.current;
   final StreamController<RidMessage> _sink;
+  final StreamController<RidTraceEvent> _traces;
   final DynamicLibrary _dl;
   late final RawReceivePort _receivePort;
   late final _zonedAdd;
 
   RidMessageChannelInternal._(this._dl, bool isDebugMode)
-      : _sink = StreamController.broadcast() {
+      : _sink = StreamController.broadcast(),
+        _traces = StreamController.broadcast() {
     _receivePort =
         RawReceivePort(_onReceivedMsg, 'rid::messaging_channel::port');
     initIsolate(this._dl, 'rid_init_msg_isolate',
@@ -88,41 +250,59 @@ class RidMessageChannelInternal implements RidMessageChannel {
     _zonedAdd = _zone.registerUnaryCallback(_add);
   }
 
-  void _onReceivedMsg(String reply) {
+  void _onReceivedMsg(Uint8List reply) {
     _zone.runUnary(_zonedAdd, reply);
   }
 
-  void _add(String reply) {
-    if (!_sink.isClosed) {
-      _sink.add(_decode(reply));
+  void _add(Uint8List reply) {
+    final frame = RidFrame.decode(reply);
+    if (frame.variant == RID_TRACE_VARIANT) {
+      if (!_traces.isClosed) {
+        _traces.add(RidTraceEvent._decode(frame.payload));
+      }
+    } else if (!_sink.isClosed) {
+      _sink.add(_decode(frame));
     }
   }
 
-  RidMessage _decode(String data) {
-    int sepIdx = data.indexOf(_MSG_SEPARATOR);
-    final type = data.substring(0, sepIdx);
-    final msgType = _ridMsgTypeFromString(type);
+  RidMessage _decode(RidFrame frame) {
+    final msgType = RidMessageType.values[frame.variant];
+    final r = frame.payload;
+    final message = r.readString();
+    final details = r.readBool() ? r.readString() : null;
+    if (!r.readBool()) return RidMessage._(msgType, message, details);
 
-    final msg = data.substring(sepIdx + 1);
-    sepIdx = msg.indexOf(_MSG_SEPARATOR);
-    if (sepIdx < 0) {
-      // No details
-      return RidMessage._(msgType, msg, null);
-    } else {
-      final message = msg.substring(0, sepIdx);
-      final details = msg.substring(sepIdx + 1);
-      return RidMessage._(msgType, message, details);
-    }
+    // Info about the record logged via the `log` crate, see `rid-ffi/src/logger.rs`
+    final level = RidLogLevel.values[r.readU8()];
+    final target = r.readString();
+    final modulePath = r.readBool() ? r.readString() : null;
+    final file = r.readBool() ? r.readString() : null;
+    final line = r.readBool() ? r.readU32() : null;
+    final timestamp = DateTime.fromMillisecondsSinceEpoch(r.readU64());
+    return RidMessage._(
+      msgType,
+      message,
+      details,
+      level: level,
+      target: target,
+      modulePath: modulePath,
+      file: file,
+      line: line,
+      timestamp: timestamp,
+    );
   }
 
   Stream<RidMessage> get stream => _sink.stream;
 
+  Stream<RidTraceEvent> get traces => _traces.stream;
+
   int get nativePort {
     return _receivePort.sendPort.nativePort;
   }
 
   Future<void> dispose() {
     _receivePort.close();
+    _traces.close();
     return _sink.close();
   }
 
@@ -1,16 +1,142 @@
 import 'dart:async';
 import 'dart:ffi';
 import 'dart:isolate';
+import 'dart:typed_data';
 import '_isolate_binding.dart' show initIsolate;
-
-const String RESPONSE_SEPARATOR = '^';
+import '_rid_frame.dart'
+    show RidFrame, RID_STORE_CHANGES_VARIANT, RID_PANIC_VARIANT;
 
 abstract class IReply {
   int? get reqId;
   String? get data;
+
+  /// `true` if this reply signals that the request it responds to failed, i.e. it is a variant of
+  /// the `#[rid::reply]` enum marked with `#[rid(error)]`.
+  bool get isError;
+
+  /// `true` if this reply reports progress of the request it responds to, i.e. it is a variant of
+  /// the `#[rid::reply]` enum marked with `#[rid(progress)]`.
+  bool get isProgress;
+}
+
+/// Completes the Future returned by a message method when the request was cancelled via
+/// [RidRequest.cancel].
+class CancelledException implements Exception {
+  /// Id of the request that was cancelled.
+  final int reqId;
+
+  /// The message call that was cancelled, i.e. `msgSearch(foo)`.
+  final String? message;
+
+  CancelledException(this.reqId, this.message);
+
+  @override
+  String toString() {
+    final messageString = message == null ? '' : ' $message';
+    return 'CancelledException: request$messageString with reqId: $reqId was cancelled';
+  }
+}
+
+/// Thrown when Rust panicked while processing a call from Dart.
+///
+/// Exported functions and message methods throw it right away if Rust panicked during the call.
+/// The Future returned by a message method completes with it if Rust panicked while updating the
+/// store in response to that message.
+class RidPanicException implements Exception {
+  /// The message Rust panicked with.
+  final String message;
+
+  /// The call into Rust that panicked, i.e. `msgAddTodo(Buy milk)`.
+  final String? call;
+
+  /// Location and backtrace of the panic if known.
+  final String? details;
+
+  RidPanicException(this.message, [this.call, this.details]);
+
+  @override
+  String toString() {
+    final callString = call == null ? '' : ' during $call';
+    final detailsString = details == null ? '' : '\n$details';
+    return 'RidPanicException: Rust panicked$callString: $message$detailsString';
+  }
+}
+
+/// Returned by message methods in order to track the request that was sent to Rust.
+///
+/// It can be awaited directly like the [reply] Future which resolves with the final reply.
+/// Any progress replies posted before that are added to the [progress] stream which closes once
+/// the final reply arrives.
+class RidRequest<TReply extends IReply> implements Future<TReply> {
+  /// Id of the request.
+  final int reqId;
+
+  /// Progress replies to this request.
+  final Stream<TReply> progress;
+
+  /// Resolves with the final reply to this request.
+  final Future<TReply> reply;
+
+  final void Function() _cancel;
+
+  RidRequest(this.reqId, this.progress, this.reply, this._cancel);
+
+  /// Returns a request with the same [progress] stream which resolves with the provided [reply].
+  RidRequest<TReply> withReply(Future<TReply> reply) =>
+      RidRequest<TReply>(reqId, progress, reply, _cancel);
+
+  /// Cancels this request unless its final reply arrived already.
+  ///
+  /// Rust code can check if the request was cancelled via a `rid::CancelToken` for its [reqId].
+  /// The [reply] completes with a [CancelledException] right away and the [progress] stream closes.
+  void cancel() => _cancel();
+
+  @override
+  Stream<TReply> asStream() => reply.asStream();
+
+  @override
+  Future<TReply> catchError(Function onError,
+          {bool Function(Object error)? test}) =>
+      reply.catchError(onError, test: test);
+
+  @override
+  Future<R> then<R>(FutureOr<R> Function(TReply value) onValue,
+          {Function? onError}) =>
+      reply.then(onValue, onError: onError);
+
+  @override
+  Future<TReply> timeout(Duration timeLimit,
+          {FutureOr<TReply> Function()? onTimeout}) =>
+      reply.timeout(timeLimit, onTimeout: onTimeout);
+
+  @override
+  Future<TReply> whenComplete(FutureOr<void> Function() action) =>
+      reply.whenComplete(action);
+}
+
+/// Completes the Future returned by a message method when Rust responds with an error reply.
+class RidReplyException<TReply extends IReply> implements Exception {
+  /// Id of the request that failed.
+  final int reqId;
+
+  /// The message call that failed, i.e. `msgAddTodo(Buy milk)`.
+  final String? message;
+
+  /// The error reply which holds the payload of the error.
+  final TReply reply;
+
+  RidReplyException(this.reqId, this.message, this.reply);
+
+  @override
+  String toString() {
+    final messageString = message == null ? '' : ' $message';
+    return 'RidReplyException: request$messageString with reqId: $reqId failed\n$reply';
+  }
 }
 
-typedef Decode<TReply> = TReply Function(int packedBase, String? data);
+// Replies are posted from Rust as a frame holding the variant index and request id, followed by
+// the data of the reply encoded into the rid wire format.
+typedef Decode<TReply> = TReply Function(RidFrame frame);
 
 abstract class RidReplyChannel<TReply extends IReply> {
   Stream<TReply> get stream;
@@ -21,6 +147,8 @@ class RidReplyChannelInternal<TReply extends IReply>
     implements RidReplyChannel<TReply> {
   final _zone = Zone.current;
   final StreamController<TReply> _sink;
+  final StreamController<int> _storeChanges;
+  final StreamController<RidFrame> _panics;
   final Decode<TReply> _decode;
   final DynamicLibrary _dl;
   late final RawReceivePort _receivePort;
@@ -28,45 +156,99 @@ class RidReplyChannelInternal<TReply extends IReply>
   int _lastReqId = 0;
 
   RidReplyChannelInternal._(this._dl, this._decode, bool isDebugMode)
-      : _sink = StreamController.broadcast() {
+      : _sink = StreamController.broadcast(),
+        _storeChanges = StreamController.broadcast(),
+        _panics = StreamController.broadcast() {
     _receivePort = RawReceivePort(_onReceivedReply, 'rid::reply_channel::port');
     initIsolate(this._dl, 'rid_init_reply_isolate',
         _receivePort.sendPort.nativePort, isDebugMode);
     _zonedAdd = _zone.registerUnaryCallback(_add);
   }
 
-  void _onReceivedReply(String reply) {
+  void _onReceivedReply(Uint8List reply) {
     _zone.runUnary(_zonedAdd, reply);
   }
 
-  void _add(String reply) {
-    if (!_sink.isClosed) {
-      final sepIdx = reply.indexOf(RESPONSE_SEPARATOR);
-      final base = int.parse(reply.substring(0, sepIdx));
-      final String? data =
-          reply.length > sepIdx ? reply.substring(sepIdx + 1) : null;
-      _sink.add(_decode(base, data));
+  void _add(Uint8List reply) {
+    if (_sink.isClosed) return;
+    final frame = RidFrame.decode(reply);
+    if (frame.variant == RID_STORE_CHANGES_VARIANT) {
+      _storeChanges.add(frame.payload.readU64());
+    } else if (frame.variant == RID_PANIC_VARIANT) {
+      _panics.add(frame);
+    } else {
+      _sink.add(_decode(frame));
     }
   }
 
   Stream<TReply> get stream => _sink.stream;
-  Future<TReply> reply(int reqId) {
+
+  /// Emits the bitmask of store fields that changed each time the store was updated.
+  Stream<int> get storeChanges => _storeChanges.stream;
+
+  /// Tracks the request with [reqId] by collecting its progress replies and resolving with its
+  /// final reply, see [RidRequest].
+  /// The final reply completes with a [RidReplyException] if it is an error reply and the
+  /// [message] call that was made is included with that exception.
+  /// It completes with a [RidPanicException] if Rust panicked while processing the request.
+  RidRequest<TReply> request(int reqId, [String? message]) {
     assert(reqId != 0, "Invalid requestID ");
-    return stream
-        .firstWhere((res) => res.reqId == reqId)
-        .onError((error, stackTrace) {
-      print(
-          "The responseChannel was disposed while a message was waiting for a reply.\n"
+    // Progress replies are buffered until the stream is listened to
+    final progress = StreamController<TReply>();
+    final reply = Completer<TReply>();
+    late final StreamSubscription<TReply> subscription;
+    late final StreamSubscription<RidFrame> panicSubscription;
+
+    void completeWith(void Function() complete) {
+      if (reply.isCompleted) return;
+      subscription.cancel();
+      panicSubscription.cancel();
+      progress.close();
+      complete();
+    }
+
+    panicSubscription =
+        _panics.stream.where((frame) => frame.reqId == reqId).listen((frame) {
+      final panicMessage = frame.payload.readString();
+      final details = frame.payload.readString();
+      completeWith(() => reply
+          .completeError(RidPanicException(panicMessage, message, details)));
+    });
+
+    subscription = stream.where((res) => res.reqId == reqId).listen((res) {
+      if (res.isProgress) {
+        progress.add(res);
+      } else if (res.isError) {
+        completeWith(() => reply
+            .completeError(RidReplyException<TReply>(reqId, message, res)));
+      } else {
+        completeWith(() => reply.complete(res));
+      }
+    }, onDone: () {
+      completeWith(() => reply.completeError(StateError(
+          "The replyChannel was disposed while a message was waiting for a reply.\n"
           "Did you forget to `await` the reply to the message with reqId: '$reqId'?\n"
-          "Ignore the message further down about type 'Null'.\n"
-          "The real problem is that no reply for the message was posted yet, but the reply \n"
-          "stream is being disposed most likely via `store.dispose()` causing the following:.\n");
-      print(error);
-      print(stackTrace);
-      return null as TReply;
+          "No reply for the message was posted yet, but the reply stream is being disposed\n"
+          "most likely via `store.dispose()`.")));
     });
+
+    void cancel() {
+      completeWith(() {
+        _cancelRequest(reqId);
+        reply.completeError(CancelledException(reqId, message));
+      });
+    }
+
+    return RidRequest<TReply>(reqId, progress.stream, reply.future, cancel);
   }
 
+  /// Resolves with the final reply to the request with [reqId], see [request].
+  Future<TReply> reply(int reqId, [String? message]) =>
+      request(reqId, message).reply;
+
+  late final void Function(int) _cancelRequest = _dl.lookupFunction<
+      Void Function(Uint64), void Function(int)>('rid_cancel_request');
+
   int get nativePort {
     return _receivePort.sendPort.nativePort;
   }
@@ -78,6 +260,8 @@ class RidReplyChannelInternal<TReply extends IReply>
 
   Future<void> dispose() {
     _receivePort.close();
+    _storeChanges.close();
+    _panics.close();
     return _sink.close();
   }
 
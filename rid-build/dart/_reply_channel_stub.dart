@@ -1,4 +1,6 @@
 class RidReplyChannelStub {
+  /// Store changes are posted via the reply channel and thus never emitted by the stub.
+  Stream<int> get storeChanges => Stream.empty();
   Future<void> dispose() => Future.value();
 }
 
@@ -0,0 +1,69 @@
+//
+// Equality of collections held by rid Dart classes.
+//
+// Dart compares lists, sets and maps by identity. Generated classes compare and hash fields
+// holding them via the below instead, so that two instances holding equal items are equal,
+// i.e. when a `RidBuilder` checks if the selected state changed.
+//
+
+/// Compares [a] and [b] via `==`, comparing lists, sets and maps item by item, including nested
+/// ones.
+bool ridDeepEquals(Object? a, Object? b) {
+  if (identical(a, b)) return true;
+  if (a is List && b is List) {
+    if (a.length != b.length) return false;
+    for (var i = 0; i < a.length; i++) {
+      if (!ridDeepEquals(a[i], b[i])) return false;
+    }
+    return true;
+  }
+  if (a is Set && b is Set) {
+    if (a.length != b.length) return false;
+    return a.every((x) => b.contains(x) || b.any((y) => ridDeepEquals(x, y)));
+  }
+  if (a is Map && b is Map) {
+    if (a.length != b.length) return false;
+    for (final key in a.keys) {
+      if (!b.containsKey(key) || !ridDeepEquals(a[key], b[key])) return false;
+    }
+    return true;
+  }
+  return a == b;
+}
+
+/// Hashes [value] consistent with [ridDeepEquals], combining the hash codes of the items of
+/// lists, sets and maps.
+int ridDeepHash(Object? value) {
+  if (value is List) {
+    var hash = 0;
+    for (final item in value) {
+      hash = _ridCombineHash(hash, ridDeepHash(item));
+    }
+    return hash;
+  }
+  // Sets and maps are unordered, thus the hashes of their items are combined independent of
+  // their order
+  if (value is Set) {
+    var hash = 0;
+    for (final item in value) {
+      hash = (hash + ridDeepHash(item)) & 0x1fffffff;
+    }
+    return hash;
+  }
+  if (value is Map) {
+    var hash = 0;
+    for (final entry in value.entries) {
+      final entryHash = _ridCombineHash(
+          ridDeepHash(entry.key), ridDeepHash(entry.value));
+      hash = (hash + entryHash) & 0x1fffffff;
+    }
+    return hash;
+  }
+  return value.hashCode;
+}
+
+int _ridCombineHash(int hash, int value) {
+  hash = 0x1fffffff & (hash + value);
+  hash = 0x1fffffff & (hash + ((0x0007ffff & hash) << 10));
+  return hash ^ (hash >> 6);
+}
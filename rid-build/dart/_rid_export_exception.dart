@@ -0,0 +1,13 @@
+//
+// Exception thrown when a Rust function exported via #[rid::export] returns an error.
+// Custom error types, i.e. #[rid::model] structs and enums, are thrown as a subclass carrying the error.
+//
+class RidExportException implements Exception {
+  final String message;
+  const RidExportException(this.message);
+
+  @override
+  String toString() {
+    return 'RidExportException: $message';
+  }
+}
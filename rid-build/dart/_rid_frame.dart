@@ -0,0 +1,93 @@
+import 'dart:convert';
+import 'dart:typed_data';
+
+// -----------------
+// Frame
+// -----------------
+// Replies and messages are posted from Rust as a single byte array, called a frame, which
+// consists of a header followed by the payload encoded into the rid wire format.
+//
+// | version: u8 | variant: u32 | req_id: u64 | payload ... |
+//
+// See `rid-ffi/src/frame.rs` for the Rust side.
+const int RID_FRAME_VERSION = 1;
+
+// -----------------
+// Reserved Variants
+// -----------------
+// Frames that aren't replies nor messages use reserved variants counting down from 0xffffffff,
+// see `rid-ffi/src/frame.rs` which needs to be kept in sync.
+
+/// Variant of frames posted after a store update whose payload is the bitmask of changed store
+/// fields, see `rid-ffi/src/store_changes.rs`.
+const int RID_STORE_CHANGES_VARIANT = 0xffffffff;
+
+/// Variant of frames posted when Rust panicked while processing a request whose payload is the
+/// panic message followed by its details, see `rid-ffi/src/panic.rs`.
+const int RID_PANIC_VARIANT = 0xfffffffe;
+
+/// Variant of frames on the message channel holding a span or event of the Rust `tracing` crate,
+/// see `rid-ffi/src/tracing_layer.rs`.
+const int RID_TRACE_VARIANT = 0xfffffffd;
+
+class RidFrame {
+  /// Index of the reply variant or type of the message.
+  final int variant;
+
+  /// Id of the request a reply responds to, `null` if it doesn't carry one.
+  final int? reqId;
+
+  /// Reader positioned at the start of the payload.
+  final RidWireReader payload;
+
+  RidFrame._(this.variant, this.reqId, this.payload);
+
+  static RidFrame decode(Uint8List bytes) {
+    final r = RidWireReader(bytes);
+    final version = r.readU8();
+    if (version != RID_FRAME_VERSION) {
+      throw StateError(
+          'Unsupported rid frame version $version, expected $RID_FRAME_VERSION. '
+          'Make sure the generated Dart code is in sync with the Rust library.');
+    }
+    final variant = r.readU32();
+    final reqId = r.readU64();
+    return RidFrame._(variant, reqId == 0 ? null : reqId, r);
+  }
+}
+
+//
+// Decodes values which Rust encoded into the rid wire format via `rid::_RidEncode`.
+//
+class RidWireReader {
+  final ByteData _data;
+  int _offset = 0;
+
+  RidWireReader(Uint8List bytes)
+      : _data = ByteData.view(bytes.buffer, bytes.offsetInBytes, bytes.length);
+
+  int _advance(int len) {
+    final offset = _offset;
+    _offset += len;
+    return offset;
+  }
+
+  int readU8() => _data.getUint8(_advance(1));
+  int readI8() => _data.getInt8(_advance(1));
+  int readU16() => _data.getUint16(_advance(2), Endian.little);
+  int readI16() => _data.getInt16(_advance(2), Endian.little);
+  int readU32() => _data.getUint32(_advance(4), Endian.little);
+  int readI32() => _data.getInt32(_advance(4), Endian.little);
+  int readU64() => _data.getUint64(_advance(8), Endian.little);
+  int readI64() => _data.getInt64(_advance(8), Endian.little);
+  double readF32() => _data.getFloat32(_advance(4), Endian.little);
+  double readF64() => _data.getFloat64(_advance(8), Endian.little);
+  bool readBool() => readU8() != 0;
+  int readLen() => readU32();
+
+  String readString() {
+    final len = readLen();
+    final offset = _advance(len);
+    return utf8.decode(_data.buffer.asUint8List(_data.offsetInBytes + offset, len));
+  }
+}
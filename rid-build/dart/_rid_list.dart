@@ -0,0 +1,102 @@
+//
+// Lazy views of collections owned by Rust.
+//
+
+/// A read only [List] whose items are read from Rust on demand instead of converting the entire
+/// collection into Dart at once, i.e. to back a `ListView.builder` over a large `Vec` store field.
+///
+/// Use the generated store accessors to obtain one, i.e. `store.todosList()`.
+///
+/// Without a [window] each access reads the item from the store while it is locked.
+/// Thus the list always reflects the current state of the store. The length is cached while the
+/// store stays locked, so that wrapping an iteration in `ridStoreLock`/`ridStoreUnlock` reads it
+/// only once.
+///
+/// With a [window] items are read in chunks of that size inside a single lock scope and cached.
+/// The length is cached as well and at most [maxCachedWindows] chunks are kept, evicting the
+/// least recently used one.
+/// Call [invalidate] once the underlying collection changed, i.e. when `Store.changes` includes
+/// the field, in order to read fresh items.
+class RidList<T> extends dart_collection.ListBase<T>
+    with dart_collection.UnmodifiableListMixin<T> {
+  final int Function() _readLength;
+  final T Function(int idx, int length) _readItem;
+  final int window;
+  final int maxCachedWindows;
+
+  int? _length;
+  int? _lockedLength;
+  int? _lockedLengthScope;
+  final _windows = <int, List<T>>{};
+
+  RidList(
+    this._readLength,
+    this._readItem, {
+    this.window = 0,
+    this.maxCachedWindows = 4,
+  })  : assert(window >= 0),
+        assert(maxCachedWindows > 0);
+
+  bool get _isWindowed => window > 0;
+
+  @override
+  int get length {
+    if (!_isWindowed) return _lengthInLockScope();
+    return _length ??= _readLength();
+  }
+
+  /// Reads the length once per lock scope since it cannot change while the store is locked.
+  int _lengthInLockScope() {
+    final scope = ridStoreLockScope();
+    if (scope == null) return _readLength();
+    if (_lockedLengthScope != scope) {
+      _lockedLength = _readLength();
+      _lockedLengthScope = scope;
+    }
+    return _lockedLength!;
+  }
+
+  @override
+  T operator [](int idx) {
+    if (!_isWindowed) {
+      try {
+        ridStoreLock(request: 'RidList[$idx]');
+        final len = _lengthInLockScope();
+        RangeError.checkValidIndex(idx, this, 'idx', len);
+        return _readItem(idx, len);
+      } finally {
+        ridStoreUnlock();
+      }
+    }
+
+    RangeError.checkValidIndex(idx, this, 'idx', length);
+
+    final start = idx - idx % window;
+    final items = _windows.remove(start) ?? _readWindow(start);
+    // Re-inserting moves the window to the end which marks it as the most recently used
+    _windows[start] = items;
+    if (_windows.length > maxCachedWindows) {
+      _windows.remove(_windows.keys.first);
+    }
+    return items[idx - start];
+  }
+
+  List<T> _readWindow(int start) {
+    final end = start + window < length ? start + window : length;
+    try {
+      ridStoreLock(request: 'RidList[$start..$end]');
+      // The cached length may be stale, the item reads check against the current one
+      final len = _lengthInLockScope();
+      return List<T>.generate(end - start, (i) => _readItem(start + i, len),
+          growable: false);
+    } finally {
+      ridStoreUnlock();
+    }
+  }
+
+  /// Drops the cached length and items so that they are read from the store again.
+  void invalidate() {
+    _length = null;
+    _windows.clear();
+  }
+}
@@ -0,0 +1,102 @@
+//
+// Flutter widgets that rebuild from the state of the rid store.
+//
+
+typedef RidSelect<T> = T Function(Store store);
+typedef RidValueWidgetBuilder<T> = Widget Function(BuildContext context, T value);
+
+/// Compares selected values via `==` which is generated for rid Dart state classes.
+/// Lists, sets and maps, including the ones held by rid Dart classes, are compared element by
+/// element since Dart compares them by identity.
+bool ridSelectedEquals(Object? previous, Object? next) =>
+    ridDeepEquals(previous, next);
+
+// -----------------
+// RidBuilder
+// -----------------
+
+/// Builds a widget from a value selected from the store and rebuilds it only when that value
+/// changes.
+///
+/// The [select]or runs while the store is locked, first when the widget is inserted and then
+/// each time an update changed store fields, see `Store.changes`.
+/// Provide [fields] in order to only run the [select]or when one of them changed.
+///
+/// Fields are reported as changed when the update modified them via the setters of the
+/// generated `StoreFieldSetters` trait, i.e. `set_filter` or `todos_mut`. An update that modifies
+/// the store without using any setter reports all fields as changed and thus runs the [select]or
+/// of every [RidBuilder].
+///
+/// The [builder] runs again only if the newly selected value is not equal to the previous one as
+/// determined by [equals] which defaults to [ridSelectedEquals].
+///
+/// ```dart
+/// RidBuilder<List<Todo>>(
+///   select: (store) => store.filteredTodos(),
+///   fields: {StoreField.todos, StoreField.filter},
+///   builder: (context, todos) => TodoList(todos),
+/// )
+/// ```
+class RidBuilder<T> extends StatefulWidget {
+  final RidSelect<T> select;
+  final RidValueWidgetBuilder<T> builder;
+  final Set<StoreField>? fields;
+  final bool Function(T previous, T next) equals;
+
+  const RidBuilder({
+    Key? key,
+    required this.select,
+    required this.builder,
+    this.fields,
+    this.equals = ridSelectedEquals,
+  }) : super(key: key);
+
+  @override
+  State<RidBuilder<T>> createState() => _RidBuilderState<T>();
+}
+
+class _RidBuilderState<T> extends State<RidBuilder<T>> {
+  late T _value;
+  late final dart_async.StreamSubscription<Set<StoreField>> _subscription;
+
+  @override
+  void initState() {
+    super.initState();
+    _value = _select();
+    _subscription = Store.instance.changes.listen(_onStoreChanged);
+  }
+
+  @override
+  void didUpdateWidget(RidBuilder<T> oldWidget) {
+    super.didUpdateWidget(oldWidget);
+    // The selector most likely is a new closure and may select something else
+    _value = _select();
+  }
+
+  @override
+  void dispose() {
+    _subscription.cancel();
+    super.dispose();
+  }
+
+  T _select() {
+    try {
+      ridStoreLock(request: 'RidBuilder<$T>.select');
+      return widget.select(Store.instance);
+    } finally {
+      ridStoreUnlock();
+    }
+  }
+
+  void _onStoreChanged(Set<StoreField> changed) {
+    final fields = widget.fields;
+    if (fields != null && !changed.any(fields.contains)) return;
+
+    final next = _select();
+    if (widget.equals(_value, next)) return;
+    setState(() => _value = next);
+  }
+
+  @override
+  Widget build(BuildContext context) => widget.builder(context, _value);
+}
@@ -0,0 +1,107 @@
+//
+// Encodes values which cannot be passed across FFI directly, i.e. #[rid::model] structs, enums
+// and collections that are part of a message, into the format Rust decodes via `rid::_RidDecode`.
+// Data that is part of a reply is encoded by Rust via `rid::_RidEncode` using the same format
+// and decoded via [RidWireReader] found inside `_rid_frame.dart`.
+//
+// - all numbers are little endian, `usize` is transferred as `u64`
+// - lengths of strings and collections as well as data enum variant indexes are `u32`
+// - an `Option` is a `bool` indicating if a value follows
+//
+class RidWireWriter {
+  final _builder = dart_typed_data.BytesBuilder();
+  final _scratch = dart_typed_data.ByteData(8);
+
+  void _addScratch(int len) {
+    _builder.add(_scratch.buffer.asUint8List(0, len));
+  }
+
+  void writeU8(int value) {
+    _scratch.setUint8(0, value);
+    _addScratch(1);
+  }
+
+  void writeI8(int value) {
+    _scratch.setInt8(0, value);
+    _addScratch(1);
+  }
+
+  void writeU16(int value) {
+    _scratch.setUint16(0, value, dart_typed_data.Endian.little);
+    _addScratch(2);
+  }
+
+  void writeI16(int value) {
+    _scratch.setInt16(0, value, dart_typed_data.Endian.little);
+    _addScratch(2);
+  }
+
+  void writeU32(int value) {
+    _scratch.setUint32(0, value, dart_typed_data.Endian.little);
+    _addScratch(4);
+  }
+
+  void writeI32(int value) {
+    _scratch.setInt32(0, value, dart_typed_data.Endian.little);
+    _addScratch(4);
+  }
+
+  void writeU64(int value) {
+    _scratch.setUint64(0, value, dart_typed_data.Endian.little);
+    _addScratch(8);
+  }
+
+  void writeI64(int value) {
+    _scratch.setInt64(0, value, dart_typed_data.Endian.little);
+    _addScratch(8);
+  }
+
+  void writeF32(double value) {
+    _scratch.setFloat32(0, value, dart_typed_data.Endian.little);
+    _addScratch(4);
+  }
+
+  void writeF64(double value) {
+    _scratch.setFloat64(0, value, dart_typed_data.Endian.little);
+    _addScratch(8);
+  }
+
+  void writeBool(bool value) {
+    writeU8(value ? 1 : 0);
+  }
+
+  void writeLen(int len) {
+    writeU32(len);
+  }
+
+  void writeString(String value) {
+    final bytes = dart_convert.utf8.encode(value);
+    writeLen(bytes.length);
+    _builder.add(bytes);
+  }
+
+  /// Copies the encoded bytes prefixed with their length into native memory.
+  /// The returned pointer needs to be released via [RidWireWriter.free] once Rust is done with it.
+  dart_ffi.Pointer<dart_ffi.Uint8> toNative() {
+    final bytes = _builder.toBytes();
+    final ptr = package_ffi.malloc.allocate<dart_ffi.Uint8>(bytes.length + 4);
+    final native = ptr.asTypedList(bytes.length + 4);
+    dart_typed_data.ByteData.view(native.buffer, native.offsetInBytes)
+        .setUint32(0, bytes.length, dart_typed_data.Endian.little);
+    native.setAll(4, bytes);
+    return ptr;
+  }
+
+  /// Encodes a value via the provided function and returns the result in native memory,
+  /// see [toNative].
+  static dart_ffi.Pointer<dart_ffi.Uint8> encode(
+      void Function(RidWireWriter w) encodeValue) {
+    final w = RidWireWriter();
+    encodeValue(w);
+    return w.toNative();
+  }
+
+  static void free(dart_ffi.Pointer<dart_ffi.Uint8> ptr) {
+    package_ffi.malloc.free(ptr);
+  }
+}
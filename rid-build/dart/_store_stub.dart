@@ -5,3 +5,7 @@ void ridStoreLock({String? request}) {}
 /// Stubbing the store unlock method until a store is implemented via
 /// `#[rid::message(Store, Reply)]`
 void ridStoreUnlock() {}
+
+/// Stubbing the store lock scope method until a store is implemented via
+/// `#[rid::message(Store, Reply)]`
+int? ridStoreLockScope() => null;
@@ -1,6 +1,7 @@
 use rid_common::{
-    CSTRING_FREE, DART_ASYNC, DART_COLLECTION, DART_FFI, FFI_GEN_BIND, RID_FFI,
-    STRING_TO_NATIVE_INT8,
+    CSTRING_FREE, DART_ASYNC, DART_COLLECTION, DART_FFI, FFI_GEN_BIND,
+    PANIC_CLEAR, PANIC_FLAG, PANIC_MESSAGE, RID_FFI, STRING_TO_NATIVE_INT8,
+    THROW_IF_PANICKED,
 };
 
 use crate::{
@@ -8,6 +9,8 @@ use crate::{
 };
 const PACKAGE_FFI: &str = "package_ffi";
 static RID_WIDGETS: &str = include_str!("../dart/_rid_widgets.dart");
+static RID_STORE_WIDGETS: &str =
+    include_str!("../dart/_rid_store_widgets.dart");
 static RID_UTILS_FLUTTER: &str =
     include_str!("../dart/_rid_utils_flutter.dart");
 static RID_UTILS_DART: &str = include_str!("../dart/_rid_utils_dart.dart");
@@ -15,6 +18,11 @@ static STORE_STUB_DART: &str = include_str!("../dart/_store_stub.dart");
 static REPLY_CHANNEL_STUB_DART: &str =
     include_str!("../dart/_reply_channel_stub.dart");
 static RID_CLASS_INSTANTIATION: &str = include_str!("../dart/_rid_rid.dart");
+static RID_EXPORT_EXCEPTION: &str =
+    include_str!("../dart/_rid_export_exception.dart");
+static RID_WIRE: &str = include_str!("../dart/_rid_wire.dart");
+static RID_LIST: &str = include_str!("../dart/_rid_list.dart");
+static RID_EQUALITY: &str = include_str!("../dart/_rid_equality.dart");
 
 fn dart_string_from_pointer() -> String {
     format!(
@@ -52,17 +60,49 @@ extension Rid_ExtOnString on String {{
     )
 }
 
+fn dart_throw_if_panicked() -> String {
+    format!(
+        r###"
+/// Set by Rust while a panic it caught wasn't thrown in Dart yet.
+/// Reading it doesn't call into Rust which keeps the check after each call cheap.
+final _ridPanicFlag = {rid_ffi}.{panic_flag}();
+
+/// Throws a [RidPanicException] if Rust panicked during the last [call] into it.
+void {throw_if_panicked}(String call) {{
+  if (_ridPanicFlag.value == 0) return;
+  final panicMsg = {rid_ffi}.{panic_message}();
+  if (panicMsg.address == 0) return;
+  final exception = RidPanicException(panicMsg.toDartString(), call);
+  {rid_ffi}.{panic_clear}();
+  throw exception;
+}}
+"###,
+        throw_if_panicked = THROW_IF_PANICKED,
+        rid_ffi = RID_FFI,
+        panic_flag = PANIC_FLAG,
+        panic_message = PANIC_MESSAGE,
+        panic_clear = PANIC_CLEAR,
+    )
+}
+
 fn dart_ffi_reexports() -> String {
     format!("export 'dart:ffi' show Pointer;\n")
 }
 
 fn message_channel_reexports(message_channel: &str) -> String {
     format!(
-        "export '{message_channel}' show RidMessageChannel, RidMessage, RidMessageType;\n",
+        "export '{message_channel}' show RidMessageChannel, RidMessage, RidMessageType, RidLogLevel, RidTraceEvent, RidTraceKind, ridTracesToTimeline;\n",
         message_channel = message_channel
     )
 }
 
+fn reply_channel_reexports(reply_channel: &str) -> String {
+    format!(
+        "export '{reply_channel}' show RidReplyException, RidRequest, CancelledException, RidPanicException;\n",
+        reply_channel = reply_channel
+    )
+}
+
 /// Generates Dart code from the provided cbindgen artifact, taking config into account.
 pub(crate) struct DartGenerator<'a> {
     /// Relative path to the bindings generated by Dart ffigen from where we will put the code
@@ -75,6 +115,9 @@ pub(crate) struct DartGenerator<'a> {
     /// Relative path to the reply_channel Dart implementation
     pub(crate) reply_channel: &'a str,
 
+    /// Relative path to the Dart implementation decoding frames posted by Rust
+    pub(crate) rid_frame: &'a str,
+
     /// Path to the 'target' directory of Rust binaries where we load the dynamic library from.
     pub(crate) path_to_target: &'a str,
 
@@ -106,6 +149,11 @@ impl<'a> DartGenerator<'a> {
             Project::Dart => "",
             Project::Flutter(_) => RID_WIDGETS,
         };
+        // Widgets that rebuild from store state need a store to select from
+        let flutter_store_widgets = match self.project {
+            Project::Flutter(_) if !self.needs_store_stub => RID_STORE_WIDGETS,
+            _ => "",
+        };
         let rid_utils = match self.project {
             Project::Dart => RID_UTILS_DART,
             Project::Flutter(_) => RID_UTILS_FLUTTER,
@@ -131,12 +179,15 @@ impl<'a> DartGenerator<'a> {
 {struct_exports}
 // Forwarding MessageChannel Types
 {message_channel_exports}
+// Forwarding ReplyChannel Types
+{reply_channel_exports}
 
 //
 // Open Dynamic Library
 //
 {open_dl}
 {flutter_widget_overrides}
+{flutter_store_widgets}
 //
 // Rid internal Utils
 // 
@@ -150,6 +201,14 @@ impl<'a> DartGenerator<'a> {
 
 {rid_class_instantiation}
 
+{rid_export_exception}
+{throw_if_panicked}
+{rid_wire}
+
+{rid_list}
+
+{rid_equality}
+
 {store_stub}
 {reply_channel_stub}
 "###,
@@ -157,15 +216,22 @@ impl<'a> DartGenerator<'a> {
             dart_ffi_exports = dart_ffi_reexports(),
             message_channel_exports =
                 message_channel_reexports(self.message_channel),
+            reply_channel_exports = reply_channel_reexports(self.reply_channel),
             struct_exports = self.dart_rust_type_reexports(),
             open_dl = self.dart_open_dl(),
             flutter_widget_overrides = flutter_widget_overrides,
+            flutter_store_widgets = flutter_store_widgets,
             rid_utils = rid_utils,
             extensions = extensions,
             string_from_pointer_extension = dart_string_from_pointer(),
             string_pointer_from_string_extension =
                 dart_string_pointer_from_string(),
             rid_class_instantiation = RID_CLASS_INSTANTIATION,
+            rid_export_exception = RID_EXPORT_EXCEPTION,
+            throw_if_panicked = dart_throw_if_panicked(),
+            rid_wire = RID_WIRE,
+            rid_list = RID_LIST,
+            rid_equality = RID_EQUALITY,
             store_stub = store_stub,
             reply_channel_stub = reply_channel_stub
         )
@@ -186,10 +252,13 @@ import 'package:flutter/foundation.dart' as Foundation;"###
 import 'dart:async' as {dart_async};
 import 'dart:io' as dart_io;
 import 'dart:collection' as {dart_collection};
+import 'dart:convert' as dart_convert;
+import 'dart:typed_data' as dart_typed_data;
 import 'package:ffi/ffi.dart' as {pack_ffi};
 import '{ffigen_binding}' as {ffigen_bind};
 import '{message_channel}';
 import '{reply_channel}';
+import '{rid_frame}';
 {project_specific_imports}
 "###,
             dart_ffi = DART_FFI,
@@ -198,6 +267,7 @@ import '{reply_channel}';
             ffigen_binding = self.ffigen_binding,
             message_channel = self.message_channel,
             reply_channel = self.reply_channel,
+            rid_frame = self.rid_frame,
             pack_ffi = PACKAGE_FFI,
             ffigen_bind = FFI_GEN_BIND,
             project_specific_imports = project_specific_imports,
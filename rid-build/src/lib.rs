@@ -26,6 +26,7 @@ use crate::{parsed_bindings::ParsedBindings, swift_injector::SwiftInjector};
 static ISOLATE_BINDING: &str = include_str!("../dart/_isolate_binding.dart");
 static MESSAGE_CHANNEL: &str = include_str!("../dart/_message_channel.dart");
 static REPLY_CHANNEL: &str = include_str!("../dart/_reply_channel.dart");
+static RID_FRAME: &str = include_str!("../dart/_rid_frame.dart");
 
 pub struct BuildConfig<'a> {
     pub project_root: &'a str,
@@ -54,6 +55,9 @@ pub struct BuildResult {
     /// Path to Dart that provides ResponseChannel through which user's Rust code posts messages to Dart.
     reply_channel_dart_path: String,
 
+    /// Path to Dart that decodes the frames in which Rust posts replies and messages to Dart.
+    rid_frame_dart_path: String,
+
     /// Path at which the Dart/Flutter app expects the generated Dart code to be and from which the
     /// generated code imports the darg ffigen generated bindings.
     /// This file still needs to be written.
@@ -136,6 +140,7 @@ fn generate(
         project.path_to_message_channel_dart(project_root);
     let reply_channel_dart_path =
         project.path_to_reply_channel_dart(project_root);
+    let rid_frame_dart_path = project.path_to_rid_frame_dart(project_root);
 
     // TODO: determine this as relative path from  'generated_dart_path' -> 'ffigen_generated_path'
     let ffigen_binding = &format!(
@@ -156,6 +161,10 @@ fn generate(
             .unwrap()
             .to_string_lossy()
     );
+    let rid_frame = &format!(
+        "{}",
+        rid_frame_dart_path.file_name().unwrap().to_string_lossy()
+    );
     let path_to_target =
         &format!("{}", project.path_to_target(target_crate_root).display())
             .escape_default()
@@ -175,6 +184,7 @@ fn generate(
         ffigen_binding,
         message_channel,
         reply_channel,
+        rid_frame,
         path_to_target,
         code_sections: &parsed_bindings,
         project: &project,
@@ -207,6 +217,7 @@ fn generate(
             "{}",
             reply_channel_dart_path.display()
         ),
+        rid_frame_dart_path: format!("{}", rid_frame_dart_path.display()),
         generated_bindings_h_path: format!("{}", bindings_h_path.display()),
         swift_plugin_files,
         parsed_bindings,
@@ -223,6 +234,7 @@ pub fn build(build_config: &BuildConfig) -> Result<BuildResult> {
         isolate_binding_dart_path,
         message_channel_dart_path,
         reply_channel_dart_path,
+        rid_frame_dart_path,
         parsed_bindings,
         ..
     } = &generate_result;
@@ -232,6 +244,7 @@ pub fn build(build_config: &BuildConfig) -> Result<BuildResult> {
     fs::write(isolate_binding_dart_path, ISOLATE_BINDING)?;
     fs::write(message_channel_dart_path, MESSAGE_CHANNEL)?;
     fs::write(reply_channel_dart_path, REPLY_CHANNEL)?;
+    fs::write(rid_frame_dart_path, RID_FRAME)?;
 
     let host_props = HostProps::new();
     let project_root: &Path = Path::new(build_config.project_root);
@@ -11,7 +11,9 @@ const TYPEDEF_STRUCT_LEN: usize = TYPEDEF_STRUCT.len();
 const TYPEDEF_ENUM: &str = "typedef enum ";
 const TYPEDEF_ENUM_LEN: usize = TYPEDEF_ENUM.len();
 
-const STORE_LOCK_FN: &str = "void rid_store_lock(void);";
+/// The store lock returns a pointer to the store which Dart reads while holding the lock, i.e.
+/// `const struct Store *rid_store_lock(void);`
+const STORE_LOCK_FN: &str = "*rid_store_lock(void);";
 const REPLY_CHANNEL_FN: &str = "void include_reply(void);";
 
 #[derive(Debug)]
@@ -129,7 +131,7 @@ impl ParsedBindings {
                                 &trimmed
                             ));
                         enums.push(enum_name.to_string());
-                    } else if trimmed.starts_with(STORE_LOCK_FN) {
+                    } else if trimmed.ends_with(STORE_LOCK_FN) {
                         has_store_lock = true;
                     } else if trimmed.starts_with(REPLY_CHANNEL_FN) {
                         has_reply_channel = true;
@@ -113,6 +113,15 @@ impl Project {
             .to_path_buf()
     }
 
+    pub(crate) fn path_to_rid_frame_dart(
+        &self,
+        project_root: &Path,
+    ) -> PathBuf {
+        self.path_to_generated_dir(project_root)
+            .join("_rid_frame.dart")
+            .to_path_buf()
+    }
+
     pub(crate) fn paths_to_generated_c_bindings(
         &self,
         project_root: &Path,
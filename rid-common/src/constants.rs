@@ -57,3 +57,28 @@ pub const RID_CREATE_STORE: &str = "_createStore";
 /// These exports can then be re-exported on the higher level API, extending the Dart 'Store'
 /// class as well.
 pub const STORE: &str = "Store";
+
+/// Method invoked to get the message of the error returned by the last exported function call
+/// or a null pointer if it succeeded.
+pub const EXPORT_ERROR_MESSAGE: &str = "rid_export_error_message";
+
+/// Method invoked to drop the error returned by the last exported function call once Dart
+/// resolved it.
+pub const EXPORT_ERROR_CLEAR: &str = "rid_export_error_clear";
+
+/// Method invoked to get the message of the panic caught during the last call into Rust or a
+/// null pointer if it didn't panic.
+pub const PANIC_MESSAGE: &str = "rid_panic_message";
+
+/// Method invoked once to get a pointer to the flag which is set while a panic caught during a
+/// call into Rust wasn't cleared.
+pub const PANIC_FLAG: &str = "rid_panic_flag";
+
+/// Method invoked to drop the panic caught during the last call into Rust once Dart threw it.
+pub const PANIC_CLEAR: &str = "rid_panic_clear";
+
+/// Dart function which throws a `RidPanicException` if the last call into Rust panicked.
+pub const THROW_IF_PANICKED: &str = "_ridThrowIfPanicked";
+
+/// Dart exception thrown when an exported function returns an `Err`.
+pub const RID_EXPORT_EXCEPTION: &str = "RidExportException";
@@ -0,0 +1,182 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+// -----------------
+// Cancellation
+// -----------------
+// Dart cancels a request via `RidRequest.cancel()` which calls `rid_cancel_request` with the id
+// of that request. Rust code processing the request obtains a `CancelToken` for that id and polls
+// it in order to stop working on requests that are no longer needed.
+//
+// A flag is registered by the first token obtained for a request and removed once the last token
+// for it is dropped. Cancelling a request that no token exists for, i.e. one that completed
+// already, does nothing and therefore doesn't register a flag that would never be removed.
+// The generated `rid_msg_*` functions of threaded messages obtain a token before enqueueing the
+// store update in order to not miss a request that is cancelled while it waits in the queue.
+
+type CancelFlags = HashMap<u64, Arc<AtomicBool>>;
+
+static CANCEL_FLAGS: Mutex<Option<CancelFlags>> = Mutex::new(None);
+
+fn with_cancel_flags<T>(f: impl FnOnce(&mut CancelFlags) -> T) -> T {
+    let mut flags = CANCEL_FLAGS
+        .lock()
+        .expect("[rid] cancel flags lock was poisoned");
+    f(flags.get_or_insert_with(HashMap::new))
+}
+
+/// Allows checking if Dart cancelled the request with the given id.
+///
+/// ```ignore
+/// fn update(&mut self, req_id: u64, msg: Msg) {
+///     match msg {
+///         Msg::Search(query) => {
+///             let token = rid::CancelToken::new(req_id);
+///             for chunk in self.index.chunks() {
+///                 if token.is_cancelled() {
+///                     return;
+///                 }
+///                 // ...
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Tokens can be cloned and moved to other threads in order to cancel spawned work.
+/// Cancelling a request that no token exists for has no effect, thus the token needs to be
+/// obtained before spawning work that outlives the `update` call.
+#[derive(Debug)]
+pub struct CancelToken {
+    req_id: u64,
+    flag: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new(req_id: u64) -> Self {
+        let flag = with_cancel_flags(|flags| {
+            flags
+                .entry(req_id)
+                .or_insert_with(|| Arc::new(AtomicBool::new(false)))
+                .clone()
+        });
+        Self { req_id, flag }
+    }
+
+    pub fn req_id(&self) -> u64 {
+        self.req_id
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+}
+
+impl Clone for CancelToken {
+    fn clone(&self) -> Self {
+        // Cloning while holding the lock ensures that `drop` sees an accurate count of tokens
+        with_cancel_flags(|_| Self {
+            req_id: self.req_id,
+            flag: self.flag.clone(),
+        })
+    }
+}
+
+impl Drop for CancelToken {
+    fn drop(&mut self) {
+        with_cancel_flags(|flags| {
+            // The registry holds one reference and this token the other
+            let is_last_token = Arc::strong_count(&self.flag) <= 2;
+            let is_registered = matches!(
+                flags.get(&self.req_id),
+                Some(flag) if Arc::ptr_eq(flag, &self.flag)
+            );
+            if is_last_token && is_registered {
+                flags.remove(&self.req_id);
+            }
+        })
+    }
+}
+
+/// Used by rid internally to cancel the request with the given id when Dart requests it.
+pub fn _cancel_request(req_id: u64) {
+    with_cancel_flags(|flags| {
+        if let Some(flag) = flags.get(&req_id) {
+            flag.store(true, Ordering::SeqCst);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::MutexGuard;
+
+    use super::*;
+
+    // The registry is global, so tests asserting that it is empty cannot run in parallel
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    fn serial() -> MutexGuard<'static, ()> {
+        SERIAL.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn is_registry_empty() -> bool {
+        with_cancel_flags(|flags| flags.is_empty())
+    }
+
+    #[test]
+    fn cancel_after_obtaining_token() {
+        let _serial = serial();
+        let token = CancelToken::new(1);
+        let clone = token.clone();
+        assert!(!token.is_cancelled(), "not cancelled initially");
+
+        _cancel_request(1);
+        assert!(token.is_cancelled(), "token cancelled");
+        assert!(clone.is_cancelled(), "clone cancelled");
+        assert!(!CancelToken::new(2).is_cancelled(), "other request");
+
+        drop(token);
+        assert!(!is_registry_empty(), "registered while clone is alive");
+        drop(clone);
+        assert!(is_registry_empty(), "removed with last token");
+    }
+
+    #[test]
+    fn cancel_while_queued() {
+        let _serial = serial();
+        // Obtained by the rid_msg_* function before enqueueing the update
+        let queued = CancelToken::new(3);
+        _cancel_request(3);
+        let token = CancelToken::new(3);
+        drop(queued);
+        assert!(token.is_cancelled(), "token cancelled");
+        drop(token);
+        assert!(is_registry_empty(), "removed with last token");
+    }
+
+    #[test]
+    fn cancel_without_token() {
+        let _serial = serial();
+        _cancel_request(4);
+        assert!(is_registry_empty(), "not registered");
+        assert!(!CancelToken::new(4).is_cancelled(), "token not cancelled");
+        assert!(is_registry_empty(), "removed with token");
+    }
+
+    #[test]
+    fn cancel_after_completion() {
+        let _serial = serial();
+        let token = CancelToken::new(5);
+        assert!(!token.is_cancelled(), "not cancelled while processing");
+        drop(token);
+
+        _cancel_request(5);
+        assert!(is_registry_empty(), "not registered");
+    }
+}
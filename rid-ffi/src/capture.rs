@@ -0,0 +1,217 @@
+use std::{
+    any::Any,
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    time::Duration,
+};
+
+use crate::_RidMessageType;
+
+// -----------------
+// Capture
+// -----------------
+// Rust tests have no Dart isolates to post replies and messages to. While capturing, which the
+// `rid::testing` harness enables, replies passed to [crate::post] and messages posted via rid's
+// macros or the [crate::RidLogger] are kept in memory instead so that tests can assert on them.
+
+/// A message captured while testing, it matches the `RidMessage` Dart receives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RidMessage {
+    pub msg_type: _RidMessageType,
+    pub message: String,
+    pub details: Option<String>,
+}
+
+struct Captured {
+    replies: VecDeque<Box<dyn Any + Send>>,
+    messages: VecDeque<RidMessage>,
+}
+
+static CAPTURED: Mutex<Option<Captured>> = Mutex::new(None);
+// Replies and messages are posted to Dart without locking CAPTURED unless capturing
+static CAPTURING: AtomicBool = AtomicBool::new(false);
+// Notified whenever a reply or message was captured, possibly from another thread
+static POSTED: Condvar = Condvar::new();
+
+fn captured() -> MutexGuard<'static, Option<Captured>> {
+    // A failing test panics while capturing which must not affect the following tests
+    CAPTURED.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Starts capturing replies and messages, dropping the ones captured previously.
+pub fn _start_capture() {
+    let mut captured = captured();
+    *captured = Some(Captured {
+        replies: VecDeque::new(),
+        messages: VecDeque::new(),
+    });
+    CAPTURING.store(true, Ordering::SeqCst);
+}
+
+/// Stops capturing replies and messages which are posted to Dart again.
+pub fn _stop_capture() {
+    let mut captured = captured();
+    *captured = None;
+    CAPTURING.store(false, Ordering::SeqCst);
+}
+
+pub(crate) fn is_capturing() -> bool {
+    CAPTURING.load(Ordering::SeqCst)
+}
+
+/// Captures the reply if capturing, otherwise hands it back in order to post it to Dart.
+pub(crate) fn try_capture_reply<T: Send + 'static>(reply: T) -> Result<(), T> {
+    if !is_capturing() {
+        return Err(reply);
+    }
+    match &mut *captured() {
+        Some(captured) => {
+            captured.replies.push_back(Box::new(reply));
+            POSTED.notify_all();
+            Ok(())
+        }
+        None => Err(reply),
+    }
+}
+
+/// Captures the message if capturing and returns `true` in that case.
+pub(crate) fn capture_message(
+    msg_type: _RidMessageType,
+    message: &str,
+    details: Option<&str>,
+) -> bool {
+    if !is_capturing() {
+        return false;
+    }
+    match &mut *captured() {
+        Some(captured) => {
+            captured.messages.push_back(RidMessage {
+                msg_type,
+                message: message.to_string(),
+                details: details.map(String::from),
+            });
+            POSTED.notify_all();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Removes and returns all replies captured so far in the order they were posted.
+pub fn _take_replies() -> Vec<Box<dyn Any + Send>> {
+    match &mut *captured() {
+        Some(captured) => captured.replies.drain(..).collect(),
+        None => vec![],
+    }
+}
+
+/// Removes and returns all messages captured so far in the order they were posted.
+pub fn _take_messages() -> Vec<RidMessage> {
+    match &mut *captured() {
+        Some(captured) => captured.messages.drain(..).collect(),
+        None => vec![],
+    }
+}
+
+/// Removes and returns the reply that was captured first, waiting up to the timeout for one to
+/// be posted, i.e. from a thread spawned by the store update.
+pub fn _wait_for_reply(timeout: Duration) -> Option<Box<dyn Any + Send>> {
+    wait_for(timeout, |x| x.replies.pop_front())
+}
+
+/// Removes and returns the message that was captured first, waiting up to the timeout for one to
+/// be posted.
+pub fn _wait_for_message(timeout: Duration) -> Option<RidMessage> {
+    wait_for(timeout, |x| x.messages.pop_front())
+}
+
+fn wait_for<T>(
+    timeout: Duration,
+    mut pop: impl FnMut(&mut Captured) -> Option<T>,
+) -> Option<T> {
+    let mut popped = None;
+    let _guard = POSTED
+        .wait_timeout_while(captured(), timeout, |x| match x {
+            Some(captured) => {
+                popped = pop(captured);
+                popped.is_none()
+            }
+            None => false,
+        })
+        .unwrap_or_else(PoisonError::into_inner);
+    popped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn captures_replies_and_messages_in_order() {
+        _start_capture();
+        assert!(try_capture_reply(1u8).is_ok(), "captures reply");
+        assert!(try_capture_reply("two").is_ok(), "captures reply");
+        assert!(
+            capture_message(_RidMessageType::LogInfo, "info", None),
+            "captures message"
+        );
+        assert!(
+            capture_message(_RidMessageType::Error, "error", Some("details")),
+            "captures message"
+        );
+
+        let replies = _take_replies();
+        assert_eq!(replies.len(), 2, "replies");
+        assert_eq!(replies[0].downcast_ref::<u8>(), Some(&1), "first reply");
+        assert_eq!(
+            replies[1].downcast_ref::<&str>(),
+            Some(&"two"),
+            "second reply"
+        );
+        assert!(_take_replies().is_empty(), "replies were taken");
+
+        assert_eq!(
+            _take_messages(),
+            vec![
+                RidMessage {
+                    msg_type: _RidMessageType::LogInfo,
+                    message: "info".to_string(),
+                    details: None,
+                },
+                RidMessage {
+                    msg_type: _RidMessageType::Error,
+                    message: "error".to_string(),
+                    details: Some("details".to_string()),
+                }
+            ],
+            "messages"
+        );
+
+        let poster = thread::spawn(|| {
+            thread::sleep(Duration::from_millis(10));
+            try_capture_reply(3u32).ok();
+        });
+        let reply = _wait_for_reply(Duration::from_secs(5));
+        assert_eq!(
+            reply.and_then(|x| x.downcast::<u32>().ok()).map(|x| *x),
+            Some(3),
+            "waits for reply posted from other thread"
+        );
+        poster.join().unwrap();
+        assert!(
+            _wait_for_message(Duration::from_millis(1)).is_none(),
+            "times out"
+        );
+
+        _stop_capture();
+        assert_eq!(try_capture_reply(4u8), Err(4), "hands back reply");
+        assert!(
+            !capture_message(_RidMessageType::LogInfo, "info", None),
+            "does not capture message"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::{any::Any, cell::RefCell, ffi::CString, fmt, os::raw::c_char, ptr};
+
+// Errors returned by exported functions cannot cross the FFI boundary directly.
+// Instead they are parked here until Dart picks them up right after the exported function
+// returned, which always happens on the same thread.
+struct ExportError {
+    message: CString,
+    data: Box<dyn Any>,
+}
+
+thread_local! {
+    static EXPORT_ERROR: RefCell<Option<ExportError>> = const { RefCell::new(None) };
+}
+
+/// Stores the error returned by an exported function until Dart resolves it.
+pub fn _set_export_error<E: 'static>(message: String, err: E) {
+    let message = CString::new(message.replace('\0', ""))
+        .expect("nul bytes were removed from the message");
+    EXPORT_ERROR.with(|x| {
+        x.replace(Some(ExportError {
+            message,
+            data: Box::new(err),
+        }))
+    });
+}
+
+/// Drops the error returned by the last exported function call if any.
+pub fn _clear_export_error() {
+    EXPORT_ERROR.with(|x| x.replace(None));
+}
+
+/// Message of the error returned by the last exported function call or a null pointer if that
+/// call succeeded.
+/// The pointer stays valid until the error is cleared.
+pub fn _export_error_message() -> *const c_char {
+    EXPORT_ERROR.with(|x| match &*x.borrow() {
+        Some(err) => err.message.as_ptr(),
+        None => ptr::null(),
+    })
+}
+
+/// Error returned by the last exported function call or a null pointer if that call succeeded
+/// or returned an error of a different type.
+/// The pointer stays valid until the error is cleared.
+pub fn _export_error_ref<E: 'static>() -> *const E {
+    EXPORT_ERROR.with(|x| {
+        match x
+            .borrow()
+            .as_ref()
+            .and_then(|err| err.data.downcast_ref::<E>())
+        {
+            Some(err) => err as *const E,
+            None => ptr::null(),
+        }
+    })
+}
+
+// -----------------
+// Error Messages
+// -----------------
+
+/// Wraps the error returned by an exported function in order to derive its message.
+///
+/// The `Display` implementation of the error is preferred and `Debug` is used as a fallback,
+/// i.e. for `#[rid::model]` enums that don't implement `Display`.
+///
+/// ```ignore
+/// use rid::{_DebugExportError, _DisplayExportError};
+/// let message = (&rid::_ExportErrorMessage(&err))._rid_error_message();
+/// ```
+pub struct _ExportErrorMessage<'a, E>(pub &'a E);
+
+pub trait _DisplayExportError {
+    fn _rid_error_message(&self) -> String;
+}
+
+impl<E: fmt::Display> _DisplayExportError for &_ExportErrorMessage<'_, E> {
+    fn _rid_error_message(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+pub trait _DebugExportError {
+    fn _rid_error_message(&self) -> String;
+}
+
+impl<E: fmt::Debug> _DebugExportError for _ExportErrorMessage<'_, E> {
+    fn _rid_error_message(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
@@ -0,0 +1,68 @@
+use std::ptr;
+
+use crate::RidVec;
+
+// -----------------
+// Fallback Values
+// -----------------
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Implemented by the types that functions rid exports to Dart return.
+///
+/// Provides the value returned instead when such a function panicked or returned an error.
+/// Dart never uses it since it throws right after the call.
+pub trait _RidFfiDefault: sealed::Sealed {
+    fn _rid_ffi_default() -> Self;
+}
+
+macro_rules! impl_ffi_default {
+    ($($ty:ty => $default:expr),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl _RidFfiDefault for $ty {
+                fn _rid_ffi_default() -> Self {
+                    $default
+                }
+            }
+        )*
+    };
+}
+
+impl_ffi_default!(
+    () => (),
+    bool => false,
+    u8 => 0, i8 => 0, u16 => 0, i16 => 0, u32 => 0, i32 => 0,
+    u64 => 0, i64 => 0, usize => 0, isize => 0,
+    f32 => 0.0, f64 => 0.0,
+);
+
+impl<T> sealed::Sealed for *const T {}
+impl<T> _RidFfiDefault for *const T {
+    fn _rid_ffi_default() -> Self {
+        ptr::null()
+    }
+}
+
+impl<T> sealed::Sealed for *mut T {}
+impl<T> _RidFfiDefault for *mut T {
+    fn _rid_ffi_default() -> Self {
+        ptr::null_mut()
+    }
+}
+
+impl<T> sealed::Sealed for Option<&T> {}
+impl<T> _RidFfiDefault for Option<&T> {
+    fn _rid_ffi_default() -> Self {
+        None
+    }
+}
+
+// An empty Vec, unlike a null pointer, is safe to free should Dart do so.
+impl<T> sealed::Sealed for RidVec<T> {}
+impl<T> _RidFfiDefault for RidVec<T> {
+    fn _rid_ffi_default() -> Self {
+        RidVec::from(Vec::new())
+    }
+}
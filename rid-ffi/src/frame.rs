@@ -0,0 +1,109 @@
+use crate::wire::{_RidEncode, _RidWireWriter};
+
+// -----------------
+// Frame
+// -----------------
+// Replies and messages are posted to Dart as a single byte array (a Dart `Uint8List`), called a
+// frame, which consists of a header followed by the payload encoded into the rid wire format.
+//
+// | version: u8 | variant: u32 | req_id: u64 | payload ... |
+//
+// - version: the version of the frame layout, see [RID_FRAME_VERSION]
+// - variant: the index of the reply variant or the type of the message
+// - req_id: id of the request a reply responds to, `0` if it doesn't carry one
+//
+// The Dart side decodes frames via `RidFrame.decode` found inside `_rid_frame.dart`.
+
+/// Version of the frame layout, checked by Dart when decoding a frame.
+pub const RID_FRAME_VERSION: u8 = 1;
+
+// -----------------
+// Reserved Variants
+// -----------------
+// Frames that aren't replies nor messages use reserved variants, counting down from `u32::MAX`.
+// Thus they never clash with the index of a reply variant or `_RidMessageType` nor with each
+// other. Keep them in sync with `_rid_frame.dart`.
+
+/// Variant of frames that notify Dart about changed store fields, see `store_changes.rs`.
+pub const RID_STORE_CHANGES_VARIANT: u32 = u32::MAX;
+
+/// Variant of frames that notify Dart about a panic while processing a request, see `panic.rs`.
+pub const RID_PANIC_VARIANT: u32 = u32::MAX - 1;
+
+/// Variant of frames holding a span or event on the message channel, see `tracing_layer.rs`.
+pub const RID_TRACE_VARIANT: u32 = u32::MAX - 2;
+
+/// Builds a frame to post to Dart.
+pub struct _RidFrame {
+    writer: _RidWireWriter,
+}
+
+impl _RidFrame {
+    /// Starts a frame by writing its header. Data is encoded into the payload via [Self::writer].
+    pub fn new(variant: u32, req_id: Option<u64>) -> Self {
+        let mut writer = _RidWireWriter::new();
+        writer.write_u8(RID_FRAME_VERSION);
+        writer.write_u32(variant);
+        req_id.unwrap_or(0)._rid_encode(&mut writer);
+        Self { writer }
+    }
+
+    pub fn writer(&mut self) -> &mut _RidWireWriter {
+        &mut self.writer
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.writer.into_bytes()
+    }
+}
+
+impl ::allo_isolate::IntoDart for _RidFrame {
+    fn into_dart(self) -> ::allo_isolate::ffi::DartCObject {
+        ::allo_isolate::IntoDart::into_dart(::allo_isolate::ZeroCopyBuffer(
+            self.into_bytes(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_layout() {
+        let mut frame = _RidFrame::new(3, Some(258));
+        "a^b".to_string()._rid_encode(frame.writer());
+        assert_eq!(
+            frame.into_bytes(),
+            vec![
+                1, // version
+                3, 0, 0, 0, // variant
+                2, 1, 0, 0, 0, 0, 0, 0, // req_id
+                3, 0, 0, 0, b'a', b'^', b'b', // payload
+            ]
+        );
+
+        let frame = _RidFrame::new(1, None);
+        assert_eq!(
+            frame.into_bytes(),
+            vec![1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            "no req_id"
+        );
+    }
+
+    #[test]
+    fn reserved_variants_are_distinct() {
+        let reserved = [
+            RID_STORE_CHANGES_VARIANT,
+            RID_PANIC_VARIANT,
+            RID_TRACE_VARIANT,
+        ];
+        for (idx, variant) in reserved.iter().enumerate() {
+            assert!(
+                !reserved[idx + 1..].contains(variant),
+                "{} is reserved twice",
+                variant
+            );
+        }
+    }
+}
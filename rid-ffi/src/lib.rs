@@ -1,13 +1,59 @@
+mod cancel;
+mod capture;
+mod export_error;
+mod ffi_default;
+mod frame;
+mod logger;
 mod message;
+mod message_log;
+mod msg_queue;
+mod panic;
 mod post;
 mod resolvers;
+mod store_changes;
+#[cfg(feature = "tracing")]
+mod tracing_layer;
 mod vec;
+mod wire;
 
-pub use message::{_init_msg_isolate, _post_message};
-pub use post::{
-    _encode_with_id, _encode_without_id, _init_reply_isolate, post,
+pub use cancel::{_cancel_request, CancelToken};
+pub use capture::{
+    _start_capture, _stop_capture, _take_messages, _take_replies,
+    _wait_for_message, _wait_for_reply, RidMessage,
 };
+pub use export_error::{
+    _DebugExportError, _DisplayExportError, _ExportErrorMessage,
+    _clear_export_error, _export_error_message, _export_error_ref,
+    _set_export_error,
+};
+pub use ffi_default::_RidFfiDefault;
+pub use frame::{
+    _RidFrame, RID_FRAME_VERSION, RID_PANIC_VARIANT, RID_STORE_CHANGES_VARIANT,
+    RID_TRACE_VARIANT,
+};
+pub use logger::{init_logger, RidLogger};
+pub use message::{_RidMessageType, _init_msg_isolate, _post_message};
+pub use message_log::{
+    _record_message, _set_store_snapshot, is_recording, read_message_log,
+    record_store_snapshot, start_recording, stop_recording, RidLogEntry,
+    RID_RECORD_MESSAGES,
+};
+pub use msg_queue::{_enqueue_message, message_queue_depth};
+pub use panic::{
+    _catch_panic, _catch_request_panic, _clear_panic, _panic_flag,
+    _panic_message,
+};
+pub use post::{_init_reply_isolate, post};
 pub use resolvers::*;
+pub use store_changes::{
+    _mark_store_field_changed, _post_store_changes, _start_store_changes,
+};
+#[cfg(feature = "tracing")]
+pub use tracing_layer::{init_tracing, RidTracingLayer};
 pub use vec::*;
+pub use wire::{
+    _RidDecode, _RidEncode, _RidWireReader, _RidWireWriter, _decode_wire_arg,
+    _encode_wire_result, _free_wire_result,
+};
 
 pub use allo_isolate;
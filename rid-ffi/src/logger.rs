@@ -0,0 +1,210 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::{_RidEncode, _RidMessageType, _RidWireWriter};
+
+// -----------------
+// Logger
+// -----------------
+// Records logged via the macros of the `log` crate, i.e. `info!` or `warn!`, are posted to Dart
+// once a [RidLogger] was installed. They arrive on the message channel as a `RidMessage` which
+// additionally exposes the level, target, module path, file, line and timestamp of the record.
+//
+// Log levels map to the type of the posted message as follows:
+//
+// - `Error` -> `RidMessageType.Error`
+// - `Warn`  -> `RidMessageType.LogWarn`
+// - `Info`  -> `RidMessageType.LogInfo`
+// - `Debug` -> `RidMessageType.LogDebug`
+// - `Trace` -> `RidMessageType.LogTrace`
+
+/// Implementation of [log::Log] which posts records to the Dart message channel.
+///
+/// ```ignore
+/// rid::RidLogger::new()
+///     .max_level(log::LevelFilter::Debug)
+///     .target("todo")
+///     .init()
+///     .expect("logger is only installed once");
+/// ```
+pub struct RidLogger {
+    max_level: LevelFilter,
+    targets: Vec<String>,
+}
+
+impl Default for RidLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RidLogger {
+    /// Creates a logger which posts records of all targets up to and including the `Info` level.
+    pub fn new() -> Self {
+        Self {
+            max_level: LevelFilter::Info,
+            targets: vec![],
+        }
+    }
+
+    /// Only posts records up to and including the provided level.
+    pub fn max_level(mut self, max_level: LevelFilter) -> Self {
+        self.max_level = max_level;
+        self
+    }
+
+    /// Only posts records whose target starts with one of the targets added this way, i.e.
+    /// `"todo"` includes records logged from the `todo::app` module.
+    /// Records of all targets are posted if none was added.
+    pub fn target(mut self, target: &str) -> Self {
+        self.targets.push(target.to_string());
+        self
+    }
+
+    /// Installs this logger as the global logger which can happen only once.
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        let max_level = self.max_level;
+        log::set_logger(Box::leak(Box::new(self)))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+
+    fn is_target_enabled(&self, target: &str) -> bool {
+        self.targets.is_empty()
+            || self.targets.iter().any(|x| target.starts_with(x.as_str()))
+    }
+}
+
+/// Installs a [RidLogger] with default settings, see [RidLogger::new].
+pub fn init_logger() -> Result<(), SetLoggerError> {
+    RidLogger::new().init()
+}
+
+impl Log for RidLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.max_level
+            && self.is_target_enabled(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let message = record.args().to_string();
+        if crate::message::can_post_messages() {
+            crate::message::post_log_message(
+                message_type(record.level()),
+                message,
+                LogRecordInfo::from(record),
+            );
+        } else {
+            // Records logged before Dart initialized the message channel aren't lost entirely
+            eprintln!("[{}] {}: {}", record.level(), record.target(), message);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn message_type(level: Level) -> _RidMessageType {
+    match level {
+        Level::Error => _RidMessageType::Error,
+        Level::Warn => _RidMessageType::LogWarn,
+        Level::Info => _RidMessageType::LogInfo,
+        Level::Debug => _RidMessageType::LogDebug,
+        Level::Trace => _RidMessageType::LogTrace,
+    }
+}
+
+/// Information about a log record which is posted along with its message.
+pub(crate) struct LogRecordInfo {
+    level: Level,
+    target: String,
+    module_path: Option<String>,
+    file: Option<String>,
+    line: Option<u32>,
+    /// Milliseconds since the Unix epoch at which the record was logged.
+    timestamp: u64,
+}
+
+impl From<&Record<'_>> for LogRecordInfo {
+    fn from(record: &Record) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_millis() as u64)
+            .unwrap_or(0);
+        Self {
+            level: record.level(),
+            target: record.target().to_string(),
+            module_path: record.module_path().map(String::from),
+            file: record.file().map(String::from),
+            line: record.line(),
+            timestamp,
+        }
+    }
+}
+
+/// Encoded in the order Dart decodes it inside `_message_channel.dart`, the level is encoded as
+/// the index of the matching `RidLogLevel`.
+impl _RidEncode for LogRecordInfo {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        (self.level as u8 - 1)._rid_encode(writer);
+        self.target._rid_encode(writer);
+        self.module_path._rid_encode(writer);
+        self.file._rid_encode(writer);
+        self.line._rid_encode(writer);
+        self.timestamp._rid_encode(writer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(level: Level, target: &str) -> Metadata<'_> {
+        Metadata::builder().level(level).target(target).build()
+    }
+
+    #[test]
+    fn filters_by_level_and_target() {
+        let logger = RidLogger::new();
+        assert!(logger.enabled(&metadata(Level::Info, "todo")), "info");
+        assert!(!logger.enabled(&metadata(Level::Debug, "todo")), "debug");
+
+        let logger = RidLogger::new()
+            .max_level(LevelFilter::Trace)
+            .target("todo")
+            .target("rid");
+        assert!(logger.enabled(&metadata(Level::Trace, "todo::app")), "todo");
+        assert!(logger.enabled(&metadata(Level::Warn, "rid")), "rid");
+        assert!(!logger.enabled(&metadata(Level::Error, "hyper")), "hyper");
+    }
+
+    #[test]
+    fn encodes_record_info() {
+        let record = Record::builder()
+            .args(format_args!("hello"))
+            .level(Level::Warn)
+            .target("todo")
+            .module_path(Some("todo::app"))
+            .file(Some("src/app.rs"))
+            .line(Some(3))
+            .build();
+        let info = LogRecordInfo::from(&record);
+        assert!(info.timestamp > 0, "timestamp");
+
+        let mut writer = _RidWireWriter::new();
+        info._rid_encode(&mut writer);
+        let bytes = writer.into_bytes();
+
+        let mut expected = _RidWireWriter::new();
+        1u8._rid_encode(&mut expected);
+        "todo"._rid_encode(&mut expected);
+        Some("todo::app".to_string())._rid_encode(&mut expected);
+        Some("src/app.rs".to_string())._rid_encode(&mut expected);
+        Some(3u32)._rid_encode(&mut expected);
+        info.timestamp._rid_encode(&mut expected);
+        assert_eq!(bytes, expected.into_bytes());
+    }
+}
@@ -1,4 +1,9 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{_RidEncode, _RidFrame, logger::LogRecordInfo};
+
 static mut RID_MSG_ISOLATE: Option<MsgIsolate> = None;
+static RID_MSG_ISOLATE_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
 // NOTE: Mostly duplicated from ./post.rs, but hard to avoid since each is using
 // a separate global.
@@ -57,10 +62,103 @@ pub fn _init_msg_isolate(port: i64) {
         }
         RID_MSG_ISOLATE = Some(MsgIsolate::new(port));
     }
+    RID_MSG_ISOLATE_INITIALIZED.store(true, Ordering::SeqCst);
+}
+
+/// Returns `true` once Dart initialized the isolate that messages are posted to.
+pub(crate) fn is_msg_isolate_initialized() -> bool {
+    RID_MSG_ISOLATE_INITIALIZED.load(Ordering::SeqCst)
+}
+
+/// Type of a message posted to Dart, each variant matches the `RidMessageType` of the same
+/// index on the Dart side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum _RidMessageType {
+    Severe,
+    Error,
+    LogWarn,
+    LogInfo,
+    LogDebug,
+    MsgInfo,
+    MsgWarn,
+    LogTrace,
+}
+
+impl _RidMessageType {
+    /// Label of a message printed when it cannot be posted to Dart.
+    fn label(&self) -> &'static str {
+        use _RidMessageType::*;
+        match self {
+            Severe => "ERR!",
+            Error => "ERR: ",
+            LogWarn => "WARN",
+            LogInfo => "INFO",
+            LogDebug => "DEBG",
+            MsgInfo => "MSGI",
+            MsgWarn => "MSGW",
+            LogTrace => "TRCE",
+        }
+    }
+}
+
+/// Returns `true` if messages are posted to Dart or captured while testing.
+pub(crate) fn can_post_messages() -> bool {
+    is_msg_isolate_initialized() || crate::capture::is_capturing()
+}
+
+/// Posts a message to Dart as a frame whose variant is the message type and whose payload holds
+/// the message followed by the optional details and the optional info about the log record the
+/// message originated from.
+/// While testing via `rid::testing` the message is captured instead and it is printed if Dart
+/// didn't initialize the message channel.
+pub fn _post_message(
+    msg_type: _RidMessageType,
+    msg: String,
+    details: Option<String>,
+) {
+    post_frame(msg_type, msg, details, None);
+}
+
+/// Posts a message for a record logged via the `log` crate, see [crate::RidLogger].
+pub(crate) fn post_log_message(
+    msg_type: _RidMessageType,
+    msg: String,
+    record: LogRecordInfo,
+) {
+    post_frame(msg_type, msg, None, Some(record));
+}
+
+fn post_frame(
+    msg_type: _RidMessageType,
+    msg: String,
+    details: Option<String>,
+    record: Option<LogRecordInfo>,
+) {
+    if crate::capture::capture_message(msg_type, &msg, details.as_deref()) {
+        return;
+    }
+    if !is_msg_isolate_initialized() {
+        // Messages posted before Dart initialized the message channel or while running Rust
+        // tests without capturing them aren't lost entirely
+        match details {
+            Some(details) => {
+                eprintln!("{}: {} {}", msg_type.label(), msg, details)
+            }
+            None => eprintln!("{}: {}", msg_type.label(), msg),
+        }
+        return;
+    }
+    let mut frame = _RidFrame::new(msg_type as u32, None);
+    msg._rid_encode(frame.writer());
+    details._rid_encode(frame.writer());
+    record._rid_encode(frame.writer());
+    post_msg_frame(frame);
 }
 
-pub fn _post_message(msg: impl ::allo_isolate::IntoDart) {
-    MsgIsolate::isolate().post(msg);
+/// Posts a frame to the message channel whose variant is outside the range of message types,
+/// i.e. a span or event of the `tracing` crate.
+pub(crate) fn post_msg_frame(frame: _RidFrame) {
+    MsgIsolate::isolate().post(frame);
 }
 
 // -----------------
@@ -70,11 +168,7 @@ pub fn _post_message(msg: impl ::allo_isolate::IntoDart) {
 macro_rules! log_warn {
     ($($arg:tt)*) => {{
         let res = format!($($arg)*);
-
-        #[cfg(test)]
-        eprintln!("WARN: {}", res);
-        #[cfg(not(test))]
-        rid::_post_message(format!("log_warn^{}", res));
+        rid::_post_message(rid::_RidMessageType::LogWarn, res, None);
     }}
 }
 
@@ -82,11 +176,7 @@ macro_rules! log_warn {
 macro_rules! log_info {
     ($($arg:tt)*) => {{
         let res = format!($($arg)*);
-
-        #[cfg(test)]
-        eprintln!("INFO: {}", res);
-        #[cfg(not(test))]
-        rid::_post_message(format!("log_info^{}", res));
+        rid::_post_message(rid::_RidMessageType::LogInfo, res, None);
     }}
 }
 
@@ -94,11 +184,7 @@ macro_rules! log_info {
 macro_rules! log_debug {
     ($($arg:tt)*) => {{
         let res = format!($($arg)*);
-
-        #[cfg(test)]
-        eprintln!("DEBG: {}", res);
-        #[cfg(not(test))]
-        rid::_post_message(format!("log_debug^{}", res));
+        rid::_post_message(rid::_RidMessageType::LogDebug, res, None);
     }}
 }
 // -----------------
@@ -107,32 +193,36 @@ macro_rules! log_debug {
 #[macro_export]
 macro_rules! error {
     ($msg:expr) => {{
-        #[cfg(test)]
-        eprintln!("ERR:  {:?}", $msg);
-        #[cfg(not(test))]
-        rid::_post_message(format!("err_error^{:?}", $msg));
+        rid::_post_message(
+            rid::_RidMessageType::Error,
+            format!("{:?}", $msg),
+            None,
+        );
     }};
     ($msg:expr, $details:expr) => {{
-        #[cfg(test)]
-        eprintln!("ERR:  {:?} {:?}", $msg, $details);
-        #[cfg(not(test))]
-        rid::_post_message(format!("err_error^{:?}^{:?}", $msg, $details));
+        rid::_post_message(
+            rid::_RidMessageType::Error,
+            format!("{:?}", $msg),
+            Some(format!("{:?}", $details)),
+        );
     }};
 }
 
 #[macro_export]
 macro_rules! severe {
     ($msg:expr) => {{
-        #[cfg(test)]
-        eprintln!("ERR!: {:?}", $msg);
-        #[cfg(not(test))]
-        rid::_post_message(format!("err_severe^{:?}", $msg));
+        rid::_post_message(
+            rid::_RidMessageType::Severe,
+            format!("{:?}", $msg),
+            None,
+        );
     }};
     ($msg:expr, $details:expr) => {{
-        #[cfg(test)]
-        eprintln!("ERR!: {:?} {:?}", $msg, $details);
-        #[cfg(not(test))]
-        rid::_post_message(format!("err_severe^{:?}^{:?}", $msg, $details));
+        rid::_post_message(
+            rid::_RidMessageType::Severe,
+            format!("{:?}", $msg),
+            Some(format!("{:?}", $details)),
+        );
     }};
 }
 
@@ -143,11 +233,7 @@ macro_rules! severe {
 macro_rules! msg_warn {
     ($($arg:tt)*) => {{
         let res = format!($($arg)*);
-
-        #[cfg(test)]
-        eprintln!("MSGW: {}", res);
-        #[cfg(not(test))]
-        rid::_post_message(format!("msg_warn^{}", res));
+        rid::_post_message(rid::_RidMessageType::MsgWarn, res, None);
     }}
 }
 
@@ -155,10 +241,6 @@ macro_rules! msg_warn {
 macro_rules! msg_info {
     ($($arg:tt)*) => {{
         let res = format!($($arg)*);
-
-        #[cfg(test)]
-        eprintln!("MSGI: {}", res);
-        #[cfg(not(test))]
-        rid::_post_message(format!("msg_info^{}", res));
+        rid::_post_message(rid::_RidMessageType::MsgInfo, res, None);
     }}
 }
@@ -0,0 +1,347 @@
+use std::{
+    env, fmt,
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, MutexGuard, Once, PoisonError,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{_RidDecode, _RidEncode, _RidWireReader, _RidWireWriter};
+
+// -----------------
+// Message Log
+// -----------------
+// Messages Dart sends to the store can be recorded into a message log in order to replay them
+// later via `rid::replay`, i.e. to reproduce a bug reported by QA. Recording is opt-in, either
+// via [start_recording] or by setting the [RID_RECORD_MESSAGES] environment variable to the path
+// of the log before the first message is sent.
+//
+// The generated `rid_msg_*` functions record each message before applying it to the store.
+// Stores that derive `Debug` register a snapshot of themselves which is recorded when recording
+// stops, i.e. when Dart frees the store, so that replaying the log verifies the final state.
+// Additionally the app may record snapshots of the store via [record_store_snapshot] at any
+// point.
+//
+// The log starts with a header followed by entries, each encoded into the rid wire format and
+// prefixed with its length:
+//
+// | magic: "RIDLOG" | version: u8 | len: u32 | entry ... | len: u32 | entry ... |
+//
+// - message entry: | 0: u8 | timestamp: u64 | req_id: u64 | variant: u32 | fields ... |
+// - snapshot entry: | 1: u8 | timestamp: u64 | Debug output of the store: string |
+//
+// Timestamps are milliseconds since the Unix epoch.
+
+/// Environment variable holding the path of the log to record messages into.
+pub const RID_RECORD_MESSAGES: &str = "RID_RECORD_MESSAGES";
+
+const MAGIC: &[u8] = b"RIDLOG";
+const VERSION: u8 = 1;
+
+const MESSAGE_ENTRY: u8 = 0;
+const SNAPSHOT_ENTRY: u8 = 1;
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Option<File>> = Mutex::new(None);
+static INIT_FROM_ENV: Once = Once::new();
+static STORE_SNAPSHOT: Mutex<Option<fn() -> String>> = Mutex::new(None);
+
+fn recorder() -> MutexGuard<'static, Option<File>> {
+    RECORDER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Starts recording messages into the log at the given path, replacing an existing file.
+pub fn start_recording(path: impl AsRef<Path>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(MAGIC)?;
+    file.write_all(&[VERSION])?;
+    *recorder() = Some(file);
+    RECORDING.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Stops recording messages and closes the log.
+///
+/// The final state of the store is recorded first if the store registered a snapshot, see
+/// [_set_store_snapshot]. Thus this must not be called while the store is locked for an update.
+pub fn stop_recording() {
+    if RECORDING.load(Ordering::SeqCst) {
+        let snapshot = *STORE_SNAPSHOT
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(snapshot) = snapshot {
+            write_snapshot(snapshot());
+        }
+    }
+    RECORDING.store(false, Ordering::SeqCst);
+    recorder().take();
+}
+
+/// Used by the generated store module of stores that derive `Debug` to provide the snapshot
+/// recorded when recording stops.
+pub fn _set_store_snapshot(snapshot: fn() -> String) {
+    *STORE_SNAPSHOT
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(snapshot);
+}
+
+/// Returns `true` while messages are recorded.
+pub fn is_recording() -> bool {
+    init_from_env();
+    RECORDING.load(Ordering::SeqCst)
+}
+
+fn init_from_env() {
+    INIT_FROM_ENV.call_once(|| {
+        if let Ok(path) = env::var(RID_RECORD_MESSAGES) {
+            if let Err(err) = start_recording(&path) {
+                eprintln!(
+                    "[rid] ERROR: failed to record messages into '{}': {}",
+                    path, err
+                );
+            }
+        }
+    });
+}
+
+/// Used by the generated `rid_msg_*` functions to record the message received from Dart.
+pub fn _record_message<M: _RidEncode>(req_id: u64, msg: &M) {
+    if !is_recording() {
+        return;
+    }
+    let mut writer = entry_writer(MESSAGE_ENTRY);
+    req_id._rid_encode(&mut writer);
+    msg._rid_encode(&mut writer);
+    write_entry(writer);
+}
+
+/// Records the `Debug` output of the store while recording messages.
+/// Replaying the log optionally verifies that the replayed store matches it at that point.
+pub fn record_store_snapshot(store: &impl fmt::Debug) {
+    if !is_recording() {
+        return;
+    }
+    write_snapshot(format!("{:#?}", store));
+}
+
+fn write_snapshot(store: String) {
+    let mut writer = entry_writer(SNAPSHOT_ENTRY);
+    store._rid_encode(&mut writer);
+    write_entry(writer);
+}
+
+fn entry_writer(kind: u8) -> _RidWireWriter {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_millis() as u64)
+        .unwrap_or(0);
+    let mut writer = _RidWireWriter::new();
+    kind._rid_encode(&mut writer);
+    timestamp._rid_encode(&mut writer);
+    writer
+}
+
+fn write_entry(writer: _RidWireWriter) {
+    let entry = writer.into_bytes();
+    let mut bytes = _RidWireWriter::new();
+    bytes.write_len(entry.len());
+    bytes.write_bytes(&entry);
+
+    let mut recorder = recorder();
+    if let Some(file) = recorder.as_mut() {
+        // Entries are written at once so that a crashing app leaves a readable log behind
+        if let Err(err) = file.write_all(&bytes.into_bytes()) {
+            eprintln!("[rid] ERROR: failed to record message: {}", err);
+            RECORDING.store(false, Ordering::SeqCst);
+            recorder.take();
+        }
+    }
+}
+
+/// An entry of a message log, see [read_message_log].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RidLogEntry {
+    /// A message Dart sent to the store.
+    Message {
+        req_id: u64,
+        timestamp: u64,
+        /// The message encoded into the rid wire format, see [RidLogEntry::decode_message].
+        payload: Vec<u8>,
+    },
+    /// The `Debug` output of the store recorded via [record_store_snapshot].
+    Snapshot { timestamp: u64, store: String },
+}
+
+impl RidLogEntry {
+    /// Decodes the message of a message entry, `None` for snapshots.
+    /// Fails if the payload isn't a valid encoding of the message, i.e. when the log is corrupt
+    /// or was recorded for a different message type.
+    pub fn decode_message<M: _RidDecode>(&self) -> Option<io::Result<M>> {
+        match self {
+            RidLogEntry::Message { payload, .. } => {
+                let mut reader = _RidWireReader::fallible(payload);
+                let msg = M::_rid_decode(&mut reader);
+                let result = match reader.error() {
+                    Some(err) => Err(invalid_data(err.to_string())),
+                    None if reader.remaining() > 0 => {
+                        Err(invalid_data(format!(
+                            "{} bytes left after decoding the message",
+                            reader.remaining()
+                        )))
+                    }
+                    None => Ok(msg),
+                };
+                Some(result)
+            }
+            RidLogEntry::Snapshot { .. } => None,
+        }
+    }
+}
+
+fn invalid_data(err: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Reads the entries of a message log in the order they were recorded.
+///
+/// An incomplete entry at the end of the log, i.e. when the app was killed while writing it,
+/// is ignored.
+pub fn read_message_log(
+    path: impl AsRef<Path>,
+) -> io::Result<Vec<RidLogEntry>> {
+    let bytes = fs::read(path)?;
+    let header_len = MAGIC.len() + 1;
+    if bytes.len() < header_len || &bytes[..MAGIC.len()] != MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not a rid message log",
+        ));
+    }
+    if bytes[MAGIC.len()] != VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "unsupported rid message log version {}, expected {}",
+                bytes[MAGIC.len()],
+                VERSION
+            ),
+        ));
+    }
+
+    let mut reader = _RidWireReader::new(&bytes[header_len..]);
+    let mut entries = vec![];
+    while reader.remaining() >= 4 {
+        let len = reader.read_len();
+        if reader.remaining() < len {
+            break;
+        }
+        let mut entry = _RidWireReader::fallible(reader.read_bytes(len));
+        let kind = entry.read_u8();
+        let timestamp = u64::_rid_decode(&mut entry);
+        let parsed = match kind {
+            MESSAGE_ENTRY => RidLogEntry::Message {
+                req_id: u64::_rid_decode(&mut entry),
+                timestamp,
+                payload: entry.read_bytes(entry.remaining()).to_vec(),
+            },
+            SNAPSHOT_ENTRY => RidLogEntry::Snapshot {
+                timestamp,
+                store: entry.read_string(),
+            },
+            kind => {
+                return Err(invalid_data(format!(
+                    "unknown rid message log entry {}",
+                    kind
+                )))
+            }
+        };
+        if let Some(err) = entry.error() {
+            return Err(invalid_data(format!(
+                "corrupt rid message log entry {}: {}",
+                entries.len(),
+                err
+            )));
+        }
+        entries.push(parsed);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::OpenOptions;
+
+    #[test]
+    fn records_and_reads_messages_and_snapshots() {
+        let path = env::temp_dir()
+            .join(format!("rid_message_log_{}.ridlog", std::process::id()));
+        _record_message(1, &"dropped".to_string());
+
+        start_recording(&path).unwrap();
+        assert!(is_recording(), "recording");
+        _record_message(1, &"first".to_string());
+        _record_message(2, &vec![3u32, 4]);
+        record_store_snapshot(&vec!["todo"]);
+        stop_recording();
+        _record_message(3, &"dropped".to_string());
+
+        // Simulate an app killed while writing an entry
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(&[9, 0, 0, 0, 0])
+            .unwrap();
+
+        let entries = read_message_log(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(entries.len(), 3, "entries");
+
+        match &entries[0] {
+            RidLogEntry::Message { req_id, .. } => assert_eq!(*req_id, 1),
+            entry => panic!("not a message {:?}", entry),
+        }
+        assert_eq!(
+            entries[0].decode_message::<String>().unwrap().unwrap(),
+            "first",
+            "first message"
+        );
+        assert_eq!(
+            entries[1].decode_message::<Vec<u32>>().unwrap().unwrap(),
+            vec![3, 4],
+            "second message"
+        );
+        assert_eq!(
+            entries[0]
+                .decode_message::<Vec<u32>>()
+                .unwrap()
+                .unwrap_err()
+                .to_string(),
+            "tried to read 4 bytes at offset 8 of 9 bytes",
+            "message of other type"
+        );
+        match &entries[2] {
+            RidLogEntry::Snapshot { timestamp, store } => {
+                assert!(*timestamp > 0, "timestamp");
+                assert_eq!(store, "[\n    \"todo\",\n]", "snapshot");
+            }
+            entry => panic!("not a snapshot {:?}", entry),
+        }
+        assert!(entries[2].decode_message::<String>().is_none(), "snapshot");
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        let path = env::temp_dir()
+            .join(format!("rid_not_a_log_{}.ridlog", std::process::id()));
+        fs::write(&path, b"hello world").unwrap();
+        let err = read_message_log(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Sender},
+        Mutex,
+    },
+    thread,
+};
+
+// -----------------
+// Message Queue
+// -----------------
+// Messages of a `#[rid::message(Reply, threaded)]` enum are not applied to the store on the
+// thread that sent them from Dart. Instead the generated `rid_msg_*` functions enqueue the store
+// update which is then run by a single dedicated worker thread.
+// Since that worker processes updates one at a time in the order they were enqueued, messages
+// are applied to the store in the same order in which they were sent.
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+static MSG_QUEUE: Mutex<Option<Sender<Job>>> = Mutex::new(None);
+static MSG_QUEUE_DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+fn spawn_worker() -> Sender<Job> {
+    let (tx, rx) = mpsc::channel::<Job>();
+    thread::Builder::new()
+        .name("rid_msg_worker".to_string())
+        .spawn(move || {
+            for job in rx {
+                // Keep processing messages even if updating the store panicked for one of them
+                if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+                    eprintln!(
+                        "[rid] ERROR: processing a queued message panicked"
+                    );
+                }
+                MSG_QUEUE_DEPTH.fetch_sub(1, Ordering::SeqCst);
+            }
+        })
+        .expect("[rid] failed to spawn message worker thread");
+    tx
+}
+
+/// Enqueues a store update to be run on the message worker thread which is spawned the first
+/// time a message is enqueued.
+pub fn _enqueue_message(job: Box<dyn FnOnce() + Send + 'static>) {
+    let mut queue = MSG_QUEUE
+        .lock()
+        .expect("[rid] message queue lock was poisoned");
+    let sender = queue.get_or_insert_with(spawn_worker);
+    MSG_QUEUE_DEPTH.fetch_add(1, Ordering::SeqCst);
+    if sender.send(job).is_err() {
+        MSG_QUEUE_DEPTH.fetch_sub(1, Ordering::SeqCst);
+        panic!("[rid] message worker thread is no longer running");
+    }
+}
+
+/// Number of enqueued messages that have not been fully processed yet, including the one
+/// currently being processed.
+pub fn message_queue_depth() -> usize {
+    MSG_QUEUE_DEPTH.load(Ordering::SeqCst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{mpsc::channel, Arc};
+
+    #[test]
+    fn processes_messages_in_order_and_tracks_depth() {
+        let (release_tx, release_rx) = channel::<()>();
+        let (done_tx, done_rx) = channel::<()>();
+        let processed = Arc::new(Mutex::new(vec![]));
+
+        // Block the worker until all messages have been enqueued
+        _enqueue_message(Box::new(move || release_rx.recv().unwrap()));
+        for n in 0..10 {
+            let processed = processed.clone();
+            _enqueue_message(Box::new(move || {
+                processed.lock().unwrap().push(n)
+            }));
+        }
+        _enqueue_message(Box::new(|| panic!("failing update")));
+        _enqueue_message(Box::new(move || done_tx.send(()).unwrap()));
+
+        assert_eq!(message_queue_depth(), 13, "depth while blocked");
+
+        release_tx.send(()).unwrap();
+        done_rx.recv().unwrap();
+        while message_queue_depth() > 0 {
+            thread::yield_now();
+        }
+
+        assert_eq!(
+            *processed.lock().unwrap(),
+            (0..10).collect::<Vec<_>>(),
+            "processed in order"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    cell::RefCell,
+    ffi::CString,
+    os::raw::c_char,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Once,
+    },
+};
+
+use crate::{
+    _RidEncode, _RidFfiDefault, _RidFrame, _RidMessageType, RID_PANIC_VARIANT,
+};
+
+// -----------------
+// Panics
+// -----------------
+// Unwinding out of an `extern "C"` function either is undefined behavior or aborts the entire
+// app. Therefore the body of each function rid exports to Dart runs inside [_catch_panic] and the
+// store update triggered by a message runs inside [_catch_request_panic].
+//
+// A caught panic is posted to Dart as a `RidMessageType.Severe` message whose details hold the
+// location and backtrace of the panic. Additionally Dart throws a `RidPanicException` from the
+// call that panicked:
+//
+// - exported functions and field accessors check [_panic_flag] right after calling into Rust and
+//   only resolve the [_panic_message] if it is set
+// - the Future returned by a message method completes with the exception when its store update
+//   panicked, which is posted as a frame with the [RID_PANIC_VARIANT] variant for that request
+
+struct PanicDetails {
+    location: String,
+    backtrace: String,
+}
+
+thread_local! {
+    // Recorded by the panic hook since the payload of a caught panic lacks its location
+    static PANIC_DETAILS: RefCell<Option<PanicDetails>> = const { RefCell::new(None) };
+    // Message of the panic caught during the last call from Dart on this thread
+    static LAST_PANIC: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+// Number of threads holding a caught panic which Dart didn't clear yet.
+// Dart reads it via the pointer returned by [_panic_flag] after each call into Rust, which is much
+// cheaper than calling [_panic_message] each time.
+static PENDING_PANICS: AtomicU32 = AtomicU32::new(0);
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+/// Wraps the current panic hook in order to record the location and backtrace of each panic.
+fn install_panic_hook() {
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let location = info
+                .location()
+                .map(|x| x.to_string())
+                .unwrap_or_else(|| "unknown location".to_string());
+            let backtrace = Backtrace::force_capture().to_string();
+            PANIC_DETAILS.with(|x| {
+                x.replace(Some(PanicDetails {
+                    location,
+                    backtrace,
+                }))
+            });
+            previous(info);
+        }));
+    });
+}
+
+/// A panic caught at the FFI boundary.
+struct CaughtPanic {
+    message: String,
+    details: String,
+}
+
+impl CaughtPanic {
+    fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        let message = if let Some(msg) = payload.downcast_ref::<&str>() {
+            msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            msg.clone()
+        } else {
+            "Box<dyn Any>".to_string()
+        };
+        let details = match PANIC_DETAILS.with(|x| x.take()) {
+            Some(PanicDetails {
+                location,
+                backtrace,
+            }) => format!("at {}\n{}", location, backtrace),
+            None => "location and backtrace are unknown".to_string(),
+        };
+        Self { message, details }
+    }
+
+    /// Posts the panic to Dart as a severe message.
+    /// If Dart did not initialize the message isolate, i.e. when running Rust tests, the panic is
+    /// only printed by the default panic hook.
+    fn report(&self) {
+        if crate::message::is_msg_isolate_initialized() {
+            crate::_post_message(
+                _RidMessageType::Severe,
+                format!("Rust panicked: {}", self.message),
+                Some(self.details.clone()),
+            );
+        }
+    }
+}
+
+/// Runs the body of a function exported to Dart and catches a panic raised by it.
+///
+/// A caught panic is reported to Dart and recorded for Dart to throw it once the function
+/// returned. In that case the [_RidFfiDefault] value is returned instead which Dart discards.
+pub fn _catch_panic<R: _RidFfiDefault>(f: impl FnOnce() -> R) -> R {
+    install_panic_hook();
+    _clear_panic();
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(ret) => ret,
+        Err(payload) => {
+            let panic = CaughtPanic::from_payload(payload);
+            panic.report();
+            let message = CString::new(panic.message.replace('\0', ""))
+                .expect("nul bytes were removed from the message");
+            LAST_PANIC.with(|x| {
+                if x.replace(Some(message)).is_none() {
+                    PENDING_PANICS.fetch_add(1, Ordering::SeqCst);
+                }
+            });
+            R::_rid_ffi_default()
+        }
+    }
+}
+
+/// Runs the store update for the request with the given id and catches a panic raised by it.
+///
+/// A caught panic is reported to Dart which completes the request with an exception.
+pub fn _catch_request_panic(req_id: u64, f: impl FnOnce()) {
+    install_panic_hook();
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(f)) {
+        let panic = CaughtPanic::from_payload(payload);
+        panic.report();
+        if crate::post::is_reply_isolate_initialized() {
+            let mut frame = _RidFrame::new(RID_PANIC_VARIANT, Some(req_id));
+            panic.message._rid_encode(frame.writer());
+            panic.details._rid_encode(frame.writer());
+            crate::post(frame);
+        }
+    }
+}
+
+/// Message of the panic caught during the last call from Dart or a null pointer if that call
+/// didn't panic.
+/// The pointer stays valid until the panic is cleared.
+pub fn _panic_message() -> *const c_char {
+    LAST_PANIC.with(|x| match &*x.borrow() {
+        Some(msg) => msg.as_ptr(),
+        None => ptr::null(),
+    })
+}
+
+/// Drops the panic caught during the last call from Dart if any.
+pub fn _clear_panic() {
+    LAST_PANIC.with(|x| {
+        if x.replace(None).is_some() {
+            PENDING_PANICS.fetch_sub(1, Ordering::SeqCst);
+        }
+    });
+}
+
+/// Pointer to the number of threads holding a caught panic which wasn't cleared yet.
+/// Dart reads it once and then checks it after each call into Rust without calling back into
+/// Rust unless it is non-zero.
+pub fn _panic_flag() -> *const u32 {
+    // AtomicU32 has the same in-memory representation as u32
+    &PENDING_PANICS as *const AtomicU32 as *const u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RidVec;
+    use std::{ffi::CStr, sync::Mutex};
+
+    // Tests holding a caught panic change the process wide pending panics count and thus cannot
+    // run in parallel with the test checking it
+    static PENDING_PANICS_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn catch_panic_returns_value_or_default() {
+        let _lock = PENDING_PANICS_LOCK.lock().unwrap();
+        assert_eq!(_catch_panic(|| 42u32), 42, "returns value");
+        assert!(_panic_message().is_null(), "no panic recorded");
+
+        let ptr: *const u8 = _catch_panic(|| panic!("failed at {}", 1));
+        assert!(ptr.is_null(), "returns null pointer");
+        let msg = unsafe { CStr::from_ptr(_panic_message()) };
+        assert_eq!(msg.to_str().unwrap(), "failed at 1", "records message");
+
+        let vec: RidVec<u32> = _catch_panic(|| panic!("failed"));
+        assert_eq!(vec.length, 0, "returns empty vec");
+        vec.free();
+
+        assert!(_catch_panic(|| true), "returns value");
+        assert!(_panic_message().is_null(), "cleared by next call");
+    }
+
+    #[test]
+    fn panic_flag_is_set_until_panic_is_cleared() {
+        let _lock = PENDING_PANICS_LOCK.lock().unwrap();
+        let flag = || unsafe { *_panic_flag() };
+        let pending = flag();
+        assert_eq!(pending, 0, "no pending panics");
+        let _: u32 = _catch_panic(|| panic!("failed"));
+        assert_eq!(flag(), pending + 1, "set after panic");
+        let _: u32 = _catch_panic(|| panic!("failed again"));
+        assert_eq!(flag(), pending + 1, "counts each thread once");
+        _clear_panic();
+        assert_eq!(flag(), pending, "unset once cleared");
+        _clear_panic();
+        assert_eq!(flag(), pending, "clearing again is ignored");
+    }
+
+    #[test]
+    fn catch_panic_records_location() {
+        install_panic_hook();
+        let payload = panic::catch_unwind(|| panic!("boom")).unwrap_err();
+        let panic = CaughtPanic::from_payload(payload);
+        assert_eq!(panic.message, "boom", "message");
+        assert!(
+            panic.details.starts_with(&format!("at {}:", file!())),
+            "details start with location: {}",
+            panic.details
+        );
+    }
+
+    #[test]
+    fn catch_request_panic() {
+        let mut ran = false;
+        _catch_request_panic(1, || ran = true);
+        assert!(ran, "runs update");
+        _catch_request_panic(2, || panic!("failed update"));
+    }
+}
@@ -1,4 +1,7 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
 static mut RID_ISOLATE: Option<Isolate> = None;
+static RID_ISOLATE_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
 struct Isolate {
     _port: i64,
@@ -39,20 +42,6 @@ impl Isolate {
     }
 }
 
-/// Used by rid internally to post request results with associated topic
-pub fn _encode_with_id(enum_idx: i64, id: u64) -> i64 {
-    let val: i128 = (i64::MIN + (id << 16) as i64) as i128;
-    let val = (val | enum_idx as i128) as i64;
-    val
-}
-
-/// Used by rid internally to post request results with associated topic
-pub fn _encode_without_id(enum_idx: i64) -> i64 {
-    let val: i128 = i64::MIN as i128;
-    let val = (val | enum_idx as i128) as i64;
-    val
-}
-
 pub fn _init_reply_isolate(port: i64) {
     unsafe {
         if RID_ISOLATE.is_some() {
@@ -64,8 +53,17 @@ pub fn _init_reply_isolate(port: i64) {
         }
         RID_ISOLATE = Some(Isolate::new(port));
     }
+    RID_ISOLATE_INITIALIZED.store(true, Ordering::SeqCst);
+}
+
+/// Returns `true` once Dart initialized the isolate that replies are posted to.
+pub(crate) fn is_reply_isolate_initialized() -> bool {
+    RID_ISOLATE_INITIALIZED.load(Ordering::SeqCst)
 }
 
-pub fn post(reply: impl ::allo_isolate::IntoDart) {
-    Isolate::isolate().post(reply);
+/// Posts a reply to Dart, or captures it while testing via `rid::testing`.
+pub fn post(reply: impl ::allo_isolate::IntoDart + Send + 'static) {
+    if let Err(reply) = crate::capture::try_capture_reply(reply) {
+        Isolate::isolate().post(reply);
+    }
 }
@@ -0,0 +1,123 @@
+use std::cell::Cell;
+
+use crate::{_RidEncode, _RidFrame, RID_STORE_CHANGES_VARIANT};
+
+// -----------------
+// Store Changes
+// -----------------
+// The setters generated for each `#[rid::store]` field mark that field as changed. Once the
+// write lock of the store is released after an update, the set of fields changed while it was
+// held is posted to Dart as a frame with the [RID_STORE_CHANGES_VARIANT] variant and the bitmask
+// of changed fields as payload, where the bit at position `n` represents the `n`th store field.
+//
+// Changes are only collected on the thread holding the write lock of the store and only while
+// it is held. Thus setters called on other store instances, i.e. one created by a test or a
+// temporary store, are not reported as changes of the next update.
+//
+// Fields can also be modified directly through the write lock without using the setters. Thus
+// when the store was mutably accessed while the lock was held but no field was marked, all
+// fields are conservatively reported as changed.
+
+thread_local! {
+    /// Changes marked while this thread holds the write lock of the store, `None` otherwise.
+    static STORE_CHANGES: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Used by rid internally to collect the store fields changed on the current thread once it
+/// acquired the write lock of the store.
+pub fn _start_store_changes() {
+    STORE_CHANGES.with(|x| x.set(Some(0)));
+}
+
+/// Used by the generated store setters to mark the field at the given index as changed.
+/// Ignored unless the current thread holds the write lock of the store.
+pub fn _mark_store_field_changed(idx: u32) {
+    STORE_CHANGES.with(|x| {
+        if let Some(changes) = x.get() {
+            x.set(Some(changes | 1 << idx));
+        }
+    });
+}
+
+fn take_store_changes() -> u64 {
+    STORE_CHANGES.with(|x| x.take().unwrap_or(0))
+}
+
+fn take_store_changes_or(unmarked: u64) -> u64 {
+    match take_store_changes() {
+        0 => unmarked,
+        changes => changes,
+    }
+}
+
+/// Used by rid internally to post the fields changed while the write lock of the store was held
+/// to Dart and to stop collecting changes.
+/// The `unmarked` changes are posted instead if no field was marked, i.e. all fields if the store
+/// was mutably accessed and none otherwise.
+/// Changes are dropped if Dart did not initialize the reply isolate, i.e. when running Rust tests.
+pub fn _post_store_changes(unmarked: u64) {
+    let changes = take_store_changes_or(unmarked);
+    if changes == 0 || !crate::post::is_reply_isolate_initialized() {
+        return;
+    }
+    let mut frame = _RidFrame::new(RID_STORE_CHANGES_VARIANT, None);
+    changes._rid_encode(frame.writer());
+    crate::post(frame);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_changed_fields_until_taken() {
+        _start_store_changes();
+        _mark_store_field_changed(0);
+        _mark_store_field_changed(3);
+        _mark_store_field_changed(3);
+        _mark_store_field_changed(63);
+        assert_eq!(take_store_changes(), 1 | 1 << 3 | 1 << 63, "changes");
+        assert_eq!(take_store_changes(), 0, "reset after taking changes");
+
+        _start_store_changes();
+        _mark_store_field_changed(1);
+        _post_store_changes(0b111);
+        assert_eq!(take_store_changes(), 0, "reset after posting changes");
+
+        _start_store_changes();
+        _mark_store_field_changed(2);
+        assert_eq!(take_store_changes_or(0b111), 1 << 2, "marked changes");
+        _start_store_changes();
+        assert_eq!(take_store_changes_or(0b111), 0b111, "unmarked changes");
+        _start_store_changes();
+        assert_eq!(take_store_changes_or(0), 0, "no changes");
+    }
+
+    #[test]
+    fn ignores_changes_marked_outside_of_store_updates() {
+        // i.e. a setter called on a store created by a test
+        _mark_store_field_changed(4);
+
+        _start_store_changes();
+        _mark_store_field_changed(1);
+        assert_eq!(take_store_changes(), 1 << 1, "only changes of update");
+
+        // i.e. a setter called on a temporary store after the update
+        _mark_store_field_changed(4);
+        _start_store_changes();
+        assert_eq!(take_store_changes_or(0), 0, "no changes of next update");
+    }
+
+    #[test]
+    fn ignores_changes_marked_on_other_threads() {
+        _start_store_changes();
+        std::thread::spawn(|| {
+            _start_store_changes();
+            _mark_store_field_changed(4);
+        })
+        .join()
+        .unwrap();
+        _mark_store_field_changed(1);
+        assert_eq!(take_store_changes(), 1 << 1, "only changes of this thread");
+    }
+}
@@ -0,0 +1,405 @@
+use std::{
+    fmt,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use tracing::{
+    dispatcher::SetGlobalDefaultError,
+    field::{Field, Visit},
+    span, Event, Level, Metadata, Subscriber,
+};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    Layer,
+};
+
+use crate::{_RidEncode, _RidFrame, _RidWireWriter, RID_TRACE_VARIANT};
+
+// -----------------
+// Tracing
+// -----------------
+// Available with the `tracing` feature. Once a [RidTracingLayer] is part of the subscriber, each
+// time a span is entered or exited and each event is posted to Dart on the message channel as a
+// frame with the [RID_TRACE_VARIANT] variant. Dart exposes them via the `traces` stream of the
+// `RidMessageChannel` as `RidTraceEvent`s which `ridTracesToTimeline` converts into
+// `dart:developer` `Timeline` events.
+//
+// Records are dropped until Dart initialized the message channel.
+
+/// Implementation of [tracing_subscriber::Layer] which posts spans and events to Dart.
+///
+/// ```ignore
+/// use tracing_subscriber::{filter::LevelFilter, prelude::*};
+///
+/// tracing_subscriber::registry()
+///     .with(rid::RidTracingLayer::new().with_filter(LevelFilter::DEBUG))
+///     .init();
+/// ```
+pub struct RidTracingLayer {
+    post: fn(TraceRecord),
+}
+
+impl Default for RidTracingLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RidTracingLayer {
+    /// Creates a layer which posts all spans and events it receives, use
+    /// [Layer::with_filter] in order to limit them.
+    pub fn new() -> Self {
+        Self {
+            post: post_trace_record,
+        }
+    }
+}
+
+/// Installs a subscriber consisting only of a [RidTracingLayer] as the global default which can
+/// happen only once.
+pub fn init_tracing() -> Result<(), SetGlobalDefaultError> {
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(RidTracingLayer::new()),
+    )
+}
+
+/// Stored in the extensions of each span in order to post its fields whenever it is entered or
+/// exited and to measure how long it was entered.
+struct SpanData {
+    fields: Vec<(String, String)>,
+    // Spans of futures are entered again each time they are polled
+    entered_at: Vec<Instant>,
+}
+
+impl<S> Layer<S> for RidTracingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(
+        &self,
+        attrs: &span::Attributes<'_>,
+        id: &span::Id,
+        ctx: Context<'_, S>,
+    ) {
+        let mut fields = vec![];
+        attrs.record(&mut FieldVisitor(&mut fields));
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanData {
+                fields,
+                entered_at: vec![],
+            });
+        }
+    }
+
+    fn on_record(
+        &self,
+        id: &span::Id,
+        values: &span::Record<'_>,
+        ctx: Context<'_, S>,
+    ) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(data) = span.extensions_mut().get_mut::<SpanData>() {
+                values.record(&mut FieldVisitor(&mut data.fields));
+            }
+        }
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        let span = match ctx.span(id) {
+            Some(span) => span,
+            None => return,
+        };
+        let mut extensions = span.extensions_mut();
+        let fields = match extensions.get_mut::<SpanData>() {
+            Some(data) => {
+                data.entered_at.push(Instant::now());
+                data.fields.clone()
+            }
+            None => vec![],
+        };
+        (self.post)(TraceRecord::new(
+            TraceKind::SpanEnter,
+            span.metadata(),
+            Some(id.into_u64()),
+            span.parent().map(|x| x.id().into_u64()),
+            fields,
+            None,
+        ));
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        let span = match ctx.span(id) {
+            Some(span) => span,
+            None => return,
+        };
+        let mut extensions = span.extensions_mut();
+        let (fields, duration) = match extensions.get_mut::<SpanData>() {
+            Some(data) => (
+                data.fields.clone(),
+                data.entered_at
+                    .pop()
+                    .map(|x| x.elapsed().as_micros() as u64),
+            ),
+            None => (vec![], None),
+        };
+        (self.post)(TraceRecord::new(
+            TraceKind::SpanExit,
+            span.metadata(),
+            Some(id.into_u64()),
+            span.parent().map(|x| x.id().into_u64()),
+            fields,
+            duration,
+        ));
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = vec![];
+        event.record(&mut FieldVisitor(&mut fields));
+        (self.post)(TraceRecord::new(
+            TraceKind::Event,
+            event.metadata(),
+            None,
+            ctx.event_span(event).map(|x| x.id().into_u64()),
+            fields,
+            None,
+        ));
+    }
+}
+
+/// Collects field values formatted via [fmt::Debug], except for strings which are kept as is.
+/// A value recorded again for a field replaces the previous one.
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl FieldVisitor<'_> {
+    fn set(&mut self, field: &Field, value: String) {
+        match self.0.iter_mut().find(|(name, _)| name == field.name()) {
+            Some((_, x)) => *x = value,
+            None => self.0.push((field.name().to_string(), value)),
+        }
+    }
+}
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.set(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.set(field, format!("{:?}", value));
+    }
+}
+
+/// Kind of a [TraceRecord], encoded as the index of the matching `RidTraceKind` in Dart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TraceKind {
+    SpanEnter,
+    SpanExit,
+    Event,
+}
+
+/// A span that was entered or exited or an event posted to Dart.
+struct TraceRecord {
+    kind: TraceKind,
+    name: String,
+    target: String,
+    level: Level,
+    /// Id of the span that was entered or exited, `None` for events.
+    span_id: Option<u64>,
+    /// Id of the parent of the span or of the span the event occurred in.
+    parent_id: Option<u64>,
+    fields: Vec<(String, String)>,
+    file: Option<String>,
+    line: Option<u32>,
+    /// Microseconds since the Unix epoch at which the span was entered or exited or the event
+    /// occurred.
+    timestamp: u64,
+    /// Microseconds the span was entered for, only provided when it is exited.
+    duration: Option<u64>,
+}
+
+impl TraceRecord {
+    fn new(
+        kind: TraceKind,
+        metadata: &Metadata<'_>,
+        span_id: Option<u64>,
+        parent_id: Option<u64>,
+        fields: Vec<(String, String)>,
+        duration: Option<u64>,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_micros() as u64)
+            .unwrap_or(0);
+        Self {
+            kind,
+            name: metadata.name().to_string(),
+            target: metadata.target().to_string(),
+            level: *metadata.level(),
+            span_id,
+            parent_id,
+            fields,
+            file: metadata.file().map(String::from),
+            line: metadata.line(),
+            timestamp,
+            duration,
+        }
+    }
+}
+
+/// Encoded in the order Dart decodes it inside `_message_channel.dart`, the level is encoded as
+/// the index of the matching `RidLogLevel`.
+impl _RidEncode for TraceRecord {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        (self.kind as u8)._rid_encode(writer);
+        self.name._rid_encode(writer);
+        self.target._rid_encode(writer);
+        level_index(self.level)._rid_encode(writer);
+        self.span_id._rid_encode(writer);
+        self.parent_id._rid_encode(writer);
+        writer.write_len(self.fields.len());
+        for (name, value) in &self.fields {
+            name._rid_encode(writer);
+            value._rid_encode(writer);
+        }
+        self.file._rid_encode(writer);
+        self.line._rid_encode(writer);
+        self.timestamp._rid_encode(writer);
+        self.duration._rid_encode(writer);
+    }
+}
+
+fn level_index(level: Level) -> u8 {
+    match level {
+        Level::ERROR => 0,
+        Level::WARN => 1,
+        Level::INFO => 2,
+        Level::DEBUG => 3,
+        Level::TRACE => 4,
+    }
+}
+
+fn post_trace_record(record: TraceRecord) {
+    if crate::message::is_msg_isolate_initialized() {
+        let mut frame = _RidFrame::new(RID_TRACE_VARIANT, None);
+        record._rid_encode(frame.writer());
+        crate::message::post_msg_frame(frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static RECORDS: RefCell<Vec<TraceRecord>> = const { RefCell::new(vec![]) };
+    }
+
+    fn collect(f: impl FnOnce()) -> Vec<TraceRecord> {
+        let layer = RidTracingLayer {
+            post: |record| RECORDS.with(|x| x.borrow_mut().push(record)),
+        };
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, f);
+        RECORDS.with(|x| x.take())
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn posts_spans_and_events() {
+        let records = collect(|| {
+            let span = tracing::info_span!("load", id = 1, path = "todos");
+            let _guard = span.enter();
+            tracing::debug!(count = 2, "loaded");
+        });
+        assert_eq!(records.len(), 3, "records");
+
+        let (enter, event, exit) = (&records[0], &records[1], &records[2]);
+        assert_eq!(enter.kind, TraceKind::SpanEnter, "enter kind");
+        assert_eq!(enter.name, "load", "enter name");
+        assert_eq!(enter.level, Level::INFO, "enter level");
+        assert_eq!(
+            enter.fields,
+            fields(&[("id", "1"), ("path", "todos")]),
+            "enter fields"
+        );
+        assert!(enter.span_id.is_some(), "enter span id");
+        assert_eq!(enter.parent_id, None, "enter parent");
+        assert_eq!(enter.duration, None, "enter duration");
+
+        assert_eq!(event.kind, TraceKind::Event, "event kind");
+        assert_eq!(event.level, Level::DEBUG, "event level");
+        assert_eq!(
+            event.fields,
+            fields(&[("message", "loaded"), ("count", "2")]),
+            "event fields"
+        );
+        assert_eq!(event.span_id, None, "event span id");
+        assert_eq!(event.parent_id, enter.span_id, "event parent");
+
+        assert_eq!(exit.kind, TraceKind::SpanExit, "exit kind");
+        assert_eq!(exit.span_id, enter.span_id, "exit span id");
+        assert!(exit.duration.is_some(), "exit duration");
+    }
+
+    #[test]
+    fn records_span_fields_after_creation() {
+        let records = collect(|| {
+            let span = tracing::info_span!(
+                "save",
+                id = 1,
+                saved = tracing::field::Empty
+            );
+            span.record("id", 2);
+            span.record("saved", true);
+            span.in_scope(|| {});
+        });
+        assert_eq!(
+            records[0].fields,
+            fields(&[("id", "2"), ("saved", "true")]),
+            "fields"
+        );
+    }
+
+    #[test]
+    fn encodes_record() {
+        let record = TraceRecord {
+            kind: TraceKind::SpanExit,
+            name: "load".to_string(),
+            target: "todo".to_string(),
+            level: Level::WARN,
+            span_id: Some(1),
+            parent_id: None,
+            fields: fields(&[("id", "1")]),
+            file: Some("src/app.rs".to_string()),
+            line: Some(3),
+            timestamp: 4,
+            duration: Some(5),
+        };
+        let mut writer = _RidWireWriter::new();
+        record._rid_encode(&mut writer);
+
+        let mut expected = _RidWireWriter::new();
+        1u8._rid_encode(&mut expected);
+        "load"._rid_encode(&mut expected);
+        "todo"._rid_encode(&mut expected);
+        1u8._rid_encode(&mut expected);
+        Some(1u64)._rid_encode(&mut expected);
+        None::<u64>._rid_encode(&mut expected);
+        expected.write_len(1);
+        "id"._rid_encode(&mut expected);
+        "1"._rid_encode(&mut expected);
+        Some("src/app.rs".to_string())._rid_encode(&mut expected);
+        Some(3u32)._rid_encode(&mut expected);
+        4u64._rid_encode(&mut expected);
+        Some(5u64)._rid_encode(&mut expected);
+        assert_eq!(writer.into_bytes(), expected.into_bytes());
+    }
+}
@@ -0,0 +1,660 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    iter::FromIterator,
+    slice,
+};
+
+// -----------------
+// Wire Format
+// -----------------
+// Values which cannot be passed across FFI directly, i.e. structs or collections that are part of
+// a message, are encoded by Dart into a buffer using the below layout and decoded here.
+// The same layout is used to encode the data of replies which Rust posts to Dart.
+//
+// - all numbers are little endian, `usize` is transferred as `u64`
+// - `bool` is a single byte which is `0` for `false`
+// - lengths of strings and collections as well as data enum variant indexes are `u32`
+// - strings are transferred as their UTF-8 bytes prefixed with their length
+// - collections are prefixed with their length followed by their items, map entries are
+//   transferred as key followed by value
+// - an `Option` is a `bool` indicating if a value follows
+// - c-style enums are transferred as their `i32` discriminant
+// - structs are transferred as their fields in declaration order
+//
+// The buffer passed from Dart is prefixed with a `u32` holding the length of the encoded data.
+
+pub struct _RidWireReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    fallible: bool,
+    error: Option<String>,
+}
+
+impl<'a> _RidWireReader<'a> {
+    /// Creates a reader which panics when the data is malformed.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            offset: 0,
+            fallible: false,
+            error: None,
+        }
+    }
+
+    /// Creates a reader which records the first error instead of panicking when the data is
+    /// malformed, i.e. when it was read from a message log, see [Self::error].
+    /// All reads following an error return zeroed values.
+    pub fn fallible(bytes: &'a [u8]) -> Self {
+        Self {
+            fallible: true,
+            ..Self::new(bytes)
+        }
+    }
+
+    /// The first error encountered by a [Self::fallible] reader.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn fail(&mut self, err: String) {
+        if !self.fallible {
+            panic!("rid wire: {}", err);
+        }
+        if self.error.is_none() {
+            self.error = Some(err);
+        }
+        self.offset = self.bytes.len();
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> &'a [u8] {
+        let end = self.offset.saturating_add(len);
+        if end > self.bytes.len() {
+            self.fail(format!(
+                "tried to read {} bytes at offset {} of {} bytes",
+                len,
+                self.offset,
+                self.bytes.len()
+            ));
+            return &[];
+        }
+        let bytes = &self.bytes[self.offset..end];
+        self.offset = end;
+        bytes
+    }
+
+    /// Number of bytes that were not read yet.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    fn read_array<const N: usize>(&mut self) -> [u8; N] {
+        let mut array = [0u8; N];
+        let bytes = self.read_bytes(N);
+        if bytes.len() == N {
+            array.copy_from_slice(bytes);
+        }
+        array
+    }
+
+    pub fn read_u8(&mut self) -> u8 {
+        self.read_array::<1>()[0]
+    }
+
+    pub fn read_bool(&mut self) -> bool {
+        self.read_u8() != 0
+    }
+
+    pub fn read_u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.read_array())
+    }
+
+    pub fn read_i32(&mut self) -> i32 {
+        i32::from_le_bytes(self.read_array())
+    }
+
+    pub fn read_len(&mut self) -> usize {
+        self.read_u32() as usize
+    }
+
+    pub fn read_string(&mut self) -> String {
+        let len = self.read_len();
+        let bytes = self.read_bytes(len);
+        match String::from_utf8(bytes.to_vec()) {
+            Ok(s) => s,
+            Err(_) => {
+                self.fail("string is not valid UTF-8".to_string());
+                String::new()
+            }
+        }
+    }
+
+    /// Reads the `u32` index of a data enum variant which has to be one of the provided ones.
+    /// A [Self::fallible] reader returns the first variant for an invalid index.
+    pub fn read_variant(&mut self, enum_name: &str, variants: &[u32]) -> u32 {
+        let variant = self.read_u32();
+        if variants.contains(&variant) {
+            return variant;
+        }
+        self.fail(format!(
+            "enum {} does not include variant {}",
+            enum_name, variant
+        ));
+        variants.first().copied().unwrap_or(variant)
+    }
+
+    /// Reads the `i32` discriminant of a c-style enum which has to be one of the provided ones.
+    /// A [Self::fallible] reader returns the first discriminant for an invalid one.
+    pub fn read_discriminant(
+        &mut self,
+        enum_name: &str,
+        discriminants: &[i32],
+    ) -> i32 {
+        let discriminant = self.read_i32();
+        if discriminants.contains(&discriminant) {
+            return discriminant;
+        }
+        self.fail(format!(
+            "enum {} does not include discriminant {}",
+            enum_name, discriminant
+        ));
+        discriminants.first().copied().unwrap_or(discriminant)
+    }
+}
+
+pub struct _RidWireWriter {
+    bytes: Vec<u8>,
+}
+
+impl _RidWireWriter {
+    pub fn new() -> Self {
+        Self { bytes: vec![] }
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub fn write_u8(&mut self, val: u8) {
+        self.bytes.push(val);
+    }
+
+    pub fn write_bool(&mut self, val: bool) {
+        self.write_u8(if val { 1 } else { 0 });
+    }
+
+    pub fn write_u32(&mut self, val: u32) {
+        self.write_bytes(&val.to_le_bytes());
+    }
+
+    pub fn write_i32(&mut self, val: i32) {
+        self.write_bytes(&val.to_le_bytes());
+    }
+
+    pub fn write_len(&mut self, len: usize) {
+        self.write_u32(len as u32);
+    }
+
+    pub fn write_string(&mut self, val: &str) {
+        self.write_len(val.len());
+        self.write_bytes(val.as_bytes());
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl Default for _RidWireWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implemented by all types that can be decoded from the rid wire format.
+/// Implementations for structs and enums are generated via `#[rid::model]`.
+pub trait _RidDecode: Sized {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self;
+}
+
+/// Decodes a value from the length prefixed buffer Dart passed as a message argument.
+/// The buffer is owned and released by Dart once the message function returns.
+pub fn _decode_wire_arg<T: _RidDecode>(ptr: *const u8) -> T {
+    assert!(!ptr.is_null(), "rid wire: received null pointer");
+    let len = {
+        let prefix = unsafe { slice::from_raw_parts(ptr, 4) };
+        _RidWireReader::new(prefix).read_len()
+    };
+    let bytes = unsafe { slice::from_raw_parts(ptr.add(4), len) };
+    let mut reader = _RidWireReader::new(bytes);
+    T::_rid_decode(&mut reader)
+}
+
+/// Implemented by all types that can be encoded into the rid wire format.
+/// Implementations for structs and enums are generated via `#[rid::model]`.
+pub trait _RidEncode {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter);
+}
+
+/// Encodes data into a length prefixed buffer which is returned to Dart, i.e. the entire store
+/// state when converting it via `toDartEncoded`.
+/// The buffer needs to be released via [_free_wire_result] once Dart decoded it.
+pub fn _encode_wire_result(
+    encode: impl FnOnce(&mut _RidWireWriter),
+) -> *mut u8 {
+    let mut writer = _RidWireWriter::new();
+    // Reserve space for the length prefix which is known only after encoding
+    writer.write_u32(0);
+    encode(&mut writer);
+    let mut bytes = writer.into_bytes();
+    let len = (bytes.len() - 4) as u32;
+    bytes[..4].copy_from_slice(&len.to_le_bytes());
+    Box::into_raw(bytes.into_boxed_slice()) as *mut u8
+}
+
+/// Releases a buffer returned by [_encode_wire_result].
+pub fn _free_wire_result(ptr: *mut u8) {
+    if ptr.is_null() {
+        return;
+    }
+    let len = {
+        let prefix = unsafe { slice::from_raw_parts(ptr, 4) };
+        _RidWireReader::new(prefix).read_len()
+    };
+    let bytes = unsafe { slice::from_raw_parts_mut(ptr, len + 4) };
+    drop(unsafe { Box::from_raw(bytes as *mut [u8]) });
+}
+
+// -----------------
+// Primitives
+// -----------------
+macro_rules! impl_decode_for_number {
+    ($($ty:ty),*) => {
+        $(
+            impl _RidDecode for $ty {
+                fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+                    <$ty>::from_le_bytes(reader.read_array())
+                }
+            }
+        )*
+    };
+}
+
+impl_decode_for_number!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+macro_rules! impl_encode_for_number {
+    ($($ty:ty),*) => {
+        $(
+            impl _RidEncode for $ty {
+                fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+                    writer.write_bytes(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_encode_for_number!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl _RidDecode for usize {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        u64::_rid_decode(reader) as usize
+    }
+}
+
+impl _RidEncode for usize {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        (*self as u64)._rid_encode(writer);
+    }
+}
+
+impl _RidDecode for bool {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        reader.read_bool()
+    }
+}
+
+impl _RidEncode for bool {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        writer.write_bool(*self);
+    }
+}
+
+impl _RidDecode for String {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        reader.read_string()
+    }
+}
+
+impl _RidEncode for String {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        writer.write_string(self);
+    }
+}
+
+impl _RidEncode for &str {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        writer.write_string(self);
+    }
+}
+
+// -----------------
+// Option
+// -----------------
+impl<T: _RidDecode> _RidDecode for Option<T> {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        if reader.read_bool() {
+            Some(T::_rid_decode(reader))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: _RidEncode> _RidEncode for Option<T> {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        match self {
+            Some(val) => {
+                writer.write_bool(true);
+                val._rid_encode(writer);
+            }
+            None => writer.write_bool(false),
+        }
+    }
+}
+
+// -----------------
+// Collections
+// -----------------
+fn decode_items<T: _RidDecode, C: FromIterator<T>>(
+    reader: &mut _RidWireReader,
+) -> C {
+    let len = reader.read_len();
+    // Stops at the first error of a fallible reader instead of decoding a corrupt length
+    (0..len)
+        .map_while(|_| match reader.error {
+            Some(_) => None,
+            None => Some(T::_rid_decode(reader)),
+        })
+        .collect()
+}
+
+fn decode_entries<K: _RidDecode, V: _RidDecode, C: FromIterator<(K, V)>>(
+    reader: &mut _RidWireReader,
+) -> C {
+    let len = reader.read_len();
+    (0..len)
+        .map_while(|_| match reader.error {
+            Some(_) => None,
+            None => {
+                let key = K::_rid_decode(reader);
+                let val = V::_rid_decode(reader);
+                Some((key, val))
+            }
+        })
+        .collect()
+}
+
+fn encode_items<'a, T: _RidEncode + 'a>(
+    writer: &mut _RidWireWriter,
+    len: usize,
+    items: impl Iterator<Item = &'a T>,
+) {
+    writer.write_len(len);
+    for item in items {
+        item._rid_encode(writer);
+    }
+}
+
+fn encode_entries<'a, K: _RidEncode + 'a, V: _RidEncode + 'a>(
+    writer: &mut _RidWireWriter,
+    len: usize,
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+) {
+    writer.write_len(len);
+    for (key, val) in entries {
+        key._rid_encode(writer);
+        val._rid_encode(writer);
+    }
+}
+
+impl<T: _RidDecode> _RidDecode for Vec<T> {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        decode_items(reader)
+    }
+}
+
+impl<T: _RidDecode> _RidDecode for VecDeque<T> {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        decode_items(reader)
+    }
+}
+
+impl<T: _RidDecode + Eq + Hash> _RidDecode for HashSet<T> {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        decode_items(reader)
+    }
+}
+
+impl<T: _RidDecode + Ord> _RidDecode for BTreeSet<T> {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        decode_items(reader)
+    }
+}
+
+impl<K: _RidDecode + Eq + Hash, V: _RidDecode> _RidDecode for HashMap<K, V> {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        decode_entries(reader)
+    }
+}
+
+impl<K: _RidDecode + Ord, V: _RidDecode> _RidDecode for BTreeMap<K, V> {
+    fn _rid_decode(reader: &mut _RidWireReader) -> Self {
+        decode_entries(reader)
+    }
+}
+
+impl<T: _RidEncode> _RidEncode for Vec<T> {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        encode_items(writer, self.len(), self.iter())
+    }
+}
+
+impl<T: _RidEncode> _RidEncode for VecDeque<T> {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        encode_items(writer, self.len(), self.iter())
+    }
+}
+
+impl<T: _RidEncode> _RidEncode for HashSet<T> {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        encode_items(writer, self.len(), self.iter())
+    }
+}
+
+impl<T: _RidEncode> _RidEncode for BTreeSet<T> {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        encode_items(writer, self.len(), self.iter())
+    }
+}
+
+impl<K: _RidEncode, V: _RidEncode> _RidEncode for HashMap<K, V> {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        encode_entries(writer, self.len(), self.iter())
+    }
+}
+
+impl<K: _RidEncode, V: _RidEncode> _RidEncode for BTreeMap<K, V> {
+    fn _rid_encode(&self, writer: &mut _RidWireWriter) {
+        encode_entries(writer, self.len(), self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Writer(Vec<u8>);
+    impl Writer {
+        fn new() -> Self {
+            Self(vec![])
+        }
+        fn len(mut self, len: u32) -> Self {
+            self.0.extend_from_slice(&len.to_le_bytes());
+            self
+        }
+        fn bytes(mut self, bytes: &[u8]) -> Self {
+            self.0.extend_from_slice(bytes);
+            self
+        }
+        fn string(self, s: &str) -> Self {
+            self.len(s.len() as u32).bytes(s.as_bytes())
+        }
+        fn decode<T: _RidDecode>(self) -> T {
+            let mut buf = (self.0.len() as u32).to_le_bytes().to_vec();
+            buf.extend_from_slice(&self.0);
+            _decode_wire_arg(buf.as_ptr())
+        }
+    }
+
+    #[test]
+    fn primitives() {
+        let n: u8 = Writer::new().bytes(&[7]).decode();
+        assert_eq!(n, 7, "u8");
+        let n: i32 = Writer::new().bytes(&(-3i32).to_le_bytes()).decode();
+        assert_eq!(n, -3, "i32");
+        let n: usize = Writer::new().bytes(&42u64.to_le_bytes()).decode();
+        assert_eq!(n, 42, "usize");
+        let n: f64 = Writer::new().bytes(&1.5f64.to_le_bytes()).decode();
+        assert_eq!(n, 1.5, "f64");
+        let b: bool = Writer::new().bytes(&[1]).decode();
+        assert!(b, "bool");
+    }
+
+    #[test]
+    fn strings_and_options() {
+        let s: String = Writer::new().string("hello wörld").decode();
+        assert_eq!(s, "hello wörld", "string");
+
+        let some: Option<String> =
+            Writer::new().bytes(&[1]).string("hi").decode();
+        assert_eq!(some, Some("hi".to_string()), "some string");
+
+        let none: Option<String> = Writer::new().bytes(&[0]).decode();
+        assert_eq!(none, None, "none string");
+    }
+
+    #[test]
+    fn collections() {
+        let vec: Vec<u8> = Writer::new().len(3).bytes(&[1, 2, 3]).decode();
+        assert_eq!(vec, vec![1, 2, 3], "vec");
+
+        let nested: Vec<Vec<u8>> =
+            Writer::new().len(2).len(1).bytes(&[1]).len(0).decode();
+        assert_eq!(nested, vec![vec![1], vec![]], "nested vec");
+
+        let set: BTreeSet<u8> = Writer::new().len(2).bytes(&[2, 1]).decode();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![1, 2], "set");
+
+        let map: HashMap<String, u8> = Writer::new()
+            .len(2)
+            .string("one")
+            .bytes(&[1])
+            .string("two")
+            .bytes(&[2])
+            .decode();
+        assert_eq!(map.len(), 2, "map len");
+        assert_eq!(map["one"], 1, "map one");
+        assert_eq!(map["two"], 2, "map two");
+    }
+
+    fn roundtrip<T: _RidEncode + _RidDecode>(val: &T) -> T {
+        let mut writer = _RidWireWriter::new();
+        val._rid_encode(&mut writer);
+        Writer::new().bytes(&writer.into_bytes()).decode()
+    }
+
+    #[test]
+    fn fallible_reader() {
+        let bytes = Writer::new().len(1000).bytes(&[1, 2]).0;
+        let mut reader = _RidWireReader::fallible(&bytes);
+        let items = Vec::<u32>::_rid_decode(&mut reader);
+        assert_eq!(items, vec![0], "stops after truncated item");
+        assert_eq!(
+            reader.error(),
+            Some("tried to read 4 bytes at offset 4 of 6 bytes"),
+            "truncated"
+        );
+        assert_eq!(reader.read_u32(), 0, "zeroed after error");
+
+        let bytes = Writer::new().len(2).bytes(&[0xff, 0xfe]).0;
+        let mut reader = _RidWireReader::fallible(&bytes);
+        assert_eq!(reader.read_string(), "", "invalid string");
+        assert_eq!(reader.error(), Some("string is not valid UTF-8"), "utf8");
+
+        let bytes = Writer::new().len(7).0;
+        let mut reader = _RidWireReader::fallible(&bytes);
+        assert_eq!(reader.read_variant("Msg", &[0, 1]), 0, "first variant");
+        assert_eq!(
+            reader.error(),
+            Some("enum Msg does not include variant 7"),
+            "invalid variant"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "rid wire: enum Msg does not include variant 7")]
+    fn asserting_reader() {
+        let bytes = Writer::new().len(7).0;
+        _RidWireReader::new(&bytes).read_variant("Msg", &[0, 1]);
+    }
+
+    #[test]
+    fn encode_roundtrip() {
+        assert_eq!(roundtrip(&-7i64), -7, "i64");
+        assert_eq!(roundtrip(&42usize), 42, "usize");
+        assert_eq!(roundtrip(&2.5f32), 2.5, "f32");
+        assert!(roundtrip(&true), "bool");
+        assert_eq!(roundtrip(&"hällo".to_string()), "hällo", "string");
+        assert_eq!(roundtrip(&Some(3u8)), Some(3), "some");
+        assert_eq!(roundtrip::<Option<u8>>(&None), None, "none");
+        assert_eq!(
+            roundtrip(&vec![vec![1u16], vec![]]),
+            vec![vec![1], vec![]],
+            "nested vec"
+        );
+
+        let mut map = BTreeMap::new();
+        map.insert("one".to_string(), vec![Some(1u32)]);
+        map.insert("two".to_string(), vec![None]);
+        assert_eq!(roundtrip(&map), map, "map");
+    }
+
+    #[test]
+    fn encode_layout() {
+        let mut writer = _RidWireWriter::new();
+        Some("ab".to_string())._rid_encode(&mut writer);
+        1u32._rid_encode(&mut writer);
+        assert_eq!(
+            writer.into_bytes(),
+            vec![1, 2, 0, 0, 0, b'a', b'b', 1, 0, 0, 0],
+            "option tag, string length, string bytes, u32"
+        );
+    }
+
+    #[test]
+    fn encode_wire_result() {
+        let ptr = _encode_wire_result(|writer| {
+            "ab".to_string()._rid_encode(writer);
+            vec![1u8, 2]._rid_encode(writer);
+        });
+        let prefix = unsafe { slice::from_raw_parts(ptr, 4) };
+        assert_eq!(prefix, &[12, 0, 0, 0], "length prefix");
+
+        // Same layout as the buffers Dart passes as message arguments
+        let s: String = _decode_wire_arg(ptr);
+        assert_eq!(s, "ab", "decoded");
+        _free_wire_result(ptr);
+        _free_wire_result(std::ptr::null_mut());
+    }
+}
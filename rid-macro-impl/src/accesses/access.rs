@@ -2,9 +2,13 @@ use std::collections::HashMap;
 
 use proc_macro2::{Span, TokenStream};
 
-use crate::{attrs::TypeInfoMap, render_common::PointerTypeAlias};
+use crate::{
+    attrs::TypeInfoMap,
+    parse::rust_type::{Composite, RustType, TypeKind},
+    render_common::PointerTypeAlias,
+};
 
-use super::{HashMapAccess, VecAccess};
+use super::{HashMapAccess, SetAccess, VecAccess};
 
 /// Distinguishes between accesses that are references to fields on structs or enums vs.
 /// instances created during a method call and returned to Dart without keeping a reference
@@ -40,6 +44,51 @@ pub trait RenderableAccess {
     fn render_dart(&self, type_infos: &TypeInfoMap, comment: &str) -> String;
     fn key(&self) -> String;
     fn span(&self) -> Span;
+
+    /// Accesses needed to resolve collections nested inside the items of this collection,
+    /// i.e. the access for `Vec<u8>` when rendering the access for `Vec<Vec<u8>>`.
+    fn nested_accesses(&self) -> Vec<Box<dyn RenderableAccess>>;
+}
+
+/// Returns the [AccessKind::FieldReference] access needed to resolve the items of `ty` if it is
+/// a collection, looking through any `Option`s wrapping it.
+///
+/// Collections nested inside other collections, options or fields are references into memory
+/// owned by Rust and thus all share the same accesses.
+pub fn field_collection_access(
+    ty: &RustType,
+    ffi_prelude: &TokenStream,
+) -> Option<Box<dyn RenderableAccess>> {
+    match &ty.kind {
+        TypeKind::Composite(Composite::Vec | Composite::VecDeque, _, _) => {
+            Some(Box::new(VecAccess::new(
+                ty,
+                ty.rust_ident().clone(),
+                AccessKind::FieldReference,
+                ffi_prelude,
+            )))
+        }
+        TypeKind::Composite(Composite::HashMap | Composite::BTreeMap, _, _) => {
+            Some(Box::new(HashMapAccess::new(
+                ty,
+                ty.rust_ident(),
+                AccessKind::FieldReference,
+                ffi_prelude,
+            )))
+        }
+        TypeKind::Composite(Composite::HashSet | Composite::BTreeSet, _, _) => {
+            Some(Box::new(SetAccess::new(
+                ty,
+                ty.rust_ident(),
+                AccessKind::FieldReference,
+                ffi_prelude,
+            )))
+        }
+        TypeKind::Composite(Composite::Option, Some(inner), _) => {
+            field_collection_access(inner, ffi_prelude)
+        }
+        _ => None,
+    }
 }
 
 impl RenderableAccess for VecAccess {
@@ -62,15 +111,18 @@ impl RenderableAccess for VecAccess {
     }
 
     fn key(&self) -> String {
-        VecAccess::key_from_item_rust_ident(
-            self.item_type.rust_ident(),
-            &self.kind,
-        )
+        VecAccess::key_from_vec_type(&self.vec_type, &self.kind)
     }
 
     fn span(&self) -> Span {
         self.vec_type_ident.span()
     }
+
+    fn nested_accesses(&self) -> Vec<Box<dyn RenderableAccess>> {
+        field_collection_access(&self.item_type, &self.rust_ffi_prelude)
+            .into_iter()
+            .collect()
+    }
 }
 
 impl RenderableAccess for HashMapAccess {
@@ -93,14 +145,50 @@ impl RenderableAccess for HashMapAccess {
     }
 
     fn key(&self) -> String {
-        Self::key_from_item_rust_ident(
-            self.key_type.rust_ident(),
-            self.val_type.rust_ident(),
-            &self.kind,
-        )
+        Self::key_from_hash_map_type(&self.hash_map_type, &self.kind)
     }
 
     fn span(&self) -> Span {
         self.hash_map_type_ident.span()
     }
+
+    /// Values are resolved via the exported `get` accessor which renders the accesses for
+    /// nested collections itself.
+    fn nested_accesses(&self) -> Vec<Box<dyn RenderableAccess>> {
+        vec![]
+    }
+}
+
+impl RenderableAccess for SetAccess {
+    fn render_rust(&self) -> RenderedAccessRust {
+        match self.kind {
+            AccessKind::FieldReference => self.render_rust_field_access(),
+            AccessKind::MethodReturn => self.render_rust_method_return(),
+        }
+    }
+
+    fn render_dart(&self, type_infos: &TypeInfoMap, comment: &str) -> String {
+        match self.kind {
+            AccessKind::FieldReference => {
+                self.render_dart_for_field_reference(type_infos, comment)
+            }
+            AccessKind::MethodReturn => {
+                self.render_dart_return_from_method(type_infos, comment)
+            }
+        }
+    }
+
+    fn key(&self) -> String {
+        Self::key_from_set_type(&self.set_type, &self.kind)
+    }
+
+    fn span(&self) -> Span {
+        self.set_type_ident.span()
+    }
+
+    /// Items are copied into a Vec by the exported `items` accessor which renders the
+    /// accesses it needs itself.
+    fn nested_accesses(&self) -> Vec<Box<dyn RenderableAccess>> {
+        vec![]
+    }
 }
@@ -38,8 +38,8 @@ pub fn collection_item_access_tokens(
             quote! {
                 let s: &String = unsafe {
                     assert!(!#ptr_ident.is_null());
-                    let #ptr_ident: *mut String = #ptr_ident as *mut String;
-                    #ptr_ident.as_mut().expect("resolve ptr from collection item as_mut failed")
+                    let #ptr_ident: *const String = #ptr_ident as *const String;
+                    #ptr_ident.as_ref().expect("resolve ptr from collection item as_ref failed")
                 };
                 let cstring = ::std::ffi::CString::new(s.as_str()).unwrap();
                 cstring.into_raw()
@@ -48,8 +48,8 @@ pub fn collection_item_access_tokens(
             quote! {
                 let cstring: &::std::ffi::CString = unsafe {
                     assert!(!#ptr_ident.is_null());
-                    let #ptr_ident: *mut ::std::ffi::CString = #ptr_ident as *mut ::std::ffi::CString;
-                    #ptr_ident.as_mut().expect("resolve_ptr.as_mut failed")
+                    let #ptr_ident: *const ::std::ffi::CString = #ptr_ident as *const ::std::ffi::CString;
+                    #ptr_ident.as_ref().expect("resolve_ptr.as_ref failed")
                 };
                 cstring.clone().into_raw()
             }
@@ -57,9 +57,9 @@ pub fn collection_item_access_tokens(
             quote! {
                 let s: &str = unsafe {
                     assert!(!#ptr_ident.is_null());
-                    let #ptr_ident: *mut str = #ptr_ident as *mut str;
-                    #ptr_ident.as_mut()
-                        .expect("resolve ptr from collection item as_mut failed")
+                    let #ptr_ident: *const str = #ptr_ident as *const str;
+                    #ptr_ident.as_ref()
+                        .expect("resolve ptr from collection item as_ref failed")
                 };
                 let cstring = ::std::ffi::CString::new(s).unwrap();
                 cstring.into_raw()
@@ -19,15 +19,42 @@ pub fn resolved_dart_item_type_string(
 pub fn map_to_dart_string(
     item_type: &RustType,
     dart_item_type: &str,
+    type_infos: &TypeInfoMap,
 ) -> String {
-    if item_type.is_struct() {
-        format!(".map((raw) => raw.toDart())")
+    if item_type.is_composite() || item_type.is_struct() {
+        format!(
+            ".map((raw) => {})",
+            item_to_dart_string(item_type, dart_item_type, type_infos, "raw")
+        )
     } else if item_type.is_enum() {
         format!(
-            ".map((x) => {enum_type}.values[x])",
-            enum_type = dart_item_type
+            ".map((x) => {})",
+            item_to_dart_string(item_type, dart_item_type, type_infos, "x")
         )
     } else {
         "".to_string()
     }
 }
+
+/// Renders the conversion of the single collection item held by `var` into Dart, which is the
+/// same conversion [map_to_dart_string] applies to all items.
+pub fn item_to_dart_string(
+    item_type: &RustType,
+    dart_item_type: &str,
+    type_infos: &TypeInfoMap,
+    var: &str,
+) -> String {
+    if item_type.is_composite() {
+        DartType::from(item_type, type_infos).render_to_dart_for_snippet(var)
+    } else if item_type.is_struct() {
+        format!("{}.toDart()", var)
+    } else if item_type.is_enum() {
+        format!(
+            "{enum_type}.values[{var}]",
+            enum_type = dart_item_type,
+            var = var
+        )
+    } else {
+        var.to_string()
+    }
+}
@@ -6,11 +6,12 @@ use quote::{format_ident, quote};
 
 use super::AccessKind;
 
+/// Access to a `HashMap` or a `BTreeMap`.
 pub struct HashMapAccess {
     /// Type of the HashMap
     pub hash_map_type: RustType,
 
-    /// Identifier of type of the hash map, i.e. `HashMap`
+    /// Identifier of type of the hash map, i.e. `HashMap` or `BTreeMap`
     pub hash_map_type_ident: Ident,
 
     /// Type of the key item enclosed by the HashMap
@@ -55,11 +56,7 @@ impl HashMapAccess {
             .key_val_composite_types()
             .expect("HashMap should have key/val types");
 
-        let key = Self::key_from_item_rust_ident(
-            key_type.rust_ident(),
-            val_type.rust_ident(),
-            &kind,
-        );
+        let key = Self::key_from_hash_map_type(hash_map_ty, &kind);
 
         let fn_len_ident = format_ident!("rid_len_{}", key);
         let fn_free_ident = format_ident!("rid_free_{}", key);
@@ -82,17 +79,26 @@ impl HashMapAccess {
         }
     }
 
-    pub fn key_from_item_rust_ident(
-        key_ident: &Ident,
-        val_ident: &Ident,
+    pub fn key_from_hash_map_type(
+        hash_map_type: &RustType,
         kind: &AccessKind,
     ) -> String {
+        let (key_type, val_type) = hash_map_type
+            .key_val_composite_types()
+            .expect("HashMap should have key/val types");
+        let key_ident = key_type.ffi_type_name();
+        let val_ident = val_type.ffi_type_name();
+        let map = if hash_map_type.is_ordered() {
+            "btree_map"
+        } else {
+            "hash_map"
+        };
         match kind {
             AccessKind::FieldReference => {
-                format!("hash_map_{}_{}", key_ident, val_ident)
+                format!("{}_{}_{}", map, key_ident, val_ident)
             }
             AccessKind::MethodReturn => {
-                format!("ridhash_map_{}_{}", key_ident, val_ident)
+                format!("rid{}_{}_{}", map, key_ident, val_ident)
             }
         }
         .to_lowercase()
@@ -3,6 +3,7 @@ mod collection_item_access_tokens;
 mod collection_item_conversions;
 mod hash_map;
 mod render_collection_accesses;
+mod set;
 mod vec;
 
 pub use access::*;
@@ -10,4 +11,5 @@ pub use collection_item_access_tokens::*;
 pub use collection_item_conversions::*;
 pub use hash_map::*;
 pub use render_collection_accesses::*;
+pub use set::*;
 pub use vec::*;
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap};
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
@@ -98,6 +98,8 @@ impl RenderDartAccessConfig {
 struct AggregatedRenderedAccesses {
     rust_tokens: Vec<TokenStream>,
     darts: Vec<String>,
+    /// Keys of the accesses whose Dart was rendered
+    dart_keys: Vec<String>,
 }
 
 pub fn render_collection_accesses(
@@ -109,7 +111,10 @@ pub fn render_collection_accesses(
     if accesses.is_empty() {
         return (TokenStream::new(), String::new());
     }
-    let first_key = accesses.keys().next().unwrap().clone();
+    // Sorted by key in order to render accesses deterministically
+    let mut accesses: Vec<(String, Box<dyn RenderableAccess>)> =
+        with_nested_accesses(accesses).into_iter().collect();
+    accesses.sort_by(|(a, _), (b, _)| a.cmp(b));
     let aggregated = aggregate_collection_accesses(
         accesses,
         type_infos,
@@ -137,7 +142,7 @@ pub fn render_collection_accesses(
     {
         let dart_tokens: TokenStream = rendered_dart.parse().unwrap();
         let fn_include_dart_ident =
-            format_ident!("__include_dart_for_{}", first_key);
+            format_ident!("__include_dart_for_{}", aggregated.dart_keys[0]);
         let ffi_prelude = &rust_config.ffi_prelude_tokens;
         quote! {
             #dart_tokens
@@ -163,8 +168,26 @@ pub fn render_collection_accesses(
     )
 }
 
+/// Adds the accesses of collections nested inside the provided ones, i.e. `Vec<u8>` for
+/// `Vec<Vec<u8>>`, recursively.
+fn with_nested_accesses(
+    mut accesses: HashMap<String, Box<dyn RenderableAccess>>,
+) -> HashMap<String, Box<dyn RenderableAccess>> {
+    let mut pending: Vec<Box<dyn RenderableAccess>> = accesses
+        .values()
+        .flat_map(|x| x.nested_accesses())
+        .collect();
+    while let Some(access) = pending.pop() {
+        if let Entry::Vacant(entry) = accesses.entry(access.key()) {
+            pending.extend(access.nested_accesses());
+            entry.insert(access);
+        }
+    }
+    accesses
+}
+
 fn aggregate_collection_accesses(
-    accesses: HashMap<String, Box<dyn RenderableAccess>>,
+    accesses: Vec<(String, Box<dyn RenderableAccess>)>,
     type_infos: &TypeInfoMap,
     rust_config: &RenderRustAccessConfig,
     dart_config: &RenderDartAccessConfig,
@@ -173,12 +196,14 @@ fn aggregate_collection_accesses(
         AggregatedRenderedAccesses {
             rust_tokens: vec![],
             darts: vec![],
+            dart_keys: vec![],
         }
     } else {
-        let aggregated = accesses.values().into_iter().fold(
+        let aggregated = accesses.iter().map(|(_, x)| x).fold(
             AggregatedRenderedAccesses {
                 rust_tokens: vec![],
                 darts: vec![],
+                dart_keys: vec![],
             },
             |mut accesses, x| {
                 let key = x.key();
@@ -229,6 +254,7 @@ fn aggregate_collection_accesses(
                     let dart: String =
                         x.render_dart(type_infos, &dart_config.comment);
                     accesses.darts.push(dart);
+                    accesses.dart_keys.push(key);
                 }
                 accesses
             },
@@ -0,0 +1,88 @@
+use proc_macro2::TokenStream;
+use syn::Ident;
+
+use crate::parse::rust_type::RustType;
+use quote::format_ident;
+
+use super::AccessKind;
+
+/// Access to a `HashSet` or a `BTreeSet`.
+pub struct SetAccess {
+    /// Type of the set
+    pub set_type: RustType,
+
+    /// Identifier of type of the set, i.e. `HashSet` or `BTreeSet`
+    pub set_type_ident: Ident,
+
+    /// Type of the item enclosed by the set
+    /// Example: `String`
+    pub item_type: RustType,
+
+    /// FFI prelude applied to generated rust functions
+    pub rust_ffi_prelude: TokenStream,
+
+    /// The kind of the set, i.e. returned from a method or a field
+    pub kind: AccessKind,
+
+    /// Name of function to get set length
+    pub fn_len_ident: Ident,
+
+    /// Name of function to query if set contains an item
+    pub fn_contains_ident: Ident,
+
+    /// Name under which the function to retrieve a Vec containing the items of
+    /// the set is exported
+    pub fn_items_ident: Ident,
+
+    /// Name of function to free set (not used for field access)
+    pub fn_free_ident: Ident,
+}
+
+impl SetAccess {
+    pub fn new(
+        set_ty: &RustType,
+        set_ty_ident: &Ident,
+        kind: AccessKind,
+        ffi_prelude: &TokenStream,
+    ) -> Self {
+        let item_type = set_ty
+            .inner_composite_type()
+            .expect("Set should have item type");
+
+        let key = Self::key_from_set_type(set_ty, &kind);
+
+        let fn_len_ident = format_ident!("rid_len_{}", key);
+        let fn_free_ident = format_ident!("rid_free_{}", key);
+        let fn_contains_ident = format_ident!("rid_contains_{}", key);
+        let fn_items_ident = format_ident!("rid_items_{}", key);
+
+        Self {
+            set_type: set_ty.clone(),
+            set_type_ident: set_ty_ident.clone(),
+            item_type,
+            rust_ffi_prelude: ffi_prelude.clone(),
+            fn_len_ident,
+            fn_free_ident,
+            fn_contains_ident,
+            fn_items_ident,
+            kind,
+        }
+    }
+
+    pub fn key_from_set_type(set_type: &RustType, kind: &AccessKind) -> String {
+        let item = set_type
+            .inner_composite_type()
+            .expect("Set should have item type")
+            .ffi_type_name();
+        let set = if set_type.is_ordered() {
+            "btree_set"
+        } else {
+            "hash_set"
+        };
+        match kind {
+            AccessKind::FieldReference => format!("{}_{}", set, item),
+            AccessKind::MethodReturn => format!("rid{}_{}", set, item),
+        }
+        .to_lowercase()
+    }
+}
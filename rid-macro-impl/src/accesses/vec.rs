@@ -7,7 +7,7 @@ use quote::{format_ident, quote};
 use super::AccessKind;
 
 pub struct VecAccess {
-    /// Type of the vector
+    /// Type of the vector, i.e. `Vec<u8>` or `VecDeque<u8>`
     pub vec_type: RustType,
 
     /// Identifier of type of the vector, i.e. `Vec`
@@ -48,7 +48,7 @@ impl VecAccess {
             .inner_composite_type()
             .expect("Vec should have inner type");
 
-        let key = Self::key_from_item_rust_ident(item_type.rust_ident(), &kind);
+        let key = Self::key_from_vec_type(vec_ty, &kind);
 
         let fn_len_ident = format_ident!("rid_len_{}", key);
         let fn_free_ident = format_ident!("rid_free_{}", key);
@@ -67,13 +67,19 @@ impl VecAccess {
         }
     }
 
-    pub fn key_from_item_rust_ident(
-        ident: &Ident,
-        kind: &AccessKind,
-    ) -> String {
+    /// Owned `VecDeque`s are converted to a `Vec` before they are returned and thus share the
+    /// [AccessKind::MethodReturn] accesses with `Vec`s of the same item type.
+    pub fn key_from_vec_type(vec_type: &RustType, kind: &AccessKind) -> String {
+        let item = vec_type
+            .inner_composite_type()
+            .expect("Vec should have inner type")
+            .ffi_type_name();
         match kind {
-            AccessKind::FieldReference => format!("vec_{}", ident),
-            AccessKind::MethodReturn => format!("ridvec_{}", ident),
+            AccessKind::FieldReference if vec_type.is_vec_deque() => {
+                format!("vec_deque_{}", item)
+            }
+            AccessKind::FieldReference => format!("vec_{}", item),
+            AccessKind::MethodReturn => format!("ridvec_{}", item),
         }
         .to_lowercase()
     }
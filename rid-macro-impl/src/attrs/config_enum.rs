@@ -43,7 +43,7 @@ impl EnumConfig {
                         "cannot have rid::export attribute on enums"
                     );
                 }
-                RidAttr::Rid(attr_ident, _) => {
+                RidAttr::Rid(attr_ident, _) | RidAttr::Name(attr_ident, _) => {
                     abort!(
                         attr_ident,
                         "cannot have rid() config attributes on enums"
@@ -46,7 +46,7 @@ impl FunctionConfig {
                     is_exported = true;
                     fn_export_alias = name.clone();
                 }
-                RidAttr::Rid(attr_ident, _) => {
+                RidAttr::Rid(attr_ident, _) | RidAttr::Name(attr_ident, _) => {
                     abort!(
                         attr_ident,
                         "cannot have config rid() attributes on function exports"
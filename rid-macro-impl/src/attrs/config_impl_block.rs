@@ -42,7 +42,7 @@ impl ImplBlockConfig {
                         is_exported = true;
                     }
                 }
-                RidAttr::Rid(attr_ident, _) => {
+                RidAttr::Rid(attr_ident, _) | RidAttr::Name(attr_ident, _) => {
                     abort!(
                         attr_ident,
                         "cannot have config rid() attributes on impl blocks"
@@ -9,6 +9,9 @@ use super::{add_idents_to_type_map, Category, RidAttr, TypeInfo, TypeInfoMap};
 #[derive(Debug, Clone)]
 pub struct StructConfig {
     pub debug: bool,
+    /// If `true` a single field struct is represented as its inner type in Dart,
+    /// configured via `#[rid::model(transparent)]`
+    pub transparent: bool,
     pub type_infos: TypeInfoMap,
     pub attrs: Vec<RidAttr>,
 }
@@ -42,7 +45,7 @@ impl StructConfig {
                         "cannot have rid::export attribute on structs"
                     );
                 }
-                RidAttr::Rid(attr_ident, _) => {
+                RidAttr::Rid(attr_ident, _) | RidAttr::Name(attr_ident, _) => {
                     abort!(
                         attr_ident,
                         "cannot have config rid() attributes on structs only on its fields"
@@ -53,6 +56,7 @@ impl StructConfig {
         }
         Self {
             debug,
+            transparent: false,
             type_infos,
             attrs,
         }
@@ -4,14 +4,18 @@ use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    token, Attribute, Expr, ExprTuple, Item, LitStr, Meta, MetaList,
-    NestedMeta, Path, PathSegment, Token, Type,
+    token, Attribute, Expr, ExprTuple, Item, Lit, LitStr, Meta, MetaList,
+    MetaNameValue, NestedMeta, Path, PathSegment, Token, Type,
 };
 
 use crate::common::abort;
 
 const RID_CONFIG_SKIP: &str = "skip";
-const RID_CONFIGS: &[&str; 1] = &[RID_CONFIG_SKIP];
+const RID_CONFIG_ERROR: &str = "error";
+const RID_CONFIG_PROGRESS: &str = "progress";
+const RID_CONFIG_NAME: &str = "name";
+const RID_CONFIGS: &[&str; 3] =
+    &[RID_CONFIG_SKIP, RID_CONFIG_ERROR, RID_CONFIG_PROGRESS];
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum RidAttr {
@@ -23,8 +27,11 @@ pub enum RidAttr {
     Message(Ident, syn::Ident),
     Export(Ident, Option<Ident>),
 
-    // Rid Config Attributes
+    // Rid Config Attributes, i.e. #[rid(skip)] or #[rid(error)] and #[rid(progress)] on a reply
+    // variant
     Rid(Ident, Vec<syn::Ident>),
+    // Name under which a field is exposed to Dart, i.e. #[rid(name = "id")]
+    Name(Ident, String),
 
     // -----------------
     // Derives
@@ -41,6 +48,31 @@ impl RidAttr {
             _ => false,
         }
     }
+
+    pub fn has_error(&self) -> bool {
+        match self {
+            RidAttr::Rid(_, idents) => idents
+                .iter()
+                .any(|x| x.to_string().as_str() == RID_CONFIG_ERROR),
+            _ => false,
+        }
+    }
+
+    pub fn has_progress(&self) -> bool {
+        match self {
+            RidAttr::Rid(_, idents) => idents
+                .iter()
+                .any(|x| x.to_string().as_str() == RID_CONFIG_PROGRESS),
+            _ => false,
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            RidAttr::Name(_, name) => Some(name.as_str()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -114,6 +146,30 @@ fn idents_from_nested(
     }
 }
 
+/// Extracts the value of `name` from `#[rid(name = "id")]`.
+/// `name` cannot be combined with other rid configs in the same attribute.
+fn name_from_nested(
+    nested: Option<&Punctuated<NestedMeta, Token![,]>>,
+) -> Option<String> {
+    let nested = nested?;
+    let name = nested.iter().find_map(|meta| match meta {
+        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+            path, lit, ..
+        })) if path.is_ident(RID_CONFIG_NAME) => Some(lit),
+        _ => None,
+    })?;
+    if nested.len() != 1 {
+        abort!(nested, "rid(name = \"..\") needs to be provided by itself, i.e. #[rid(name = \"id\")]")
+    }
+    match name {
+        Lit::Str(lit_str) => Some(lit_str.value()),
+        _ => abort!(
+            name,
+            "rid(name) needs to be a string, i.e. #[rid(name = \"id\")]"
+        ),
+    }
+}
+
 fn parse_segments(
     segments: &Punctuated<PathSegment, Token![::]>,
     nested: Option<&Punctuated<NestedMeta, Token![,]>>,
@@ -125,6 +181,9 @@ fn parse_segments(
         ) = (&segments[0], &segments[1]);
         match first.to_string().as_str() {
             "rid" => {
+                if let Some(name) = name_from_nested(nested) {
+                    return Some(RidAttr::Name(first.clone(), name));
+                }
                 let idents = idents_from_nested(nested);
                 match second.to_string().as_str() {
                     "structs" => {
@@ -182,6 +241,9 @@ fn parse_segments(
                 }
             }
             "rid" => {
+                if let Some(name) = name_from_nested(nested) {
+                    return Some(RidAttr::Name(first.clone(), name));
+                }
                 let idents = idents_from_nested(nested);
                 for ident in &idents {
                     if !RID_CONFIGS.contains(&ident.to_string().as_str()) {
@@ -31,8 +31,9 @@ pub fn missing_struct_enum_info(ident: &syn::Ident) -> TokenStream {
 pub fn missing_msg_field_enum_info(ident: &syn::Ident) -> TokenStream {
     abort!(
         ident,
-        "[rid] Missing info for type {0}.\nSpecify it via: #[rid::enums({0})]\n\n\
-        Note that for message fields only primitive types, strings and enums are supported.",
+        "[rid] Missing info for type {0}.\nSpecify it via: #[rid::enums({0})] or #[rid::structs({0})]\n\n\
+        Note that for message fields only primitive types, strings, #[rid::model] structs and enums \
+        as well as Vecs, Options and other collections of those are supported.",
         ident
     )
 }
@@ -25,6 +25,7 @@ pub struct ExpandState {
 pub enum ImplementationType {
     CollectionAccess,
     DartEnum,
+    ExportError,
     UtilsModule,
     Free,
 }
@@ -38,6 +39,9 @@ impl fmt::Display for ImplementationType {
             ImplementationType::DartEnum => {
                 write!(f, "DartEnum")
             }
+            ImplementationType::ExportError => {
+                write!(f, "ExportError")
+            }
             ImplementationType::Free => {
                 write!(f, "Free")
             }
@@ -1,47 +1,81 @@
 use crate::common::state::ImplementationType;
+use rid_common::STORE;
 
 use super::state::get_state;
 use quote::{format_ident, quote_spanned};
+use syn::spanned::Spanned;
 
 use proc_macro2::TokenStream;
 
+/// Resolves the pointer Dart passes to a shared reference of the instance it points to.
+///
+/// Dart only reads through these pointers and they may point into a store snapshot which other
+/// readers share, see `#[rid::store(snapshot)]`. Therefore no `&mut` is ever created from them.
 pub fn resolve_ptr(ty: &syn::Ident) -> TokenStream {
+    let resolve_store_ptr = resolve_store_ptr(&format_ident!("ptr"), ty, false);
     quote_spanned! { ty.span() =>
         unsafe {
             assert!(!ptr.is_null());
-            let ptr: *mut #ty = &mut *ptr;
-            ptr.as_mut().expect("resolve_ptr.as_mut failed")
+            #resolve_store_ptr
+            ptr.as_ref().expect("resolve_ptr.as_ref failed")
         }
     }
 }
 
-pub fn resolve_vec_ptr(ty: &syn::Ident) -> TokenStream {
-    quote_spanned! { ty.span() =>
-        unsafe {
-            assert!(!ptr.is_null());
-            let ptr: *mut Vec<#ty> = &mut *ptr;
-            ptr.as_mut().expect("resolve_vec_ptr.as_mut failed")
+/// Resolves the pointer to the store Dart passes via the store module since it may not point
+/// to the store data itself, see `#[rid::store(snapshot)]`.
+///
+/// The resolved pointer is `*const` unless it is `mutable`, i.e. for `&mut self` exports, which
+/// the store module only supports if Dart isn't reading a shared snapshot.
+pub fn resolve_store_ptr(
+    ptr: &syn::Ident,
+    ty: &syn::Ident,
+    mutable: bool,
+) -> TokenStream {
+    if ty != STORE {
+        return TokenStream::new();
+    }
+    if mutable {
+        quote_spanned! { ty.span() =>
+            let #ptr = store::_resolve_dart_ptr_mut(#ptr as *mut #ty);
+        }
+    } else {
+        quote_spanned! { ty.span() =>
+            let #ptr = store::_resolve_dart_ptr(#ptr as *const #ty);
         }
     }
 }
 
-pub fn resolve_hash_set_ptr(ty: &syn::Ident) -> TokenStream {
-    quote_spanned! { ty.span() =>
+pub fn resolve_vec_ptr(vec_ty: &TokenStream) -> TokenStream {
+    quote_spanned! { vec_ty.span() =>
         unsafe {
             assert!(!ptr.is_null());
-            let ptr: *mut ::std::collections::HashSet<#ty> = &mut *ptr;
-            ptr.as_mut().expect("resolve_hash_set_ptr.as_mut failed")
+            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
         }
     }
 }
 
+pub fn resolve_set_ptr(arg: &syn::Ident, set_ty: &TokenStream) -> TokenStream {
+    quote_spanned! { set_ty.span() =>
+        let #arg: &#set_ty = unsafe {
+            assert!(!#arg.is_null());
+            #arg.as_ref().expect("resolve_set_ptr.as_mut failed")
+        };
+    }
+}
+
+pub fn resolve_wire_arg(arg: &syn::Ident, ty: &TokenStream) -> TokenStream {
+    quote_spanned! { ty.span() =>
+        let #arg: #ty = rid::_decode_wire_arg(#arg);
+    }
+}
+
 pub fn resolve_hash_map_ptr(
     arg: &syn::Ident,
-    key_ty: &syn::Ident,
-    val_ty: &syn::Ident,
+    map_ty: &TokenStream,
 ) -> TokenStream {
-    quote_spanned! { key_ty.span() =>
-        let #arg: &HashMap<#key_ty, #val_ty> = unsafe {
+    quote_spanned! { map_ty.span() =>
+        let #arg: &#map_ty = unsafe {
             assert!(!#arg.is_null());
             #arg.as_ref().expect("resolve_hash_map_ptr.as_mut failed")
         };
@@ -1,6 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
-use rid_common::{CSTRING_FREE, UTILS_MODULE};
+use rid_common::{
+    CSTRING_FREE, EXPORT_ERROR_CLEAR, EXPORT_ERROR_MESSAGE, PANIC_CLEAR,
+    PANIC_FLAG, PANIC_MESSAGE, UTILS_MODULE,
+};
 
 use crate::common::state::{get_state, ImplementationType};
 
@@ -22,6 +25,9 @@ pub fn utils_module_tokens() -> TokenStream {
         let cstring_free = cstring_free();
         let init_msg_isolate = init_msg_isolate();
         let init_reply_isolate = init_reply_isolate();
+        let export_error = export_error();
+        let panic = panic();
+        let cancel_request = cancel_request();
         quote! {
             mod __rid_utils_module {
                 #str_struct_declaration
@@ -29,6 +35,9 @@ pub fn utils_module_tokens() -> TokenStream {
                 #cstring_free
                 #init_msg_isolate
                 #init_reply_isolate
+                #export_error
+                #panic
+                #cancel_request
             }
         }
     } else {
@@ -92,3 +101,56 @@ fn init_reply_isolate() -> TokenStream {
         }
     }
 }
+
+// -----------------
+// Export Errors
+// -----------------
+fn export_error() -> TokenStream {
+    let export_error_message_ident = format_ident!("{}", EXPORT_ERROR_MESSAGE);
+    let export_error_clear_ident = format_ident!("{}", EXPORT_ERROR_CLEAR);
+    quote! {
+        #[no_mangle]
+        pub extern "C" fn #export_error_message_ident() -> *const ::std::os::raw::c_char {
+            rid::_export_error_message()
+        }
+        #[no_mangle]
+        pub extern "C" fn #export_error_clear_ident() {
+            rid::_clear_export_error()
+        }
+    }
+}
+
+// -----------------
+// Panics
+// -----------------
+fn panic() -> TokenStream {
+    let panic_message_ident = format_ident!("{}", PANIC_MESSAGE);
+    let panic_clear_ident = format_ident!("{}", PANIC_CLEAR);
+    let panic_flag_ident = format_ident!("{}", PANIC_FLAG);
+    quote! {
+        #[no_mangle]
+        pub extern "C" fn #panic_flag_ident() -> *const u32 {
+            rid::_panic_flag()
+        }
+        #[no_mangle]
+        pub extern "C" fn #panic_message_ident() -> *const ::std::os::raw::c_char {
+            rid::_panic_message()
+        }
+        #[no_mangle]
+        pub extern "C" fn #panic_clear_ident() {
+            rid::_clear_panic()
+        }
+    }
+}
+
+// -----------------
+// Cancellation
+// -----------------
+fn cancel_request() -> TokenStream {
+    quote! {
+        #[no_mangle]
+        pub extern "C" fn rid_cancel_request(req_id: u64) {
+            rid::_cancel_request(req_id)
+        }
+    }
+}
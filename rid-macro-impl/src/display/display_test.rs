@@ -98,8 +98,7 @@ mod structs_display_impl {
                 pub extern "C" fn rid_single_display(ptr: *mut Single) -> *const ::std::os::raw::c_char {
                     let instance = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut Single = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     let s = instance.to_string();
                     let cstring = ::std::ffi::CString::new(s.as_str()).unwrap();
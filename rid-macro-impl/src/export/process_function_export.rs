@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use proc_macro2::TokenStream;
+use quote::quote;
 use syn::Ident;
 
 use crate::{
@@ -30,6 +31,7 @@ pub fn process_function_export(
             tokens,
             ptr_type_aliases,
             vec_access,
+            collection_accesses,
         } = render_rust::render_function_export(
             parsed_fn,
             impl_ident,
@@ -57,8 +59,48 @@ pub fn process_function_export(
             }
         }
         vec_access.map(|x| accesses.insert(x.key(), Box::new(x)));
+        for x in collection_accesses {
+            accesses.insert(x.key(), x);
+        }
+
+        let export_error_tokens = match &parsed_fn.return_err {
+            Some(err) => render_export_error(err, include_ffi),
+            None => TokenStream::new(),
+        };
+
+        quote! {
+            #tokens
+            #export_error_tokens
+        }
+    }
+}
 
-        tokens
+/// Renders the Dart exception class and the method to resolve the error for custom error types
+/// returned by fallible exports.
+/// Each error type is rendered only once even if multiple functions return it.
+fn render_export_error(err_type: &RustType, include_ffi: bool) -> TokenStream {
+    if !err_type.is_typed_export_error()
+        || !get_state().needs_implementation(
+            &ImplementationType::ExportError,
+            &err_type.rust_ident().to_string(),
+        )
+    {
+        return TokenStream::new();
+    }
+    let ffi_prelude = if include_ffi {
+        ffi_prelude()
+    } else {
+        TokenStream::new()
+    };
+    let dart_exception: TokenStream = err_type
+        .render_dart_export_exception_class("///")
+        .parse()
+        .unwrap();
+    let access =
+        render_rust::render_export_error_access(err_type, &ffi_prelude);
+    quote! {
+        #dart_exception
+        #access
     }
 }
 
@@ -17,4 +17,5 @@ pub use display::rid_display_impl;
 pub use export::rid_export_impl;
 pub use message::rid_message_impl;
 pub use model::rid_ffi_model_impl;
+pub use render_rust::render_catch_ffi_panics;
 pub use reply::rid_ffi_reply_impl;
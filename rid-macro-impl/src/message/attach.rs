@@ -22,13 +22,24 @@ pub fn rid_message_impl(
         Item::Enum(item) => {
             let rid_attrs = attrs::parse_rid_attrs(&item.attrs);
             let rid_args = parse_rid_args(args);
-            if rid_args.len() == 1 {
+            let threaded = match rid_args.get(1) {
+                None => false,
+                Some(ident) if ident == "threaded" => true,
+                Some(ident) => abort!(
+                    ident,
+                    "Unknown message option '{}'.\n\
+                    Example: #[rid::message(Reply, threaded)]",
+                    ident
+                ),
+            };
+            if rid_args.len() == 1 || rid_args.len() == 2 {
                 // NOTE: hardcode the store ident here instead of removing it everywhere in case we
                 // ever want to not rely on it being name 'Store' for the message implementation.
                 let enum_config = MessageEnumConfig::new(
                     &rid_attrs,
                     format_ident!("{}", STORE),
                     &rid_args[0],
+                    threaded,
                 );
                 let parsed_message_enum = ParsedMessageEnum::new(
                     &item.ident,
@@ -48,7 +59,7 @@ pub fn rid_message_impl(
                     item,
                     "\
                 Please specify exactly one reply type which is used\nto respond to messages.\n\
-                Example: #[rid::message(Reply)]"
+                Example: #[rid::message(Reply)] or #[rid::message(Reply, threaded)]"
                 )
             }
         }
@@ -13,6 +13,9 @@ pub struct MessageEnumConfig {
     pub type_infos: TypeInfoMap,
     pub to: Ident,
     pub reply: Ident,
+    /// If `true` messages are enqueued and applied to the store on a dedicated worker thread
+    /// instead of the thread that sent them, see `#[rid::message(Reply, threaded)]`.
+    pub threaded: bool,
 }
 
 impl MessageEnumConfig {
@@ -20,6 +23,7 @@ impl MessageEnumConfig {
         attrs: &[RidAttr],
         model_ident: Ident,
         reply_ident: &Ident,
+        threaded: bool,
     ) -> Self {
         let mut debug = false;
         let mut type_infos: TypeInfoMap = TypeInfoMap(HashMap::new());
@@ -47,7 +51,7 @@ impl MessageEnumConfig {
                         "cannot have rid::export attribute on enums"
                     );
                 }
-                RidAttr::Rid(attr_ident, _) => {
+                RidAttr::Rid(attr_ident, _) | RidAttr::Name(attr_ident, _) => {
                     abort!(
                         attr_ident,
                         "cannot have config rid() attributes on message enum"
@@ -61,6 +65,7 @@ impl MessageEnumConfig {
             type_infos,
             to: model_ident,
             reply: reply_ident.clone(),
+            threaded,
         }
     }
 }
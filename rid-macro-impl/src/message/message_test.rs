@@ -14,6 +14,14 @@ use super::render_message_enum::MessageRenderConfig;
 fn render(
     input: TokenStream,
     config: &MessageRenderConfig,
+) -> (TokenStream, String) {
+    render_with_threaded(input, config, false)
+}
+
+fn render_with_threaded(
+    input: TokenStream,
+    config: &MessageRenderConfig,
+    threaded: bool,
 ) -> (TokenStream, String) {
     let item = syn::parse2::<syn::Item>(input).unwrap();
     let rid_args: Vec<Ident> = vec![format_ident!("Reply")];
@@ -25,6 +33,7 @@ fn render(
                 &rid_attrs,
                 format_ident!("{}", STORE),
                 &rid_args[0],
+                threaded,
             );
             let parsed_enum = ParsedMessageEnum::new(
                 &item.ident,
@@ -80,35 +89,41 @@ mod msg_variants_without_fields {
                 use super::*;
                 fn rid_msg_Init(__rid_req_id: u64) {
                     let __rid_msg = Msg::Init;
-                    store::write().update(__rid_req_id, __rid_msg);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
                 }
             }
         };
         let expected_dart = r###"
           extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
 
-            Future<PostedReply> msgInit({Duration? timeout}) {
+            RidRequest<PostedReply> msgInit({Duration? timeout}) {
               final reqId = _replyChannel.reqId;
               rid_ffi.rid_msg_Init(reqId, );
 
-              final reply = _isDebugMode && rid.debugReply != null
-                  ? _replyChannel.reply(reqId).then((PostedReply reply) {
+              final msgCall = 'msgInit()';
+
+              _ridThrowIfPanicked(msgCall);
+              final request = _replyChannel.request(reqId, msgCall);
+              if (!_isDebugMode) return request;
+
+              final reply = rid.debugReply != null
+                  ? request.reply.then((PostedReply reply) {
                       if (rid.debugReply != null) rid.debugReply!(reply);
                       return reply;
                     })
-                  : _replyChannel.reply(reqId);
-
-              if (!_isDebugMode) return reply;
+                  : request.reply;
 
               timeout ??= rid.replyTimeout;
-              if (timeout == null) return reply;
-              final msgCall = 'msgInit() with reqId: $reqId';
-              return _replyWithTimeout(reply, msgCall, StackTrace.current, timeout);
+              if (timeout == null) return request.withReply(reply);
+              return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
             }
           }
 
           extension MsgApiFor_Store on Store {
-            Future<PostedReply> msgInit({Duration? timeout}) {
+            RidRequest<PostedReply> msgInit({Duration? timeout}) {
               return _store.msgInit(timeout: timeout);
             }
           }
@@ -134,61 +149,73 @@ mod msg_variants_without_fields {
                 use super::*;
                 fn rid_msg_Init(__rid_req_id: u64) {
                     let __rid_msg = Msg::Init;
-                    store::write().update(__rid_req_id, __rid_msg);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
                 }
                 fn rid_msg_Deinit(__rid_req_id: u64) {
                     let __rid_msg = Msg::Deinit;
-                    store::write().update(__rid_req_id, __rid_msg);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
                 }
             }
         };
         let expected_dart = r###"
           extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
 
-            Future<PostedReply> msgInit({Duration? timeout}) {
+            RidRequest<PostedReply> msgInit({Duration? timeout}) {
               final reqId = _replyChannel.reqId;
               rid_ffi.rid_msg_Init(reqId, );
 
-              final reply = _isDebugMode && rid.debugReply != null
-                  ? _replyChannel.reply(reqId).then((PostedReply reply) {
+              final msgCall = 'msgInit()';
+
+              _ridThrowIfPanicked(msgCall);
+              final request = _replyChannel.request(reqId, msgCall);
+              if (!_isDebugMode) return request;
+
+              final reply = rid.debugReply != null
+                  ? request.reply.then((PostedReply reply) {
                       if (rid.debugReply != null) rid.debugReply!(reply);
                       return reply;
                     })
-                  : _replyChannel.reply(reqId);
-
-              if (!_isDebugMode) return reply;
+                  : request.reply;
 
               timeout ??= rid.replyTimeout;
-              if (timeout == null) return reply;
-              final msgCall = 'msgInit() with reqId: $reqId';
-              return _replyWithTimeout(reply, msgCall, StackTrace.current, timeout);
+              if (timeout == null) return request.withReply(reply);
+              return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
             }
 
-            Future<PostedReply> msgDeinit({Duration? timeout}) {
+            RidRequest<PostedReply> msgDeinit({Duration? timeout}) {
               final reqId = _replyChannel.reqId;
               rid_ffi.rid_msg_Deinit(reqId, );
 
-              final reply = _isDebugMode && rid.debugReply != null
-                  ? _replyChannel.reply(reqId).then((PostedReply reply) {
+              final msgCall = 'msgDeinit()';
+
+              _ridThrowIfPanicked(msgCall);
+              final request = _replyChannel.request(reqId, msgCall);
+              if (!_isDebugMode) return request;
+
+              final reply = rid.debugReply != null
+                  ? request.reply.then((PostedReply reply) {
                       if (rid.debugReply != null) rid.debugReply!(reply);
                       return reply;
                     })
-                  : _replyChannel.reply(reqId);
-
-              if (!_isDebugMode) return reply;
+                  : request.reply;
 
               timeout ??= rid.replyTimeout;
-              if (timeout == null) return reply;
-              final msgCall = 'msgDeinit() with reqId: $reqId';
-              return _replyWithTimeout(reply, msgCall, StackTrace.current, timeout);
+              if (timeout == null) return request.withReply(reply);
+              return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
             }
           }
 
           extension MsgApiFor_Store on Store {
-            Future<PostedReply> msgInit({Duration? timeout}) {
+            RidRequest<PostedReply> msgInit({Duration? timeout}) {
               return _store.msgInit(timeout: timeout);
             }
-            Future<PostedReply> msgDeinit({Duration? timeout}) {
+            RidRequest<PostedReply> msgDeinit({Duration? timeout}) {
               return _store.msgDeinit(timeout: timeout);
             }
           }
@@ -223,35 +250,41 @@ mod msg_variants_with_primitive_fields {
                 use super::*;
                 fn rid_msg_Add(__rid_req_id: u64, arg0: u32) {
                     let __rid_msg = Msg::Add(arg0);
-                    store::write().update(__rid_req_id, __rid_msg);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
                 }
             }
         };
         let expected_dart = r###"
           extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
 
-            Future<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, {Duration? timeout}) {
+            RidRequest<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, {Duration? timeout}) {
               final reqId = _replyChannel.reqId;
               rid_ffi.rid_msg_Add(reqId, arg0);
 
-              final reply = _isDebugMode && rid.debugReply != null
-                  ? _replyChannel.reply(reqId).then((PostedReply reply) {
+              final msgCall = 'msgAdd($arg0)';
+
+              _ridThrowIfPanicked(msgCall);
+              final request = _replyChannel.request(reqId, msgCall);
+              if (!_isDebugMode) return request;
+
+              final reply = rid.debugReply != null
+                  ? request.reply.then((PostedReply reply) {
                       if (rid.debugReply != null) rid.debugReply!(reply);
                       return reply;
                     })
-                  : _replyChannel.reply(reqId);
-
-              if (!_isDebugMode) return reply;
+                  : request.reply;
 
               timeout ??= rid.replyTimeout;
-              if (timeout == null) return reply;
-              final msgCall = 'msgAdd($arg0) with reqId: $reqId';
-              return _replyWithTimeout(reply, msgCall, StackTrace.current, timeout);
+              if (timeout == null) return request.withReply(reply);
+              return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
             }
           }
 
           extension MsgApiFor_Store on Store {
-            Future<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, {Duration? timeout}) {
+            RidRequest<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, {Duration? timeout}) {
               return _store.msgAdd(arg0, timeout: timeout);
             }
           }
@@ -284,35 +317,42 @@ mod msg_variants_with_primitive_fields {
                         .expect("Received String that wasn't valid UTF-8.")
                         .to_string();
                     let __rid_msg = Msg::Add(arg0, arg1);
-                    store::write().update(__rid_req_id, __rid_msg);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
                 }
             }
         };
         let expected_dart = r###"
            extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
           
-             Future<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, String arg1, {Duration? timeout}) {
+             RidRequest<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, String arg1, {Duration? timeout}) {
                final reqId = _replyChannel.reqId;
                rid_ffi.rid_msg_Add(reqId, arg0, arg1.toNativeInt8());
           
-               final reply = _isDebugMode && rid.debugReply != null
-                   ? _replyChannel.reply(reqId).then((PostedReply reply) {
+               final msgCall = 'msgAdd($arg0, $arg1)';
+          
+               _ridThrowIfPanicked(msgCall);
+          
+               final request = _replyChannel.request(reqId, msgCall);
+               if (!_isDebugMode) return request;
+
+               final reply = rid.debugReply != null
+                   ? request.reply.then((PostedReply reply) {
                        if (rid.debugReply != null) rid.debugReply!(reply);
                        return reply;
                      })
-                   : _replyChannel.reply(reqId);
-          
-               if (!_isDebugMode) return reply;
-          
+                   : request.reply;
+
                timeout ??= rid.replyTimeout;
-               if (timeout == null) return reply;
-               final msgCall = 'msgAdd($arg0, $arg1) with reqId: $reqId';
-               return _replyWithTimeout(reply, msgCall, StackTrace.current, timeout);
+               if (timeout == null) return request.withReply(reply);
+               return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
              }
            }
           
            extension MsgApiFor_Store on Store {
-             Future<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, String arg1, {Duration? timeout}) {
+             RidRequest<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, String arg1, {Duration? timeout}) {
                return _store.msgAdd(arg0, arg1, timeout: timeout);
              }
            }
@@ -326,6 +366,70 @@ mod msg_variants_with_primitive_fields {
     }
 }
 
+// -----------------
+// Messages with float Fields
+// -----------------
+mod msg_variants_with_float_fields {
+    use super::*;
+
+    #[test]
+    fn msg_set_price() {
+        let msg = quote! {
+            pub enum Msg {
+                SetPrice(u32, f64)
+            }
+        };
+
+        let expected_rust = quote! {
+            mod __rid_msg_ffi {
+                use super::*;
+                fn rid_msg_SetPrice(__rid_req_id: u64, arg0: u32, arg1: f64) {
+                    let __rid_msg = Msg::SetPrice(arg0, arg1);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
+                }
+            }
+        };
+
+        let expected_dart = r###"
+          extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
+            RidRequest<PostedReply> msgSetPrice(@dart_ffi.Int32() int arg0, @dart_ffi.Double() double arg1, {Duration? timeout}) {
+              final reqId = _replyChannel.reqId;
+              rid_ffi.rid_msg_SetPrice(reqId, arg0, arg1);
+              final msgCall = 'msgSetPrice($arg0, $arg1)';
+              _ridThrowIfPanicked(msgCall);
+              final request = _replyChannel.request(reqId, msgCall);
+              if (!_isDebugMode) return request;
+
+              final reply = rid.debugReply != null
+                  ? request.reply.then((PostedReply reply) {
+                      if (rid.debugReply != null) rid.debugReply!(reply);
+                      return reply;
+                    })
+                  : request.reply;
+
+              timeout ??= rid.replyTimeout;
+              if (timeout == null) return request.withReply(reply);
+              return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
+            }
+          }
+          extension MsgApiFor_Store on Store {
+            RidRequest<PostedReply> msgSetPrice(@dart_ffi.Int32() int arg0, @dart_ffi.Double() double arg1, {Duration? timeout}) {
+              return _store.msgSetPrice(arg0, arg1, timeout: timeout);
+            }
+          }
+          "###;
+
+        let rust = render_rust(&msg);
+        let dart = render_dart(&msg);
+
+        assert_eq!(rust.to_string().trim(), expected_rust.to_string().trim());
+        assert_eq!(normalize_code(&dart), normalize_code(expected_dart));
+    }
+}
+
 // -----------------
 // Messages with enum Fields
 // -----------------
@@ -346,35 +450,41 @@ mod msg_variants_with_enum_fields {
                 use super::*;
                 fn rid_msg_SetFilter(__rid_req_id: u64, arg0: Filter) {
                     let __rid_msg = Msg::SetFilter(arg0);
-                    store::write().update(__rid_req_id, __rid_msg);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
                 }
             }
         };
         let expected_dart = r###"
             extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
 
-            Future<PostedReply> msgSetFilter(int arg0, {Duration? timeout}) {
+            RidRequest<PostedReply> msgSetFilter(int arg0, {Duration? timeout}) {
                 final reqId = _replyChannel.reqId;
                 rid_ffi.rid_msg_SetFilter(reqId, arg0);
 
-                final reply = _isDebugMode && rid.debugReply != null
-                    ? _replyChannel.reply(reqId).then((PostedReply reply) {
+                final msgCall = 'msgSetFilter($arg0)';
+
+                _ridThrowIfPanicked(msgCall);
+                final request = _replyChannel.request(reqId, msgCall);
+                if (!_isDebugMode) return request;
+
+                final reply = rid.debugReply != null
+                    ? request.reply.then((PostedReply reply) {
                         if (rid.debugReply != null) rid.debugReply!(reply);
                         return reply;
-                    })
-                    : _replyChannel.reply(reqId);
-
-                if (!_isDebugMode) return reply;
+                      })
+                    : request.reply;
 
                 timeout ??= rid.replyTimeout;
-                if (timeout == null) return reply;
-                final msgCall = 'msgSetFilter($arg0) with reqId: $reqId';
-                return _replyWithTimeout(reply, msgCall, StackTrace.current, timeout);
+                if (timeout == null) return request.withReply(reply);
+                return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
             }
             }
 
             extension MsgApiFor_Store on Store {
-            Future<PostedReply> msgSetFilter(Filter arg0, {Duration? timeout}) {
+            RidRequest<PostedReply> msgSetFilter(Filter arg0, {Duration? timeout}) {
                 return _store.msgSetFilter(arg0.index, timeout: timeout);
             }
             }
@@ -389,9 +499,410 @@ mod msg_variants_with_enum_fields {
 }
 
 // -----------------
-// Messages with struct Fields
+// Messages with named Fields
+// -----------------
+mod msg_variants_with_named_fields {
+    use super::*;
+
+    #[test]
+    fn msg_move_from_to() {
+        let msg = quote! {
+            pub enum Msg {
+                Move { from: u32, to: u32 },
+            }
+        };
+
+        let expected_rust = quote! {
+            mod __rid_msg_ffi {
+                use super::*;
+                fn rid_msg_Move(__rid_req_id: u64, arg0: u32, arg1: u32) {
+                    let __rid_msg = Msg::Move { from: arg0, to: arg1, };
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
+                }
+            }
+        };
+        let expected_dart = r###"
+            extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
+
+            RidRequest<PostedReply> msgMove({required int from, required int to, Duration? timeout}) {
+                final reqId = _replyChannel.reqId;
+                rid_ffi.rid_msg_Move(reqId, from, to);
+
+                final msgCall = 'msgMove(from: $from, to: $to)';
+
+                _ridThrowIfPanicked(msgCall);
+                final request = _replyChannel.request(reqId, msgCall);
+                if (!_isDebugMode) return request;
+
+                final reply = rid.debugReply != null
+                    ? request.reply.then((PostedReply reply) {
+                        if (rid.debugReply != null) rid.debugReply!(reply);
+                        return reply;
+                      })
+                    : request.reply;
+
+                timeout ??= rid.replyTimeout;
+                if (timeout == null) return request.withReply(reply);
+                return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
+            }
+            }
+
+            extension MsgApiFor_Store on Store {
+            RidRequest<PostedReply> msgMove({required int from, required int to, Duration? timeout}) {
+                return _store.msgMove(from: from, to: to, timeout: timeout);
+            }
+            }
+        "###;
+
+        let rust = render_rust(&msg);
+        let dart = render_dart(&msg);
+
+        assert_eq!(rust.to_string().trim(), expected_rust.to_string().trim());
+        assert_eq!(normalize_code(&dart), normalize_code(expected_dart));
+    }
+
+    #[test]
+    fn msg_update_todo_with_optional_field() {
+        let msg = quote! {
+            #[rid::enums(Filter)]
+            pub enum Msg {
+                UpdateTodo { todo_id: u32, filter: Filter, due_date: Option<String> },
+            }
+        };
+
+        let expected_rust = quote! {
+            mod __rid_msg_ffi {
+                use super::*;
+                fn rid_msg_UpdateTodo(
+                    __rid_req_id: u64,
+                    arg0: u32,
+                    arg1: Filter,
+                    arg2: *const u8
+                ) {
+                    let arg2: Option<String> = rid::_decode_wire_arg(arg2);
+                    let __rid_msg = Msg::UpdateTodo {
+                        todo_id: arg0,
+                        filter: arg1,
+                        due_date: arg2,
+                    };
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
+                }
+            }
+        };
+        let expected_dart = r###"
+            extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
+
+            RidRequest<PostedReply> msgUpdateTodo({required int todoId, required int filter, String? dueDate, Duration? timeout}) {
+                final reqId = _replyChannel.reqId;
+                final dueDatePtr = RidWireWriter.encode((w) {
+                  {
+                    final opt0 = dueDate;
+                    if (opt0 == null) {
+                      w.writeBool(false);
+                    } else {
+                      w.writeBool(true);
+                      w.writeString(opt0);
+                    }
+                  }
+                });
+                rid_ffi.rid_msg_UpdateTodo(reqId, todoId, filter, dueDatePtr);
+                RidWireWriter.free(dueDatePtr);
+
+                final msgCall = 'msgUpdateTodo(todoId: $todoId, filter: $filter, dueDate: $dueDate)';
+
+                _ridThrowIfPanicked(msgCall);
+                final request = _replyChannel.request(reqId, msgCall);
+                if (!_isDebugMode) return request;
+
+                final reply = rid.debugReply != null
+                    ? request.reply.then((PostedReply reply) {
+                        if (rid.debugReply != null) rid.debugReply!(reply);
+                        return reply;
+                      })
+                    : request.reply;
+
+                timeout ??= rid.replyTimeout;
+                if (timeout == null) return request.withReply(reply);
+                return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
+            }
+            }
+
+            extension MsgApiFor_Store on Store {
+            RidRequest<PostedReply> msgUpdateTodo({required int todoId, required Filter filter, String? dueDate, Duration? timeout}) {
+                return _store.msgUpdateTodo(todoId: todoId, filter: filter.index, dueDate: dueDate, timeout: timeout);
+            }
+            }
+        "###;
+
+        let rust = render_rust(&msg);
+        let dart = render_dart(&msg);
+
+        assert_eq!(rust.to_string().trim(), expected_rust.to_string().trim());
+        assert_eq!(normalize_code(&dart), normalize_code(expected_dart));
+    }
+}
+
+// -----------------
+// Messages with struct and collection Fields
+// -----------------
+// Those are encoded by Dart into the rid wire format and decoded on the Rust side. Thus the
+// message receives a copy of the value and never points into memory owned by Dart.
+mod msg_variants_with_wire_fields {
+    use super::*;
+
+    #[test]
+    fn msg_add_point() {
+        let msg = quote! {
+            #[rid::structs(Point)]
+            pub enum Msg {
+                AddPoint(Point),
+            }
+        };
+
+        let expected_rust = quote! {
+            mod __rid_msg_ffi {
+                use super::*;
+                fn rid_msg_AddPoint(__rid_req_id: u64, arg0: *const u8) {
+                    let arg0: Point = rid::_decode_wire_arg(arg0);
+                    let __rid_msg = Msg::AddPoint(arg0);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
+                }
+            }
+        };
+        let expected_dart = r###"
+            extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
+
+            RidRequest<PostedReply> msgAddPoint(Point arg0, {Duration? timeout}) {
+                final reqId = _replyChannel.reqId;
+                final arg0Ptr = RidWireWriter.encode((w) {
+                  _ridEncodePoint(w, arg0);
+                });
+                rid_ffi.rid_msg_AddPoint(reqId, arg0Ptr);
+                RidWireWriter.free(arg0Ptr);
+
+                final msgCall = 'msgAddPoint($arg0)';
+
+                _ridThrowIfPanicked(msgCall);
+                final request = _replyChannel.request(reqId, msgCall);
+                if (!_isDebugMode) return request;
+
+                final reply = rid.debugReply != null
+                    ? request.reply.then((PostedReply reply) {
+                        if (rid.debugReply != null) rid.debugReply!(reply);
+                        return reply;
+                      })
+                    : request.reply;
+
+                timeout ??= rid.replyTimeout;
+                if (timeout == null) return request.withReply(reply);
+                return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
+            }
+            }
+
+            extension MsgApiFor_Store on Store {
+            RidRequest<PostedReply> msgAddPoint(Point arg0, {Duration? timeout}) {
+                return _store.msgAddPoint(arg0, timeout: timeout);
+            }
+            }
+        "###;
+
+        let rust = render_rust(&msg);
+        let dart = render_dart(&msg);
+
+        assert_eq!(rust.to_string().trim(), expected_rust.to_string().trim());
+        assert_eq!(normalize_code(&dart), normalize_code(expected_dart));
+    }
+
+    #[test]
+    fn msg_add_points_with_id_and_filter() {
+        let msg = quote! {
+            #[rid::structs(Point)]
+            #[rid::enums(Filter)]
+            pub enum Msg {
+                AddPoints(u32, Vec<Point>, Option<Filter>),
+            }
+        };
+
+        let expected_rust = quote! {
+            mod __rid_msg_ffi {
+                use super::*;
+                fn rid_msg_AddPoints(
+                    __rid_req_id: u64,
+                    arg0: u32,
+                    arg1: *const u8,
+                    arg2: *const u8
+                ) {
+                    let arg1: Vec<Point> = rid::_decode_wire_arg(arg1);
+                    let arg2: Option<Filter> = rid::_decode_wire_arg(arg2);
+                    let __rid_msg = Msg::AddPoints(arg0, arg1, arg2);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    rid::_catch_request_panic(__rid_req_id, move || {
+                        store::write().update(__rid_req_id, __rid_msg);
+                    });
+                }
+            }
+        };
+        let expected_dart = r###"
+            extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
+
+            RidRequest<PostedReply> msgAddPoints(@dart_ffi.Int32() int arg0, List<Point> arg1, Filter? arg2, {Duration? timeout}) {
+                final reqId = _replyChannel.reqId;
+                final arg1Ptr = RidWireWriter.encode((w) {
+                  w.writeLen(arg1.length);
+                  for (final item0 in arg1) {
+                    _ridEncodePoint(w, item0);
+                  }
+                });
+                final arg2Ptr = RidWireWriter.encode((w) {
+                  {
+                    final opt0 = arg2;
+                    if (opt0 == null) {
+                      w.writeBool(false);
+                    } else {
+                      w.writeBool(true);
+                      w.writeI32(opt0.index);
+                    }
+                  }
+                });
+                rid_ffi.rid_msg_AddPoints(reqId, arg0, arg1Ptr, arg2Ptr);
+                RidWireWriter.free(arg1Ptr);
+                RidWireWriter.free(arg2Ptr);
+
+                final msgCall = 'msgAddPoints($arg0, $arg1, $arg2)';
+
+                _ridThrowIfPanicked(msgCall);
+                final request = _replyChannel.request(reqId, msgCall);
+                if (!_isDebugMode) return request;
+
+                final reply = rid.debugReply != null
+                    ? request.reply.then((PostedReply reply) {
+                        if (rid.debugReply != null) rid.debugReply!(reply);
+                        return reply;
+                      })
+                    : request.reply;
+
+                timeout ??= rid.replyTimeout;
+                if (timeout == null) return request.withReply(reply);
+                return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
+            }
+            }
+
+            extension MsgApiFor_Store on Store {
+            RidRequest<PostedReply> msgAddPoints(@dart_ffi.Int32() int arg0, List<Point> arg1, Filter? arg2, {Duration? timeout}) {
+                return _store.msgAddPoints(arg0, arg1, arg2, timeout: timeout);
+            }
+            }
+        "###;
+
+        let rust = render_rust(&msg);
+        let dart = render_dart(&msg);
+
+        assert_eq!(rust.to_string().trim(), expected_rust.to_string().trim());
+        assert_eq!(normalize_code(&dart), normalize_code(expected_dart));
+    }
+}
+
+// -----------------
+// Threaded Messages
 // -----------------
-// These are not supported since when sending a pointer to a struct we cannot be sure if it is
-// still valid or not due to store changes in between obtaining and sending it.
-// Therefore it is recommended to send an id instead in order to have the matching instance be
-// obtained fresh on the Rust side.
+mod msg_variants_threaded {
+    use super::*;
+
+    fn render_threaded(input: &TokenStream) -> (TokenStream, String) {
+        let rust = render_with_threaded(
+            input.clone(),
+            &MessageRenderConfig {
+                rust_only: true,
+                ..MessageRenderConfig::bare()
+            },
+            true,
+        )
+        .0;
+        let dart = render_with_threaded(
+            input.clone(),
+            &MessageRenderConfig {
+                dart_code_only: true,
+                ..MessageRenderConfig::bare()
+            },
+            true,
+        )
+        .1;
+        (rust, dart)
+    }
+
+    #[test]
+    fn msg_add_threaded() {
+        let msg = quote! {
+            pub enum Msg {
+                Add(u32),
+            }
+        };
+
+        let expected_rust = quote! {
+            mod __rid_msg_ffi {
+                use super::*;
+                fn rid_msg_Add(__rid_req_id: u64, arg0: u32) {
+                    let __rid_msg = Msg::Add(arg0);
+                    rid::_record_message(__rid_req_id, &__rid_msg);
+                    let __rid_cancel_token = rid::CancelToken::new(__rid_req_id);
+                    rid::_enqueue_message(Box::new(move || {
+                        rid::_catch_request_panic(__rid_req_id, move || {
+                            store::write().update(__rid_req_id, __rid_msg);
+                        });
+                        drop(__rid_cancel_token);
+                    }));
+                }
+                fn rid_msg_queue_depth() -> usize {
+                    rid::message_queue_depth()
+                }
+            }
+        };
+        let expected_dart = r###"
+          extension Rid_Message_ExtOnPointerStoreForMsg on dart_ffi.Pointer<ffigen_bind.RawStore> {
+
+            RidRequest<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, {Duration? timeout}) {
+              final reqId = _replyChannel.reqId;
+              rid_ffi.rid_msg_Add(reqId, arg0);
+
+              final msgCall = 'msgAdd($arg0)';
+
+              _ridThrowIfPanicked(msgCall);
+              final request = _replyChannel.request(reqId, msgCall);
+              if (!_isDebugMode) return request;
+
+              final reply = rid.debugReply != null
+                  ? request.reply.then((PostedReply reply) {
+                      if (rid.debugReply != null) rid.debugReply!(reply);
+                      return reply;
+                    })
+                  : request.reply;
+
+              timeout ??= rid.replyTimeout;
+              if (timeout == null) return request.withReply(reply);
+              return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
+            }
+          }
+
+          extension MsgApiFor_Store on Store {
+            RidRequest<PostedReply> msgAdd(@dart_ffi.Int32() int arg0, {Duration? timeout}) {
+              return _store.msgAdd(arg0, timeout: timeout);
+            }
+            /// Number of messages that were sent but not yet processed by Rust.
+            int get msgQueueDepth => rid_ffi.rid_msg_queue_depth();
+          }
+        "###;
+        let (rust, dart) = render_threaded(&msg);
+
+        assert_eq!(rust.to_string().trim(), expected_rust.to_string().trim());
+        assert_eq!(normalize_code(&dart), normalize_code(expected_dart));
+    }
+}
@@ -3,6 +3,7 @@ mod config_message_enum;
 pub mod parsed_message_enum;
 pub mod parsed_variant;
 pub mod render_message_enum;
+mod render_message_wire;
 mod store_msg_extensions;
 pub mod variant_field;
 
@@ -13,3 +14,5 @@ pub use variant_field::*;
 
 #[cfg(test)]
 pub mod message_test;
+#[cfg(test)]
+mod render_message_wire_test;
@@ -20,6 +20,11 @@ impl ParsedMessageVariant {
             fields,
         }
     }
+
+    /// Returns `true` if the fields of this variant are named, i.e. `Move { from: u32, to: u32 }`.
+    pub fn has_named_fields(&self) -> bool {
+        self.fields.iter().any(|x| x.is_named())
+    }
 }
 
 fn extract_fields(v: Variant, types: &TypeInfoMap) -> Vec<MessageVariantField> {
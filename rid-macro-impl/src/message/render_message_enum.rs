@@ -1,8 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned, IdentFragment};
 use rid_common::{
-    DART_ASYNC, DART_FFI, FFI_GEN_BIND, RID_DEBUG_REPLY, RID_FFI,
-    RID_MSG_TIMEOUT, STRING_TO_NATIVE_INT8, _RID_REPLY_CHANNEL,
+    _RID_REPLY_CHANNEL, DART_ASYNC, DART_FFI, FFI_GEN_BIND, RID_DEBUG_REPLY,
+    RID_FFI, RID_MSG_TIMEOUT, STRING_TO_NATIVE_INT8, THROW_IF_PANICKED,
 };
 use syn::Ident;
 
@@ -11,7 +11,7 @@ use crate::{
     common::{
         derive_error, prefixes::reply_class_name_for_enum, tokens::resolve_ptr,
     },
-    render_dart::RenderDartTypeOpts,
+    render_dart::{prefix_dart_lines, RenderDartTypeOpts},
     render_rust::{ffi_prelude, RustArg},
     reply,
 };
@@ -21,6 +21,7 @@ use super::{parsed_variant::ParsedMessageVariant, ParsedMessageEnum};
 pub struct MessageRenderConfig {
     pub include_ffi: bool,
     pub render_reply_check: bool,
+    pub render_wire_impls: bool,
     pub dart_code_only: bool,
     pub rust_only: bool,
     pub render_utils_module: bool,
@@ -31,6 +32,7 @@ impl Default for MessageRenderConfig {
         Self {
             include_ffi: true,
             render_reply_check: true,
+            render_wire_impls: true,
             dart_code_only: false,
             rust_only: false,
             render_utils_module: true,
@@ -43,6 +45,7 @@ impl MessageRenderConfig {
         Self {
             include_ffi: false,
             render_reply_check: false,
+            render_wire_impls: false,
             dart_code_only: false,
             rust_only: false,
             render_utils_module: false,
@@ -65,6 +68,11 @@ impl ParsedMessageEnum {
             .parsed_variants
             .iter()
             .map(|v| self.render_rust_method(v, config));
+        let queue_depth_tokens = if self.config.threaded {
+            self.render_rust_queue_depth(config)
+        } else {
+            TokenStream::new()
+        };
         let dart_comment = self.render_dart_extension(config);
         let module_ident = &self.module_ident;
 
@@ -74,6 +82,12 @@ impl ParsedMessageEnum {
             TokenStream::new()
         };
 
+        let wire_impls = if config.render_wire_impls {
+            self.render_wire_impls()
+        } else {
+            TokenStream::new()
+        };
+
         // Don't include dart in rust if we only want rust but also if the dart
         // comments contain code only which is not parseable as rust.
         let dart_tokens: TokenStream =
@@ -89,8 +103,10 @@ impl ParsedMessageEnum {
                   use super::*;
                   #dart_tokens
                   #(#method_tokens)*
+                  #queue_depth_tokens
                 }
                 #reply_check
+                #wire_impls
             },
             dart_comment,
         )
@@ -117,7 +133,13 @@ impl ParsedMessageEnum {
             .fields
             .iter()
             .enumerate()
-            .map(|(slot, f)| RustArg::from(&f.rust_ty, slot))
+            .map(|(slot, f)| {
+                if f.is_wire() {
+                    RustArg::from_wire(&f.rust_ty, slot)
+                } else {
+                    RustArg::from(&f.rust_ty, slot)
+                }
+            })
             .collect();
 
         let args = if arg_idents.is_empty() {
@@ -166,14 +188,44 @@ impl ParsedMessageEnum {
         // TODO: getting error in the right place if the model struct doesn't implement udpate at
         // all, however when it is implemented incorrectly then the error doesn't even mention the
         // method name
-        let update_method = quote_spanned! { self.struct_ident.span() =>
-            store::write().update(#req_id_ident, #msg_ident);
+        // A panicking update completes the request with an exception on the Dart side.
+        // The message is recorded before it is applied, see rid::start_recording.
+        let update_method = if self.config.threaded {
+            // The message is fully resolved at this point, i.e. it doesn't reference any memory
+            // owned by Dart, and thus can be applied to the store on the message worker thread.
+            // The cancel token, kept until the update ran, ensures that a request cancelled while
+            // it is queued is seen as cancelled by the tokens the update obtains.
+            quote_spanned! { self.struct_ident.span() =>
+                let __rid_cancel_token = rid::CancelToken::new(#req_id_ident);
+                rid::_enqueue_message(Box::new(move || {
+                    rid::_catch_request_panic(#req_id_ident, move || {
+                        store::write().update(#req_id_ident, #msg_ident);
+                    });
+                    drop(__rid_cancel_token);
+                }));
+            }
+        } else {
+            quote_spanned! { self.struct_ident.span() =>
+                rid::_catch_request_panic(#req_id_ident, move || {
+                    store::write().update(#req_id_ident, #msg_ident);
+                });
+            }
         };
 
         let msg = if msg_args.len() == 0 {
             quote_spanned! { variant_ident.span() =>
                 let #msg_ident = #enum_ident::#variant_ident;
             }
+        } else if variant.has_named_fields() {
+            let named_args = variant.fields.iter().zip(arg_idents.iter()).map(
+                |(f, RustArg { arg_ident, .. })| {
+                    let field_ident = f.ident.as_ref().unwrap();
+                    quote_spanned! { fn_ident.span() => #field_ident: #arg_ident, }
+                },
+            );
+            quote_spanned! { variant_ident.span() =>
+                let #msg_ident = #enum_ident::#variant_ident { #(#named_args)* };
+            }
         } else {
             quote_spanned! { variant_ident.span() =>
                 let #msg_ident = #enum_ident::#variant_ident(#(#msg_args)*);
@@ -190,11 +242,30 @@ impl ParsedMessageEnum {
              #ffi_prelude fn #fn_ident(#req_id_ident: u64#(#args)* ) {
                 #(#args_resolvers_tokens)*
                 #msg
+                rid::_record_message(#req_id_ident, &#msg_ident);
                 #update_method
             }
         }
     }
 
+    /// Renders the function which exposes the number of messages that were enqueued but not
+    /// processed yet when messages are `threaded`.
+    fn render_rust_queue_depth(
+        &self,
+        config: &MessageRenderConfig,
+    ) -> TokenStream {
+        let ffi_prelude = if config.include_ffi {
+            ffi_prelude()
+        } else {
+            TokenStream::new()
+        };
+        quote_spanned! { self.ident.span() =>
+            #ffi_prelude fn rid_msg_queue_depth() -> usize {
+                rid::message_queue_depth()
+            }
+        }
+    }
+
     fn render_reply_check(&self) -> TokenStream {
         let reply_ident = &self.config.reply;
         let reply_mod_ident =
@@ -231,6 +302,7 @@ impl ParsedMessageEnum {
                 r###"{comment}
 {comment} Future<{class_name}> _replyWithTimeout(
 {comment}   Future<{class_name}> reply,
+{comment}   void Function() cancel,
 {comment}   String msgCall,
 {comment}   StackTrace applicationStack,
 {comment}   Duration timeout,
@@ -241,8 +313,10 @@ impl ParsedMessageEnum {
 {comment} ---- Internal Stack ----
 {comment} ''';
 {comment} 
-{comment}   return reply.timeout(timeout,
-{comment}       onTimeout: () => throw {dart_async}.TimeoutException(failureMsg, timeout));
+{comment}   return reply.timeout(timeout, onTimeout: () {{
+{comment}     cancel();
+{comment}     throw {dart_async}.TimeoutException(failureMsg, timeout);
+{comment}   }});
 {comment} }}
 {comment}"###,
                 class_name = class_name,
@@ -303,33 +377,85 @@ impl ParsedMessageEnum {
             ffi_arg: String,
         }
 
-        // NOTE: we don't support data of custom types inside message variants
-        // Only primitives and Strings are allowed
+        // Primitives, Strings and c-style enums are passed across FFI directly while all other
+        // types are encoded into the rid wire format, see MessageVariantField::is_wire.
         let type_infos = TypeInfoMap::default();
+        // Type attributes cannot precede `required` and are omitted for named parameters
+        let raw_opts = || {
+            if variant.has_named_fields() {
+                RenderDartTypeOpts::raw()
+            } else {
+                RenderDartTypeOpts::attr_raw()
+            }
+        };
 
         let args_info: Vec<(usize, DartArg)> = variant
             .fields
             .iter()
             .map(|f| {
-                let ffi_arg = f.dart_ty.render_resolved_ffi_arg(f.slot);
-                DartArg {
-                    arg: format!("arg{}", f.slot),
-                    ty: f.rust_ty.render_dart_type(
-                        &type_infos,
-                        RenderDartTypeOpts::attr_raw(),
-                    ),
-                    ffi_arg,
+                let arg = f.dart_arg_name();
+                if f.is_wire() {
+                    DartArg {
+                        ty: f.rust_ty.render_dart_type(
+                            self.type_infos(),
+                            RenderDartTypeOpts::plain(),
+                        ),
+                        ffi_arg: format!("{}Ptr", arg),
+                        arg,
+                    }
+                } else {
+                    DartArg {
+                        ty: f.rust_ty.render_dart_type(&type_infos, raw_opts()),
+                        ffi_arg: f.dart_ty.render_resolved_ffi_var(&arg),
+                        arg,
+                    }
                 }
             })
             .enumerate()
             .collect();
 
-        let args_decl = args_info.iter().fold(
-            "".to_string(),
-            |acc, (idx, DartArg { arg, ty, .. })| {
-                format!("{acc}{ty} {arg}, ", acc = acc, ty = ty, arg = arg)
-            },
-        );
+        let wire_fields = variant.fields.iter().filter(|f| f.is_wire());
+        let encode_wire_args = wire_fields
+            .clone()
+            .map(|f| {
+                let arg = f.dart_arg_name();
+                format!(
+                    r###"{comment}     final {arg}Ptr = RidWireWriter.encode((w) {{
+{encode}
+{comment}     }});
+"###,
+                    arg = arg,
+                    encode = prefix_dart_lines(
+                        &f.rust_ty.render_dart_wire_encode(&arg, 0),
+                        &format!("{}       ", comment)
+                    ),
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("");
+        let free_wire_args = wire_fields
+            .map(|f| {
+                format!(
+                    "{comment}     RidWireWriter.free({arg}Ptr);\n",
+                    arg = f.dart_arg_name(),
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("");
+
+        let params: Vec<DartParam> = args_info
+            .iter()
+            .zip(variant.fields.iter())
+            .map(|((_, DartArg { arg, ty, .. }), f)| DartParam {
+                name: arg.to_string(),
+                ty: ty.to_string(),
+                optional: f.rust_ty.is_option(),
+            })
+            .collect();
+        let params_decl =
+            render_dart_params(&params, variant.has_named_fields());
 
         let (args_call, args_string) = args_info.iter().fold(
             ("".to_string(), "".to_string()),
@@ -343,12 +469,21 @@ impl ParsedMessageEnum {
                         comma = comma,
                         arg = ffi_arg
                     ),
-                    format!(
-                        "{acc}{comma}${arg}",
-                        acc = args_string_acc,
-                        comma = comma,
-                        arg = arg
-                    ),
+                    if variant.has_named_fields() {
+                        format!(
+                            "{acc}{comma}{arg}: ${arg}",
+                            acc = args_string_acc,
+                            comma = comma,
+                            arg = arg
+                        )
+                    } else {
+                        format!(
+                            "{acc}{comma}${arg}",
+                            acc = args_string_acc,
+                            comma = comma,
+                            arg = arg
+                        )
+                    },
                 )
             },
         );
@@ -356,34 +491,39 @@ impl ParsedMessageEnum {
         // NOTE: related code rendered via src/reply/render_reply_dart.rs, i.e. RID_DEBUG_REPLY
         format!(
             r###"
-{comment}   Future<{class_name}> {dart_method_name}({args_decl}{{Duration? timeout}}) {{
+{comment}   RidRequest<{class_name}> {dart_method_name}({params_decl}) {{
 {comment}     final reqId = {_RID_REPLY_CHANNEL}.reqId;
-{comment}     {rid_ffi}.{method_name}(reqId, {args_call});
+{encode_wire_args}{comment}     {rid_ffi}.{method_name}(reqId, {args_call});
+{free_wire_args}{comment}
+{comment}     final msgCall = '{dart_method_name}({args_string})';
+{comment}     {throw_if_panicked}(msgCall);
+{comment}     final request = {_RID_REPLY_CHANNEL}.request(reqId, msgCall);
+{comment}     if (!_isDebugMode) return request;
 {comment}
-{comment}     final reply = _isDebugMode && {rid_debug_reply} != null
-{comment}         ? {_RID_REPLY_CHANNEL}.reply(reqId).then(({class_name} reply) {{
+{comment}     final reply = {rid_debug_reply} != null
+{comment}         ? request.reply.then(({class_name} reply) {{
 {comment}             if ({rid_debug_reply} != null) {rid_debug_reply}!(reply);
 {comment}             return reply;
 {comment}           }})
-{comment}         : {_RID_REPLY_CHANNEL}.reply(reqId);
-{comment}     
-{comment}     if (!_isDebugMode) return reply;
+{comment}         : request.reply;
 {comment}
 {comment}     timeout ??= {rid_msg_timeout};
-{comment}     if (timeout == null) return reply;
-{comment}     final msgCall = '{dart_method_name}({args_string}) with reqId: $reqId';
-{comment}     return _replyWithTimeout(reply, msgCall, StackTrace.current, timeout);
+{comment}     if (timeout == null) return request.withReply(reply);
+{comment}     return request.withReply(_replyWithTimeout(reply, request.cancel, '$msgCall with reqId: $reqId', StackTrace.current, timeout));
 {comment}   }}"###,
             class_name = class_name,
             dart_method_name = self.dart_method_name(&fn_ident.to_string()),
             method_name = fn_ident.to_string(),
-            args_decl = args_decl,
+            params_decl = params_decl,
             args_call = args_call,
             args_string = args_string,
+            encode_wire_args = encode_wire_args,
+            free_wire_args = free_wire_args,
             rid_ffi = RID_FFI,
             _RID_REPLY_CHANNEL = _RID_REPLY_CHANNEL,
             rid_debug_reply = RID_DEBUG_REPLY,
             rid_msg_timeout = RID_MSG_TIMEOUT,
+            throw_if_panicked = THROW_IF_PANICKED,
             comment = comment
         )
     }
@@ -395,3 +535,43 @@ impl ParsedMessageEnum {
         format!("{}{}", self.ident_lower_camel, shortened)
     }
 }
+
+/// A parameter of a generated Dart message method.
+pub(super) struct DartParam {
+    pub name: String,
+    pub ty: String,
+    /// `true` if the parameter is nullable and thus doesn't need to be provided when named
+    pub optional: bool,
+}
+
+/// Renders the parameters of a Dart message method including the trailing optional `timeout`.
+///
+/// Fields of tuple variants are rendered as positional parameters, i.e.
+/// `int arg0, String arg1, {Duration? timeout}`, while fields of struct variants are rendered
+/// as named parameters, i.e. `{required int from, String? label, Duration? timeout}`.
+pub(super) fn render_dart_params(params: &[DartParam], named: bool) -> String {
+    if named {
+        let named_params = params.iter().fold(
+            "".to_string(),
+            |acc, DartParam { name, ty, optional }| {
+                let required = if *optional { "" } else { "required " };
+                format!(
+                    "{acc}{required}{ty} {name}, ",
+                    acc = acc,
+                    required = required,
+                    ty = ty,
+                    name = name
+                )
+            },
+        );
+        format!("{{{}Duration? timeout}}", named_params)
+    } else {
+        let positional_params = params.iter().fold(
+            "".to_string(),
+            |acc, DartParam { name, ty, .. }| {
+                format!("{acc}{ty} {name}, ", acc = acc, ty = ty, name = name)
+            },
+        );
+        format!("{}{{Duration? timeout}}", positional_params)
+    }
+}
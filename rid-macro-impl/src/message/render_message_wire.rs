@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+
+use super::{parsed_variant::ParsedMessageVariant, ParsedMessageEnum};
+
+impl ParsedMessageEnum {
+    /// Renders implementations of `rid::_RidEncode` and `rid::_RidDecode` for this message enum
+    /// which the generated `rid_msg_*` functions use to record the messages Dart sends and which
+    /// `rid::replay` uses to apply them to the store again.
+    ///
+    /// Unlike for `#[rid::model]` enums every variant, including the ones without fields, is
+    /// encoded as its `u32` index followed by its fields.
+    ///
+    /// ```rust
+    /// impl rid::_RidEncode for Msg {
+    ///     fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+    ///         match self {
+    ///             Self::AddTodo(field0) => {
+    ///                 writer.write_u32(0);
+    ///                 rid::_RidEncode::_rid_encode(field0, writer);
+    ///             }
+    ///             Self::RemoveCompleted => {
+    ///                 writer.write_u32(1);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl rid::_RidDecode for Msg {
+    ///     fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+    ///         match reader.read_variant("Msg", &[0, 1]) {
+    ///             0 => Self::AddTodo(rid::_RidDecode::_rid_decode(reader)),
+    ///             1 => Self::RemoveCompleted,
+    ///             n => panic!("enum Msg does not include variant {}", n),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn render_wire_impls(&self) -> TokenStream {
+        let ident = &self.ident;
+
+        let encode_arms = self
+            .parsed_variants
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| render_encode_arm(idx as u32, variant));
+        let decode_arms = self
+            .parsed_variants
+            .iter()
+            .enumerate()
+            .map(|(idx, variant)| render_decode_arm(idx as u32, variant));
+        let panic_quote = format!(
+            "enum {ident} does not include variant {{}}",
+            ident = ident
+        );
+        let enum_name = ident.to_string();
+        let variants =
+            (0..self.parsed_variants.len() as u32).collect::<Vec<_>>();
+
+        quote_spanned! { ident.span() =>
+            impl rid::_RidEncode for #ident {
+                fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                    match self {
+                        #(#encode_arms)*
+                    }
+                }
+            }
+
+            impl rid::_RidDecode for #ident {
+                fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                    match reader.read_variant(#enum_name, &[#(#variants),*]) {
+                        #(#decode_arms)*
+                        n => panic!(#panic_quote, n),
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_encode_arm(idx: u32, variant: &ParsedMessageVariant) -> TokenStream {
+    let variant_ident = &variant.ident;
+    let bindings: Vec<syn::Ident> = variant
+        .fields
+        .iter()
+        .map(|x| match &x.ident {
+            Some(field_ident) => field_ident.clone(),
+            None => format_ident!("field{}", x.slot),
+        })
+        .collect();
+    let pattern = if variant.fields.is_empty() {
+        quote! { Self::#variant_ident }
+    } else if variant.has_named_fields() {
+        quote! { Self::#variant_ident { #(#bindings),* } }
+    } else {
+        quote! { Self::#variant_ident(#(#bindings),*) }
+    };
+    quote! {
+        #pattern => {
+            writer.write_u32(#idx);
+            #(rid::_RidEncode::_rid_encode(#bindings, writer);)*
+        }
+    }
+}
+
+fn render_decode_arm(idx: u32, variant: &ParsedMessageVariant) -> TokenStream {
+    let variant_ident = &variant.ident;
+    let decoded_fields = variant.fields.iter().map(|x| match &x.ident {
+        Some(field_ident) => quote! {
+            #field_ident: rid::_RidDecode::_rid_decode(reader),
+        },
+        None => quote! { rid::_RidDecode::_rid_decode(reader), },
+    });
+    let construct = if variant.fields.is_empty() {
+        quote! { Self::#variant_ident }
+    } else if variant.has_named_fields() {
+        quote! { Self::#variant_ident { #(#decoded_fields)* } }
+    } else {
+        quote! { Self::#variant_ident(#(#decoded_fields)*) }
+    };
+    quote! { #idx => #construct, }
+}
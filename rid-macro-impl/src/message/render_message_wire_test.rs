@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use rid_common::STORE;
+use syn::Item;
+
+use crate::{
+    attrs,
+    message::{MessageEnumConfig, ParsedMessageEnum},
+};
+
+fn render(input: TokenStream) -> TokenStream {
+    let item = syn::parse2::<syn::Item>(input).unwrap();
+    match item {
+        Item::Enum(item) => {
+            let rid_attrs = attrs::parse_rid_attrs(&item.attrs);
+            let enum_config = MessageEnumConfig::new(
+                &rid_attrs,
+                format_ident!("{}", STORE),
+                &format_ident!("Reply"),
+                false,
+            );
+            ParsedMessageEnum::new(
+                &item.ident,
+                item.variants.clone(),
+                enum_config,
+            )
+            .render_wire_impls()
+        }
+        _ => panic!("Unexpected item, we're trying to parse enums here"),
+    }
+}
+
+#[test]
+fn render_variants_without_fields() {
+    let res = render(quote! {
+        pub enum Msg {
+            Start,
+            Stop
+        }
+    });
+    let expected = quote! {
+        impl rid::_RidEncode for Msg {
+            fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                match self {
+                    Self::Start => {
+                        writer.write_u32(0u32);
+                    }
+                    Self::Stop => {
+                        writer.write_u32(1u32);
+                    }
+                }
+            }
+        }
+
+        impl rid::_RidDecode for Msg {
+            fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                match reader.read_variant("Msg", &[0u32, 1u32]) {
+                    0u32 => Self::Start,
+                    1u32 => Self::Stop,
+                    n => panic!("enum Msg does not include variant {}", n),
+                }
+            }
+        }
+    };
+    assert_eq!(res.to_string(), expected.to_string());
+}
+
+#[test]
+fn render_tuple_and_struct_variants() {
+    let res = render(quote! {
+        pub enum Msg {
+            AddTodo(String, u8),
+            Move { from: u32, to: u32 },
+        }
+    });
+    let expected = quote! {
+        impl rid::_RidEncode for Msg {
+            fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                match self {
+                    Self::AddTodo(field0, field1) => {
+                        writer.write_u32(0u32);
+                        rid::_RidEncode::_rid_encode(field0, writer);
+                        rid::_RidEncode::_rid_encode(field1, writer);
+                    }
+                    Self::Move { from, to } => {
+                        writer.write_u32(1u32);
+                        rid::_RidEncode::_rid_encode(from, writer);
+                        rid::_RidEncode::_rid_encode(to, writer);
+                    }
+                }
+            }
+        }
+
+        impl rid::_RidDecode for Msg {
+            fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                match reader.read_variant("Msg", &[0u32, 1u32]) {
+                    0u32 => Self::AddTodo(
+                        rid::_RidDecode::_rid_decode(reader),
+                        rid::_RidDecode::_rid_decode(reader),
+                    ),
+                    1u32 => Self::Move {
+                        from: rid::_RidDecode::_rid_decode(reader),
+                        to: rid::_RidDecode::_rid_decode(reader),
+                    },
+                    n => panic!("enum Msg does not include variant {}", n),
+                }
+            }
+        }
+    };
+    assert_eq!(res.to_string(), expected.to_string());
+}
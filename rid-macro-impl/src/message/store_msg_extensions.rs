@@ -1,5 +1,5 @@
 use heck::MixedCase;
-use rid_common::{DART_FFI, FFI_GEN_BIND, RID_CREATE_STORE};
+use rid_common::{DART_FFI, FFI_GEN_BIND, RID_CREATE_STORE, RID_FFI};
 use syn::Ident;
 
 use crate::{
@@ -7,7 +7,11 @@ use crate::{
     render_dart::RenderDartTypeOpts,
 };
 
-use super::{parsed_variant::ParsedMessageVariant, ParsedMessageEnum};
+use super::{
+    parsed_variant::ParsedMessageVariant,
+    render_message_enum::{render_dart_params, DartParam},
+    ParsedMessageEnum,
+};
 
 impl ParsedMessageEnum {
     pub fn render_store_api(&self, comment: &str) -> String {
@@ -24,14 +28,27 @@ impl ParsedMessageEnum {
             .collect::<Vec<String>>()
             .join("\n");
 
+        let queue_depth = if self.config.threaded {
+            format!(
+                r###"
+{comment}   /// Number of messages that were sent but not yet processed by Rust.
+{comment}   int get msgQueueDepth => {rid_ffi}.rid_msg_queue_depth();"###,
+                rid_ffi = RID_FFI,
+                comment = comment
+            )
+        } else {
+            "".to_string()
+        };
+
         format!(
             r###"
 {comment} extension MsgApiFor_{Store} on {Store} {{
-{msg_methods}
+{msg_methods}{queue_depth}
 {comment} }}
 "###,
             Store = store_ident,
             msg_methods = msg_methods,
+            queue_depth = queue_depth,
             comment = comment
         )
     }
@@ -46,49 +63,50 @@ impl ParsedMessageEnum {
         let method_name = self.dart_method_name(&fn_ident.to_string());
         let api_method_name = method_name.to_mixed_case();
 
-        struct DartArg {
-            arg: String,
-            ty: String,
-        }
-        let args_info: Vec<(usize, DartArg)> = variant
+        let params: Vec<DartParam> = variant
             .fields
             .iter()
-            .map(|f| DartArg {
-                arg: format!("arg{}", f.slot),
-                ty: f.rust_ty.render_dart_type(
-                    &self.type_infos(),
-                    RenderDartTypeOpts::attr(),
-                ),
+            .map(|f| {
+                // Type attributes cannot precede `required` and are omitted for named parameters
+                let opts = if f.is_named() {
+                    RenderDartTypeOpts::plain()
+                } else {
+                    RenderDartTypeOpts::attr()
+                };
+                DartParam {
+                    name: f.dart_arg_name(),
+                    ty: f.rust_ty.render_dart_type(self.type_infos(), opts),
+                    optional: f.rust_ty.is_option(),
+                }
             })
-            .enumerate()
             .collect();
+        let params_decl =
+            render_dart_params(&params, variant.has_named_fields());
 
-        let (args_decl, args_call) = args_info.iter().fold(
-            ("".to_string(), "".to_string()),
-            |(decl_acc, args_acc), (idx, DartArg { arg, ty })| {
-                let field = &variant.fields[*idx];
-                let to_raw = if field.is_enum() { ".index" } else { "" };
-                (
-                    format!(
-                        "{acc}{ty} {arg}, ",
-                        acc = decl_acc,
-                        ty = ty,
-                        arg = arg
-                    ),
-                    format!(
-                        "{acc}{arg}{to_raw}, ",
-                        acc = args_acc,
-                        arg = arg,
-                        to_raw = to_raw
-                    ),
+        let args_call = variant.fields.iter().fold("".to_string(), |acc, f| {
+            let arg = f.dart_arg_name();
+            let to_raw = if f.is_enum() { ".index" } else { "" };
+            if f.is_named() {
+                format!(
+                    "{acc}{arg}: {arg}{to_raw}, ",
+                    acc = acc,
+                    arg = arg,
+                    to_raw = to_raw
+                )
+            } else {
+                format!(
+                    "{acc}{arg}{to_raw}, ",
+                    acc = acc,
+                    arg = arg,
+                    to_raw = to_raw
                 )
-            },
-        );
+            }
+        });
         let posted_reply_type =
             reply_class_name_for_enum(&self.reply_dart_enum_name);
 
         format!(
-            r###"{comment}   Future<{PostedReply}> {msgApiMethod}({args_decl}{{Duration? timeout}}) {{
+            r###"{comment}   RidRequest<{PostedReply}> {msgApiMethod}({params_decl}) {{
 {comment}     return {_store}.{msgMethod}({args}timeout: timeout);
 {comment}   }}"###,
             _store = store_field,
@@ -96,7 +114,7 @@ impl ParsedMessageEnum {
             msgApiMethod = api_method_name,
             msgMethod = method_name,
             args = args_call,
-            args_decl = args_decl,
+            params_decl = params_decl,
             comment = comment
         )
     }
@@ -1,3 +1,4 @@
+use heck::MixedCase;
 use syn::Field;
 
 use crate::{
@@ -9,8 +10,15 @@ use crate::{
     },
 };
 
+/// Names of locals used inside the generated Dart message methods which thus cannot be used as
+/// the names of named parameters.
+const RESERVED_DART_ARG_NAMES: [&str; 4] =
+    ["timeout", "reqId", "reply", "msgCall"];
+
 #[derive(Debug)]
 pub struct MessageVariantField {
+    /// The name of the field for struct variants, `None` for tuple variants
+    pub ident: Option<syn::Ident>,
     pub ty: syn::Type,
     pub rust_ty: RustType,
     pub dart_ty: DartType,
@@ -19,6 +27,17 @@ pub struct MessageVariantField {
 
 impl MessageVariantField {
     pub fn new(f: Field, slot: usize, types: &TypeInfoMap) -> Self {
+        if let Some(ident) = &f.ident {
+            let dart_name = ident.to_string().to_mixed_case();
+            if RESERVED_DART_ARG_NAMES.contains(&dart_name.as_str()) {
+                abort!(
+                    ident,
+                    "[rid] message fields cannot be named '{}' as it is used by the generated Dart method",
+                    ident
+                )
+            }
+        }
+        let ident = f.ident.clone();
         let ty = f.ty;
         let rust_ty = RustType::from_type(&ty, types);
         let rust_ty = match rust_ty {
@@ -30,9 +49,17 @@ impl MessageVariantField {
                 &f.ident.as_ref().unwrap_or(&rust_ty.rust_ident()),
             );
         };
+        if !is_passed_directly(&rust_ty) && !rust_ty.is_wire_encodable() {
+            abort!(
+                ty,
+                "[rid] message fields need to be primitives, strings, #[rid::model] structs \
+                or enums as well as Vecs, Options and other collections of those"
+            )
+        }
         let dart_ffi_ty = DartType::from(&rust_ty, types);
 
         Self {
+            ident,
             ty: ty.clone(),
             rust_ty,
             dart_ty: dart_ffi_ty,
@@ -40,7 +67,32 @@ impl MessageVariantField {
         }
     }
 
+    /// Name of the argument in the generated Dart methods, i.e. the lower camel cased field name
+    /// for struct variants and `arg0` for tuple variants.
+    pub fn dart_arg_name(&self) -> String {
+        match &self.ident {
+            Some(ident) => ident.to_string().to_mixed_case(),
+            None => format!("arg{}", self.slot),
+        }
+    }
+
+    /// Returns `true` for fields of struct variants which are passed as named parameters in Dart
+    pub fn is_named(&self) -> bool {
+        self.ident.is_some()
+    }
+
     pub fn is_enum(&self) -> bool {
         self.rust_ty.is_enum()
     }
+
+    /// Returns `true` if this field is passed from Dart encoded in the rid wire format, i.e.
+    /// structs, data enums and collections.
+    /// Primitives, strings and c-style enums are passed across FFI directly.
+    pub fn is_wire(&self) -> bool {
+        !is_passed_directly(&self.rust_ty)
+    }
+}
+
+fn is_passed_directly(rust_ty: &RustType) -> bool {
+    rust_ty.is_primitive() || rust_ty.is_string() || rust_ty.is_enum()
 }
@@ -1,14 +1,51 @@
 use proc_macro2::TokenStream;
-use quote::quote_spanned;
-use syn::Item;
+use quote::{quote, quote_spanned};
+use syn::{Item, NestedMeta};
 
-use super::{render_enum::render_enum, render_struct::render_struct};
-use crate::common::{abort, utils_module_tokens};
+use super::{
+    render_enum::render_enum, render_struct::render_struct, store::StoreMode,
+};
+use crate::{
+    attrs::parse_rid_args,
+    common::{abort, utils_module_tokens},
+};
+
+const MODEL_ARG_TRANSPARENT: &str = "transparent";
+const STORE_ARG_SNAPSHOT: &str = "snapshot";
+
+pub fn rid_ffi_model_impl(
+    item: &Item,
+    args: &[NestedMeta],
+    is_store: bool,
+) -> TokenStream {
+    let mut is_transparent = false;
+    let mut store_mode = if is_store {
+        Some(StoreMode::Locked)
+    } else {
+        None
+    };
+    for arg in parse_rid_args(args) {
+        match arg.to_string().as_str() {
+            STORE_ARG_SNAPSHOT if is_store => {
+                store_mode = Some(StoreMode::Snapshot)
+            }
+            MODEL_ARG_TRANSPARENT if !is_store => is_transparent = true,
+            _ if is_store => abort!(
+                arg,
+                "Only rid::store({}) is supported",
+                STORE_ARG_SNAPSHOT
+            ),
+            _ => abort!(
+                arg,
+                "Only rid::model({}) is supported",
+                MODEL_ARG_TRANSPARENT
+            ),
+        }
+    }
 
-pub fn rid_ffi_model_impl(item: &Item, is_store: bool) -> TokenStream {
     match item {
         Item::Struct(struct_item) => {
-            let tokens = render_struct(struct_item, is_store);
+            let tokens = render_struct(struct_item, store_mode, is_transparent);
             let utils_module = utils_module_tokens();
             quote_spanned! { struct_item.ident.span() =>
                 #item
@@ -17,17 +54,33 @@ pub fn rid_ffi_model_impl(item: &Item, is_store: bool) -> TokenStream {
             }
         }
         Item::Enum(enum_item) => {
+            if is_transparent {
+                abort!(
+                    enum_item.ident,
+                    "rid::model(transparent) can only be applied to structs"
+                )
+            }
             let tokens = render_enum(enum_item);
             let utils_module = utils_module_tokens();
+            // Enums with data carrying variants are passed as opaque pointers
+            let repr_c =
+                if enum_item.variants.iter().all(|x| x.fields.is_empty()) {
+                    quote! { #[repr(C)] }
+                } else {
+                    TokenStream::new()
+                };
             quote_spanned! { enum_item.ident.span() =>
-                #[repr(C)]
+                #repr_c
                 #item
                 #tokens
                 #utils_module
             }
         }
         _ => {
-            abort!(item, "rid::model attribute can only be applied to structs and c-style enums")
+            abort!(
+                item,
+                "rid::model attribute can only be applied to structs and enums"
+            )
         }
     }
 }
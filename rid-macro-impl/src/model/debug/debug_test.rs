@@ -101,8 +101,7 @@ mod structs_debug_impl {
                 pub extern "C" fn rid_single_debug(ptr: *mut Single) -> *const ::std::os::raw::c_char {
                     let single = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut Single = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     let s = format!("{:?}", single);
                     let cstring = ::std::ffi::CString::new(s.as_str()).unwrap();
@@ -113,8 +112,7 @@ mod structs_debug_impl {
                 pub extern "C" fn rid_single_debug_pretty(ptr: *mut Single) -> *const ::std::os::raw::c_char {
                     let single = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut Single = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     let s = format!("{:#?}", single);
                     let cstring = ::std::ffi::CString::new(s.as_str()).unwrap();
@@ -1,7 +1,11 @@
 mod render_dart_field_access;
 mod render_dart_field_return_type;
+mod render_enum_field_access;
 mod render_field_access;
 mod render_rust_field_access;
 
 #[cfg(test)]
 mod render_field_access_test;
+
+#[cfg(test)]
+mod render_enum_field_access_test;
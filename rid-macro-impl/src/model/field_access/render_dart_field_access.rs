@@ -8,7 +8,7 @@ use crate::{
     parse::{dart_type::DartType, ParsedStruct, ParsedStructField},
     render_dart::RenderDartTypeOpts,
 };
-use rid_common::{DART_FFI, FFI_GEN_BIND, RID_FFI};
+use rid_common::{DART_FFI, FFI_GEN_BIND, RID_FFI, THROW_IF_PANICKED};
 
 impl ParsedStruct {
     pub fn render_dart_fields_access_extension(
@@ -85,7 +85,12 @@ impl ParsedStruct {
 }
 
 impl DartType {
-    fn render_field_access_getter_body(
+    /// Renders the body of the getter which resolves a field via the provided FFI method.
+    ///
+    /// Like exported functions each getter checks if Rust panicked while resolving the field,
+    /// i.e. when accessing the field of another variant, in order to throw instead of returning
+    /// a fallback value.
+    pub fn render_field_access_getter_body(
         &self,
         ffi_method_ident: &Ident,
         comment: &str,
@@ -94,51 +99,81 @@ impl DartType {
         let half_indent = "  ";
         match self {
             // -----------------
-            // Int
+            // Nullable
             // -----------------
-            DartType::Int32(nullable) if *nullable => {
-                todo!("DartType:render_field_access_getter_body:Int32:nullable")
-            }
-            DartType::Int64(nullable) if *nullable => {
-                todo!("DartType:render_field_access_getter_body:Int64:nullable")
+            // Options are passed as a pointer to the wrapped value except for strings which
+            // are converted right away.
+            DartType::String(nullable) if *nullable => format!(
+                r###"{{
+{comment}{indent}{dart_ffi}.Pointer<{dart_ffi}.Int8> ptr = {rid_ffi}.{ffi_method}(this);
+{comment}{indent}{throw_if_panicked}('{ffi_method}');
+{comment}{indent}if (ptr.address == 0x0) return null;
+{comment}{indent}String s = ptr.toDartString();
+{comment}{indent}ptr.free();
+{comment}{indent}return s;
+{comment}{half_indent}}}"###,
+                dart_ffi = DART_FFI,
+                rid_ffi = RID_FFI,
+                ffi_method = ffi_method_ident,
+                throw_if_panicked = THROW_IF_PANICKED,
+                indent = indent,
+                half_indent = half_indent,
+                comment = comment
+            ),
+            DartType::Int32(nullable)
+            | DartType::Int64(nullable)
+            | DartType::Float32(nullable)
+            | DartType::Float64(nullable)
+            | DartType::Bool(nullable)
+            | DartType::Custom(nullable, _, _)
+            | DartType::Vec(nullable, _)
+            | DartType::HashMap(nullable, _, _)
+            | DartType::BTreeMap(nullable, _, _)
+            | DartType::HashSet(nullable, _)
+            | DartType::BTreeSet(nullable, _)
+                if *nullable =>
+            {
+                format!(
+                    "{{ final ptr = {rid_ffi}.{ffi_method}(this); {throw_if_panicked}('{ffi_method}'); return ptr.address == 0x0 ? null : ptr; }}",
+                    rid_ffi = RID_FFI,
+                    ffi_method = ffi_method_ident,
+                    throw_if_panicked = THROW_IF_PANICKED,
+                )
             }
+            // -----------------
+            // Int
+            // -----------------
             DartType::Int32(_) | DartType::Int64(_) => format!(
-                "{{ return {rid_ffi}.{ffi_method}(this); }}",
+                "{{ final res = {rid_ffi}.{ffi_method}(this); {throw_if_panicked}('{ffi_method}'); return res; }}",
                 rid_ffi = RID_FFI,
                 ffi_method = ffi_method_ident,
+                throw_if_panicked = THROW_IF_PANICKED,
             ),
             // -----------------
-            // Bool
+            // Float
             // -----------------
-            DartType::Bool(nullable) if *nullable => {
-                todo!("DartType:render_field_access_getter_body:Bool:nullable")
-            }
-            DartType::Bool(_) => format!(
-                "{{ return {rid_ffi}.{ffi_method}(this) != 0; }}",
+            DartType::Float32(_) | DartType::Float64(_) => format!(
+                "{{ final res = {rid_ffi}.{ffi_method}(this); {throw_if_panicked}('{ffi_method}'); return res; }}",
                 rid_ffi = RID_FFI,
                 ffi_method = ffi_method_ident,
+                throw_if_panicked = THROW_IF_PANICKED,
             ),
             // -----------------
-            // String
+            // Bool
             // -----------------
-            DartType::String(nullable) if *nullable => format!(
-                r###"{{
-{comment}{indent}{dart_ffi}.Pointer<{dart_ffi}.Int8> ptr = {rid_ffi}.{ffi_method}(this);
-{comment}{indent}if (ptr.address == 0x0) return null;
-{string_resolution}
-{comment}{half_indent}}}"###,
-                string_resolution =
-                    string_resolution(indent, comment, ffi_method_ident),
-                dart_ffi = DART_FFI,
+            DartType::Bool(_) => format!(
+                "{{ final res = {rid_ffi}.{ffi_method}(this); {throw_if_panicked}('{ffi_method}'); return res != 0; }}",
                 rid_ffi = RID_FFI,
                 ffi_method = ffi_method_ident,
-                indent = indent,
-                half_indent = half_indent,
-                comment = comment
+                throw_if_panicked = THROW_IF_PANICKED,
             ),
+            // -----------------
+            // String
+            // -----------------
             DartType::String(_) => format!(
                 r###"{{
 {comment}{indent}{dart_ffi}.Pointer<{dart_ffi}.Int8>? ptr = {rid_ffi}.{ffi_method}(this);
+{comment}{indent}{throw_if_panicked}('{ffi_method}');
 {string_resolution}
 {comment}{half_indent}}}"###,
                 string_resolution =
@@ -146,6 +181,7 @@ impl DartType {
                 dart_ffi = DART_FFI,
                 rid_ffi = RID_FFI,
                 ffi_method = ffi_method_ident,
+                throw_if_panicked = THROW_IF_PANICKED,
                 indent = indent,
                 half_indent = half_indent,
                 comment = comment
@@ -154,22 +190,28 @@ impl DartType {
             // Custom
             // -----------------
             DartType::Custom(_, _, _) => format!(
-                "{{ return {rid_ffi}.{ffi_method}(this); }}",
+                "{{ final res = {rid_ffi}.{ffi_method}(this); {throw_if_panicked}('{ffi_method}'); return res; }}",
                 rid_ffi = RID_FFI,
-                ffi_method = ffi_method_ident
+                ffi_method = ffi_method_ident,
+                throw_if_panicked = THROW_IF_PANICKED,
             ),
             // -----------------
             // Collection Types
             // -----------------
             DartType::Vec(_, _) => format!(
-                "{{ return {rid_ffi}.{ffi_method}(this); }}",
+                "{{ final res = {rid_ffi}.{ffi_method}(this); {throw_if_panicked}('{ffi_method}'); return res; }}",
                 rid_ffi = RID_FFI,
-                ffi_method = ffi_method_ident
+                ffi_method = ffi_method_ident,
+                throw_if_panicked = THROW_IF_PANICKED,
             ),
-            DartType::HashMap(_, _, _) => format!(
-                "{{ return {rid_ffi}.{ffi_method}(this); }}",
+            DartType::HashMap(_, _, _)
+            | DartType::BTreeMap(_, _, _)
+            | DartType::HashSet(_, _)
+            | DartType::BTreeSet(_, _) => format!(
+                "{{ final res = {rid_ffi}.{ffi_method}(this); {throw_if_panicked}('{ffi_method}'); return res; }}",
                 rid_ffi = RID_FFI,
-                ffi_method = ffi_method_ident
+                ffi_method = ffi_method_ident,
+                throw_if_panicked = THROW_IF_PANICKED,
             ),
             // -----------------
             // Invalid
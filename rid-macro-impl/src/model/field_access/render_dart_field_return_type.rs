@@ -40,42 +40,39 @@ impl RustType {
             // -----------------
             // Collection Types
             // -----------------
-            K::Composite(Composite::Vec, inner_type, _) => match inner_type {
-                Some(ty) => {
-                    let item_type = ty.rust_ident();
-                    format!(
-                        "{dart_ffi}.Pointer<{ffigen_bind}.Vec_{ty}>",
-                        dart_ffi = DART_FFI,
-                        ffigen_bind = FFI_GEN_BIND,
-                        ty = item_type
-                    )
-                }
-                None => {
-                    abort!(
-                        self.rust_ident(),
-                        "Rust Vec composite should include inner type"
-                    )
-                }
-            },
-            K::Composite(Composite::HashMap, key_type, val_type) => match (key_type, val_type) {
-                (Some(key), Some(val)) => {
-                    let key_type = key.rust_ident();
-                    let val_type = val.rust_ident();
-                    format!(
-                        "{dart_ffi}.Pointer<{ffigen_bind}.HashMap_{key}__{val}>",
-                        dart_ffi = DART_FFI,
-                        ffigen_bind = FFI_GEN_BIND,
-                        key = key_type,
-                        val  = val_type
-                    )
-                }
-                _ => {
-                    abort!(
-                        self.rust_ident(),
-                        "Rust HashMap composite should include key and val type"
-                    )
-                }
-            },
+            K::Composite(
+                Composite::Vec
+                | Composite::VecDeque
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                Some(_),
+                _,
+            )
+            | K::Composite(
+                Composite::HashMap | Composite::BTreeMap,
+                Some(_),
+                Some(_),
+            ) => self.render_dart_item_pointer_type(),
+            K::Composite(
+                Composite::Vec
+                | Composite::VecDeque
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                _,
+                _,
+            ) => {
+                abort!(
+                    self.rust_ident(),
+                    "Rust {} composite should include inner type",
+                    self.rust_ident()
+                )
+            }
+            K::Composite(Composite::HashMap | Composite::BTreeMap, _, _) => {
+                abort!(
+                    self.rust_ident(),
+                    "Rust HashMap composite should include key and val type"
+                )
+            }
             // -----------------
             // Option
             // -----------------
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use heck::SnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote_spanned};
+use rid_common::{DART_FFI, FFI_GEN_BIND, RID_FFI, THROW_IF_PANICKED};
+use syn::{Ident, Index, Member};
+
+use super::render_rust_field_access::render_rust_field_access_method;
+use crate::{
+    accesses::{
+        render_collection_accesses, RenderDartAccessConfig,
+        RenderRustAccessConfig, RenderableAccess,
+    },
+    common::tokens::resolve_ptr,
+    parse::{ParsedEnum, ParsedEnumVariant, ParsedEnumVariantField},
+};
+
+impl ParsedEnum {
+    /// Renders field accesses for enums whose variants carry data.
+    /// Since those enums aren't `#[repr(C)]` Dart receives a pointer to them and needs to resolve
+    /// which variant it points to via the `discriminant` getter before accessing the fields of
+    /// that variant.
+    ///
+    /// The below example shows which Rust methods are generated for a given enum.
+    ///
+    /// ```rust
+    /// pub enum Shape {
+    ///     Circle(f64),
+    ///     Empty,
+    /// }
+    ///
+    /// fn rid_shape_discriminant(ptr: *mut Shape) -> i32 {
+    ///     let receiver = /* resolve ptr */;
+    ///     match receiver {
+    ///         Shape::Circle { .. } => 0,
+    ///         Shape::Empty { .. } => 1,
+    ///     }
+    /// }
+    ///
+    /// fn rid_shape_circle_field0(ptr: *mut Shape) -> f64 {
+    ///     let receiver = /* resolve ptr */;
+    ///     let field = match receiver {
+    ///         Shape::Circle { 0: field, .. } => field,
+    ///         _ => panic!(/* .. */),
+    ///     };
+    ///     (*field)
+    /// }
+    /// ```
+    pub fn render_field_access(
+        &self,
+        rust_config: &RenderRustAccessConfig,
+        dart_config: &RenderDartAccessConfig,
+    ) -> (TokenStream, String) {
+        let mut collection_accesses: HashMap<
+            String,
+            Box<dyn RenderableAccess>,
+        > = HashMap::new();
+
+        let discriminant_tokens = self.render_rust_discriminant(rust_config);
+        let field_tokens: TokenStream = self
+            .variants
+            .iter()
+            .flat_map(|variant| {
+                variant.fields.iter().map(move |field| (variant, field))
+            })
+            .map(|(variant, field)| {
+                let (tokens, access) = self.render_rust_variant_field_access(
+                    rust_config,
+                    variant,
+                    field,
+                );
+                if let Some(access) = access {
+                    collection_accesses.insert(access.key(), access);
+                }
+                tokens
+            })
+            .collect();
+
+        let rust_tokens = if rust_config.render {
+            quote_spanned! { self.ident.span() =>
+                #discriminant_tokens
+                #field_tokens
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        let (access_tokens, dart_accesses_string) = render_collection_accesses(
+            collection_accesses,
+            self.type_infos(),
+            rust_config,
+            dart_config,
+        );
+
+        let (dart_tokens, dart_string) = if dart_config.render {
+            self.render_dart_fields_access_extension(dart_config)
+        } else {
+            (TokenStream::new(), "".to_string())
+        };
+
+        let mod_name = format_ident!(
+            "__{}_field_access",
+            self.ident.to_string().to_snake_case()
+        );
+        (
+            quote_spanned! {self.ident.span() =>
+                mod #mod_name {
+                    use super::*;
+                    #access_tokens
+
+                    #dart_tokens
+                    #rust_tokens
+                }
+            },
+            format!("{}\n{}", dart_string, dart_accesses_string),
+        )
+    }
+
+    fn discriminant_method_ident(&self) -> Ident {
+        format_ident!(
+            "rid_{}_discriminant",
+            self.ident.to_string().to_lowercase()
+        )
+    }
+
+    // -----------------
+    // Rust
+    // -----------------
+    fn render_rust_discriminant(
+        &self,
+        config: &RenderRustAccessConfig,
+    ) -> TokenStream {
+        let enum_ident = &self.ident;
+        let fn_ident = self.discriminant_method_ident();
+        let resolve_receiver = resolve_ptr(enum_ident);
+        let ffi_prelude = &config.ffi_prelude_tokens;
+
+        let arms: Vec<TokenStream> = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_ident = &variant.ident;
+                let discriminant = variant.discriminant as i32;
+                quote_spanned! { variant_ident.span() =>
+                    #enum_ident::#variant_ident { .. } => #discriminant
+                }
+            })
+            .collect();
+
+        quote_spanned! { fn_ident.span() =>
+            #ffi_prelude fn #fn_ident(ptr: *mut #enum_ident) -> i32 {
+                let receiver = #resolve_receiver;
+                match receiver {
+                    #(#arms),*
+                }
+            }
+        }
+    }
+
+    fn render_rust_variant_field_access(
+        &self,
+        config: &RenderRustAccessConfig,
+        variant: &ParsedEnumVariant,
+        field: &ParsedEnumVariantField,
+    ) -> (TokenStream, Option<Box<dyn RenderableAccess>>) {
+        let enum_ident = &self.ident;
+        let variant_ident = &variant.ident;
+        let fn_ident = field.method_ident(enum_ident, variant_ident);
+        let resolve_receiver = resolve_ptr(enum_ident);
+
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(field.slot)),
+        };
+        let panic_msg = format!(
+            "{fn_ident} can only be called on {enum_ident}::{variant_ident}",
+            fn_ident = fn_ident,
+            enum_ident = enum_ident,
+            variant_ident = variant_ident,
+        );
+
+        let resolve_field = quote_spanned! { fn_ident.span() =>
+            let receiver = #resolve_receiver;
+            #[allow(unreachable_patterns)]
+            let field = match receiver {
+                #enum_ident::#variant_ident { #member: field, .. } => field,
+                _ => panic!(#panic_msg),
+            };
+        };
+        let field_expr = quote_spanned! { fn_ident.span() => (*field) };
+
+        render_rust_field_access_method(
+            config,
+            enum_ident,
+            &fn_ident,
+            &field.rust_type,
+            &resolve_field,
+            &field_expr,
+        )
+    }
+
+    // -----------------
+    // Dart
+    // -----------------
+    fn render_dart_fields_access_extension(
+        &self,
+        render_config: &RenderDartAccessConfig,
+    ) -> (TokenStream, String) {
+        let comment = &render_config.comment;
+        let field_accesses = self
+            .variants
+            .iter()
+            .flat_map(|variant| {
+                variant.fields.iter().map(move |field| {
+                    self.render_dart_variant_field_access(
+                        variant,
+                        field,
+                        render_config,
+                    )
+                })
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let s = format!(
+            r###"
+{comment}```dart
+{comment}extension Rid_Model_ExtOnPointer{raw_ident} on {dart_ffi}.Pointer<{ffigen_bind}.{raw_ident}> {{
+{comment}  int get discriminant {{ final res = {rid_ffi}.{discriminant_method}(this); {throw_if_panicked}('{discriminant_method}'); return res; }}
+{field_accesses}
+{comment}}}
+{comment}```
+        "###,
+            raw_ident = self.raw_ident(),
+            dart_ffi = DART_FFI,
+            ffigen_bind = FFI_GEN_BIND,
+            rid_ffi = RID_FFI,
+            throw_if_panicked = THROW_IF_PANICKED,
+            discriminant_method = self.discriminant_method_ident(),
+            field_accesses = field_accesses,
+            comment = comment
+        );
+        let tokens = if render_config.tokens {
+            s.parse().unwrap()
+        } else {
+            TokenStream::new()
+        };
+        (tokens, s)
+    }
+
+    fn render_dart_variant_field_access(
+        &self,
+        variant: &ParsedEnumVariant,
+        field: &ParsedEnumVariantField,
+        render_config: &RenderDartAccessConfig,
+    ) -> String {
+        let dart_ty = &field.dart_type;
+        let dart_return_ty = field.rust_type.render_dart_field_return_type();
+        let dart_ty_attr_str = match dart_ty.render_type_attribute() {
+            Some(attr) => {
+                format!(
+                    "{comment}  {attr}\n",
+                    comment = &render_config.comment,
+                    attr = attr
+                )
+            }
+            None => "".to_string(),
+        };
+        let getter_body = dart_ty.render_field_access_getter_body(
+            &field.method_ident(&self.ident, &variant.ident),
+            &render_config.comment,
+        );
+
+        format!(
+            "{dart_ty_attr}{comment}  {dart_return_ty} get {getter_ident} {body}",
+            dart_ty_attr = dart_ty_attr_str,
+            dart_return_ty = dart_return_ty,
+            getter_ident = variant.field_getter_ident(field),
+            body = getter_body,
+            comment = &render_config.comment
+        )
+    }
+}
+
+impl ParsedEnumVariant {
+    /// Name of the getter on the raw enum pointer which resolves the field of this variant,
+    /// i.e. `circle_field0`.
+    pub fn field_getter_ident(&self, field: &ParsedEnumVariantField) -> String {
+        format!(
+            "{}_{}",
+            self.ident.to_string().to_snake_case(),
+            field.name()
+        )
+    }
+}
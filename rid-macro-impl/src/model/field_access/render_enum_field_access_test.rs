@@ -0,0 +1,174 @@
+use crate::{
+    accesses::{AccessRender, RenderDartAccessConfig, RenderRustAccessConfig},
+    attrs::EnumConfig,
+    parse::ParsedEnum,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn parse_enum(input: TokenStream) -> ParsedEnum {
+    let item = syn::parse2::<syn::Item>(input).unwrap();
+    match item {
+        syn::Item::Enum(enum_item) => {
+            let enum_config = EnumConfig::from(&enum_item);
+            ParsedEnum::from(&enum_item, enum_config)
+        }
+        _ => panic!("Testing enum rendering only"),
+    }
+}
+
+fn render_rust_field_access(input: TokenStream) -> TokenStream {
+    parse_enum(input)
+        .render_field_access(
+            &RenderRustAccessConfig::for_rust_tests(AccessRender::Force),
+            &RenderDartAccessConfig::for_rust_tests(),
+        )
+        .0
+}
+
+fn render_dart_field_access(input: TokenStream) -> String {
+    parse_enum(input)
+        .render_field_access(
+            &RenderRustAccessConfig::for_dart_tests(AccessRender::Omit),
+            &RenderDartAccessConfig::for_dart_tests(AccessRender::Omit),
+        )
+        .1
+}
+
+mod data_enum_field_access {
+    use super::*;
+
+    // -----------------
+    // Tuple Variant
+    // -----------------
+    #[test]
+    fn tuple_variant_f64_rust() {
+        let input: TokenStream = quote! {
+            enum Shape {
+               Circle(f64),
+               Empty,
+            }
+        };
+
+        let expected = quote! {
+            mod __shape_field_access {
+                use super::*;
+                fn rid_shape_discriminant(ptr: *mut Shape) -> i32 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    match receiver {
+                        Shape::Circle { .. } => 0i32,
+                        Shape::Empty { .. } => 1i32
+                    }
+                }
+                fn rid_shape_circle_field0(ptr: *mut Shape) -> f64 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    #[allow(unreachable_patterns)]
+                    let field = match receiver {
+                        Shape::Circle { 0: field, .. } => field,
+                        _ => panic!("rid_shape_circle_field0 can only be called on Shape::Circle"),
+                    };
+                    (*field)
+                }
+            }
+        };
+
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+
+    #[test]
+    fn tuple_variant_f64_dart() {
+        let input: TokenStream = quote! {
+            enum Shape {
+               Circle(f64),
+               Empty,
+            }
+        };
+
+        let expected = r#"
+```dart
+extension Rid_Model_ExtOnPointerRawShape on dart_ffi.Pointer<ffigen_bind.RawShape> {
+  int get discriminant { final res = rid_ffi.rid_shape_discriminant(this); _ridThrowIfPanicked('rid_shape_discriminant'); return res; }
+  @dart_ffi.Double()
+  double get circle_field0 { final res = rid_ffi.rid_shape_circle_field0(this); _ridThrowIfPanicked('rid_shape_circle_field0'); return res; }
+}
+```
+ "#;
+
+        let tokens = render_dart_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.trim());
+    }
+
+    // -----------------
+    // Struct Variant
+    // -----------------
+    #[test]
+    fn struct_variant_bool_rust() {
+        let input: TokenStream = quote! {
+            enum Status {
+               Pending,
+               Failed { retry: bool },
+            }
+        };
+
+        let expected = quote! {
+            mod __status_field_access {
+                use super::*;
+                fn rid_status_discriminant(ptr: *mut Status) -> i32 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    match receiver {
+                        Status::Pending { .. } => 0i32,
+                        Status::Failed { .. } => 1i32
+                    }
+                }
+                fn rid_status_failed_retry(ptr: *mut Status) -> u8 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    #[allow(unreachable_patterns)]
+                    let field = match receiver {
+                        Status::Failed { retry: field, .. } => field,
+                        _ => panic!("rid_status_failed_retry can only be called on Status::Failed"),
+                    };
+                    if (*field) { 1 } else { 0 }
+                }
+            }
+        };
+
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+
+    #[test]
+    fn struct_variant_custom_struct_dart() {
+        let input: TokenStream = quote! {
+            #[rid::structs(Todo)]
+            enum Status {
+               Pending,
+               Done { todo: Todo },
+            }
+        };
+
+        let expected = r#"
+```dart
+extension Rid_Model_ExtOnPointerRawStatus on dart_ffi.Pointer<ffigen_bind.RawStatus> {
+  int get discriminant { final res = rid_ffi.rid_status_discriminant(this); _ridThrowIfPanicked('rid_status_discriminant'); return res; }
+  dart_ffi.Pointer<ffigen_bind.RawTodo> get done_todo { final res = rid_ffi.rid_status_done_todo(this); _ridThrowIfPanicked('rid_status_done_todo'); return res; }
+}
+```
+ "#;
+
+        let tokens = render_dart_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.trim());
+    }
+}
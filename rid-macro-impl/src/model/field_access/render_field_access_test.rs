@@ -84,8 +84,7 @@ mod struct_field_access_single_primitives {
                 fn rid_mystruct_n(ptr: *mut MyStruct) -> u8 {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     receiver.n
                 }
@@ -108,7 +107,7 @@ mod struct_field_access_single_primitives {
 ```dart
 extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
   @dart_ffi.Int32()
-  int get n { return rid_ffi.rid_mystruct_n(this); }
+  int get n { final res = rid_ffi.rid_mystruct_n(this); _ridThrowIfPanicked('rid_mystruct_n'); return res; }
 }
 ```
  "#;
@@ -134,8 +133,7 @@ extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawM
                 fn rid_mystruct_n(ptr: *mut MyStruct) -> i64 {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     receiver.n
                 }
@@ -158,7 +156,77 @@ extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawM
 ```dart
 extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
   @dart_ffi.Int64()
-  int get n { return rid_ffi.rid_mystruct_n(this); }
+  int get n { final res = rid_ffi.rid_mystruct_n(this); _ridThrowIfPanicked('rid_mystruct_n'); return res; }
+}
+```
+ "#;
+
+        let tokens = render_dart_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.trim());
+    }
+
+    // -----------------
+    // f64
+    // -----------------
+    #[test]
+    fn primitive_f64_rust() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               n: f64
+            }
+        };
+
+        let expected = quote! {
+            mod __my_struct_field_access {
+                use super::*;
+                fn rid_mystruct_n(ptr: *mut MyStruct) -> f64 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    receiver.n
+                }
+            }
+        };
+
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+
+    #[test]
+    fn primitive_f64_dart() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               n: f64
+            }
+        };
+
+        let expected = r#"
+```dart
+extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
+  @dart_ffi.Double()
+  double get n { final res = rid_ffi.rid_mystruct_n(this); _ridThrowIfPanicked('rid_mystruct_n'); return res; }
+}
+```
+ "#;
+
+        let tokens = render_dart_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.trim());
+    }
+
+    #[test]
+    fn primitive_f32_dart() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               n: f32
+            }
+        };
+
+        let expected = r#"
+```dart
+extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
+  @dart_ffi.Float()
+  double get n { final res = rid_ffi.rid_mystruct_n(this); _ridThrowIfPanicked('rid_mystruct_n'); return res; }
 }
 ```
  "#;
@@ -184,8 +252,7 @@ extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawM
                 fn rid_mystruct_n(ptr: *mut MyStruct) -> u8 {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     if receiver.n {
                         1
@@ -211,7 +278,7 @@ extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawM
         let expected = r#"
 ```dart
 extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
-  bool get n { return rid_ffi.rid_mystruct_n(this) != 0; }
+  bool get n { final res = rid_ffi.rid_mystruct_n(this); _ridThrowIfPanicked('rid_mystruct_n'); return res != 0; }
 }
 ```
  "#;
@@ -246,8 +313,7 @@ mod struct_field_access_single_strings {
                 fn rid_mystruct_s(ptr: *mut MyStruct) -> *const ::std::os::raw::c_char {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     let cstring = ::std::ffi::CString::new(receiver.s.as_str())
                         .expect(&format!("Invalid string encountered"));
@@ -256,8 +322,7 @@ mod struct_field_access_single_strings {
                 fn rid_mystruct_s_len(ptr: *mut MyStruct) -> usize {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     receiver.s.len()
                 }
@@ -281,6 +346,7 @@ mod struct_field_access_single_strings {
 extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
   String get s {
     dart_ffi.Pointer<dart_ffi.Int8>? ptr = rid_ffi.rid_mystruct_s(this);
+    _ridThrowIfPanicked('rid_mystruct_s');
     int len = rid_ffi.rid_mystruct_s_len(this);
     String s = ptr.toDartString(len);
     ptr.free();
@@ -312,16 +378,14 @@ extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawM
                 fn rid_mystruct_s(ptr: *mut MyStruct) -> *const ::std::os::raw::c_char {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     unsafe { &*receiver.s.as_ptr() }
                 }
                 fn rid_mystruct_s_len(ptr: *mut MyStruct) -> usize {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     receiver.s.as_bytes().len()
                 }
@@ -345,6 +409,7 @@ extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawM
 extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
   String get s {
     dart_ffi.Pointer<dart_ffi.Int8>? ptr = rid_ffi.rid_mystruct_s(this);
+    _ridThrowIfPanicked('rid_mystruct_s');
     int len = rid_ffi.rid_mystruct_s_len(this);
     String s = ptr.toDartString(len);
     ptr.free();
@@ -383,8 +448,7 @@ mod struct_field_access_single_custom_struct {
                 fn rid_mystruct_todo(ptr: *mut MyStruct) -> *const Todo {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     &receiver.todo as *const _ as *const Todo
                 }
@@ -408,7 +472,7 @@ mod struct_field_access_single_custom_struct {
         let expected = r#"
 ```dart
 extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
-  dart_ffi.Pointer<ffigen_bind.RawTodo> get todo { return rid_ffi.rid_mystruct_todo(this); }
+  dart_ffi.Pointer<ffigen_bind.RawTodo> get todo { final res = rid_ffi.rid_mystruct_todo(this); _ridThrowIfPanicked('rid_mystruct_todo'); return res; }
 }
 ```
 "#;
@@ -444,16 +508,14 @@ mod struct_field_access_single_vec_custom_struct {
                     fn rid_len_vec_todo(ptr: *mut Vec<Todo>) -> usize {
                         unsafe {
                             assert!(!ptr.is_null());
-                            let ptr: *mut Vec<Todo> = &mut *ptr;
-                            ptr.as_mut().expect("resolve_vec_ptr.as_mut failed")
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
                         }
                         .len()
                     }
                     fn rid_get_item_vec_todo(ptr: *mut Vec<Todo>, idx: usize) -> *const Todo {
                         let item = unsafe {
                             assert!(!ptr.is_null());
-                            let ptr: *mut Vec<Todo> = &mut *ptr;
-                            ptr.as_mut().expect("resolve_vec_ptr.as_mut failed")
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
                         }
                         .get(idx)
                         .expect(&format!(
@@ -467,8 +529,7 @@ mod struct_field_access_single_vec_custom_struct {
                 fn rid_mystruct_todos(ptr: *mut MyStruct) -> *const Vec<Todo> {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     &receiver.todos as *const _ as *const Vec<Todo>
                 }
@@ -490,7 +551,7 @@ mod struct_field_access_single_vec_custom_struct {
         let expected = r#"
 ```dart
 extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
-  dart_ffi.Pointer<ffigen_bind.Vec_Todo> get todos { return rid_ffi.rid_mystruct_todos(this); }
+  dart_ffi.Pointer<ffigen_bind.Vec_Todo> get todos { final res = rid_ffi.rid_mystruct_todos(this); _ridThrowIfPanicked('rid_mystruct_todos'); return res; }
 }
 ```
 "#;
@@ -525,16 +586,14 @@ mod struct_field_access_single_vec_u8 {
                     fn rid_len_vec_u8(ptr: *mut Vec<u8>) -> usize {
                         unsafe {
                             assert!(!ptr.is_null());
-                            let ptr: *mut Vec<u8> = &mut *ptr;
-                            ptr.as_mut().expect("resolve_vec_ptr.as_mut failed")
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
                         }
                         .len()
                     }
                     fn rid_get_item_vec_u8(ptr: *mut Vec<u8>, idx: usize) -> u8 {
                         let item = unsafe {
                             assert!(!ptr.is_null());
-                            let ptr: *mut Vec<u8> = &mut *ptr;
-                            ptr.as_mut().expect("resolve_vec_ptr.as_mut failed")
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
                         }
                         .get(idx)
                         .expect(&format!(
@@ -548,8 +607,7 @@ mod struct_field_access_single_vec_u8 {
                 fn rid_mystruct_todos(ptr: *mut MyStruct) -> *const Vec<u8> {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     &receiver.todos as *const _ as *const Vec<u8>
                 }
@@ -646,8 +704,7 @@ mod struct_field_access_single_hash_map_u8_u8 {
                 fn rid_mystruct_u8s(ptr: *mut MyStruct) -> *const HashMap<u8, u8> {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     &receiver.u8s as *const _ as *const HashMap<u8, u8>
                 }
@@ -662,6 +719,62 @@ mod struct_field_access_single_hash_map_u8_u8 {
     }
 }
 
+// -----------------
+// Single Field VecDeque<u8>
+// -----------------
+mod struct_field_access_single_vec_deque_u8 {
+    use super::*;
+
+    #[test]
+    fn vec_deque_u8_rust() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               history: VecDeque<u8>
+            }
+        };
+
+        let allow = allow_prelude();
+        let expected = quote! {
+            mod __my_struct_field_access {
+                use super::*;
+                #allow
+                mod mod_vec_deque_u8_access {
+                    use super::*;
+                    fn rid_len_vec_deque_u8(ptr: *mut VecDeque<u8>) -> usize {
+                        unsafe {
+                            assert!(!ptr.is_null());
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
+                        }
+                        .len()
+                    }
+                    fn rid_get_item_vec_deque_u8(ptr: *mut VecDeque<u8>, idx: usize) -> u8 {
+                        let item = unsafe {
+                            assert!(!ptr.is_null());
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
+                        }
+                        .get(idx)
+                        .expect(&format!(
+                            "Failed to access {fn_get_ident}({idx})",
+                            fn_get_ident = "rid_get_item_vec_deque_u8",
+                            idx = idx
+                        ));
+                        *item
+                    }
+                }
+                fn rid_mystruct_history(ptr: *mut MyStruct) -> *const VecDeque<u8> {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    &receiver.history as *const _ as *const VecDeque<u8>
+                }
+            }
+        };
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+}
+
 mod struct_field_access_rid_skip {
     use crate::common::dump_tokens;
 
@@ -698,8 +811,7 @@ mod struct_field_access_rid_skip {
                 fn rid_mystruct_but_not_me(ptr: *mut MyStruct) -> u32 {
                     let receiver = unsafe {
                         assert!(!ptr.is_null());
-                        let ptr: *mut MyStruct = &mut *ptr;
-                        ptr.as_mut().expect("resolve_ptr.as_mut failed")
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
                     };
                     receiver.but_not_me
                 }
@@ -710,3 +822,263 @@ mod struct_field_access_rid_skip {
         assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
     }
 }
+
+// -----------------
+// Unnamed Fields
+// -----------------
+mod struct_field_access_unnamed_fields {
+    use super::*;
+
+    #[test]
+    fn newtype_u32_rust() {
+        let input: TokenStream = quote! {
+            struct TodoId(u32);
+        };
+
+        let expected = quote! {
+            mod __todo_id_field_access {
+                use super::*;
+                fn rid_todoid_field0(ptr: *mut TodoId) -> u32 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    receiver.0
+                }
+            }
+        };
+
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+
+    #[test]
+    fn named_tuple_fields_rust() {
+        let input: TokenStream = quote! {
+            struct Point(#[rid(name = "x")] i32, #[rid(name = "y")] i32);
+        };
+
+        let expected = quote! {
+            mod __point_field_access {
+                use super::*;
+                fn rid_point_x(ptr: *mut Point) -> i32 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    receiver.0
+                }
+                fn rid_point_y(ptr: *mut Point) -> i32 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    receiver.1
+                }
+            }
+        };
+
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+
+    #[test]
+    fn tuple_fields_dart() {
+        let input: TokenStream = quote! {
+            struct Pair(u8, #[rid(name = "label")] String);
+        };
+
+        let expected = r#"
+```dart
+extension Rid_Model_ExtOnPointerRawPair on dart_ffi.Pointer<ffigen_bind.RawPair> {
+  @dart_ffi.Int32()
+  int get field0 { final res = rid_ffi.rid_pair_field0(this); _ridThrowIfPanicked('rid_pair_field0'); return res; }
+  String get label {
+    dart_ffi.Pointer<dart_ffi.Int8>? ptr = rid_ffi.rid_pair_label(this);
+    _ridThrowIfPanicked('rid_pair_label');
+    int len = rid_ffi.rid_pair_label_len(this);
+    String s = ptr.toDartString(len);
+    ptr.free();
+    return s;
+  }
+}
+```
+ "#;
+
+        let tokens = render_dart_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.trim());
+    }
+}
+
+// -----------------
+// Nested Collections
+// -----------------
+mod struct_field_access_nested_collections {
+    use super::*;
+
+    #[test]
+    fn vec_vec_u8_rust() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               grid: Vec<Vec<u8>>
+            }
+        };
+
+        let allow = allow_prelude();
+        let expected = quote! {
+            mod __my_struct_field_access {
+                use super::*;
+                #allow
+                mod mod_vec_u8_access {
+                    use super::*;
+                    fn rid_len_vec_u8(ptr: *mut Vec<u8>) -> usize {
+                        unsafe {
+                            assert!(!ptr.is_null());
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
+                        }
+                        .len()
+                    }
+                    fn rid_get_item_vec_u8(ptr: *mut Vec<u8>, idx: usize) -> u8 {
+                        let item = unsafe {
+                            assert!(!ptr.is_null());
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
+                        }
+                        .get(idx)
+                        .expect(&format!(
+                            "Failed to access {fn_get_ident}({idx})",
+                            fn_get_ident = "rid_get_item_vec_u8",
+                            idx = idx
+                        ));
+                        *item
+                    }
+                }
+                #allow
+                mod mod_vec_vec_u8_access {
+                    use super::*;
+                    fn rid_len_vec_vec_u8(ptr: *mut Vec<Vec<u8> >) -> usize {
+                        unsafe {
+                            assert!(!ptr.is_null());
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
+                        }
+                        .len()
+                    }
+                    fn rid_get_item_vec_vec_u8(
+                        ptr: *mut Vec<Vec<u8> >,
+                        idx: usize
+                    ) -> *const Vec<u8> {
+                        let item = unsafe {
+                            assert!(!ptr.is_null());
+                            ptr.as_ref().expect("resolve_vec_ptr.as_ref failed")
+                        }
+                        .get(idx)
+                        .expect(&format!(
+                            "Failed to access {fn_get_ident}({idx})",
+                            fn_get_ident = "rid_get_item_vec_vec_u8",
+                            idx = idx
+                        ));
+                        item as *const Vec<u8>
+                    }
+                }
+                fn rid_mystruct_grid(ptr: *mut MyStruct) -> *const Vec<Vec<u8> > {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    &receiver.grid as *const _ as *const Vec<Vec<u8> >
+                }
+            }
+        };
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+
+    #[test]
+    fn option_u32_rust() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               slot: Option<u32>
+            }
+        };
+
+        let expected = quote! {
+            mod __my_struct_field_access {
+                use super::*;
+                fn rid_mystruct_slot(ptr: *mut MyStruct) -> *const u32 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    rid::_option_ref_to_pointer((&receiver.slot).as_ref())
+                }
+            }
+        };
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+
+    #[test]
+    fn option_f64_rust() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               weight: Option<f64>
+            }
+        };
+
+        let expected = quote! {
+            mod __my_struct_field_access {
+                use super::*;
+                fn rid_mystruct_weight(ptr: *mut MyStruct) -> *const f64 {
+                    let receiver = unsafe {
+                        assert!(!ptr.is_null());
+                        ptr.as_ref().expect("resolve_ptr.as_ref failed")
+                    };
+                    rid::_option_ref_to_pointer((&receiver.weight).as_ref())
+                }
+            }
+        };
+        let tokens = render_rust_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.to_string().trim());
+    }
+
+    #[test]
+    fn option_f32_dart() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               discount: Option<f32>
+            }
+        };
+
+        let expected = r#"
+```dart
+extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
+  @dart_ffi.Float()
+  dart_ffi.Pointer<dart_ffi.Float>? get discount { final ptr = rid_ffi.rid_mystruct_discount(this); _ridThrowIfPanicked('rid_mystruct_discount'); return ptr.address == 0x0 ? null : ptr; }
+}
+```
+ "#;
+
+        let tokens = render_dart_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.trim());
+    }
+
+    #[test]
+    fn option_f64_dart() {
+        let input: TokenStream = quote! {
+            struct MyStruct {
+               weight: Option<f64>
+            }
+        };
+
+        let expected = r#"
+```dart
+extension Rid_Model_ExtOnPointerRawMyStruct on dart_ffi.Pointer<ffigen_bind.RawMyStruct> {
+  @dart_ffi.Double()
+  dart_ffi.Pointer<dart_ffi.Double>? get weight { final ptr = rid_ffi.rid_mystruct_weight(this); _ridThrowIfPanicked('rid_mystruct_weight'); return ptr.address == 0x0 ? null : ptr; }
+}
+```
+ "#;
+
+        let tokens = render_dart_field_access(input);
+        assert_eq!(tokens.to_string().trim(), expected.trim());
+    }
+}
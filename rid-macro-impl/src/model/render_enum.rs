@@ -1,18 +1,22 @@
 use crate::{
-    attrs::{parse_derive_attrs, EnumConfig},
+    attrs::{parse_derive_attrs, Derive, EnumConfig},
     common::extract_variant_names,
     model::debug::render_debug,
     parse::{rust_type::RustType, ParsedEnum},
+    render_rust::allow_prelude,
 };
 use proc_macro2::TokenStream;
 
-use quote::quote_spanned;
+use quote::{format_ident, quote_spanned};
 use syn::ItemEnum;
 
 pub fn render_enum(enum_item: &ItemEnum) -> TokenStream {
     let derive = parse_derive_attrs(&enum_item.attrs);
     let enum_config = EnumConfig::from(enum_item);
     let parsed_enum = ParsedEnum::from(enum_item, enum_config);
+    if parsed_enum.has_data_variants() {
+        return render_data_enum(&parsed_enum, &derive);
+    }
 
     // -----------------
     // Dart Enum
@@ -46,6 +50,12 @@ pub fn render_enum(enum_item: &ItemEnum) -> TokenStream {
     // -----------------
     let resolution_impl = parsed_enum.render_enum_resolution_impl();
 
+    // -----------------
+    // Decode from/encode into rid wire format when part of a message/reply
+    // -----------------
+    let wire_decode_impl = parsed_enum.render_wire_decode_impl();
+    let wire_encode_impl = parsed_enum.render_wire_encode_impl();
+
     // -----------------
     // Combine all the above
     // -----------------
@@ -54,5 +64,73 @@ pub fn render_enum(enum_item: &ItemEnum) -> TokenStream {
         #export_enum_tokens
         #resolution_impl
         #derive_debug_tokens
+        #wire_decode_impl
+        #wire_encode_impl
+    }
+}
+
+/// Renders an enum whose variants carry data, i.e. `Circle(f64)`.
+/// Unlike c-style enums it is passed to Dart as a pointer, just like structs, and thus needs to
+/// be included via `#[rid::structs(Shape)]` wherever it is used.
+fn render_data_enum(parsed_enum: &ParsedEnum, derive: &Derive) -> TokenStream {
+    let ident = &parsed_enum.ident;
+
+    // -----------------
+    // Dart Class Hierarchy including toDart()
+    // -----------------
+    let dart_classes: TokenStream = parsed_enum
+        .render_dart_data_enum_classes("///")
+        .parse()
+        .unwrap();
+    let mod_ident = format_ident!("__rid_{}_dart_mod", ident);
+    let fn_ident = format_ident!("_to_dart_for_{}", ident);
+    let allow = allow_prelude();
+    let dart_tokens = quote_spanned! { ident.span() =>
+        #allow
+        mod #mod_ident {
+            #dart_classes
+            #[no_mangle]
+            pub extern "C" fn #fn_ident() {}
+        }
+    };
+
+    // -----------------
+    // derive(Debug)
+    // -----------------
+    let derive_debug_tokens = if derive.debug {
+        let rust_type = RustType::from_owned_struct(ident);
+        render_debug(rust_type, &None, Default::default())
+    } else {
+        TokenStream::new()
+    };
+
+    // -----------------
+    // rid::model variant and field access
+    // -----------------
+    let (field_access_tokens, _) = parsed_enum
+        .render_field_access(&Default::default(), &Default::default());
+
+    // -----------------
+    // Decode from/encode into rid wire format when part of a message/reply
+    // -----------------
+    let wire_decode_impl = if parsed_enum.is_wire_encodable() {
+        let decode_impl = parsed_enum.render_wire_decode_impl();
+        let encode_impl = parsed_enum.render_wire_encode_impl();
+        quote_spanned! { ident.span() =>
+            #decode_impl
+            #encode_impl
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    // -----------------
+    // Combine all the above
+    // -----------------
+    quote_spanned! { ident.span() =>
+        #dart_tokens
+        #derive_debug_tokens
+        #field_access_tokens
+        #wire_decode_impl
     }
 }
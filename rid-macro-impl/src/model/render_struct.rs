@@ -1,8 +1,14 @@
-use super::{debug::render_debug, to_dart::render_to_dart};
+use super::{
+    debug::render_debug,
+    to_dart::{render_model_encode, render_to_dart},
+};
 use crate::{
     attrs::{parse_derive_attrs, StructConfig},
     common::abort,
-    model::store::{render_store_field_wrapper_extension, render_store_module},
+    model::store::{
+        render_store_changes, render_store_encode,
+        render_store_field_wrapper_extension, render_store_module, StoreMode,
+    },
     parse,
     parse::{rust_type::RustType, ParsedStruct},
 };
@@ -10,14 +16,26 @@ use proc_macro2::TokenStream;
 use quote::quote_spanned;
 use syn::{Fields, ItemStruct};
 
-pub fn render_struct(struct_item: &ItemStruct, is_store: bool) -> TokenStream {
+pub fn render_struct(
+    struct_item: &ItemStruct,
+    store_mode: Option<StoreMode>,
+    is_transparent: bool,
+) -> TokenStream {
+    let is_store = store_mode.is_some();
     let derive = parse_derive_attrs(&struct_item.attrs);
-    let struct_config = StructConfig::from(&struct_item);
+    let mut struct_config = StructConfig::from(struct_item);
+    struct_config.transparent = is_transparent;
     let parsed_struct = ParsedStruct::new(
         &struct_item,
         &struct_item.ident,
         struct_config.clone(),
     );
+    if is_transparent && (is_store || parsed_struct.fields.len() != 1) {
+        abort!(
+            struct_item.ident,
+            "rid::model(transparent) can only be applied to structs with exactly one field"
+        )
+    }
 
     // -----------------
     // Dart Class
@@ -38,20 +56,32 @@ pub fn render_struct(struct_item: &ItemStruct, is_store: bool) -> TokenStream {
     // -----------------
     // Store Module
     // -----------------
-    let (store_module, store_wrapper_tokens) = if is_store {
-        (
-            render_store_module(&struct_item.ident),
-            render_store_field_wrapper_extension(&parsed_struct),
-        )
+    let (store_module, store_wrapper_tokens, store_changes_tokens) =
+        match store_mode {
+            Some(store_mode) => (
+                render_store_module(
+                    &struct_item.ident,
+                    store_mode,
+                    derive.debug,
+                ),
+                render_store_field_wrapper_extension(&parsed_struct),
+                render_store_changes(&parsed_struct),
+            ),
+            None => {
+                (TokenStream::new(), TokenStream::new(), TokenStream::new())
+            }
+        };
+    let encode_tokens = if is_store {
+        render_store_encode(&parsed_struct)
     } else {
-        (TokenStream::new(), TokenStream::new())
+        render_model_encode(&parsed_struct)
     };
 
     // -----------------
     // rid::model field access
     // -----------------
     let exports = match &struct_item.fields {
-        Fields::Named(fields) => {
+        Fields::Named(_) | Fields::Unnamed(_) => {
             let parsed_struct = ParsedStruct::new(
                 &struct_item,
                 &struct_item.ident,
@@ -61,16 +91,29 @@ pub fn render_struct(struct_item: &ItemStruct, is_store: bool) -> TokenStream {
                 .render_field_access(&Default::default(), &Default::default());
             tokens
         }
-        Fields::Unnamed(_) => abort!(
-            struct_item.ident,
-            "not yet supporting structs with unnamed fields"
-        ),
         Fields::Unit => abort!(
             struct_item.ident,
             "structs without fields cannot be a rid::model"
         ),
     };
 
+    // -----------------
+    // Decode from/encode into rid wire format when part of a message/reply
+    // -----------------
+    // Fields excluded via `#[rid(skip)]` are not encoded and thus the struct can only be
+    // decoded when it has none.
+    let wire_decode_tokens = if !is_store && parsed_struct.is_wire_encodable() {
+        parsed_struct.render_wire_decode_impl()
+    } else {
+        TokenStream::new()
+    };
+    let wire_encode_tokens =
+        if !is_store && parsed_struct.has_wire_encodable_fields() {
+            parsed_struct.render_wire_encode_impl()
+        } else {
+            TokenStream::new()
+        };
+
     // -----------------
     // Combine all the above
     // -----------------
@@ -80,5 +123,9 @@ pub fn render_struct(struct_item: &ItemStruct, is_store: bool) -> TokenStream {
         #store_module
         #exports
         #store_wrapper_tokens
+        #store_changes_tokens
+        #encode_tokens
+        #wire_decode_tokens
+        #wire_encode_tokens
     }
 }
@@ -1,6 +1,16 @@
 mod store_api;
+mod store_changes;
+mod store_encode;
 mod store_field_wrappers;
 mod store_module;
 pub use store_api::*;
+pub use store_changes::*;
+pub use store_encode::*;
 pub use store_field_wrappers::*;
 pub use store_module::*;
+
+#[cfg(test)]
+mod store_changes_test;
+
+#[cfg(test)]
+mod store_encode_test;
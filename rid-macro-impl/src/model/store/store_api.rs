@@ -13,11 +13,18 @@ impl ParsedStruct {
         let store_field = store_field_ident(store_ident);
 
         let debug_api = if derive.debug {
-            format!("{comment}   String debug([bool pretty = false]) => _store.debug(pretty);", comment = comment)
+            format!("{comment}   String debug([bool pretty = false]) => _read((store) => store.debug(pretty), 'store.debug()');", comment = comment)
         } else {
             "".to_string()
         };
 
+        // Transfer the entire store in one FFI call when possible, see `render_store_encode`
+        let to_dart = if self.can_encode_store_state() {
+            "toDartEncoded"
+        } else {
+            "toDart"
+        };
+
         format!(
             r###"
 {comment} // rid API that provides memory safety and which is recommended to use.
@@ -40,7 +47,7 @@ impl ParsedStruct {
 {comment}     return {_store}.runLocked(accessor, request: request);
 {comment}   }}
 {comment}
-{comment}   {Store}State toDartState() => _store.toDart();
+{comment}   {Store}State toDartState() => _read((store) => store.{to_dart}(), 'store.toDartState()');
 {debug_api}
 {comment}
 {comment}   /// Disposes the store and closes the Rust reply channel in order to allow the app
@@ -66,6 +73,7 @@ impl ParsedStruct {
             _store = store_field,
             createStore = RID_CREATE_STORE,
             debug_api = debug_api,
+            to_dart = to_dart,
             comment = comment
         )
     }
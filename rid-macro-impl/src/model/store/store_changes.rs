@@ -0,0 +1,163 @@
+use heck::MixedCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote_spanned};
+use rid_common::_RID_REPLY_CHANNEL;
+
+use crate::{
+    parse::{ParsedStruct, ParsedStructField},
+    render_rust::allow_prelude,
+};
+
+/// Changed fields are tracked via a `u64` bitmask and thus only for stores with at most 64
+/// fields. Updates of larger stores report all fields as changed.
+const MAX_TRACKED_FIELDS: usize = 64;
+
+/// Renders setters for all store fields which mark the field as changed.
+/// The changed fields are posted to Dart after the store is updated and surfaced via the
+/// `changes` stream of the Dart `Store`, i.e. to only rebuild widgets that depend on them.
+///
+/// For a store field `todos` this renders `todos_mut()` and `set_todos(..)` as part of the
+/// `StoreFieldSetters` trait so that they never clash with methods the store already has.
+///
+/// Field level changes are only reported if updates use these setters. Fields modified without
+/// them are not marked and if no field was marked during an update that mutably accessed the
+/// store, all fields are reported as changed via `_RID_ALL_FIELDS`.
+pub fn render_store_changes(parsed_struct: &ParsedStruct) -> TokenStream {
+    parsed_struct.render_store_changes()
+}
+
+impl ParsedStruct {
+    fn render_store_changes(&self) -> TokenStream {
+        let store_ident = &self.ident;
+        let tracks_fields = self.fields.len() <= MAX_TRACKED_FIELDS;
+        let (setter_decls, setters): (Vec<TokenStream>, Vec<TokenStream>) =
+            self.fields
+                .iter()
+                .enumerate()
+                .map(|(idx, x)| {
+                    let idx = if tracks_fields {
+                        Some(idx as u32)
+                    } else {
+                        None
+                    };
+                    x.render_change_tracking_setters(idx)
+                })
+                .unzip();
+        let all_fields: u64 = match self.fields.len() {
+            len if len < MAX_TRACKED_FIELDS => (1 << len) - 1,
+            _ => u64::MAX,
+        };
+        let setters_trait_ident = format_ident!("{}FieldSetters", self.ident);
+        let setters_trait_doc = format!(
+            " Setters of the {store} fields which mark the field as changed in order to only \
+            report changed fields to Dart.\n\n Updates that modify fields without them report \
+            all fields as changed. Bring it into scope when updating the {store} outside of the \
+            module that defines it.",
+            store = self.ident
+        );
+
+        let comment = "///";
+        let field_enum = format!("{}Field", self.ident);
+        let dart_fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|x| x.ident.to_string().to_mixed_case())
+            .collect();
+        let dart_tokens: TokenStream = format!(
+            r###"
+{comment} ```dart
+{comment} /// Fields of the store, used to report which of them changed during an update.
+{comment} enum {FieldEnum} {{ {fields} }}
+{comment}
+{comment} extension StoreChangesOn_{Store} on {Store} {{
+{comment}   /// Emits the set of fields that changed each time the store was updated.
+{comment}   ///
+{comment}   /// Fields are only reported individually if the update modified them via the
+{comment}   /// generated setters, i.e. `set_filter` or `todos_mut`, otherwise all fields are reported.
+{comment}   Stream<Set<{FieldEnum}>> get changes =>
+{comment}       {_RID_REPLY_CHANNEL}.storeChanges.map({to_fields});
+{comment} }}
+{comment} ```"###,
+            Store = self.ident,
+            FieldEnum = field_enum,
+            fields = dart_fields.join(", "),
+            to_fields = if tracks_fields {
+                format!(
+                    "(mask) => {FieldEnum}.values\n{comment}           .where((field) => mask & (1 << field.index) != 0)\n{comment}           .toSet()",
+                    FieldEnum = field_enum,
+                    comment = comment
+                )
+            } else {
+                format!(
+                    "(_) => {FieldEnum}.values.toSet()",
+                    FieldEnum = field_enum
+                )
+            },
+            _RID_REPLY_CHANNEL = _RID_REPLY_CHANNEL,
+            comment = comment,
+        )
+        .parse()
+        .unwrap();
+
+        let mod_ident = format_ident!("{}_store_changes", self.ident);
+        let fn_ident = format_ident!("_include_{}_store_changes", self.ident);
+        let allow = allow_prelude();
+        quote_spanned! { self.ident.span() =>
+            #[doc = #setters_trait_doc]
+            pub trait #setters_trait_ident {
+                #(#setter_decls)*
+            }
+
+            impl #setters_trait_ident for #store_ident {
+                #(#setters)*
+            }
+
+            impl #store_ident {
+                /// Mask of all fields posted as changed when none was marked during an update.
+                pub(crate) const _RID_ALL_FIELDS: u64 = #all_fields;
+            }
+
+            #allow
+            mod #mod_ident {
+                #dart_tokens
+                #[no_mangle]
+                pub extern "C" fn #fn_ident() {}
+            }
+        }
+    }
+}
+
+impl ParsedStructField {
+    /// Renders the declarations and implementations of the setters of this field.
+    /// They mark the field at `idx` as changed unless changes of the store aren't tracked per
+    /// field.
+    fn render_change_tracking_setters(
+        &self,
+        idx: Option<u32>,
+    ) -> (TokenStream, TokenStream) {
+        let member = &self.member;
+        let ty = &self.ty;
+        let mut_ident = format_ident!("{}_mut", self.ident);
+        let set_ident = format_ident!("set_{}", self.ident);
+        let mark_changed = idx.map(|idx| {
+            quote_spanned! { self.ident.span() =>
+                rid::_mark_store_field_changed(#idx);
+            }
+        });
+        let decls = quote_spanned! { self.ident.span() =>
+            fn #mut_ident(&mut self) -> &mut #ty;
+            fn #set_ident(&mut self, val: #ty);
+        };
+        let setters = quote_spanned! { self.ident.span() =>
+            fn #mut_ident(&mut self) -> &mut #ty {
+                #mark_changed
+                &mut self.#member
+            }
+            fn #set_ident(&mut self, val: #ty) {
+                #mark_changed
+                self.#member = val;
+            }
+        };
+        (decls, setters)
+    }
+}
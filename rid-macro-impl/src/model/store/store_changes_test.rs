@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{attrs::StructConfig, parse::ParsedStruct};
+
+use super::render_store_changes;
+
+fn render(input: TokenStream) -> String {
+    let item = syn::parse2::<syn::ItemStruct>(input).unwrap();
+    let parsed_struct =
+        ParsedStruct::new(&item, &item.ident, StructConfig::from(&item));
+    render_store_changes(&parsed_struct).to_string()
+}
+
+#[test]
+fn store_setters_mark_fields_changed() {
+    let res = render(quote! {
+        struct Store {
+            count: u32,
+            #[rid(skip)]
+            cache: Vec<u8>,
+            last_added: String,
+        }
+    });
+    let expected = quote! {
+        #[doc = " Setters of the Store fields which mark the field as changed in order to only report changed fields to Dart.\n\n Updates that modify fields without them report all fields as changed. Bring it into scope when updating the Store outside of the module that defines it."]
+        pub trait StoreFieldSetters {
+            fn count_mut(&mut self) -> &mut u32;
+            fn set_count(&mut self, val: u32);
+            fn last_added_mut(&mut self) -> &mut String;
+            fn set_last_added(&mut self, val: String);
+        }
+        impl StoreFieldSetters for Store {
+            fn count_mut(&mut self) -> &mut u32 {
+                rid::_mark_store_field_changed(0u32);
+                &mut self.count
+            }
+            fn set_count(&mut self, val: u32) {
+                rid::_mark_store_field_changed(0u32);
+                self.count = val;
+            }
+            fn last_added_mut(&mut self) -> &mut String {
+                rid::_mark_store_field_changed(1u32);
+                &mut self.last_added
+            }
+            fn set_last_added(&mut self, val: String) {
+                rid::_mark_store_field_changed(1u32);
+                self.last_added = val;
+            }
+        }
+        impl Store {
+            #[doc = r" Mask of all fields posted as changed when none was marked during an update."]
+            pub(crate) const _RID_ALL_FIELDS: u64 = 3u64;
+        }
+    };
+    assert!(res.starts_with(&expected.to_string()), "setters: {}", res);
+    assert!(
+        res.contains("enum StoreField { count, lastAdded }"),
+        "dart field enum: {}",
+        res
+    );
+    assert!(
+        res.contains("Stream<Set<StoreField>> get changes"),
+        "dart changes stream: {}",
+        res
+    );
+}
+
+#[test]
+fn store_with_more_fields_than_tracked_reports_all_fields() {
+    let fields = (0..65u32).map(|x| {
+        let ident = quote::format_ident!("f{}", x);
+        quote! { #ident: u8, }
+    });
+    let res = render(quote! {
+        struct Store {
+            #(#fields)*
+        }
+    });
+    let expected_setters = quote! {
+        fn f0_mut(&mut self) -> &mut u8 {
+            &mut self.f0
+        }
+        fn set_f0(&mut self, val: u8) {
+            self.f0 = val;
+        }
+    };
+    assert!(
+        res.contains(&expected_setters.to_string()),
+        "setters don't mark fields: {}",
+        res
+    );
+    assert!(
+        !res.contains("_mark_store_field_changed"),
+        "no field marked: {}",
+        res
+    );
+    assert!(
+        res.contains(
+            &quote! { pub(crate) const _RID_ALL_FIELDS: u64 = 18446744073709551615u64; }
+                .to_string()
+        ),
+        "all fields mask: {}",
+        res
+    );
+    assert!(
+        res.contains("storeChanges.map((_) => StoreField.values.toSet())"),
+        "dart reports all fields: {}",
+        res
+    );
+}
@@ -0,0 +1,118 @@
+use heck::MixedCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote_spanned};
+
+use crate::{
+    common::{prefixes::store_state_class_ident, tokens::resolve_ptr},
+    model::to_dart::{render_to_dart_encoded_extension, EncodeFnIdents},
+    parse::ParsedStruct,
+    render_dart::dart_wire_decode_fn_name,
+    render_rust::allow_prelude,
+};
+
+/// Renders the conversion of the entire store into its Dart state via a single FFI call.
+///
+/// Rust encodes all store fields, including nested models and collections, into one buffer
+/// using the rid wire format which is decoded by the rendered Dart code.
+/// This is much faster than resolving each field and collection item via a separate FFI call
+/// which is what `toDart()` does.
+///
+/// Nothing is rendered if the store has no fields or any store field cannot be encoded in which
+/// case `toDartState()` falls back to `toDart()`.
+pub fn render_store_encode(parsed_struct: &ParsedStruct) -> TokenStream {
+    if parsed_struct.can_encode_store_state() {
+        parsed_struct.render_store_encode()
+    } else {
+        TokenStream::new()
+    }
+}
+
+impl ParsedStruct {
+    /// Returns `true` if the store state can be transferred to Dart in one FFI call, see
+    /// [render_store_encode].
+    pub fn can_encode_store_state(&self) -> bool {
+        !self.fields.is_empty() && self.has_wire_encodable_fields()
+    }
+
+    fn render_store_encode(&self) -> TokenStream {
+        let store_ident = &self.ident;
+        let method_prefix =
+            format!("rid_{}", self.ident.to_string().to_lowercase());
+        let fn_idents = EncodeFnIdents {
+            encode: format_ident!("{}_encode_state", method_prefix),
+            free: format_ident!("{}_free_encoded_state", method_prefix),
+        };
+        let resolve_store = resolve_ptr(store_ident);
+
+        let encode_fields = self.fields.iter().map(|x| {
+            let member = &x.member;
+            quote_spanned! { x.ident.span() =>
+                rid::_RidEncode::_rid_encode(&store.#member, writer);
+            }
+        });
+
+        let comment = "///";
+        let state_class = store_state_class_ident(&self.ident).to_string();
+        let decode_fields: String = self
+            .fields
+            .iter()
+            .map(|x| {
+                format!(
+                    "{comment}     {decode},\n",
+                    decode = x.rust_type.render_dart_wire_decode(),
+                    comment = comment
+                )
+            })
+            .collect();
+        let to_dart_encoded = render_to_dart_encoded_extension(
+            &self.ident,
+            &self.raw_ident,
+            &state_class,
+            &fn_idents,
+            comment,
+        );
+        let dart_tokens: TokenStream = format!(
+            r###"
+{comment} ```dart
+{comment} // Decodes {State} from the rid wire format when converting the store via `toDartEncoded`
+{comment} {State} {decode_fn}(RidWireReader r) {{
+{comment}   return {State}._(
+{decode_fields}{comment}   );
+{comment} }}{to_dart_encoded}
+{comment} ```"###,
+            State = state_class,
+            decode_fn = dart_wire_decode_fn_name(&state_class),
+            decode_fields = decode_fields,
+            to_dart_encoded = to_dart_encoded,
+            comment = comment,
+        )
+        .parse()
+        .unwrap();
+
+        let mod_ident = format_ident!("__rid_{}_encode_state", self.ident);
+        let EncodeFnIdents {
+            encode: encode_ident,
+            free: free_ident,
+        } = fn_idents;
+        let allow = allow_prelude();
+        quote_spanned! { self.ident.span() =>
+            #allow
+            mod #mod_ident {
+                use super::*;
+                #dart_tokens
+                #[no_mangle]
+                pub extern "C" fn #encode_ident(ptr: *mut #store_ident) -> *mut u8 {
+                    let store = #resolve_store;
+                    rid::_encode_wire_result(|writer| {
+                        #(#encode_fields)*
+                    })
+                }
+
+                #[no_mangle]
+                pub extern "C" fn #free_ident(ptr: *mut u8) {
+                    rid::_free_wire_result(ptr)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    attrs::{parse_derive_attrs, StructConfig},
+    model::to_dart::{render_to_dart, DartRenderImplConfig},
+    parse::ParsedStruct,
+};
+
+use super::render_store_encode;
+
+fn render(input: TokenStream) -> String {
+    let item = syn::parse2::<syn::ItemStruct>(input).unwrap();
+    let parsed_struct =
+        ParsedStruct::new(&item, &item.ident, StructConfig::from(&item));
+    render_store_encode(&parsed_struct).to_string()
+}
+
+#[test]
+fn store_encodes_included_fields() {
+    let res = render(quote! {
+        struct Store {
+            count: u32,
+            #[rid(skip)]
+            cache: Vec<u8>,
+            title: String,
+        }
+    });
+    let expected_encode = quote! {
+        pub extern "C" fn rid_store_encode_state(ptr: *mut Store) -> *mut u8 {
+            let store = unsafe {
+                assert!(!ptr.is_null());
+                let ptr = store::_resolve_dart_ptr(ptr as *const Store);
+                ptr.as_ref().expect("resolve_ptr.as_ref failed")
+            };
+            rid::_encode_wire_result(|writer| {
+                rid::_RidEncode::_rid_encode(&store.count, writer);
+                rid::_RidEncode::_rid_encode(&store.title, writer);
+            })
+        }
+    };
+    assert!(
+        res.contains(&expected_encode.to_string()),
+        "encode: {}",
+        res
+    );
+    assert!(
+        res.contains("pub extern \"C\" fn rid_store_free_encoded_state"),
+        "free: {}",
+        res
+    );
+    assert!(
+        res.contains(
+            r#"# [doc = "   return StoreState._("] # [doc = "     r.readU32(),"] # [doc = "     r.readString(),"] # [doc = "   );"]"#
+        ),
+        "dart decode: {}",
+        res
+    );
+}
+
+/// Names of the fields in the order `toDart` passes them to the `StoreState` constructor.
+fn to_dart_field_order(input: TokenStream) -> Vec<String> {
+    let item = syn::parse2::<syn::ItemStruct>(input).unwrap();
+    let parsed_struct =
+        ParsedStruct::new(&item, &item.ident, StructConfig::from(&item));
+    let res = render_to_dart(
+        &parsed_struct,
+        true,
+        &parse_derive_attrs(&item.attrs),
+        DartRenderImplConfig::for_tests(),
+    )
+    .to_string();
+    let start = "final instance = StoreState._(";
+    let args = &res[res.find(start).expect("toDart") + start.len()..];
+    args[..args.find(')').unwrap()]
+        .split(", ")
+        .map(|x| x.trim_start_matches("this.").to_string())
+        .collect()
+}
+
+/// Names of the fields in the order `toDartEncoded` encodes them.
+fn encoded_field_order(input: TokenStream) -> Vec<String> {
+    let res = render(input);
+    let start = "_rid_encode (& store .";
+    res.split(start)
+        .skip(1)
+        .map(|x| x[..x.find(',').unwrap()].trim().to_string())
+        .collect()
+}
+
+#[test]
+fn store_encodes_fields_in_to_dart_order() {
+    let input = quote! {
+        struct Store {
+            #[rid(skip)]
+            id: u64,
+            count: u32,
+            title: String,
+            #[rid(skip)]
+            cache: Vec<u8>,
+            done: bool,
+            #[rid(skip)]
+            scratch: String,
+        }
+    };
+    let to_dart = to_dart_field_order(input.clone());
+    assert_eq!(to_dart, vec!["count", "title", "done"], "toDart");
+    assert_eq!(encoded_field_order(input), to_dart, "toDartEncoded");
+}
+
+#[test]
+fn store_with_fields_that_cannot_be_encoded() {
+    let res = render(quote! {
+        struct Store {
+            count: u32,
+            name: &'static str,
+        }
+    });
+    assert_eq!(res, "", "renders nothing");
+}
+
+#[test]
+fn store_without_fields() {
+    let res = render(quote! {
+        struct Store {
+            #[rid(skip)]
+            cache: Vec<u8>,
+        }
+    });
+    assert_eq!(res, "", "renders nothing");
+}
@@ -4,6 +4,7 @@ use quote::{format_ident, quote_spanned};
 use syn::Ident;
 
 use crate::{
+    accesses::resolved_dart_item_type_string,
     attrs::TypeInfoMap,
     parse::{ParsedStruct, ParsedStructField},
     render_dart::RenderDartTypeOpts,
@@ -89,7 +90,7 @@ impl ParsedStructField {
             .rust_type
             .render_dart_type(type_infos, RenderDartTypeOpts::attr());
 
-        format!(
+        let getter = format!(
             r###"
 {comment}     {Type} get {field} =>
 {comment}       _read(({store}) => {to_dart}, '{store}.{raw_field}');"###,
@@ -99,6 +100,46 @@ impl ParsedStructField {
             store = store_instance,
             to_dart = to_dart,
             comment = comment,
-        )
+        );
+
+        match self.render_lazy_list_wrapper(
+            &store_instance,
+            type_infos,
+            comment,
+        ) {
+            Some(list) => format!("{}\n{}", getter, list),
+            None => getter,
+        }
+    }
+
+    /// Renders a method returning a [RidList] for `Vec` and `VecDeque` fields which reads items
+    /// on demand instead of converting the entire collection.
+    fn render_lazy_list_wrapper(
+        &self,
+        store: &str,
+        type_infos: &TypeInfoMap,
+        comment: &str,
+    ) -> Option<String> {
+        if !(self.rust_type.is_vec() || self.rust_type.is_vec_deque()) {
+            return None;
+        }
+        let item_type = self.rust_type.inner_composite_type()?;
+        let dart_item_type =
+            resolved_dart_item_type_string(&item_type, type_infos);
+        let field = self.ident.to_string().to_mixed_case();
+        let raw_field = &self.ident;
+
+        Some(format!(
+            r###"
+{comment}     RidList<{Item}> {field}List({{int window = 0, int maxCachedWindows = 4}}) => RidList(
+{comment}       () => _read(({store}) => {store}.{raw_field}.length, '{store}.{raw_field}.length'),
+{comment}       (idx, length) => _read(({store}) => {store}.{raw_field}.toDartAt(idx, length: length), '{store}.{raw_field}[$idx]'),
+{comment}       window: window, maxCachedWindows: maxCachedWindows);"###,
+            Item = dart_item_type,
+            field = field,
+            raw_field = raw_field,
+            store = store,
+            comment = comment,
+        ))
     }
 }
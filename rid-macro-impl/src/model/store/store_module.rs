@@ -9,10 +9,31 @@ use crate::{
     },
 };
 use rid_common::{
-    DART_FFI, FFI_GEN_BIND, RID_CREATE_STORE, RID_DEBUG_LOCK, RID_DEBUG_REPLY,
-    RID_FFI, RID_MSG_TIMEOUT, _RID_REPLY_CHANNEL,
+    _RID_REPLY_CHANNEL, DART_FFI, FFI_GEN_BIND, RID_CREATE_STORE,
+    RID_DEBUG_LOCK, RID_DEBUG_REPLY, RID_FFI, RID_MSG_TIMEOUT,
 };
-pub fn render_store_module(store_ident: &syn::Ident) -> TokenStream {
+/// Determines how the store is shared between Rust code updating it and Dart reading it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StoreMode {
+    /// The store is guarded by a `RwLock` which Dart read locks while accessing it,
+    /// i.e. during a Flutter build, during which updates block until Dart unlocks the store.
+    Locked,
+    /// Selected via `#[rid::store(snapshot)]`.
+    /// An immutable snapshot of the store is published after each update and Dart pins the
+    /// latest snapshot while accessing the store. Thus Dart never blocks updates and vice versa.
+    /// This requires the store to implement `Clone` since the latest snapshot shares its data
+    /// with the store updates are applied to, thus every update that modifies the store clones it.
+    /// Reading through `Store.raw` resolves to the pinned snapshot and thus is only valid
+    /// while Dart locks the store.
+    /// Exports taking `&mut self` on the store panic since the snapshot Dart reads is shared.
+    Snapshot,
+}
+
+pub fn render_store_module(
+    store_ident: &syn::Ident,
+    store_mode: StoreMode,
+    derive_debug: bool,
+) -> TokenStream {
     if &store_ident.to_string() != "Store" {
         abort!(
             store_ident,
@@ -21,16 +42,28 @@ pub fn render_store_module(store_ident: &syn::Ident) -> TokenStream {
     }
     let raw_store_ident = raw_typedef_ident(store_ident);
 
+    let (run_locked_doc, locked_store) = match store_mode {
+        StoreMode::Locked => (
+            "///   /// Executes the provided callback while locking the store to guarantee that the
+///   /// store is not modified while that callback runs.",
+            "this",
+        ),
+        StoreMode::Snapshot => (
+            "///   /// Executes the provided callback with the latest snapshot of the store which is
+///   /// pinned while the callback runs and thus not modified by updates.",
+            "_ridPinnedStore!",
+        ),
+    };
+
     let store_extension_dart: TokenStream = format!(
         r###"
 /// ```dart
 /// extension rid_store_specific_extension on {dart_ffi}.Pointer<{ffigen_bind}.{RawStore}> {{
-///   /// Executes the provided callback while locking the store to guarantee that the
-///   /// store is not modified while that callback runs.
+{run_locked_doc}
 ///   T runLocked<T>(T Function({dart_ffi}.Pointer<{ffigen_bind}.{RawStore}>) fn, {{String? request}}) {{
 ///     try {{
 ///       ridStoreLock(request: request);
-///       return fn(this);
+///       return fn({locked_store});
 ///     }} finally {{
 ///       ridStoreUnlock();
 ///     }}
@@ -46,15 +79,42 @@ pub fn render_store_module(store_ident: &syn::Ident) -> TokenStream {
         _RID_REPLY_CHANNEL = _RID_REPLY_CHANNEL,
         dart_ffi = DART_FFI,
         ffigen_bind = FFI_GEN_BIND,
-        RawStore = raw_store_ident
+        RawStore = raw_store_ident,
+        run_locked_doc = run_locked_doc,
+        locked_store = locked_store,
     )
     .parse()
     .unwrap();
 
+    let (pinned_store_decl, lock_store, unlock_store) = match store_mode {
+        StoreMode::Locked => (
+            "".to_string(),
+            format!("{rid_ffi}.rid_store_lock();", rid_ffi = RID_FFI),
+            format!("{rid_ffi}.rid_store_unlock();", rid_ffi = RID_FFI),
+        ),
+        StoreMode::Snapshot => (
+            format!(
+                "\n/// {dart_ffi}.Pointer<{ffigen_bind}.{RawStore}>? _ridPinnedStore;",
+                dart_ffi = DART_FFI,
+                ffigen_bind = FFI_GEN_BIND,
+                RawStore = raw_store_ident,
+            ),
+            format!(
+                "_ridPinnedStore = {rid_ffi}.rid_store_lock();",
+                rid_ffi = RID_FFI
+            ),
+            format!(
+                "{{ {rid_ffi}.rid_store_unlock(); _ridPinnedStore = null; }}",
+                rid_ffi = RID_FFI
+            ),
+        ),
+    };
+
     let rid_store_lock_wrapper: TokenStream = format!(
         r###"
 /// ```dart
 /// int _locks = 0;
+/// int _lockScopes = 0;{pinned_store_decl}
 ///
 /// void Function(bool, int, {{String? request}})? _RID_DEBUG_LOCK = (bool locking, int locks, {{String? request}}) {{
 ///   if (locking) {{
@@ -72,7 +132,10 @@ pub fn render_store_module(store_ident: &syn::Ident) -> TokenStream {
 /// }}
 ///
 /// void ridStoreLock({{String? request}}) {{
-///   if (_locks == 0) {rid_ffi}.rid_store_lock();
+///   if (_locks == 0) {{
+///     _lockScopes++;
+///     {lock_store}
+///   }}
 ///   _locks++;
 ///   if ({RID_DEBUG_LOCK} != null) {RID_DEBUG_LOCK}!(true, _locks, request: request);
 /// }}
@@ -80,12 +143,18 @@ pub fn render_store_module(store_ident: &syn::Ident) -> TokenStream {
 /// void ridStoreUnlock() {{
 ///   _locks--;
 ///   if ({RID_DEBUG_LOCK} != null) {RID_DEBUG_LOCK}!(false, _locks);
-///   if (_locks == 0) {rid_ffi}.rid_store_unlock();
+///   if (_locks == 0) {unlock_store}
 /// }}
+///
+/// /// Identifies the current lock scope in order to cache values read from the store until it
+/// /// is unlocked, `null` if the store isn't locked.
+/// int? ridStoreLockScope() => _locks > 0 ? _lockScopes : null;
 /// ```
 "###,
         RID_DEBUG_LOCK = RID_DEBUG_LOCK,
-        rid_ffi = RID_FFI,
+        pinned_store_decl = pinned_store_decl,
+        lock_store = lock_store,
+        unlock_store = unlock_store,
     )
     .parse()
     .unwrap();
@@ -117,56 +186,51 @@ pub fn render_store_module(store_ident: &syn::Ident) -> TokenStream {
     .parse()
     .unwrap();
 
+    // Stores that derive `Debug` record their final state when message recording stops
+    let register_snapshot = if derive_debug {
+        quote_spanned! { store_ident.span() =>
+            rid::_set_store_snapshot(|| format!("{:#?}", &*read()));
+        }
+    } else {
+        TokenStream::new()
+    };
+    let store_access = match store_mode {
+        StoreMode::Locked => {
+            render_locked_store_access(store_ident, &register_snapshot)
+        }
+        StoreMode::Snapshot => {
+            render_snapshot_store_access(store_ident, &register_snapshot)
+        }
+    };
+
     quote_spanned! {store_ident.span() =>
         pub mod store {
             use super::*;
-            /// cbindgen:ignore
-            static mut STORE_LOCK: Option<::std::sync::RwLock<#store_ident>> = None;
+            // An update that panicked while holding the write lock poisons it. Panics are caught
+            // and reported to Dart, thus the store stays accessible and poisoning is ignored.
+            use ::std::sync::PoisonError;
             /// cbindgen:ignore
             static mut STORE_ACCESS: Option<RidStoreAccess> = None;
             /// cbindgen:ignore
             static INIT_STORE: ::std::sync::Once = ::std::sync::Once::new();
-            /// cbindgen:ignore
-            static mut LOCK_READ_GUARD: Option<
-                ::std::sync::RwLockReadGuard<'static, #store_ident>,
-            > = None;
 
-            struct RidStoreAccess {
-                lock: &'static ::std::sync::RwLock<#store_ident>,
-            }
+            #store_access
 
-            impl RidStoreAccess {
-                fn instance() -> &'static RidStoreAccess {
-                    unsafe {
-                        INIT_STORE.call_once(|| {
-                            STORE_LOCK = Some(::std::sync::RwLock::new(
-                                #store_ident::create(),
-                            ));
-                            STORE_ACCESS = Some(RidStoreAccess {
-                                lock: STORE_LOCK.as_ref().unwrap(),
-                            });
-                        });
-                        STORE_ACCESS.as_ref().unwrap()
-                    }
+            /// Fields posted as changed when none was marked while the write lock was held.
+            /// If the store was mutably accessed any field could have changed.
+            fn unmarked_changes(modified: bool) -> u64 {
+                if modified {
+                    #store_ident::_RID_ALL_FIELDS
+                } else {
+                    0
                 }
             }
 
-            // -----------------
-            // API used by rid internally and for multi threading scenarios
-            // -----------------
-
-            /// Locks store for reading and allows non-mutable access
-            /// A read lock can be aquired when no other write lock is in use.
-            /// Multiple read locks can be given out in parallel.
-            pub fn read() -> ::std::sync::RwLockReadGuard<'static, #store_ident> {
-                RidStoreAccess::instance().lock.read().unwrap()
-            }
-
-            /// Locks store for writing and allows mutable access
-            /// A write lock can be aquired when no other read nor write lock is in use.
-            /// Only one write lock can be aquired.
-            pub fn write() -> ::std::sync::RwLockWriteGuard<'static, #store_ident> {
-                RidStoreAccess::instance().lock.write().unwrap()
+            impl rid::_RidGlobalStore for #store_ident {
+                type WriteGuard = StoreWriteGuard;
+                fn _write() -> StoreWriteGuard {
+                    write()
+                }
             }
 
             // -----------------
@@ -175,31 +239,18 @@ pub fn render_store_module(store_ident: &syn::Ident) -> TokenStream {
             #rid_create_store_wrapper
             #[no_mangle]
             pub extern "C" fn create_store() -> *const #store_ident {
-                let store = RidStoreAccess::instance().lock.read().unwrap();
-                &*store as *const #store_ident
+                RidStoreAccess::instance().dart_store()
             }
 
             #rid_store_lock_wrapper
             #[no_mangle]
-            pub extern "C" fn rid_store_lock() {
-                if unsafe { LOCK_READ_GUARD.is_some() } {
-                    eprintln!("WARN trying to lock an already locked store");
-                } else {
-                    unsafe {
-                        LOCK_READ_GUARD = Some(read());
-                    }
-                }
+            pub extern "C" fn rid_store_lock() -> *const #store_ident {
+                RidStoreAccess::instance().dart_lock()
             }
 
             #[no_mangle]
             pub extern "C" fn rid_store_unlock() {
-                if unsafe { LOCK_READ_GUARD.is_none() } {
-                    eprintln!("WARN trying to unlock an already unlocked store");
-                } else {
-                    unsafe {
-                        LOCK_READ_GUARD = None;
-                    }
-                }
+                RidStoreAccess::instance().dart_unlock()
             }
 
             #store_extension_dart
@@ -209,8 +260,316 @@ pub fn render_store_module(store_ident: &syn::Ident) -> TokenStream {
                 // though that isn't necessary as the app will exit after the store was freed.
                 // For now we just make sure we wait for any thread that as a read or write lock
                 // to complete before we return from this method.
+                // Recording messages stops as well, which records the final state of the store.
+                rid::stop_recording();
                 let _write_lock = write();
             }
         }
     }
 }
+
+fn render_locked_store_access(
+    store_ident: &syn::Ident,
+    register_snapshot: &TokenStream,
+) -> TokenStream {
+    quote_spanned! {store_ident.span() =>
+        /// cbindgen:ignore
+        static mut STORE_LOCK: Option<::std::sync::RwLock<#store_ident>> = None;
+        /// cbindgen:ignore
+        static mut LOCK_READ_GUARD: Option<
+            ::std::sync::RwLockReadGuard<'static, #store_ident>,
+        > = None;
+
+        struct RidStoreAccess {
+            lock: &'static ::std::sync::RwLock<#store_ident>,
+        }
+
+        impl RidStoreAccess {
+            fn instance() -> &'static RidStoreAccess {
+                unsafe {
+                    INIT_STORE.call_once(|| {
+                        STORE_LOCK = Some(::std::sync::RwLock::new(
+                            #store_ident::create(),
+                        ));
+                        STORE_ACCESS = Some(RidStoreAccess {
+                            lock: STORE_LOCK.as_ref().unwrap(),
+                        });
+                        #register_snapshot
+                    });
+                    STORE_ACCESS.as_ref().unwrap()
+                }
+            }
+
+            fn dart_store(&self) -> *const #store_ident {
+                let store = self.lock.read().unwrap_or_else(PoisonError::into_inner);
+                &*store as *const #store_ident
+            }
+
+            fn dart_lock(&self) -> *const #store_ident {
+                unsafe {
+                    if LOCK_READ_GUARD.is_some() {
+                        eprintln!("WARN trying to lock an already locked store");
+                    } else {
+                        LOCK_READ_GUARD = Some(
+                            self.lock.read().unwrap_or_else(PoisonError::into_inner),
+                        );
+                    }
+                    &**LOCK_READ_GUARD.as_ref().unwrap() as *const #store_ident
+                }
+            }
+
+            fn dart_unlock(&self) {
+                if unsafe { LOCK_READ_GUARD.is_none() } {
+                    eprintln!("WARN trying to unlock an already unlocked store");
+                } else {
+                    unsafe {
+                        LOCK_READ_GUARD = None;
+                    }
+                }
+            }
+        }
+
+        /// Used by rid internally to resolve the store pointer Dart passes.
+        pub fn _resolve_dart_ptr(ptr: *const #store_ident) -> *const #store_ident {
+            ptr
+        }
+
+        /// Used by rid internally to resolve the store pointer Dart passes to `&mut self` exports.
+        pub fn _resolve_dart_ptr_mut(ptr: *mut #store_ident) -> *mut #store_ident {
+            ptr
+        }
+
+        // -----------------
+        // API used by rid internally and for multi threading scenarios
+        // -----------------
+
+        /// Write lock of the store returned by [write].
+        pub struct StoreWriteGuard {
+            guard: Option<::std::sync::RwLockWriteGuard<'static, #store_ident>>,
+            modified: bool,
+        }
+
+        impl ::std::ops::Deref for StoreWriteGuard {
+            type Target = #store_ident;
+            fn deref(&self) -> &Self::Target {
+                self.guard.as_ref().unwrap()
+            }
+        }
+
+        impl ::std::ops::DerefMut for StoreWriteGuard {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                self.modified = true;
+                self.guard.as_mut().unwrap()
+            }
+        }
+
+        /// Locks store for reading and allows non-mutable access
+        /// A read lock can be aquired when no other write lock is in use.
+        /// Multiple read locks can be given out in parallel.
+        pub fn read() -> ::std::sync::RwLockReadGuard<'static, #store_ident> {
+            RidStoreAccess::instance()
+                .lock
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+        }
+
+        /// Locks store for writing and allows mutable access
+        /// A write lock can be aquired when no other read nor write lock is in use.
+        /// Only one write lock can be aquired.
+        /// Store fields changed while the lock is held are posted to Dart once it is released.
+        pub fn write() -> StoreWriteGuard {
+            let guard = RidStoreAccess::instance()
+                .lock
+                .write()
+                .unwrap_or_else(PoisonError::into_inner);
+            rid::_start_store_changes();
+            StoreWriteGuard {
+                guard: Some(guard),
+                modified: false,
+            }
+        }
+
+        impl Drop for StoreWriteGuard {
+            fn drop(&mut self) {
+                // Release the lock first so that Dart can read the changed fields right away
+                self.guard.take();
+                rid::_post_store_changes(unmarked_changes(self.modified));
+            }
+        }
+    }
+}
+
+fn render_snapshot_store_access(
+    store_ident: &syn::Ident,
+    register_snapshot: &TokenStream,
+) -> TokenStream {
+    let unpinned_panic = format!(
+        "[rid] The {store} created with #[rid::store(snapshot)] can only be read while \
+        Dart locks it, i.e. via {store}.instance or {store}.raw.runLocked",
+        store = store_ident
+    );
+    let mut_export_panic = format!(
+        "[rid] The {store} created with #[rid::store(snapshot)] cannot be modified by \
+        `&mut self` exports, send a message to update it instead",
+        store = store_ident
+    );
+    quote_spanned! {store_ident.span() =>
+        /// cbindgen:ignore
+        static mut STORE_LOCK: Option<
+            ::std::sync::Mutex<::std::sync::Arc<#store_ident>>,
+        > = None;
+        /// cbindgen:ignore
+        static mut PINNED_SNAPSHOT: Option<::std::sync::Arc<#store_ident>> = None;
+
+        struct RidStoreAccess {
+            /// The store updates are applied to which shares its data with the latest snapshot,
+            /// thus it is cloned when it is modified
+            lock: &'static ::std::sync::Mutex<::std::sync::Arc<#store_ident>>,
+            /// The latest snapshot of the store which is published after each update
+            snapshot: ::std::sync::Mutex<::std::sync::Arc<#store_ident>>,
+            /// Identifies the store Dart created, reading through it resolves to the snapshot
+            /// Dart pinned. It is never read itself and only allocated to have a unique address.
+            dart_store: Box<u8>,
+        }
+
+        impl RidStoreAccess {
+            fn instance() -> &'static RidStoreAccess {
+                unsafe {
+                    INIT_STORE.call_once(|| {
+                        let store = ::std::sync::Arc::new(#store_ident::create());
+                        STORE_LOCK = Some(::std::sync::Mutex::new(store.clone()));
+                        STORE_ACCESS = Some(RidStoreAccess {
+                            lock: STORE_LOCK.as_ref().unwrap(),
+                            snapshot: ::std::sync::Mutex::new(store),
+                            dart_store: Box::new(0),
+                        });
+                        #register_snapshot
+                    });
+                    STORE_ACCESS.as_ref().unwrap()
+                }
+            }
+
+            fn snapshot(&self) -> ::std::sync::Arc<#store_ident> {
+                self.snapshot.lock().unwrap_or_else(PoisonError::into_inner).clone()
+            }
+
+            fn publish(&self, snapshot: ::std::sync::Arc<#store_ident>) {
+                // The previous snapshot is dropped after the snapshot lock was released
+                let _previous = ::std::mem::replace(
+                    &mut *self.snapshot.lock().unwrap_or_else(PoisonError::into_inner),
+                    snapshot,
+                );
+            }
+
+            fn dart_store(&self) -> *const #store_ident {
+                &*self.dart_store as *const u8 as *const #store_ident
+            }
+
+            fn dart_lock(&self) -> *const #store_ident {
+                unsafe {
+                    if PINNED_SNAPSHOT.is_some() {
+                        eprintln!("WARN trying to lock an already locked store");
+                    } else {
+                        PINNED_SNAPSHOT = Some(self.snapshot());
+                    }
+                    ::std::sync::Arc::as_ptr(PINNED_SNAPSHOT.as_ref().unwrap())
+                }
+            }
+
+            fn dart_unlock(&self) {
+                if unsafe { PINNED_SNAPSHOT.is_none() } {
+                    eprintln!("WARN trying to unlock an already unlocked store");
+                } else {
+                    unsafe {
+                        PINNED_SNAPSHOT = None;
+                    }
+                }
+            }
+
+            fn resolve_dart_ptr(&self, ptr: *const #store_ident) -> *const #store_ident {
+                if ptr != self.dart_store() {
+                    return ptr;
+                }
+                match unsafe { (*::std::ptr::addr_of!(PINNED_SNAPSHOT)).as_ref() } {
+                    Some(snapshot) => ::std::sync::Arc::as_ptr(snapshot),
+                    None => panic!(#unpinned_panic),
+                }
+            }
+        }
+
+        /// Used by rid internally to resolve the store pointer Dart passes.
+        /// Dart may keep the pointer returned by `create_store` and read the store through it,
+        /// which resolves to the snapshot Dart pinned while locking the store.
+        pub fn _resolve_dart_ptr(ptr: *const #store_ident) -> *const #store_ident {
+            RidStoreAccess::instance().resolve_dart_ptr(ptr)
+        }
+
+        /// Used by rid internally to resolve the store pointer Dart passes to `&mut self` exports.
+        /// Snapshots are shared by all readers and therefore never modified.
+        pub fn _resolve_dart_ptr_mut(_ptr: *mut #store_ident) -> *mut #store_ident {
+            panic!(#mut_export_panic)
+        }
+
+        // -----------------
+        // API used by rid internally and for multi threading scenarios
+        // -----------------
+
+        /// Write lock of the store returned by [write].
+        /// The store is cloned when it is first modified while the lock is held since the latest
+        /// snapshot shares its data.
+        pub struct StoreWriteGuard {
+            guard: Option<
+                ::std::sync::MutexGuard<'static, ::std::sync::Arc<#store_ident>>,
+            >,
+            modified: bool,
+        }
+
+        impl ::std::ops::Deref for StoreWriteGuard {
+            type Target = #store_ident;
+            fn deref(&self) -> &Self::Target {
+                self.guard.as_ref().unwrap()
+            }
+        }
+
+        impl ::std::ops::DerefMut for StoreWriteGuard {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                self.modified = true;
+                ::std::sync::Arc::make_mut(self.guard.as_mut().unwrap())
+            }
+        }
+
+        /// Returns the latest snapshot of the store which allows non-mutable access.
+        /// Reading a snapshot never blocks nor is blocked by writers.
+        pub fn read() -> ::std::sync::Arc<#store_ident> {
+            RidStoreAccess::instance().snapshot()
+        }
+
+        /// Locks store for writing and allows mutable access
+        /// Only one write lock can be aquired.
+        /// A snapshot of the store is published when the lock is released and store fields
+        /// changed while it was held are posted to Dart.
+        pub fn write() -> StoreWriteGuard {
+            let guard = RidStoreAccess::instance()
+                .lock
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            rid::_start_store_changes();
+            StoreWriteGuard {
+                guard: Some(guard),
+                modified: false,
+            }
+        }
+
+        impl Drop for StoreWriteGuard {
+            fn drop(&mut self) {
+                if let Some(guard) = self.guard.take() {
+                    // Publish before releasing the lock so the next update builds on this one
+                    if self.modified {
+                        RidStoreAccess::instance().publish(guard.clone());
+                    }
+                }
+                rid::_post_store_changes(unmarked_changes(self.modified));
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod to_dart;
+mod to_dart_encoded;
 pub use to_dart::*;
+pub use to_dart_encoded::*;
 
 #[cfg(test)]
 mod to_dart_test;
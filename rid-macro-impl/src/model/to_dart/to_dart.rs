@@ -18,6 +18,8 @@ use crate::{
     render_rust::{allow_prelude, RenderedDisplayImpl},
 };
 
+use super::render_to_dart_encoded_extension;
+
 pub struct DartRenderImplConfig {
     render_dart_only: bool,
 }
@@ -73,6 +75,22 @@ pub fn render_to_dart(
     let to_dart_extension = parsed_struct
         .render_struct_pointer_to_class_extension(&render_class_config);
 
+    // -----------------
+    // toDartEncoded() for models which are transferred in one FFI call
+    // -----------------
+    let to_dart_encoded_extension =
+        if !is_store && parsed_struct.can_encode_model() {
+            render_to_dart_encoded_extension(
+                &parsed_struct.ident,
+                &parsed_struct.raw_ident,
+                &parsed_struct.ident.to_string(),
+                &parsed_struct.model_encode_fn_idents(),
+                comment,
+            )
+        } else {
+            "".to_string()
+        };
+
     // -----------------
     // Dart Code Block
     // -----------------
@@ -80,10 +98,11 @@ pub fn render_to_dart(
         r###"
 {comment} ```dart
 {dart_store_api}
-{to_dart_extension}
+{to_dart_extension}{to_dart_encoded_extension}
 {comment} ```"###,
         dart_store_api = dart_store_api,
         to_dart_extension = to_dart_extension,
+        to_dart_encoded_extension = to_dart_encoded_extension,
         comment = comment
     );
     let dart_tokens: TokenStream = dart_code.parse().unwrap();
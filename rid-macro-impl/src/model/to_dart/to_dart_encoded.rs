@@ -0,0 +1,142 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote_spanned};
+use rid_common::{DART_FFI, FFI_GEN_BIND, RID_FFI, THROW_IF_PANICKED};
+
+use crate::{
+    common::tokens::resolve_ptr, parse::ParsedStruct,
+    render_dart::dart_wire_decode_fn_name, render_rust::allow_prelude,
+};
+
+/// Names of the FFI functions which encode a struct into the rid wire format and release the
+/// encoded buffer again.
+pub struct EncodeFnIdents {
+    pub encode: syn::Ident,
+    pub free: syn::Ident,
+}
+
+/// Renders the Dart extension method `toDartEncoded` which instantiates the Dart `class_name`
+/// from the buffer that Rust encodes via one FFI call.
+///
+/// Example:
+///
+/// ```dart
+/// extension Rid_ToDartEncoded_ExtOnTodo on dart_ffi.Pointer<ffigen_bind.RawTodo> {
+///   Todo toDartEncoded() {
+///     ridStoreLock();
+///     final ptr = rid_ffi.rid_todo_encode(this);
+///     try {
+///       _ridThrowIfPanicked('rid_todo_encode');
+///       final len = dart_typed_data.ByteData.sublistView(ptr.asTypedList(4))
+///           .getUint32(0, dart_typed_data.Endian.little);
+///       return _ridDecodeTodo(RidWireReader(ptr.elementAt(4).asTypedList(len)));
+///     } finally {
+///       rid_ffi.rid_todo_free_encoded(ptr);
+///       ridStoreUnlock();
+///     }
+///   }
+/// }
+/// ```
+pub fn render_to_dart_encoded_extension(
+    ident: &syn::Ident,
+    raw_ident: &syn::Ident,
+    class_name: &str,
+    fn_idents: &EncodeFnIdents,
+    comment: &str,
+) -> String {
+    format!(
+        r###"
+{comment}
+{comment} extension Rid_ToDartEncoded_ExtOn{ident} on {dart_ffi}.Pointer<{ffigen_bind}.{RawIdent}> {{
+{comment}   /// Instantiates a Dart {class_name} from the {ident} which Rust encodes in one FFI call.
+{comment}   /// This is much faster than resolving each field and collection item via a separate
+{comment}   /// FFI call.
+{comment}   {class_name} toDartEncoded() {{
+{comment}     ridStoreLock();
+{comment}     final ptr = {rid_ffi}.{encode_fn}(this);
+{comment}     try {{
+{comment}       {throw_if_panicked}('{encode_fn}');
+{comment}       final len = dart_typed_data.ByteData.sublistView(ptr.asTypedList(4))
+{comment}           .getUint32(0, dart_typed_data.Endian.little);
+{comment}       return {decode_fn}(RidWireReader(ptr.elementAt(4).asTypedList(len)));
+{comment}     }} finally {{
+{comment}       {rid_ffi}.{free_fn}(ptr);
+{comment}       ridStoreUnlock();
+{comment}     }}
+{comment}   }}
+{comment} }}"###,
+        ident = ident,
+        RawIdent = raw_ident,
+        class_name = class_name,
+        decode_fn = dart_wire_decode_fn_name(class_name),
+        encode_fn = fn_idents.encode,
+        free_fn = fn_idents.free,
+        dart_ffi = DART_FFI,
+        ffigen_bind = FFI_GEN_BIND,
+        rid_ffi = RID_FFI,
+        throw_if_panicked = THROW_IF_PANICKED,
+        comment = comment,
+    )
+}
+
+/// Renders the conversion of a `#[rid::model]` into its Dart class via a single FFI call.
+///
+/// Rust encodes the model including nested models and collections into one buffer which is
+/// decoded by the Dart decode function that is rendered for the model anyways, see
+/// `render_dart_wire_decode_fn`. The model's `toDart()` then delegates to `toDartEncoded()`.
+///
+/// Nothing is rendered for transparent models, which resolve to their only field anyways, or
+/// models with fields that cannot be encoded.
+pub fn render_model_encode(parsed_struct: &ParsedStruct) -> TokenStream {
+    if parsed_struct.can_encode_model() {
+        parsed_struct.render_model_encode()
+    } else {
+        TokenStream::new()
+    }
+}
+
+impl ParsedStruct {
+    /// Returns `true` if this model is transferred to Dart in one FFI call, see
+    /// [render_model_encode].
+    pub fn can_encode_model(&self) -> bool {
+        !self.config.transparent && self.has_wire_encodable_fields()
+    }
+
+    pub fn model_encode_fn_idents(&self) -> EncodeFnIdents {
+        let method_prefix =
+            format!("rid_{}", self.ident.to_string().to_lowercase());
+        EncodeFnIdents {
+            encode: format_ident!("{}_encode", method_prefix),
+            free: format_ident!("{}_free_encoded", method_prefix),
+        }
+    }
+
+    fn render_model_encode(&self) -> TokenStream {
+        let ident = &self.ident;
+        let EncodeFnIdents {
+            encode: encode_ident,
+            free: free_ident,
+        } = self.model_encode_fn_idents();
+        let resolve_model = resolve_ptr(ident);
+
+        let mod_ident = format_ident!("__rid_{}_encode", ident);
+        let allow = allow_prelude();
+        quote_spanned! { ident.span() =>
+            #allow
+            mod #mod_ident {
+                use super::*;
+                #[no_mangle]
+                pub extern "C" fn #encode_ident(ptr: *mut #ident) -> *mut u8 {
+                    let model = #resolve_model;
+                    rid::_encode_wire_result(|writer| {
+                        rid::_RidEncode::_rid_encode(model, writer);
+                    })
+                }
+
+                #[no_mangle]
+                pub extern "C" fn #free_ident(ptr: *mut u8) {
+                    rid::_free_wire_result(ptr)
+                }
+            }
+        }
+    }
+}
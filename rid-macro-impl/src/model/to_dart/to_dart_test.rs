@@ -3,7 +3,7 @@ use crate::{
     parse::ParsedStruct,
 };
 
-use super::{render_to_dart, DartRenderImplConfig};
+use super::{render_model_encode, render_to_dart, DartRenderImplConfig};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, Item};
@@ -31,4 +31,86 @@ fn render(input: proc_macro2::TokenStream, is_store: bool) -> TokenStream {
     }
 }
 
-// TODO: write tests
+fn render_encode(input: proc_macro2::TokenStream) -> String {
+    let struct_item = syn::parse2::<syn::ItemStruct>(input).unwrap();
+    let parsed_struct = ParsedStruct::new(
+        &struct_item,
+        &struct_item.ident,
+        StructConfig::from(&struct_item),
+    );
+    render_model_encode(&parsed_struct).to_string()
+}
+
+#[test]
+fn model_to_dart_uses_single_ffi_call() {
+    let input = quote! {
+        struct Todo {
+            id: u32,
+            title: String,
+            tags: Vec<String>,
+        }
+    };
+    let res = render(input.clone(), false).to_string();
+    assert!(
+        res.contains(
+            r#"# [doc = "   Todo toDart() => this.toDartEncoded();"]"#
+        ),
+        "toDart delegates: {}",
+        res
+    );
+    assert!(
+        res.contains(
+            r#"# [doc = "     final ptr = rid_ffi.rid_todo_encode(this);"]"#
+        ),
+        "toDartEncoded: {}",
+        res
+    );
+    assert!(
+        res.contains(
+            r#"# [doc = "       rid_ffi.rid_todo_free_encoded(ptr);"]"#
+        ),
+        "free: {}",
+        res
+    );
+
+    let expected_encode = quote! {
+        pub extern "C" fn rid_todo_encode(ptr: *mut Todo) -> *mut u8 {
+            let model = unsafe {
+                assert!(!ptr.is_null());
+                ptr.as_ref().expect("resolve_ptr.as_ref failed")
+            };
+            rid::_encode_wire_result(|writer| {
+                rid::_RidEncode::_rid_encode(model, writer);
+            })
+        }
+    };
+    let res = render_encode(input);
+    assert!(
+        res.contains(&expected_encode.to_string()),
+        "encode: {}",
+        res
+    );
+}
+
+#[test]
+fn transparent_model_to_dart_resolves_field() {
+    let input = quote! {
+        struct TodoId(u32);
+    };
+    let item = syn::parse2::<syn::ItemStruct>(input.clone()).unwrap();
+    let mut struct_config = StructConfig::from(&item);
+    struct_config.transparent = true;
+    let parsed_struct = ParsedStruct::new(&item, &item.ident, struct_config);
+    let res = render_to_dart(
+        &parsed_struct,
+        false,
+        &parse_derive_attrs(&item.attrs),
+        DartRenderImplConfig::for_tests(),
+    )
+    .to_string();
+    assert!(!res.contains("toDartEncoded"), "toDart: {}", res);
+    assert!(
+        render_model_encode(&parsed_struct).is_empty(),
+        "no encode for transparent model"
+    );
+}
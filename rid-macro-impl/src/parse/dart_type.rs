@@ -13,11 +13,16 @@ use super::ParsedReference;
 pub enum DartType {
     Int32(bool),
     Int64(bool),
+    Float32(bool),
+    Float64(bool),
     Bool(bool),
     String(bool),
     Custom(bool, TypeInfo, String),
     Vec(bool, Box<DartType>),
     HashMap(bool, Box<DartType>, Box<DartType>),
+    BTreeMap(bool, Box<DartType>, Box<DartType>),
+    HashSet(bool, Box<DartType>),
+    BTreeSet(bool, Box<DartType>),
     Unit,
 }
 
@@ -46,8 +51,10 @@ impl DartType {
                         DartType::Int32(nullable)
                     }
                     U64 | I64 => DartType::Int64(nullable),
-                    USize => DartType::Int64(nullable),
                     // assuming 64-bit target
+                    USize => DartType::Int64(nullable),
+                    F32 => DartType::Float32(nullable),
+                    F64 => DartType::Float64(nullable),
                     Bool => DartType::Bool(nullable),
                 }
             }
@@ -62,7 +69,7 @@ impl DartType {
             }
             Unit => DartType::Unit,
             Composite(composite, fst_ty, snd_ty) => match composite {
-                C::Vec => {
+                C::Vec | C::VecDeque | C::HashSet | C::BTreeSet => {
                     let inner = DartType::from_with_nullable(
                         fst_ty
                             .as_ref()
@@ -71,14 +78,22 @@ impl DartType {
                         type_infos,
                         false,
                     );
-                    DartType::Vec(nullable, Box::new(inner))
+                    match composite {
+                        C::HashSet => {
+                            DartType::HashSet(nullable, Box::new(inner))
+                        }
+                        C::BTreeSet => {
+                            DartType::BTreeSet(nullable, Box::new(inner))
+                        }
+                        _ => DartType::Vec(nullable, Box::new(inner)),
+                    }
                 }
                 C::Option => DartType::from_with_nullable(
                     fst_ty.as_ref().unwrap(),
                     type_infos,
                     true,
                 ),
-                C::HashMap => {
+                C::HashMap | C::BTreeMap => {
                     let fst_inner = DartType::from_with_nullable(
                         fst_ty
                             .as_ref()
@@ -95,11 +110,19 @@ impl DartType {
                         type_infos,
                         false,
                     );
-                    DartType::HashMap(
-                        nullable,
-                        Box::new(fst_inner),
-                        Box::new(snd_inner),
-                    )
+                    if composite == &C::HashMap {
+                        DartType::HashMap(
+                            nullable,
+                            Box::new(fst_inner),
+                            Box::new(snd_inner),
+                        )
+                    } else {
+                        DartType::BTreeMap(
+                            nullable,
+                            Box::new(fst_inner),
+                            Box::new(snd_inner),
+                        )
+                    }
                 }
                 C::Custom(_, _) => abort!(
                     rust_type.rust_ident(),
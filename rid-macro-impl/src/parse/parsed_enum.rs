@@ -1,9 +1,10 @@
+use heck::SnakeCase;
 use syn::{
     punctuated::Punctuated, token::Comma, Field, Ident, ItemEnum, Variant,
 };
 
 use crate::{
-    attrs::{EnumConfig, RidAttr, TypeInfoMap},
+    attrs::{raw_typedef_ident, EnumConfig, RidAttr, TypeInfoMap},
     common::abort,
     parse_rid_attrs,
 };
@@ -59,6 +60,28 @@ impl ParsedEnum {
     pub fn type_infos(&self) -> &TypeInfoMap {
         &self.config.type_infos
     }
+
+    /// Identifier of the opaque type which cbindgen/ffigen generate for data carrying enums,
+    /// i.e. RawShape.
+    pub fn raw_ident(&self) -> Ident {
+        raw_typedef_ident(&self.ident)
+    }
+
+    /// Returns `true` if at least one variant carries data, i.e. `Circle(f64)` or
+    /// `Failed { reason: String }`.
+    /// Those enums cannot be passed as `#[repr(C)]` ints and are exposed via pointers instead.
+    pub fn has_data_variants(&self) -> bool {
+        self.variants.iter().any(|x| !x.fields.is_empty())
+    }
+
+    /// Returns `true` if this enum can be encoded into the rid wire format and thus be passed as
+    /// part of a message, which requires the fields of all variants to be encodable.
+    pub fn is_wire_encodable(&self) -> bool {
+        self.variants
+            .iter()
+            .flat_map(|x| x.fields.iter())
+            .all(|x| x.rust_type.is_wire_encodable())
+    }
 }
 
 // -----------------
@@ -94,6 +117,11 @@ impl ParsedEnumVariant {
             discriminant,
         }
     }
+
+    /// Returns `true` if the fields of this variant are named, i.e. `Failed { reason: String }`.
+    pub fn has_named_fields(&self) -> bool {
+        self.fields.iter().any(|x| x.ident.is_some())
+    }
 }
 
 // -----------------
@@ -101,6 +129,9 @@ impl ParsedEnumVariant {
 // -----------------
 #[derive(Debug, PartialEq)]
 pub struct ParsedEnumVariantField {
+    /// The name of the field for struct variants, `None` for tuple variants
+    pub ident: Option<Ident>,
+
     /// The Rust type of the field
     pub rust_type: RustType,
 
@@ -112,19 +143,41 @@ pub struct ParsedEnumVariantField {
 }
 
 impl ParsedEnumVariantField {
-    // NOTE: unlike the `message` enum (src/message/variant_field.rs) here we don't support custom
-    // types yet
     fn from(f: &Field, slot: usize, type_infos: &TypeInfoMap) -> Self {
-        let rust_type = RustType::from_plain_type(&f.ty);
+        let rust_type = RustType::from_type(&f.ty, type_infos);
         let rust_type = match rust_type {
             Some(x) => x,
             None => abort!(f.ident, "invalid rust type"),
         };
         let dart_type = DartType::from(&rust_type, type_infos);
         Self {
+            ident: f.ident.clone(),
             rust_type,
             dart_type,
             slot,
         }
     }
+
+    /// The name of the field, i.e. `reason` for struct variants and `field0` for tuple variants.
+    pub fn name(&self) -> String {
+        match &self.ident {
+            Some(ident) => ident.to_string(),
+            None => format!("field{}", self.slot),
+        }
+    }
+
+    /// The FFI method used to access this field, i.e. `rid_shape_circle_field0`.
+    pub fn method_ident(
+        &self,
+        enum_ident: &Ident,
+        variant_ident: &Ident,
+    ) -> Ident {
+        let fn_name = format!(
+            "rid_{}_{}_{}",
+            enum_ident.to_string().to_lowercase(),
+            variant_ident.to_string().to_snake_case(),
+            self.name()
+        );
+        Ident::new(&fn_name, variant_ident.span())
+    }
 }
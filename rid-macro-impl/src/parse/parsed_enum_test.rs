@@ -127,4 +127,42 @@ mod field_enums {
             "variant first field"
         );
     }
+
+    #[test]
+    fn enum_struct_variant_with_named_fields() {
+        let parsed_enum = parse(quote! {
+            enum Status { Pending, Failed { reason: String, code: u8 } }
+        });
+        assert!(parsed_enum.has_data_variants(), "has data variants");
+
+        let pending = &parsed_enum.variants[0];
+        assert!(!pending.has_named_fields(), "pending has no named fields");
+
+        let failed = &parsed_enum.variants[1];
+        assert!(failed.has_named_fields(), "failed has named fields");
+        assert_eq!(failed.fields[0].name(), "reason", "first field name");
+        assert_eq!(failed.fields[1].name(), "code", "second field name");
+        assert_eq!(
+            failed.fields[1].rust_type.kind,
+            TypeKind::Primitive(Primitive::U8),
+            "second field type"
+        );
+    }
+
+    #[test]
+    fn enum_tuple_variant_field_names() {
+        let parsed_enum = parse(quote! {
+            enum Shape { Rect(u8, u8) }
+        });
+        let rect = &parsed_enum.variants[0];
+        assert_eq!(rect.fields[0].name(), "field0", "first field name");
+        assert_eq!(rect.fields[1].name(), "field1", "second field name");
+        assert_eq!(
+            rect.fields[1]
+                .method_ident(&parsed_enum.ident, &rect.ident)
+                .to_string(),
+            "rid_shape_rect_field1",
+            "field method ident"
+        );
+    }
 }
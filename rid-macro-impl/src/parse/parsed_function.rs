@@ -28,9 +28,13 @@ pub struct ParsedFunction {
     /// The `args` converted into `DartArg` to use when rendering Dart code
     pub dart_args: Vec<DartArg>,
 
-    /// The type of arg returned by the original function
+    /// The type of arg returned by the original function.
+    /// For functions returning `Result<T, E>` this is `T`.
     pub return_arg: RustType,
 
+    /// The error type `E` if the original function returns `Result<T, E>`
+    pub return_err: Option<RustType>,
+
     /// Function config with extra information like type_infos [TypeInfoMap]
     pub config: FunctionConfig,
 }
@@ -92,29 +96,58 @@ impl ParsedFunction {
             };
         }
 
-        let return_arg = match output {
-            ReturnType::Default => RustType::new(
-                ident.clone(),
-                TypeKind::Unit,
-                ParsedReference::Owned,
-                RustTypeContext::Default,
+        let (return_arg, return_err) = match output {
+            ReturnType::Default => (
+                RustType::new(
+                    ident.clone(),
+                    TypeKind::Unit,
+                    ParsedReference::Owned,
+                    RustTypeContext::Default,
+                ),
+                None,
             ),
-            ReturnType::Type(_, ty) => {
-                match RustType::from_boxed_type(ty.clone(), &config.type_infos)
-                {
-                    Some(rust_type) => rust_type,
+            ReturnType::Type(_, ty) => match result_ok_err_types(&ty) {
+                Some((ok_ty, err_ty)) => {
+                    let return_arg =
+                        match RustType::from_type(ok_ty, &config.type_infos) {
+                            Some(rust_type) => rust_type,
+                            None => abort!(
+                                ok_ty,
+                                "[rid] Type not supported for exported functions {:#?}",
+                                ok_ty
+                            ),
+                        };
+                    let return_err =
+                        match RustType::from_type(err_ty, &config.type_infos) {
+                            Some(rust_type) => rust_type,
+                            None => abort!(
+                                err_ty,
+                                "[rid] Type not supported as error of exported functions {:#?}",
+                                err_ty
+                            ),
+                        };
+                    (return_arg, Some(return_err))
+                }
+                None => match RustType::from_boxed_type(
+                    ty.clone(),
+                    &config.type_infos,
+                ) {
+                    Some(rust_type) => (rust_type, None),
                     None => abort!(
                         ty,
                         "[rid] Type not supported for exported functions {:#?}",
                         *ty
                     ),
-                }
-            }
+                },
+            },
         };
 
-        let return_arg = match owner {
-            Some((ident, _)) => return_arg.self_unaliased(ident.to_string()),
-            None => return_arg,
+        let (return_arg, return_err) = match owner {
+            Some((ident, _)) => (
+                return_arg.self_unaliased(ident.to_string()),
+                return_err.map(|x| x.self_unaliased(ident.to_string())),
+            ),
+            None => (return_arg, return_err),
         };
 
         let fn_ident_alias = config.fn_export_alias.clone();
@@ -131,6 +164,7 @@ impl ParsedFunction {
             receiver,
             args,
             return_arg,
+            return_err,
             config,
             dart_args,
         }
@@ -141,4 +175,33 @@ impl ParsedFunction {
     pub fn type_infos(&self) -> &TypeInfoMap {
         &self.config.type_infos
     }
+
+    /// Returns `true` if the original function returns a `Result<T, E>`
+    pub fn is_fallible(&self) -> bool {
+        self.return_err.is_some()
+    }
+}
+
+/// Extracts `T` and `E` if the provided type is a `Result<T, E>`.
+fn result_ok_err_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    use syn::*;
+    let segment = match ty {
+        Type::Path(TypePath { path, .. }) => path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Result" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+            args,
+            ..
+        }) if args.len() == 2 => match (&args[0], &args[1]) {
+            (GenericArgument::Type(ok_ty), GenericArgument::Type(err_ty)) => {
+                Some((ok_ty, err_ty))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
 }
@@ -308,3 +308,62 @@ mod receiver {
         assert_eq!(ret_ty, TypeKind::Unit, "returns ()");
     }
 }
+
+mod result_return {
+    use super::*;
+
+    #[test]
+    fn result_u8_string() {
+        let ParsedFunction {
+            return_arg: RustType { kind: ret_ty, .. },
+            return_err,
+            ..
+        } = parse(quote! {
+            fn me() -> Result<u8, String> {}
+        });
+
+        assert_eq!(ret_ty, TypeKind::Primitive(Primitive::U8), "ok is u8");
+        assert_matches!(
+            return_err,
+            Some(RustType {
+                kind: TypeKind::Value(Value::String),
+                ..
+            }),
+            "err is String"
+        );
+    }
+
+    #[test]
+    fn result_string_custom_enum() {
+        let parsed = parse(quote! {
+            #[rid::enums(MyError)]
+            fn me() -> Result<String, MyError> {}
+        });
+
+        assert!(parsed.is_fallible(), "is fallible");
+        assert_eq!(
+            parsed.return_arg.kind,
+            TypeKind::Value(Value::String),
+            "ok is String"
+        );
+        let return_err = parsed.return_err.expect("has error type");
+        assert_matches!(
+            &return_err.kind,
+            TypeKind::Value(Value::Custom(TypeInfo { key: _, cat, .. }, name)) => {
+                assert_eq!(
+                    (cat, name.as_str()),
+                    (&attrs::Category::Enum, "MyError"),
+                    "custom error type"
+                );
+            }
+        );
+    }
+
+    #[test]
+    fn non_result_is_not_fallible() {
+        let parsed = parse(quote! {
+            fn me() -> Option<u8> {}
+        });
+        assert!(!parsed.is_fallible(), "is not fallible");
+    }
+}
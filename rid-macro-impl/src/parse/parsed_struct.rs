@@ -13,6 +13,9 @@ pub struct ParsedStruct {
     pub raw_ident: Ident,
     pub fields: Vec<ParsedStructField>,
     pub config: StructConfig,
+    /// `true` if any field was excluded via `#[rid(skip)]` in which case the struct cannot be
+    /// reconstructed from its fields, i.e. when decoding it from a message
+    pub has_skipped_fields: bool,
 }
 
 impl ParsedStruct {
@@ -22,19 +25,36 @@ impl ParsedStruct {
         let fields = item
             .fields
             .iter()
-            .filter_map(|f| match should_include_field(f) {
-                true => Some(ParsedStructField::new(f, &config.type_infos)),
+            .enumerate()
+            .filter_map(|(slot, f)| match should_include_field(f) {
+                true => {
+                    Some(ParsedStructField::new(f, slot, &config.type_infos))
+                }
                 fale => None,
             })
-            .collect();
+            .collect::<Vec<ParsedStructField>>();
+        let has_skipped_fields = fields.len() != item.fields.len();
         Self {
             ident,
             raw_ident,
             fields,
             config,
+            has_skipped_fields,
         }
     }
 
+    /// Returns `true` if this struct can be decoded from the rid wire format and thus be passed
+    /// as part of a message, which requires all its fields to be included and encodable.
+    pub fn is_wire_encodable(&self) -> bool {
+        !self.has_skipped_fields && self.has_wire_encodable_fields()
+    }
+
+    /// Returns `true` if all fields that are not excluded via `#[rid(skip)]` can be encoded into
+    /// the rid wire format, i.e. in order to transfer the store state to Dart in one shot.
+    pub fn has_wire_encodable_fields(&self) -> bool {
+        self.fields.iter().all(|x| x.rust_type.is_wire_encodable())
+    }
+
     /// Information about custom types used for fields that are part of this struct
     pub fn type_infos(&self) -> &TypeInfoMap {
         &self.config.type_infos
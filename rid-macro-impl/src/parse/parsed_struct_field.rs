@@ -1,20 +1,38 @@
-use syn::{Field, Ident};
+use quote::format_ident;
+use syn::{Field, Ident, Index, Member};
 
-use crate::{attrs::TypeInfoMap, common::abort};
+use crate::{attrs::TypeInfoMap, common::abort, parse_rid_attrs};
 
 use super::{dart_type::DartType, rust_type::RustType};
 
 #[derive(Debug)]
 pub struct ParsedStructField {
+    /// The name of the field, for unnamed fields it is either provided via `#[rid(name = "id")]`
+    /// or derived from the field's position, i.e. `field0`
     pub ident: syn::Ident,
+    /// The member used to access the field in Rust, i.e. `id` or `0`
+    pub member: Member,
+    /// The type of the field as declared in Rust
+    pub ty: syn::Type,
     pub rust_type: RustType,
     pub dart_type: DartType,
 }
 
 impl ParsedStructField {
-    pub fn new(f: &Field, type_infos: &TypeInfoMap) -> Self {
-        // unwrap is ok here since we only support structs with named fields
-        let ident = f.ident.as_ref().unwrap().clone();
+    pub fn new(f: &Field, slot: usize, type_infos: &TypeInfoMap) -> Self {
+        let (ident, member) = match &f.ident {
+            Some(ident) => (ident.clone(), Member::Named(ident.clone())),
+            None => {
+                let name = parse_rid_attrs(&f.attrs)
+                    .iter()
+                    .find_map(|x| x.name().map(|name| name.to_string()));
+                let ident = match name {
+                    Some(name) => format_ident!("{}", name),
+                    None => format_ident!("field{}", slot),
+                };
+                (ident, Member::Unnamed(Index::from(slot)))
+            }
+        };
         let rust_type = RustType::from_type(&f.ty, type_infos);
         let rust_type = match rust_type {
             Some(x) => x,
@@ -26,6 +44,8 @@ impl ParsedStructField {
         let dart_type = DartType::from(&rust_type, type_infos);
         Self {
             ident,
+            member,
+            ty: f.ty.clone(),
             rust_type,
             dart_type,
         }
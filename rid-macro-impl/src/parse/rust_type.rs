@@ -182,6 +182,10 @@ impl RustType {
         self.kind.is_primitive()
     }
 
+    pub fn is_bool(&self) -> bool {
+        matches!(self.kind, TypeKind::Primitive(Primitive::Bool))
+    }
+
     pub fn is_string(&self) -> bool {
         self.kind.is_string()
     }
@@ -210,6 +214,57 @@ impl RustType {
         self.kind.is_vec()
     }
 
+    pub fn is_hash_map(&self) -> bool {
+        self.kind.is_hash_map()
+    }
+
+    pub fn is_vec_deque(&self) -> bool {
+        self.kind.is_vec_deque()
+    }
+
+    /// `true` for collections of key/value pairs, i.e. `HashMap` and `BTreeMap`
+    pub fn is_map(&self) -> bool {
+        self.kind.is_map()
+    }
+
+    /// `true` for collections of unique items, i.e. `HashSet` and `BTreeSet`
+    pub fn is_set(&self) -> bool {
+        self.kind.is_set()
+    }
+
+    /// `true` for collections that are ordered by their keys or items, i.e. `BTreeMap` and
+    /// `BTreeSet`
+    pub fn is_ordered(&self) -> bool {
+        matches!(
+            self.kind,
+            TypeKind::Composite(
+                Composite::BTreeMap | Composite::BTreeSet,
+                _,
+                _
+            )
+        )
+    }
+
+    pub fn is_option(&self) -> bool {
+        self.kind.is_option()
+    }
+
+    /// `true` for types represented by a Dart `List`, `Set` or `Map`, including optional ones,
+    /// which Dart compares by identity
+    pub fn is_dart_collection(&self) -> bool {
+        match &self.kind {
+            TypeKind::Composite(Composite::Option, Some(inner), _) => {
+                inner.is_dart_collection()
+            }
+            _ => {
+                self.is_vec()
+                    || self.is_vec_deque()
+                    || self.is_map()
+                    || self.is_set()
+            }
+        }
+    }
+
     pub fn is_collection_item(&self) -> bool {
         self.context == RustTypeContext::CollectionItem
     }
@@ -218,6 +273,41 @@ impl RustType {
         self.kind.inner_composite_rust_type()
     }
 
+    /// The type wrapped by this `Option`, unwrapping nested options, i.e. `u8` for
+    /// `Option<Option<u8>>`.
+    /// Returns `None` if this isn't an `Option`.
+    pub fn flattened_option_type(&self) -> Option<RustType> {
+        match &self.kind {
+            TypeKind::Composite(Composite::Option, Some(inner), _) => {
+                inner.flattened_option_type().or(Some(*inner.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Name cbindgen uses for this type when it is part of an FFI signature, i.e.
+    /// `Vec_Vec_u8` for `Vec<Vec<u8>>` or `HashMap_String__Vec_u32` for
+    /// `HashMap<String, Vec<u32>>`.
+    /// For non-composite types this is the same as [RustType::rust_ident].
+    pub fn ffi_type_name(&self) -> String {
+        match &self.kind {
+            TypeKind::Composite(
+                Composite::HashMap | Composite::BTreeMap,
+                Some(key_type),
+                Some(val_type),
+            ) => format!(
+                "{}_{}__{}",
+                self.rust_ident(),
+                key_type.ffi_type_name(),
+                val_type.ffi_type_name()
+            ),
+            TypeKind::Composite(_, Some(inner_type), _) => {
+                format!("{}_{}", self.rust_ident(), inner_type.ffi_type_name())
+            }
+            _ => self.rust_ident().to_string(),
+        }
+    }
+
     pub fn key_val_composite_types(&self) -> Option<(RustType, RustType)> {
         self.kind.key_val_composite_rust_types()
     }
@@ -343,6 +433,28 @@ impl TypeKind {
         }
     }
 
+    pub fn is_hash_map(&self) -> bool {
+        matches!(self, TypeKind::Composite(Composite::HashMap, _, _))
+    }
+
+    pub fn is_vec_deque(&self) -> bool {
+        matches!(self, TypeKind::Composite(Composite::VecDeque, _, _))
+    }
+
+    pub fn is_map(&self) -> bool {
+        matches!(
+            self,
+            TypeKind::Composite(Composite::HashMap | Composite::BTreeMap, _, _)
+        )
+    }
+
+    pub fn is_set(&self) -> bool {
+        matches!(
+            self,
+            TypeKind::Composite(Composite::HashSet | Composite::BTreeSet, _, _)
+        )
+    }
+
     pub fn is_option(&self) -> bool {
         if let TypeKind::Composite(Composite::Option, _, _) = self {
             true
@@ -371,9 +483,14 @@ impl TypeKind {
         match self {
             TypeKind::Primitive(_) => None,
             TypeKind::Value(_) => None,
-            TypeKind::Composite(Composite::Vec, inner, _) => {
-                inner.as_ref().map(|x| (*x.clone()))
-            }
+            TypeKind::Composite(
+                Composite::Vec
+                | Composite::VecDeque
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                inner,
+                _,
+            ) => inner.as_ref().map(|x| *x.clone()),
             TypeKind::Composite(_, _, _) => None,
             TypeKind::Unit => None,
             TypeKind::Unknown => None,
@@ -384,7 +501,11 @@ impl TypeKind {
         match self {
             TypeKind::Primitive(_) => None,
             TypeKind::Value(_) => None,
-            TypeKind::Composite(Composite::HashMap, key_ty, val_ty) => {
+            TypeKind::Composite(
+                Composite::HashMap | Composite::BTreeMap,
+                key_ty,
+                val_ty,
+            ) => {
                 let key = key_ty
                     .as_ref()
                     .map(|x| *x.clone())
@@ -417,6 +538,8 @@ pub enum Primitive {
     U64,
     I64,
     USize,
+    F32,
+    F64,
     Bool,
 }
 
@@ -432,6 +555,8 @@ impl Debug for Primitive {
             Primitive::U64 => "Primitive::U64",
             Primitive::I64 => "Primitive::I64",
             Primitive::USize => "Primitive::Usize",
+            Primitive::F32 => "Primitive::F32",
+            Primitive::F64 => "Primitive::F64",
             Primitive::Bool => "Primitive::Bool",
         };
         write!(f, "{}", ty)
@@ -527,8 +652,12 @@ impl Value {
 #[derive(Clone, PartialEq)]
 pub enum Composite {
     Vec,
+    VecDeque,
     Option,
     HashMap,
+    BTreeMap,
+    HashSet,
+    BTreeSet,
     Custom(TypeInfo, String),
 }
 
@@ -536,8 +665,12 @@ impl Debug for Composite {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Composite::Vec => write!(f, "Composite::Vec"),
+            Composite::VecDeque => write!(f, "Composite::VecDeque"),
             Composite::Option => write!(f, "Composite::Option"),
             Composite::HashMap => write!(f, "Composite::HashMap"),
+            Composite::BTreeMap => write!(f, "Composite::BTreeMap"),
+            Composite::HashSet => write!(f, "Composite::HashSet"),
+            Composite::BTreeSet => write!(f, "Composite::BTreeSet"),
             Composite::Custom(type_info, name) => {
                 write!(f, "Composite::Custom({:?}, \"{}\")", type_info, name)
             }
@@ -613,6 +746,8 @@ fn ident_to_kind(
                 "u64" => return TypeKind::Primitive(Primitive::U64),
                 "i64" => return TypeKind::Primitive(Primitive::I64),
                 "usize" => return TypeKind::Primitive(Primitive::USize),
+                "f32" => return TypeKind::Primitive(Primitive::F32),
+                "f64" => return TypeKind::Primitive(Primitive::F64),
                 "bool" => return TypeKind::Primitive(Primitive::Bool),
                 _ => {}
             };
@@ -646,14 +781,20 @@ fn ident_to_kind(
             // -----------------
             1 => match &args[0] {
                 GenericArgument::Type(ty) => match ident_str.as_str() {
-                    "Vec" => {
+                    "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => {
                         let inner = resolve_rust_ty(
                             ty,
                             type_infos,
                             RustTypeContext::CollectionItem,
                         )
                         .map(|x| Box::new(x));
-                        TypeKind::Composite(Composite::Vec, inner, None)
+                        let composite = match ident_str.as_str() {
+                            "Vec" => Composite::Vec,
+                            "VecDeque" => Composite::VecDeque,
+                            "HashSet" => Composite::HashSet,
+                            _ => Composite::BTreeSet,
+                        };
+                        TypeKind::Composite(composite, inner, None)
                     }
                     "Option" => {
                         let inner = resolve_rust_ty(
@@ -695,7 +836,7 @@ fn ident_to_kind(
                 (GenericArgument::Type(ty1), GenericArgument::Type(ty2))
                 => {
                     match ident_str.as_str() {
-                        "HashMap" => {
+                        "HashMap" | "BTreeMap" => {
                             let inner1 = resolve_rust_ty(
                                 ty1,
                                 type_infos,
@@ -708,11 +849,12 @@ fn ident_to_kind(
                                 RustTypeContext::CollectionItem,
                             )
                             .map(|x| Box::new(x));
-                            TypeKind::Composite(
-                                Composite::HashMap,
-                                inner1,
-                                inner2,
-                            )
+                            let composite = if ident_str == "HashMap" {
+                                Composite::HashMap
+                            } else {
+                                Composite::BTreeMap
+                            };
+                            TypeKind::Composite(composite, inner1, inner2)
                         }
                         _ => todo!("Not yet handling custom angle bracketed types with {} type parameters", args.len()),
                     }
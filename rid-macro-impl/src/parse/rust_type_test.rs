@@ -83,6 +83,28 @@ mod primitive {
         assert_matches!(ty.reference, ParsedReference::Ref(None));
         assert_matches!(ty.kind, TypeKind::Primitive(Primitive::I64));
     }
+
+    #[test]
+    fn f32() {
+        let res = parse(quote! { fn f(x: f32) {} });
+        let ty = res.expect("extracts rust type");
+
+        assert_eq!(ty.dart_wrapper_rust_string(), "f32", "ident");
+        assert_eq!(ty.rust_ident().to_string(), "f32", "rust ident");
+        assert_matches!(ty.reference, ParsedReference::Owned);
+        assert_matches!(ty.kind, TypeKind::Primitive(Primitive::F32));
+    }
+
+    #[test]
+    fn ref_f64() {
+        let res = parse(quote! { fn f(x: &f64) {} });
+        let ty = res.expect("extracts rust type");
+
+        assert_eq!(ty.dart_wrapper_rust_string(), "f64", "ident");
+        assert_eq!(ty.rust_ident().to_string(), "f64", "rust ident");
+        assert_matches!(ty.reference, ParsedReference::Ref(None));
+        assert_matches!(ty.kind, TypeKind::Primitive(Primitive::F64));
+    }
 }
 
 // -----------------
@@ -482,3 +504,92 @@ mod composite_option {
         };
     }
 }
+
+// -----------------
+// Nested Composites
+// -----------------
+mod nested_composites {
+    use super::*;
+
+    #[test]
+    fn ffi_type_names() {
+        let name = |input: proc_macro2::TokenStream| {
+            parse(input).expect("extracts rust type").ffi_type_name()
+        };
+
+        assert_eq!(name(quote! { fn f(x: Vec<Vec<u8>>) {} }), "Vec_Vec_u8");
+        assert_eq!(
+            name(quote! { fn f(x: Vec<Option<u32>>) {} }),
+            "Vec_Option_u32"
+        );
+        assert_eq!(
+            name(quote! { fn f(x: HashMap<String, Vec<u32>>) {} }),
+            "HashMap_String__Vec_u32"
+        );
+        assert_eq!(name(quote! { fn f(x: u8) {} }), "u8");
+    }
+
+    #[test]
+    fn flattened_option_type() {
+        let ty = parse(quote! { fn f(x: Option<Option<Vec<u8>>>) {} })
+            .expect("extracts rust type");
+        let inner = ty.flattened_option_type().expect("is option");
+        assert_eq!(inner.ffi_type_name(), "Vec_u8");
+    }
+}
+
+// -----------------
+// Sets, Sorted Maps and Queues
+// -----------------
+mod std_collections {
+    use super::*;
+
+    #[test]
+    fn vec_deque_u8() {
+        let ty = parse(quote! { fn f(x: VecDeque<u8>) {} })
+            .expect("extracts rust type");
+
+        assert_eq!(ty.rust_ident().to_string(), "VecDeque", "rust ident");
+        assert!(ty.is_vec_deque(), "is vec deque");
+        assert!(!ty.is_vec(), "is not a vec");
+        assert_eq!(ty.ffi_type_name(), "VecDeque_u8");
+        assert_matches!(
+            ty.inner_composite_type().expect("has inner type").kind,
+            TypeKind::Primitive(Primitive::U8)
+        );
+    }
+
+    #[test]
+    fn btree_map_string_u32() {
+        let ty = parse(quote! { fn f(x: BTreeMap<String, u32>) {} })
+            .expect("extracts rust type");
+
+        assert_matches!(
+            ty.kind,
+            TypeKind::Composite(Composite::BTreeMap, _, _)
+        );
+        assert!(ty.is_map(), "is map");
+        assert!(ty.is_ordered(), "is ordered");
+        assert!(!ty.is_hash_map(), "is not a hash map");
+        assert_eq!(ty.ffi_type_name(), "BTreeMap_String__u32");
+        let (key, val) = ty.key_val_composite_types().expect("has key and val");
+        assert_matches!(key.kind, TypeKind::Value(Value::String));
+        assert_matches!(val.kind, TypeKind::Primitive(Primitive::U32));
+    }
+
+    #[test]
+    fn sets() {
+        let hash_set = parse(quote! { fn f(x: HashSet<String>) {} })
+            .expect("extracts rust type");
+        assert!(hash_set.is_set(), "hash set is set");
+        assert!(!hash_set.is_ordered(), "hash set is not ordered");
+        assert_eq!(hash_set.ffi_type_name(), "HashSet_String");
+
+        let btree_set = parse(quote! { fn f(x: &BTreeSet<u64>) {} })
+            .expect("extracts rust type");
+        assert!(btree_set.is_set(), "btree set is set");
+        assert!(btree_set.is_ordered(), "btree set is ordered");
+        assert_matches!(btree_set.reference, ParsedReference::Ref(_));
+        assert_eq!(btree_set.ffi_type_name(), "BTreeSet_u64");
+    }
+}
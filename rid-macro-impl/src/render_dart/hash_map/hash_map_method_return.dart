@@ -0,0 +1,12 @@
+/// extension Rid_RidHashMap_ExtOn{hash_map_type} on {pointer_hash_map_type} {
+///   {dart_collection}.{dart_map_type}<{resolved_dart_key_type}, {resolved_dart_val_type}> toDartAndDispose() {
+///     final hashMap = this.toDart();
+///     this.dispose();
+///     return hashMap;
+///   }
+///
+///   // Only call this on HashMaps returned from exported functions by value, never on HashMap fields.
+///   void dispose() {
+///     {rid_ffi}.{fn_free_ident}(this);
+///   }
+/// }
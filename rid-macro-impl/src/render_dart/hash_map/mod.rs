@@ -1,4 +1,4 @@
-use rid_common::{DART_COLLECTION, RID_FFI};
+use rid_common::{DART_COLLECTION, RID_FFI, THROW_IF_PANICKED};
 
 use crate::{
     accesses::{
@@ -9,6 +9,8 @@ use crate::{
 
 const TEMPLATE_FIELD_ACCESS: &str =
     std::include_str!("./hash_map_field_access.dart");
+const TEMPLATE_METHOD_RETURN: &str =
+    std::include_str!("./hash_map_method_return.dart");
 
 impl HashMapAccess {
     pub fn render_dart_for_field_reference(
@@ -22,12 +24,7 @@ impl HashMapAccess {
             .key_type
             .render_dart_resolved_ffi_var(type_infos, "key");
 
-        // NOTE: this works as long as key/val types only have alpha-numeric chars
-        let hash_map_type = format!(
-            "HashMap_{key}__{val}",
-            key = self.key_type.rust_ident(),
-            val = self.val_type.rust_ident()
-        );
+        let hash_map_type = self.dart_hash_map_type();
 
         let resolved_dart_key_type =
             resolved_dart_item_type_string(&self.key_type, type_infos);
@@ -42,17 +39,32 @@ impl HashMapAccess {
             "".to_string()
         };
 
-        let val_to_dart = if self.val_type.is_string_like() {
-            ".toDartString()".to_string()
-        } else if self.val_type.is_primitive() {
-            ".value".to_string()
+        // Option values are flattened, thus `get` resolves the wrapped value directly
+        let val_type = self
+            .val_type
+            .flattened_option_type()
+            .unwrap_or_else(|| self.val_type.clone());
+        let val_from_ptr = if val_type.is_string_like() {
+            "ptr.toDartString()".to_string()
+        } else if val_type.is_bool() {
+            "ptr.value != 0".to_string()
+        } else if val_type.is_primitive() {
+            "ptr.value".to_string()
+        } else if val_type.is_enum() {
+            format!("{}.values[ptr.value]", val_type.rust_ident())
+        } else {
+            "ptr.toDart()".to_string()
+        };
+        let nullable_dart_val_type = if self.val_type.is_option() {
+            resolved_dart_val_type.clone()
         } else {
-            ".toDart()".to_string()
+            format!("{}?", resolved_dart_val_type)
         };
 
         TEMPLATE_FIELD_ACCESS
             .replace("/// ", comment)
             .replace("{hash_map_type}", &hash_map_type)
+            .replace("{dart_map_type}", self.dart_map_type())
             .replace("{pointer_hash_map_type}", &pointer_hash_map_type)
             // key
             .replace("{key_ffi_arg}", &key_ffi_arg)
@@ -60,7 +72,8 @@ impl HashMapAccess {
             .replace("{key_to_dart}", &key_to_dart)
             // val
             .replace("{resolved_dart_val_type}", &resolved_dart_val_type)
-            .replace("{val_to_dart}", &val_to_dart)
+            .replace("{nullable_dart_val_type}", &nullable_dart_val_type)
+            .replace("{val_from_ptr}", &val_from_ptr)
             // fn idents
             .replace("{fn_len_ident}", &self.fn_len_ident.to_string())
             .replace("{fn_get_ident}", &self.fn_get_ident.to_string())
@@ -71,15 +84,46 @@ impl HashMapAccess {
             .replace("{fn_keys_ident}", &self.fn_keys_ident.to_string())
             // namespaces
             .replace("{rid_ffi}", RID_FFI)
+            .replace("{throw_if_panicked}", THROW_IF_PANICKED)
             .replace("{dart_collection}", DART_COLLECTION)
     }
 
+    /// HashMaps returned from a method share the accessors rendered for field references and
+    /// only add methods to free them.
     pub fn render_dart_return_from_method(
         &self,
         type_infos: &TypeInfoMap,
         comment: &str,
     ) -> String {
-        // TODO(thlorenz): HashMap
-        "".to_string()
+        let pointer_hash_map_type =
+            self.hash_map_type.render_dart_field_return_type();
+        let resolved_dart_key_type =
+            resolved_dart_item_type_string(&self.key_type, type_infos);
+        let resolved_dart_val_type =
+            resolved_dart_item_type_string(&self.val_type, type_infos);
+
+        TEMPLATE_METHOD_RETURN
+            .replace("/// ", comment)
+            .replace("{hash_map_type}", &self.dart_hash_map_type())
+            .replace("{dart_map_type}", self.dart_map_type())
+            .replace("{pointer_hash_map_type}", &pointer_hash_map_type)
+            .replace("{resolved_dart_key_type}", &resolved_dart_key_type)
+            .replace("{resolved_dart_val_type}", &resolved_dart_val_type)
+            .replace("{fn_free_ident}", &self.fn_free_ident.to_string())
+            .replace("{rid_ffi}", RID_FFI)
+            .replace("{dart_collection}", DART_COLLECTION)
+    }
+
+    fn dart_hash_map_type(&self) -> String {
+        self.hash_map_type.ffi_type_name()
+    }
+
+    /// A `BTreeMap` converts to a `LinkedHashMap` in order to preserve the order of its keys.
+    fn dart_map_type(&self) -> &'static str {
+        if self.hash_map_type.is_ordered() {
+            "LinkedHashMap"
+        } else {
+            "HashMap"
+        }
     }
 }
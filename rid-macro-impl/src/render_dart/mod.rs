@@ -1,9 +1,12 @@
 pub mod hash_map;
+mod render_class_overrides;
 mod render_dart_arg;
 mod render_dart_type;
+mod render_data_enum_class;
 mod render_debug_extension;
 mod render_display_extension;
 mod render_enum;
+mod render_export_error;
 mod render_function_body;
 mod render_function_export;
 mod render_function_reexport;
@@ -11,13 +14,18 @@ mod render_instance_method_extension;
 mod render_pointer_type;
 mod render_struct_class;
 mod render_to_return_type;
+mod render_wire_encode;
+pub mod set;
 pub mod vec;
 
+pub use render_class_overrides::*;
 pub use render_dart_arg::*;
 pub use render_dart_type::*;
+pub use render_data_enum_class::*;
 pub use render_debug_extension::*;
 pub use render_display_extension::*;
 pub use render_enum::*;
+pub use render_export_error::*;
 pub use render_function_body::*;
 pub use render_function_export::*;
 pub use render_function_reexport::*;
@@ -25,6 +33,11 @@ pub use render_instance_method_extension::*;
 pub use render_pointer_type::*;
 pub use render_struct_class::*;
 pub use render_to_return_type::*;
+pub use render_wire_encode::*;
 
+#[cfg(test)]
+mod render_class_overrides_test;
+#[cfg(test)]
+mod render_data_enum_class_test;
 #[cfg(test)]
 pub mod render_function_export_test;
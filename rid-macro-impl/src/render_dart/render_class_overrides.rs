@@ -0,0 +1,164 @@
+// -----------------
+// Class overrides
+// -----------------
+// Dart classes representing Rust structs and data enum variants are compared, hashed and printed
+// by their fields. The below render the respective overrides given the fields of the class.
+
+/// A field of a Dart class which the class overrides are rendered for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DartClassField {
+    /// The Dart name of the field, i.e. `dueDate`.
+    pub name: String,
+    /// `true` if the field holds a `List`, `Set` or `Map` which Dart compares by identity and
+    /// which therefore is compared and hashed element by element.
+    pub is_collection: bool,
+}
+
+/// Renders the `==` operator override comparing the provided fields.
+///
+/// Example:
+///
+/// ```dart
+/// @override
+/// bool operator ==(Object other) {
+///   return identical(this, other) ||
+///     other is Todo &&
+///         id == other.id &&
+///         ridDeepEquals(tags, other.tags);
+/// }
+/// ```
+pub fn render_dart_equals_operator(
+    class_name: &str,
+    fields: &[DartClassField],
+    comment: &str,
+) -> String {
+    let comparison = if fields.is_empty() {
+        format!(" other is {class_name};", class_name = class_name)
+    } else {
+        let field_comparisons = fields
+            .iter()
+            .map(|x| {
+                let comparison = if x.is_collection {
+                    format!(
+                        "ridDeepEquals({field}, other.{field})",
+                        field = x.name
+                    )
+                } else {
+                    format!("{field} == other.{field}", field = x.name)
+                };
+                format!(
+                    "{comment}           {comparison}",
+                    comparison = comparison,
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" &&\n");
+        format!(
+            "\n{comment}       other is {class_name} &&\n{field_comparisons};",
+            class_name = class_name,
+            field_comparisons = field_comparisons,
+            comment = comment
+        )
+    };
+    format!(
+        r###"{comment}   @override
+{comment}   bool operator ==(Object other) {{
+{comment}     return identical(this, other) ||{comparison}
+{comment}   }}"###,
+        comparison = comparison,
+        comment = comment
+    )
+}
+
+/// Renders the `hashCode` override combining the hash codes of the provided fields.
+///
+/// Example:
+///
+/// ```dart
+/// @override
+/// int get hashCode {
+///   return
+///     id.hashCode ^
+///     ridDeepHash(tags);
+/// }
+/// ```
+pub fn render_dart_hash_code(
+    fields: &[DartClassField],
+    comment: &str,
+) -> String {
+    let hash = if fields.is_empty() {
+        " runtimeType.hashCode;".to_string()
+    } else {
+        let field_xors = fields
+            .iter()
+            .map(|x| {
+                let hash = if x.is_collection {
+                    format!("ridDeepHash({})", x.name)
+                } else {
+                    format!("{}.hashCode", x.name)
+                };
+                format!(
+                    "{comment}       {hash}",
+                    hash = hash,
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" ^\n");
+        format!("\n{};", field_xors)
+    };
+    format!(
+        r###"{comment}   @override
+{comment}   int get hashCode {{
+{comment}     return{hash}
+{comment}   }}"###,
+        hash = hash,
+        comment = comment
+    )
+}
+
+/// Renders the `toString` override printing the provided fields, one per line if there are
+/// more than six.
+///
+/// Example:
+///
+/// ```dart
+/// @override
+/// String toString() {
+///   return 'Todo{id: $id, title: $title}';
+/// }
+/// ```
+pub fn render_dart_to_string_override(
+    class_name: &str,
+    fields: &[DartClassField],
+    comment: &str,
+) -> String {
+    let multi_line = fields.len() > 6;
+    let fields = fields
+        .iter()
+        .map(|x| format!("{field}: ${field}", field = x.name))
+        .collect::<Vec<String>>();
+    let description = if multi_line {
+        format!(
+            "'''{class_name} {{\n{comment}   {fields}\n{comment} }}'''",
+            class_name = class_name,
+            fields = fields.join(&format!("\n{}   ", comment)),
+            comment = comment
+        )
+    } else {
+        format!(
+            "'{class_name}{{{fields}}}'",
+            class_name = class_name,
+            fields = fields.join(", ")
+        )
+    };
+    format!(
+        r###"{comment}   @override
+{comment}   String toString() {{
+{comment}     return {description};
+{comment}   }}"###,
+        description = description,
+        comment = comment
+    )
+}
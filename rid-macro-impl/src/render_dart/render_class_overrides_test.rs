@@ -0,0 +1,118 @@
+use super::{
+    render_dart_equals_operator, render_dart_hash_code,
+    render_dart_to_string_override, DartClassField,
+};
+
+fn fields(names: &[&str]) -> Vec<DartClassField> {
+    names
+        .iter()
+        .map(|x| DartClassField {
+            name: x.to_string(),
+            is_collection: false,
+        })
+        .collect()
+}
+
+#[test]
+fn class_with_fields() {
+    let fields = fields(&["id", "title"]);
+    assert_eq!(
+        render_dart_equals_operator("Todo", &fields, "///"),
+        r###"///   @override
+///   bool operator ==(Object other) {
+///     return identical(this, other) ||
+///       other is Todo &&
+///           id == other.id &&
+///           title == other.title;
+///   }"###
+    );
+    assert_eq!(
+        render_dart_hash_code(&fields, "///"),
+        r###"///   @override
+///   int get hashCode {
+///     return
+///       id.hashCode ^
+///       title.hashCode;
+///   }"###
+    );
+    assert_eq!(
+        render_dart_to_string_override("Todo", &fields, "///"),
+        r###"///   @override
+///   String toString() {
+///     return 'Todo{id: $id, title: $title}';
+///   }"###
+    );
+}
+
+#[test]
+fn class_with_collection_fields() {
+    let mut fields = fields(&["id", "tags", "scores"]);
+    fields[1].is_collection = true;
+    fields[2].is_collection = true;
+    assert_eq!(
+        render_dart_equals_operator("Todo", &fields, "///"),
+        r###"///   @override
+///   bool operator ==(Object other) {
+///     return identical(this, other) ||
+///       other is Todo &&
+///           id == other.id &&
+///           ridDeepEquals(tags, other.tags) &&
+///           ridDeepEquals(scores, other.scores);
+///   }"###
+    );
+    assert_eq!(
+        render_dart_hash_code(&fields, "///"),
+        r###"///   @override
+///   int get hashCode {
+///     return
+///       id.hashCode ^
+///       ridDeepHash(tags) ^
+///       ridDeepHash(scores);
+///   }"###
+    );
+}
+
+#[test]
+fn class_without_fields() {
+    assert_eq!(
+        render_dart_equals_operator("ShapeEmpty", &[], "///"),
+        r###"///   @override
+///   bool operator ==(Object other) {
+///     return identical(this, other) || other is ShapeEmpty;
+///   }"###
+    );
+    assert_eq!(
+        render_dart_hash_code(&[], "///"),
+        r###"///   @override
+///   int get hashCode {
+///     return runtimeType.hashCode;
+///   }"###
+    );
+    assert_eq!(
+        render_dart_to_string_override("ShapeEmpty", &[], "///"),
+        r###"///   @override
+///   String toString() {
+///     return 'ShapeEmpty{}';
+///   }"###
+    );
+}
+
+#[test]
+fn to_string_of_class_with_many_fields() {
+    let fields = fields(&["a", "b", "c", "d", "e", "f", "g"]);
+    assert_eq!(
+        render_dart_to_string_override("Letters", &fields, "///"),
+        r###"///   @override
+///   String toString() {
+///     return '''Letters {
+///   a: $a
+///   b: $b
+///   c: $c
+///   d: $d
+///   e: $e
+///   f: $f
+///   g: $g
+/// }''';
+///   }"###
+    );
+}
@@ -31,6 +31,10 @@ impl DartType {
                 "int?".to_string()
             }
             Int32(_) | Int64(_) => "int".to_string(),
+            Float32(nullable) | Float64(nullable) if *nullable => {
+                "double?".to_string()
+            }
+            Float32(_) | Float64(_) => "double".to_string(),
             Bool(nullable) if *nullable => "bool?".to_string(),
             Bool(_) => "bool".to_string(),
             // -----------------
@@ -87,6 +91,32 @@ impl DartType {
                     val = val.render_type(raw)
                 )
             }
+            // Ordered collections keep the order of their Rust counterparts
+            BTreeMap(nullable, key, val) => {
+                format!(
+                    "{dart_collection}.LinkedHashMap<{key}, {val}>{nullable}",
+                    dart_collection = DART_COLLECTION,
+                    key = key.render_type(raw),
+                    val = val.render_type(raw),
+                    nullable = if *nullable { "?" } else { "" }
+                )
+            }
+            HashSet(nullable, item) => {
+                format!(
+                    "{dart_collection}.HashSet<{item}>{nullable}",
+                    dart_collection = DART_COLLECTION,
+                    item = item.render_type(raw),
+                    nullable = if *nullable { "?" } else { "" }
+                )
+            }
+            BTreeSet(nullable, item) => {
+                format!(
+                    "{dart_collection}.LinkedHashSet<{item}>{nullable}",
+                    dart_collection = DART_COLLECTION,
+                    item = item.render_type(raw),
+                    nullable = if *nullable { "?" } else { "" }
+                )
+            }
             // -----------------
             // Invalid
             // -----------------
@@ -102,21 +132,32 @@ impl DartType {
             DartType::Int64(_) => {
                 Some(format!("@{dart_ffi}.Int64()", dart_ffi = DART_FFI))
             }
+            DartType::Float32(_) => {
+                Some(format!("@{dart_ffi}.Float()", dart_ffi = DART_FFI))
+            }
+            DartType::Float64(_) => {
+                Some(format!("@{dart_ffi}.Double()", dart_ffi = DART_FFI))
+            }
             _ => None,
         }
     }
 
-    fn render_resolved_ffi_var(&self, var: &str) -> String {
+    pub fn render_resolved_ffi_var(&self, var: &str) -> String {
         use DartType::*;
         match self {
             // -----------------
             // Primitives
             // -----------------
             // TODO(thlorenz): All the below also would resolve to a different type when nullable
-            Int32(nullable) | Int64(nullable) if *nullable => {
+            Int32(nullable) | Int64(nullable) | Float32(nullable)
+            | Float64(nullable)
+                if *nullable =>
+            {
                 format!("{}", var)
             }
-            Int32(_) | Int64(_) => format!("{var}", var = var),
+            Int32(_) | Int64(_) | Float32(_) | Float64(_) => {
+                var.to_string()
+            }
             Bool(nullable) if *nullable => {
                 format!("{var} == null ? 0 : {var} ? 1 : 0", var = var)
             }
@@ -146,7 +187,10 @@ impl DartType {
             // Collection Types
             // -----------------
             Vec(_, _) => format!("{var}", var = var),
-            HashMap(_, _, _) => format!("{var}", var = var),
+            HashMap(_, _, _)
+            | BTreeMap(_, _, _)
+            | HashSet(_, _)
+            | BTreeSet(_, _) => var.to_string(),
 
             // -----------------
             // Invalid
@@ -166,18 +210,25 @@ impl DartType {
             // -----------------
             // Primitives
             // -----------------
-            Int32(nullable) | Int64(nullable) | Bool(nullable) if *nullable => {
-                format!("{snip}?", snip = snip)
+            // NOTE: Raw nullable primitives are passed as pointer to the value
+            Int32(nullable) | Int64(nullable) | Float32(nullable)
+            | Float64(nullable)
+                if *nullable =>
+            {
+                format!("{snip}?.value", snip = snip)
+            }
+            Bool(nullable) if *nullable => format!(
+                "() {{ final x = {snip}; return x == null ? null : x.value != 0; }}()",
+                snip = snip
+            ),
+            Int32(_) | Int64(_) | Float32(_) | Float64(_) | Bool(_) => {
+                snip.to_string()
             }
-            Int32(_) | Int64(_) | Bool(_) => snip.to_string(),
 
             // -----------------
             // Strings
             // -----------------
             // NOTE: Raw Strings are already converted to Dart Strings
-            String(nullable) if *nullable => {
-                format!("{snip}?", snip = snip)
-            }
             String(_) => snip.to_string(),
 
             // -----------------
@@ -186,9 +237,10 @@ impl DartType {
             Custom(nullable, info, type_name) if *nullable => {
                 use Category::*;
                 match info.cat {
-                    // i.e. () { final x = store.filter; return x != null ? Filter.values[x] : null; }()
+                    // Raw nullable enums are passed as pointer to the discriminant, i.e.
+                    // () { final x = store.filter; return x != null ? Filter.values[x.value] : null; }()
                     Enum => format!(
-                        "() {{ final x = {snip}; return x != null ? {type_name}.values[x] : null; }}()",
+                        "() {{ final x = {snip}; return x != null ? {type_name}.values[x.value] : null; }}()",
                         type_name = type_name,
                         snip = snip
                     ),
@@ -227,10 +279,21 @@ impl DartType {
 
             // NOTE: All hashmaps are expected have a `toDart` extension method implemented
             // which maps all it's keys/values `toDart` before converting it `toHashMap`
-            HashMap(nullable, _, _) if *nullable => {
+            HashMap(nullable, _, _) | BTreeMap(nullable, _, _) if *nullable => {
+                format!("{snip}?.toDart()", snip = snip)
+            }
+            HashMap(_, _, _) | BTreeMap(_, _, _) => {
+                format!("{snip}.toDart()", snip = snip)
+            }
+
+            // NOTE: All sets are expected have a `toDart` extension method implemented
+            // which maps all it's items `toDart` before converting it `toSet`
+            HashSet(nullable, _) | BTreeSet(nullable, _) if *nullable => {
                 format!("{snip}?.toDart()", snip = snip)
             }
-            HashMap(_, _, _) => format!("{snip}.toDart()", snip = snip),
+            HashSet(_, _) | BTreeSet(_, _) => {
+                format!("{snip}.toDart()", snip = snip)
+            }
 
             // -----------------
             // Invalid
@@ -0,0 +1,423 @@
+use heck::MixedCase;
+use rid_common::{DART_FFI, FFI_GEN_BIND};
+
+use crate::{
+    parse::{ParsedEnum, ParsedEnumVariant},
+    render_dart::{
+        dart_wire_decode_fn_name, dart_wire_encode_fn_name, prefix_dart_lines,
+        render_dart_equals_operator, render_dart_hash_code,
+        render_dart_to_string_override, DartClassField,
+    },
+};
+
+impl ParsedEnum {
+    /// Renders a Dart class hierarchy for a Rust enum whose variants carry data.
+    ///
+    /// It consists of an abstract base class, one subclass per variant and an extension method
+    /// `toDart` to instantiate the matching subclass by resolving the variant and its fields
+    /// from Rust.
+    ///
+    /// The supported Dart SDKs (< 3.0) have no `sealed` classes and thus `switch` statements
+    /// over the subclasses cannot be checked for exhaustiveness. Instead the base class provides
+    /// `when` whose callbacks are all `required`, so that the compiler ensures that every variant
+    /// is handled. The base class only has a private constructor which prevents extending it
+    /// outside of the generated library, however it can still be implemented.
+    ///
+    /// Example:
+    ///
+    /// ```dart
+    /// abstract class Shape {
+    ///   const Shape._();
+    ///   T when<T>({
+    ///     required T Function(ShapeCircle) circle,
+    ///     required T Function(ShapeEmpty) empty,
+    ///   }) { .. }
+    /// }
+    ///
+    /// class ShapeCircle extends Shape {
+    ///   final double field0;
+    ///   const ShapeCircle(this.field0) : super._();
+    /// }
+    ///
+    /// class ShapeEmpty extends Shape {
+    ///   const ShapeEmpty() : super._();
+    /// }
+    /// ```
+    pub fn render_dart_data_enum_classes(&self, comment: &str) -> String {
+        let base_class = self.render_dart_base_class(comment);
+        let variant_classes = self
+            .variants
+            .iter()
+            .map(|x| self.render_dart_variant_class(x, comment))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let to_dart_extension = self.render_dart_to_dart_extension(comment);
+        let wire_encode_fn = format!(
+            "{}{}",
+            self.render_dart_wire_encode_fn(comment),
+            self.render_dart_wire_decode_fn(comment)
+        );
+
+        format!(
+            r###"{comment}
+{comment} ```dart
+{comment}
+{comment} // Dart class hierarchy representation of {ident}.
+{base_class}
+{variant_classes}
+{comment}
+{comment} // Extension method `toDart` to instantiate the matching {ident} subclass by resolving the variant and its fields from Rust
+{to_dart_extension}{wire_encode_fn}
+{comment} ```"###,
+            ident = self.ident,
+            base_class = base_class,
+            variant_classes = variant_classes,
+            to_dart_extension = to_dart_extension,
+            wire_encode_fn = wire_encode_fn,
+            comment = comment
+        )
+    }
+
+    // -----------------
+    // Wire Encoding
+    // -----------------
+    /// Renders a Dart function which encodes an instance of this enum into the rid wire format
+    /// so that it can be passed to Rust as part of a message.
+    /// The index of the variant is written first, followed by its fields.
+    ///
+    /// Example:
+    ///
+    /// ```dart
+    /// void _ridEncodeShape(RidWireWriter w, Shape value) {
+    ///   if (value is ShapeCircle) {
+    ///     w.writeU32(0);
+    ///     w.writeF64(value.field0);
+    ///     return;
+    ///   }
+    ///   if (value is ShapeEmpty) {
+    ///     w.writeU32(1);
+    ///     return;
+    ///   }
+    ///   throw StateError('Unknown Shape variant $value');
+    /// }
+    /// ```
+    fn render_dart_wire_encode_fn(&self, comment: &str) -> String {
+        if !self.is_wire_encodable() {
+            return "".to_string();
+        }
+        let field_prefix = format!("{}     ", comment);
+        let variant_checks = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let encode_fields = variant
+                    .fields
+                    .iter()
+                    .map(|x| {
+                        let var = format!("value.{}", x.name().to_mixed_case());
+                        format!(
+                            "{}\n",
+                            prefix_dart_lines(
+                                &x.rust_type.render_dart_wire_encode(&var, 0),
+                                &field_prefix,
+                            )
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join("");
+                format!(
+                    r###"{comment}   if (value is {class_name}) {{
+{comment}     w.writeU32({discriminant});
+{encode_fields}{comment}     return;
+{comment}   }}"###,
+                    class_name = self.dart_variant_class_name(variant),
+                    discriminant = variant.discriminant,
+                    encode_fields = encode_fields,
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        format!(
+            r###"
+{comment}
+{comment} // Encodes {ident} into the rid wire format when it is passed to Rust as part of a message
+{comment} void {fn_name}(RidWireWriter w, {ident} value) {{
+{variant_checks}
+{comment}   throw StateError('Unknown {ident} variant $value');
+{comment} }}"###,
+            ident = self.ident,
+            fn_name = dart_wire_encode_fn_name(&self.ident.to_string()),
+            variant_checks = variant_checks,
+            comment = comment
+        )
+    }
+
+    /// Renders a Dart function which decodes an instance of this enum from the rid wire format
+    /// when it is posted from Rust as part of a reply.
+    /// The index of the variant is read first, followed by its fields.
+    ///
+    /// Example:
+    ///
+    /// ```dart
+    /// Shape _ridDecodeShape(RidWireReader r) {
+    ///   final variant = r.readU32();
+    ///   switch (variant) {
+    ///     case 0:
+    ///       return ShapeCircle(
+    ///         r.readF64(),
+    ///       );
+    ///     case 1:
+    ///       return ShapeEmpty();
+    ///   }
+    ///   throw StateError('Unknown Shape variant $variant');
+    /// }
+    /// ```
+    fn render_dart_wire_decode_fn(&self, comment: &str) -> String {
+        if !self.is_wire_encodable() {
+            return "".to_string();
+        }
+        let variant_cases = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let class_name = self.dart_variant_class_name(variant);
+                let instance = if variant.fields.is_empty() {
+                    format!("{}()", class_name)
+                } else {
+                    let decode_fields = variant
+                        .fields
+                        .iter()
+                        .map(|x| {
+                            format!(
+                                "{comment}         {decode},\n",
+                                decode = x.rust_type.render_dart_wire_decode(),
+                                comment = comment
+                            )
+                        })
+                        .collect::<Vec<String>>()
+                        .join("");
+                    format!(
+                        "{class_name}(\n{decode_fields}{comment}       )",
+                        class_name = class_name,
+                        decode_fields = decode_fields,
+                        comment = comment
+                    )
+                };
+                format!(
+                    r###"{comment}     case {discriminant}:
+{comment}       return {instance};"###,
+                    discriminant = variant.discriminant,
+                    instance = instance,
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        format!(
+            r###"
+{comment}
+{comment} // Decodes {ident} from the rid wire format when it is posted from Rust as part of a reply
+{comment} {ident} {fn_name}(RidWireReader r) {{
+{comment}   final variant = r.readU32();
+{comment}   switch (variant) {{
+{variant_cases}
+{comment}   }}
+{comment}   throw StateError('Unknown {ident} variant $variant');
+{comment} }}"###,
+            ident = self.ident,
+            fn_name = dart_wire_decode_fn_name(&self.ident.to_string()),
+            variant_cases = variant_cases,
+            comment = comment
+        )
+    }
+
+    // -----------------
+    // Base Class
+    // -----------------
+    fn render_dart_base_class(&self, comment: &str) -> String {
+        let when_params = self
+            .variants
+            .iter()
+            .map(|x| {
+                format!(
+                    "{comment}     required T Function({class_name}) {param},",
+                    class_name = self.dart_variant_class_name(x),
+                    param = x.ident.to_string().to_mixed_case(),
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let when_checks = self
+            .variants
+            .iter()
+            .map(|x| {
+                format!(
+                    "{comment}     if (self is {class_name}) return {param}(self);",
+                    class_name = self.dart_variant_class_name(x),
+                    param = x.ident.to_string().to_mixed_case(),
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r###"{comment} abstract class {ident} {{
+{comment}   const {ident}._();
+{comment}
+{comment}   /// Invokes the callback matching the variant of this instance.
+{comment}   /// All variants need to be handled which ensures that none is forgotten.
+{comment}   T when<T>({{
+{when_params}
+{comment}   }}) {{
+{comment}     final self = this;
+{when_checks}
+{comment}     throw StateError('Unknown {ident} variant $self');
+{comment}   }}
+{comment} }}"###,
+            ident = self.ident,
+            when_params = when_params,
+            when_checks = when_checks,
+            comment = comment
+        )
+    }
+
+    // -----------------
+    // Variant Classes
+    // -----------------
+    fn render_dart_variant_class(
+        &self,
+        variant: &ParsedEnumVariant,
+        comment: &str,
+    ) -> String {
+        let class_name = self.dart_variant_class_name(variant);
+        let fields: Vec<DartClassField> = variant
+            .fields
+            .iter()
+            .map(|x| DartClassField {
+                name: x.name().to_mixed_case(),
+                is_collection: x.rust_type.is_dart_collection(),
+            })
+            .collect();
+
+        let field_declarations = variant
+            .fields
+            .iter()
+            .map(|x| {
+                let (ty, _) = x
+                    .rust_type
+                    .render_dart_and_ffi_type(self.type_infos(), false);
+                format!(
+                    "{comment}   final {ty} {name};\n",
+                    ty = ty,
+                    name = x.name().to_mixed_case(),
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("");
+        let constructor_fields = fields
+            .iter()
+            .map(|x| format!("this.{}", x.name))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let equals_operator =
+            render_dart_equals_operator(&class_name, &fields, comment);
+        let hash_code = render_dart_hash_code(&fields, comment);
+        let to_string_override =
+            render_dart_to_string_override(&class_name, &fields, comment);
+
+        format!(
+            r###"{comment}
+{comment} class {class_name} extends {ident} {{
+{field_declarations}{comment}
+{comment}   const {class_name}({constructor_fields}) : super._();
+{comment}
+{equals_operator}
+{hash_code}
+{to_string_override}
+{comment} }}"###,
+            ident = self.ident,
+            class_name = class_name,
+            field_declarations = field_declarations,
+            constructor_fields = constructor_fields,
+            equals_operator = equals_operator,
+            hash_code = hash_code,
+            to_string_override = to_string_override,
+            comment = comment
+        )
+    }
+
+    // -----------------
+    // toDart Extension
+    // -----------------
+    fn render_dart_to_dart_extension(&self, comment: &str) -> String {
+        let raw_class_name = format!(
+            "{dart_ffi}.Pointer<{ffigen_bind}.{raw_ident}>",
+            dart_ffi = DART_FFI,
+            ffigen_bind = FFI_GEN_BIND,
+            raw_ident = self.raw_ident()
+        );
+        let cases = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let constructor_args = variant
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        field.rust_type.render_to_dart_for_arg(
+                            self.type_infos(),
+                            &format!(
+                                "this.{}",
+                                variant.field_getter_ident(field)
+                            ),
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!(
+                    r###"{comment}         case {discriminant}:
+{comment}           return {class_name}({constructor_args});"###,
+                    discriminant = variant.discriminant,
+                    class_name = self.dart_variant_class_name(variant),
+                    constructor_args = constructor_args,
+                    comment = comment
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r###"{comment} extension Rid_ToDart_ExtOn{ident} on {raw_class_name} {{
+{comment}   {ident} toDart() {{
+{comment}     ridStoreLock();
+{comment}     try {{
+{comment}       final discriminant = this.discriminant;
+{comment}       switch (discriminant) {{
+{cases}
+{comment}       }}
+{comment}       throw StateError('enum {ident} does not include discriminant $discriminant');
+{comment}     }} finally {{
+{comment}       ridStoreUnlock();
+{comment}     }}
+{comment}   }}
+{comment} }}"###,
+            ident = self.ident,
+            raw_class_name = raw_class_name,
+            cases = cases,
+            comment = comment
+        )
+    }
+
+    /// Name of the Dart class representing the given variant, i.e. `ShapeCircle`.
+    pub fn dart_variant_class_name(
+        &self,
+        variant: &ParsedEnumVariant,
+    ) -> String {
+        format!("{}{}", self.ident, variant.ident)
+    }
+}
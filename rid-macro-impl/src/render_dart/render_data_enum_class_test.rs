@@ -0,0 +1,57 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{attrs::EnumConfig, parse::ParsedEnum};
+
+fn render(input: TokenStream) -> String {
+    let item = syn::parse2::<syn::ItemEnum>(input).unwrap();
+    ParsedEnum::from(&item, EnumConfig::from(&item))
+        .render_dart_data_enum_classes("")
+}
+
+#[test]
+fn variant_classes_have_public_constructor_only() {
+    let res = render(quote! {
+        enum Shape {
+            Circle(f64),
+            Empty,
+        }
+    });
+    assert!(res.contains("abstract class Shape {\n   const Shape._();"));
+    assert!(res.contains(
+        " class ShapeCircle extends Shape {\n   final double field0;\n\n   const ShapeCircle(this.field0) : super._();\n\n"
+    ));
+    assert!(res.contains("   const ShapeEmpty() : super._();\n\n"));
+    assert!(!res.contains("ShapeCircle._("), "{}", res);
+}
+
+#[test]
+fn to_dart_unlocks_store_when_resolving_fails() {
+    let res = render(quote! {
+        enum Shape {
+            Circle(f64),
+            Empty,
+        }
+    });
+    assert!(
+        res.contains(
+            r###"   Shape toDart() {
+     ridStoreLock();
+     try {
+       final discriminant = this.discriminant;
+       switch (discriminant) {
+         case 0:
+           return ShapeCircle(this.circle_field0);
+         case 1:
+           return ShapeEmpty();
+       }
+       throw StateError('enum Shape does not include discriminant $discriminant');
+     } finally {
+       ridStoreUnlock();
+     }
+   }"###
+        ),
+        "{}",
+        res
+    );
+}
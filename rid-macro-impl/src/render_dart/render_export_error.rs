@@ -0,0 +1,118 @@
+use rid_common::{
+    EXPORT_ERROR_CLEAR, EXPORT_ERROR_MESSAGE, RID_EXPORT_EXCEPTION, RID_FFI,
+};
+
+use crate::{
+    attrs::Category,
+    parse::{
+        dart_type::DartType,
+        rust_type::{RustType, TypeKind, Value},
+    },
+};
+
+impl RustType {
+    /// Custom error types, i.e. `#[rid::model]` structs and enums, are passed to Dart as part of
+    /// the thrown exception. All other errors only provide their message.
+    pub fn is_typed_export_error(&self) -> bool {
+        match &self.kind {
+            TypeKind::Value(Value::Custom(info, _)) => {
+                info.cat != Category::Prim
+            }
+            _ => false,
+        }
+    }
+
+    /// Name of the Dart exception thrown when an exported function returns this type as error,
+    /// i.e. `TodoErrorException`.
+    pub fn dart_export_exception_name(&self) -> String {
+        if self.is_typed_export_error() {
+            format!("{}Exception", self.rust_ident())
+        } else {
+            RID_EXPORT_EXCEPTION.to_string()
+        }
+    }
+
+    /// Renders the check performed right after calling a fallible export which throws a Dart
+    /// exception if the Rust function returned an error.
+    ///
+    /// The error is converted like any other value of its type, i.e. C-style enums are resolved
+    /// from their discriminant while structs and enums with data carrying variants, both included
+    /// via `#[rid::structs]`, are resolved via `toDart()` before the error is cleared.
+    ///
+    /// ```dart
+    /// final errMsg = rid_ffi.rid_export_error_message();
+    /// if (errMsg.address != 0) {
+    ///   final exception = TodoErrorException(errMsg.toDartString(),
+    ///       TodoError.values[rid_ffi.rid_export_error_todoerror()]);
+    ///   rid_ffi.rid_export_error_clear();
+    ///   throw exception;
+    /// }
+    /// ```
+    pub fn render_dart_export_error_check(
+        &self,
+        indent: &str,
+        comment: &str,
+    ) -> String {
+        let exception_args = match &self.kind {
+            TypeKind::Value(Value::Custom(info, name))
+                if self.is_typed_export_error() =>
+            {
+                let get_error_snip = format!(
+                    "{rid_ffi}.{access}()",
+                    rid_ffi = RID_FFI,
+                    access = self.export_error_access_ident()
+                );
+                let error = DartType::Custom(false, info.clone(), name.clone())
+                    .render_to_dart_for_snippet(&get_error_snip);
+                format!("errMsg.toDartString(), {}", error)
+            }
+            _ => "errMsg.toDartString()".to_string(),
+        };
+        format!(
+            r###"{comment}{indent}  final errMsg = {rid_ffi}.{error_message}();
+{comment}{indent}  if (errMsg.address != 0) {{
+{comment}{indent}    final exception = {exception}({exception_args});
+{comment}{indent}    {rid_ffi}.{error_clear}();
+{comment}{indent}    throw exception;
+{comment}{indent}  }}"###,
+            rid_ffi = RID_FFI,
+            error_message = EXPORT_ERROR_MESSAGE,
+            error_clear = EXPORT_ERROR_CLEAR,
+            exception = self.dart_export_exception_name(),
+            exception_args = exception_args,
+            comment = comment,
+            indent = indent
+        )
+    }
+
+    /// Renders the Dart exception class carrying the message and the Dart representation of a
+    /// custom error type.
+    ///
+    /// ```dart
+    /// class TodoErrorException extends RidExportException {
+    ///   final TodoError error;
+    ///   const TodoErrorException(String message, this.error) : super(message);
+    /// }
+    /// ```
+    pub fn render_dart_export_exception_class(&self, comment: &str) -> String {
+        let exception = self.dart_export_exception_name();
+        format!(
+            r###"{comment} ```dart
+{comment} // Thrown when an exported function returns a {error_ty} error.
+{comment} class {exception} extends {base_exception} {{
+{comment}   final {error_ty} error;
+{comment}   const {exception}(String message, this.error) : super(message);
+{comment}
+{comment}   @override
+{comment}   String toString() {{
+{comment}     return '{exception}: $message ($error)';
+{comment}   }}
+{comment} }}
+{comment} ```"###,
+            exception = exception,
+            base_exception = RID_EXPORT_EXCEPTION,
+            error_ty = self.rust_ident(),
+            comment = comment
+        )
+    }
+}
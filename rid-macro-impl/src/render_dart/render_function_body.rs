@@ -1,5 +1,5 @@
 use quote::quote_spanned;
-use rid_common::{DART_FFI, FFI_GEN_BIND, RID_FFI};
+use rid_common::{DART_FFI, FFI_GEN_BIND, RID_FFI, THROW_IF_PANICKED};
 use syn::Ident;
 
 const RES_IDENT: &str = "res";
@@ -22,6 +22,7 @@ impl RustType {
         rid_fn_ident: &Ident,
         receiver: &Option<ParsedReceiver>,
         args: &[DartArg],
+        return_err: &Option<RustType>,
         indent: &str,
         comment: &str,
     ) -> String {
@@ -68,7 +69,7 @@ impl RustType {
                 this_arg = this_arg,
                 params = params
             ),
-           K::Composite(Composite::Vec, rust_type, _) => format!(
+           K::Composite(Composite::Vec | Composite::VecDeque, rust_type, _) => format!(
                 "{comment}{indent}  final {res_ident} = {rid_ffi}.{rid_fn_ident}({this_arg}{params});",
                 comment = comment,
                 indent = indent,
@@ -88,15 +89,46 @@ impl RustType {
                 this_arg = this_arg,
                 params = params
             ),
-            K::Composite(Composite::HashMap, key_type, val_type) => {
-                // TODO(thlorenz): HashMap 
-                abort!(self.rust_ident(), "TODO: RustType::render_fn_body K::Composite::HashMap<{:?}, {:?}>", key_type, val_type)
-            },
+            K::Composite(
+                Composite::HashMap
+                | Composite::BTreeMap
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                _,
+                _,
+            ) => format!(
+                "{comment}{indent}  final {res_ident} = {rid_ffi}.{rid_fn_ident}({this_arg}{params});",
+                comment = comment,
+                indent = indent,
+                res_ident = RES_IDENT,
+                rid_ffi = RID_FFI,
+                rid_fn_ident = rid_fn_ident,
+                this_arg = this_arg,
+                params = params
+            ),
             K::Composite(kind, _, _) => {
                 abort!(self.rust_ident(), "TODO: RustType::render_fn_body K::Composite({:?})", kind)
             }
             K::Unknown => abort!(self.rust_ident(), "TODO: RustType::render_fn_body K::Unknown"),
         };
+        // Panics are checked first since the function didn't return an error if it panicked
+        let call = format!(
+            "{call}\n{comment}{indent}  {throw_if_panicked}('{rid_fn_ident}');",
+            call = call,
+            comment = comment,
+            indent = indent,
+            throw_if_panicked = THROW_IF_PANICKED,
+            rid_fn_ident = rid_fn_ident,
+        );
+        let call = match return_err {
+            Some(err) => format!(
+                "{call}\n{error_check}",
+                call = call,
+                error_check =
+                    err.render_dart_export_error_check(indent, comment)
+            ),
+            None => call,
+        };
         format!(
             r###"{call}
 {comment}{indent}  {to_return_type}
@@ -27,6 +27,7 @@ impl ParsedFunction {
             receiver,
             args,
             return_arg,
+            return_err,
             dart_args,
             ..
         } = self;
@@ -40,6 +41,7 @@ impl ParsedFunction {
             &rid_fn_ident,
             receiver,
             &dart_args,
+            return_err,
             indent,
             &comment,
         );
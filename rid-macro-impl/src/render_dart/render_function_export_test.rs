@@ -71,6 +71,7 @@ mod impl_method_basic {
         let expected = r###"
 dart_ffi.Pointer<ffigen_bind.RawTodo> get_todo() {
   final res = rid_ffi.rid_export_Model_get_todo(this);
+  _ridThrowIfPanicked('rid_export_Model_get_todo');
   final ret = res;
   return ret;
 }
@@ -91,6 +92,7 @@ dart_ffi.Pointer<ffigen_bind.RawTodo> get_todo() {
         let expected = r###"
 int run(@dart_ffi.Int32() int arg0) {
   final res = rid_ffi.rid_export_Model_run(this, arg0);
+  _ridThrowIfPanicked('rid_export_Model_run');
   final ret = res;
   return ret;
 }
@@ -120,6 +122,7 @@ mod impl_method_returning_option {
         let expected = r###"
 dart_ffi.Pointer<ffigen_bind.RawTodo>? find_todo(@dart_ffi.Int32() int arg0) {
   final res = rid_ffi.rid_export_Model_find_todo(this, arg0);
+  _ridThrowIfPanicked('rid_export_Model_find_todo');
   final ret = res.address == 0x0 ? null : res;
   return ret;
 }
@@ -137,8 +140,9 @@ dart_ffi.Pointer<ffigen_bind.RawTodo>? find_todo(@dart_ffi.Int32() int arg0) {
             "Model",
         );
         let expected = r###"
-dart_ffi.Pointer<ffigen_bind.u32>? convert(@dart_ffi.Int32() int arg0) {
+dart_ffi.Pointer<dart_ffi.Uint32>? convert(@dart_ffi.Int32() int arg0) {
   final res = rid_ffi.rid_export_Model_convert(arg0);
+  _ridThrowIfPanicked('rid_export_Model_convert');
   final ret = res.address == 0x0 ? null : res;
   return ret;
 }
@@ -169,6 +173,7 @@ mod impl_method_returning_vec {
         let expected = r###"
 ffigen_bind.RidVec_u8 get_u8s() {
   final res = rid_ffi.rid_export_Model_get_u8s(this);
+  _ridThrowIfPanicked('rid_export_Model_get_u8s');
   final ret = res;
   return ret;
 }
@@ -177,3 +182,139 @@ ffigen_bind.RidVec_u8 get_u8s() {
         assert_eq!(res, expected)
     }
 }
+
+// -----------------
+// Result returns
+// -----------------
+mod impl_method_returning_result {
+    use super::*;
+
+    #[test]
+    fn u8_arg_return_result_u32_string() {
+        let res = render_impl(
+            quote! {
+                fn checked_half(&self, n: u8) -> Result<u32, String> { }
+            },
+            "Model",
+        );
+        let expected = r###"
+int checked_half(@dart_ffi.Int32() int arg0) {
+  final res = rid_ffi.rid_export_Model_checked_half(this, arg0);
+  _ridThrowIfPanicked('rid_export_Model_checked_half');
+  final errMsg = rid_ffi.rid_export_error_message();
+  if (errMsg.address != 0) {
+    final exception = RidExportException(errMsg.toDartString());
+    rid_ffi.rid_export_error_clear();
+    throw exception;
+  }
+  final ret = res;
+  return ret;
+}
+"###
+        .trim();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn no_args_return_result_todo_ref_custom_enum() {
+        let res = render_impl(
+            quote! {
+                #[rid::structs(Todo)]
+                #[rid::enums(TodoError)]
+                fn first_todo(&self) -> Result<&Todo, TodoError> { }
+            },
+            "Model",
+        );
+        let expected = r###"
+dart_ffi.Pointer<ffigen_bind.RawTodo> first_todo() {
+  final res = rid_ffi.rid_export_Model_first_todo(this);
+  _ridThrowIfPanicked('rid_export_Model_first_todo');
+  final errMsg = rid_ffi.rid_export_error_message();
+  if (errMsg.address != 0) {
+    final exception = TodoErrorException(errMsg.toDartString(), TodoError.values[rid_ffi.rid_export_error_todoerror()]);
+    rid_ffi.rid_export_error_clear();
+    throw exception;
+  }
+  final ret = res;
+  return ret;
+}
+"###
+        .trim();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn u32_arg_return_result_u32_data_enum() {
+        let res = render_impl(
+            quote! {
+                #[rid::structs(TransferError)]
+                fn preview_transfer(&self, amount: u32) -> Result<u32, TransferError> { }
+            },
+            "Model",
+        );
+        let expected = r###"
+int preview_transfer(@dart_ffi.Int32() int arg0) {
+  final res = rid_ffi.rid_export_Model_preview_transfer(this, arg0);
+  _ridThrowIfPanicked('rid_export_Model_preview_transfer');
+  final errMsg = rid_ffi.rid_export_error_message();
+  if (errMsg.address != 0) {
+    final exception = TransferErrorException(errMsg.toDartString(), rid_ffi.rid_export_error_transfererror().toDart());
+    rid_ffi.rid_export_error_clear();
+    throw exception;
+  }
+  final ret = res;
+  return ret;
+}
+"###
+        .trim();
+        assert_eq!(res, expected);
+    }
+}
+
+// -----------------
+// HashMap returns
+// -----------------
+mod impl_method_returning_hash_map {
+    use super::*;
+
+    #[test]
+    fn no_args_return_hash_map_string_u32() {
+        let res = render_impl(
+            quote! {
+                fn counts(&self) -> HashMap<String, u32> { }
+            },
+            "Model",
+        );
+        let expected = r###"
+dart_ffi.Pointer<ffigen_bind.HashMap_String__u32> counts() {
+  final res = rid_ffi.rid_export_Model_counts(this);
+  _ridThrowIfPanicked('rid_export_Model_counts');
+  final ret = res;
+  return ret;
+}
+"###
+        .trim();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn no_args_return_hash_map_ref_u8_todo() {
+        let res = render_impl(
+            quote! {
+                #[rid::structs(Todo)]
+                fn todos(&self) -> &HashMap<u8, Todo> { }
+            },
+            "Model",
+        );
+        let expected = r###"
+dart_ffi.Pointer<ffigen_bind.HashMap_u8__Todo> todos() {
+  final res = rid_ffi.rid_export_Model_todos(this);
+  _ridThrowIfPanicked('rid_export_Model_todos');
+  final ret = res;
+  return ret;
+}
+"###
+        .trim();
+        assert_eq!(res, expected);
+    }
+}
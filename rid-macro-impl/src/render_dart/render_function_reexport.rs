@@ -69,8 +69,15 @@ impl ParsedFunction {
             passed_args = passed_args,
         );
 
-        let value_to_dart = DartType::from(&return_arg, self.type_infos())
-            .render_to_dart_for_snippet(&get_value_snip);
+        // Owned maps and sets are freed right after they were converted
+        let value_to_dart = if (return_arg.is_map() || return_arg.is_set())
+            && return_arg.reference.is_owned()
+        {
+            format!("{}.toDartAndDispose()", get_value_snip)
+        } else {
+            DartType::from(return_arg, self.type_infos())
+                .render_to_dart_for_snippet(&get_value_snip)
+        };
 
         // NOTE: that we depend on the Store `_read` instance method here, if we need this to work
         // on other #[rid::model] instance we need to use `Store.instance.runLocked(...)` directly
@@ -7,7 +7,7 @@ use crate::{
     attrs::{Category, TypeInfoMap},
     common::{abort, state::get_state},
     parse::{
-        rust_type::{Composite, RustType, TypeKind, Value},
+        rust_type::{Composite, Primitive, RustType, TypeKind, Value},
         ParsedReference,
     },
     render_common::PointerTypeAlias,
@@ -38,7 +38,27 @@ impl RustType {
             // -----------------
             // Composites Vec
             // -----------------
-            K::Composite(Composite::Vec, inner_type, _) => match inner_type {
+            // Same pointer type as for Vec fields in order to share the accessors.
+            K::Composite(Composite::Vec | Composite::VecDeque, _, _)
+                if !self.reference.is_owned() =>
+            {
+                self.render_dart_field_return_type()
+            }
+            // Owned VecDeques are converted to a Vec before they are passed to Dart.
+            K::Composite(Composite::Vec | Composite::VecDeque, Some(ty), _)
+                if ty.is_composite() =>
+            {
+                format!(
+                    "{ffigen_bind}.RidVec_{ty}",
+                    ffigen_bind = FFI_GEN_BIND,
+                    ty = ty.ffi_type_name()
+                )
+            }
+            K::Composite(
+                Composite::Vec | Composite::VecDeque,
+                inner_type,
+                _,
+            ) => match inner_type {
                 Some(ty) => {
                     let item_type = if ty.is_enum() {
                         "i32".to_string()
@@ -66,40 +86,28 @@ impl RustType {
                 }
             },
             // -----------------
-            // Composites HashMap
+            // Composites HashMap, BTreeMap, HashSet, BTreeSet
             // -----------------
-            K::Composite(Composite::HashMap, key_type, val_type) => {
-                // TODO(thlorenz): HashMap
-                abort!(
-                        self.rust_ident(),
-                        "TODO: RustType::render_dart_pointer_type K::Composite::HashMap<{:?}, {:?}>",
-                        key_type, val_type
-                    )
-            }
+            // Same pointer type as for fields of those types in order to share the accessors.
+            K::Composite(
+                Composite::HashMap
+                | Composite::BTreeMap
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                _,
+                _,
+            ) => self.render_dart_field_return_type(),
             // -----------------
             // Composites Option
             // -----------------
-            K::Composite(Composite::Option, inner_type, _) => {
-                match inner_type {
-                    Some(ty) => {
-                        let pointer = format!(
-                            "{dart_ffi}.Pointer<{ffigen_bind}.{ty}>?",
-                            dart_ffi = DART_FFI,
-                            ffigen_bind = FFI_GEN_BIND,
-                            ty = inner_type
-                                .as_ref()
-                                .unwrap()
-                                .dart_wrapper_rust_string(),
-                        );
-                        pointer
-                    }
-                    None => {
-                        abort!(
-                            self.rust_ident(),
-                            "Rust Option composite should include inner type"
-                        )
-                    }
-                }
+            K::Composite(Composite::Option, Some(_), _) => {
+                self.render_dart_option_type()
+            }
+            K::Composite(Composite::Option, None, _) => {
+                abort!(
+                    self.rust_ident(),
+                    "Rust Option composite should include inner type"
+                )
             }
             K::Composite(kind, _, _) => {
                 abort!(
@@ -124,6 +132,85 @@ impl RustType {
     }
 }
 
+impl RustType {
+    /// Renders the Dart type that an `Option` nested inside a collection or a field is resolved
+    /// to, see [RustType::render_option_to_return].
+    /// Strings are converted right away while all other types are passed as a nullable pointer
+    /// to the wrapped value, i.e. `dart_ffi.Pointer<dart_ffi.Uint32>?` for `Option<u32>`.
+    pub fn render_dart_option_type(&self) -> String {
+        match self.flattened_option_type() {
+            Some(ty) if ty.is_string_like() => "String?".to_string(),
+            Some(ty) => format!("{}?", ty.render_dart_item_pointer_type()),
+            None => abort!(
+                self.rust_ident(),
+                "render_dart_option_type expects an Option with inner type"
+            ),
+        }
+    }
+
+    /// Renders the Dart type of a pointer to an instance of this type, i.e.
+    /// `dart_ffi.Pointer<dart_ffi.Uint32>` for `u32` or `dart_ffi.Pointer<ffigen_bind.Vec_u8>`
+    /// for `Vec<u8>`.
+    pub fn render_dart_item_pointer_type(&self) -> String {
+        use TypeKind as K;
+        match &self.kind {
+            K::Primitive(prim) => format!(
+                "{dart_ffi}.Pointer<{dart_ffi}.{native_type}>",
+                dart_ffi = DART_FFI,
+                native_type = prim.render_dart_native_type()
+            ),
+            K::Value(Value::CString | Value::String | Value::Str) => format!(
+                "{dart_ffi}.Pointer<{dart_ffi}.Int8>",
+                dart_ffi = DART_FFI
+            ),
+            K::Value(val) => val.render_dart_pointer_type(&format_ident!(
+                "{}",
+                self.dart_wrapper_rust_string()
+            )),
+            K::Composite(
+                Composite::Vec
+                | Composite::VecDeque
+                | Composite::HashMap
+                | Composite::BTreeMap
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                _,
+                _,
+            ) => format!(
+                "{dart_ffi}.Pointer<{ffigen_bind}.{ty}>",
+                dart_ffi = DART_FFI,
+                ffigen_bind = FFI_GEN_BIND,
+                ty = self.ffi_type_name()
+            ),
+            _ => abort!(
+                self.rust_ident(),
+                "Cannot render Dart pointer type for this type"
+            ),
+        }
+    }
+}
+
+impl Primitive {
+    /// Native type used by `dart:ffi` to represent this primitive, i.e. `Uint32` for `u32`.
+    fn render_dart_native_type(&self) -> &'static str {
+        use Primitive::*;
+        match self {
+            U8 => "Uint8",
+            I8 => "Int8",
+            U16 => "Uint16",
+            I16 => "Int16",
+            U32 => "Uint32",
+            I32 => "Int32",
+            U64 => "Uint64",
+            I64 => "Int64",
+            USize => "Uint64",
+            F32 => "Float",
+            F64 => "Double",
+            Bool => "Uint8",
+        }
+    }
+}
+
 impl Value {
     fn render_dart_pointer_type(&self, ident: &Ident) -> String {
         use Category as C;
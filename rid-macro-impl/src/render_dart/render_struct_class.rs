@@ -5,6 +5,11 @@ use crate::{
     attrs::TypeInfoMap,
     common::prefixes::store_state_class_ident,
     parse::{ParsedStruct, ParsedStructField},
+    render_dart::{
+        dart_wire_decode_fn_name, dart_wire_encode_fn_name, prefix_dart_lines,
+        render_dart_equals_operator, render_dart_hash_code,
+        render_dart_to_string_override, DartClassField,
+    },
 };
 
 pub struct ParsedStructRenderConfig {
@@ -20,6 +25,9 @@ impl ParsedStruct {
         &self,
         config: &ParsedStructRenderConfig,
     ) -> String {
+        if self.config.transparent {
+            return self.render_transparent_struct_pointer_extension(config);
+        }
         let class_name = if config.is_store {
             store_state_class_ident(&self.ident).to_string()
         } else {
@@ -38,6 +46,23 @@ impl ParsedStruct {
             self.render_dart_class(config, &constructor_fields, &class_name);
         if config.dart_class_only {
             dart_class
+        } else if !config.is_store && self.can_encode_model() {
+            format!(
+                r###"{comment}
+{comment} // Dart class representation of {ident}.
+{dart_class}
+{comment}
+{comment} // Extension method `toDart` to instantiate a Dart {ident} which Rust encodes in one FFI call
+{comment} extension Rid_ToDart_ExtOn{ident} on {raw_class_name} {{
+{comment}   {class_name} toDart() => this.toDartEncoded();
+{comment} }}{wire_encode_fn}"###,
+                ident = self.ident,
+                dart_class = dart_class,
+                class_name = class_name,
+                raw_class_name = raw_class_name,
+                wire_encode_fn = self.render_dart_wire_fns(config),
+                comment = config.comment
+            )
         } else {
             format!(
                 r###"{comment}
@@ -52,17 +77,199 @@ impl ParsedStruct {
 {comment}      ridStoreUnlock();
 {comment}      return instance;
 {comment}   }}
-{comment} }}"###,
+{comment} }}{wire_encode_fn}"###,
                 ident = self.ident,
                 dart_class = dart_class,
                 class_name = class_name,
                 raw_class_name = raw_class_name,
                 constructor_args = constructor_args,
+                wire_encode_fn = self.render_dart_wire_fns(config),
                 comment = config.comment
             )
         }
     }
 
+    /// Renders the Dart functions which encode/decode this struct into/from the rid wire format.
+    fn render_dart_wire_fns(
+        &self,
+        config: &ParsedStructRenderConfig,
+    ) -> String {
+        format!(
+            "{}{}",
+            self.render_dart_wire_encode_fn(config),
+            self.render_dart_wire_decode_fn(config)
+        )
+    }
+
+    /// Renders a Dart function which encodes an instance of this struct into the rid wire format
+    /// so that it can be passed to Rust as part of a message.
+    /// Nothing is rendered for the store or structs that cannot be encoded.
+    ///
+    /// Example:
+    ///
+    /// ```dart
+    /// void _ridEncodePoint(RidWireWriter w, Point value) {
+    ///   w.writeI32(value.x);
+    ///   w.writeI32(value.y);
+    /// }
+    /// ```
+    fn render_dart_wire_encode_fn(
+        &self,
+        config: &ParsedStructRenderConfig,
+    ) -> String {
+        if config.is_store || !self.is_wire_encodable() {
+            return "".to_string();
+        }
+        let field_prefix = format!("{}   ", config.comment);
+        let encode_fields = if self.config.transparent {
+            // Transparent structs are represented by the Dart type of their only field
+            let field = &self.fields[0];
+            prefix_dart_lines(
+                &field.rust_type.render_dart_wire_encode("value", 0),
+                &field_prefix,
+            )
+        } else {
+            self.fields
+                .iter()
+                .map(|x| {
+                    let var = format!(
+                        "value.{}",
+                        x.ident.to_string().to_mixed_case()
+                    );
+                    prefix_dart_lines(
+                        &x.rust_type.render_dart_wire_encode(&var, 0),
+                        &field_prefix,
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+        format!(
+            r###"
+{comment}
+{comment} // Encodes {ident} into the rid wire format when it is passed to Rust as part of a message
+{comment} void {fn_name}(RidWireWriter w, {ident} value) {{
+{encode_fields}
+{comment} }}"###,
+            ident = self.ident,
+            fn_name = dart_wire_encode_fn_name(&self.ident.to_string()),
+            encode_fields = encode_fields,
+            comment = config.comment
+        )
+    }
+
+    /// Renders a Dart function which decodes an instance of this struct from the rid wire format
+    /// when it is posted from Rust as part of a reply or store state.
+    /// Nothing is rendered for the store or structs whose included fields cannot be encoded.
+    ///
+    /// Example:
+    ///
+    /// ```dart
+    /// Point _ridDecodePoint(RidWireReader r) {
+    ///   return Point(
+    ///     r.readI32(),
+    ///     r.readI32(),
+    ///   );
+    /// }
+    /// ```
+    fn render_dart_wire_decode_fn(
+        &self,
+        config: &ParsedStructRenderConfig,
+    ) -> String {
+        if config.is_store || !self.has_wire_encodable_fields() {
+            return "".to_string();
+        }
+        let instance = if self.config.transparent {
+            // Transparent structs are represented by the Dart type of their only field
+            self.fields[0].rust_type.render_dart_wire_decode()
+        } else {
+            let decode_fields = self
+                .fields
+                .iter()
+                .map(|x| {
+                    format!(
+                        "{comment}     {decode},\n",
+                        decode = x.rust_type.render_dart_wire_decode(),
+                        comment = config.comment
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("");
+            format!(
+                "{ident}(\n{decode_fields}{comment}   )",
+                ident = self.ident,
+                decode_fields = decode_fields,
+                comment = config.comment
+            )
+        };
+        format!(
+            r###"
+{comment}
+{comment} // Decodes {ident} from the rid wire format when it is posted from Rust as part of a reply
+{comment} {ident} {fn_name}(RidWireReader r) {{
+{comment}   return {instance};
+{comment} }}"###,
+            ident = self.ident,
+            fn_name = dart_wire_decode_fn_name(&self.ident.to_string()),
+            instance = instance,
+            comment = config.comment
+        )
+    }
+
+    /// Renders a Dart type alias for a transparent struct which resolves to the Dart type of its
+    /// only field, together with a `toDart` extension which returns that field's value.
+    ///
+    /// Example:
+    ///
+    /// ```dart
+    /// typedef TodoId = int;
+    ///
+    /// extension Rid_ToDart_ExtOnTodoId on dart_ffi.Pointer<ffigen_bind.RawTodoId> {
+    ///   TodoId toDart() {
+    ///      ridStoreLock();
+    ///      final instance = this.field0;
+    ///      ridStoreUnlock();
+    ///      return instance;
+    ///   }
+    /// }
+    /// ```
+    fn render_transparent_struct_pointer_extension(
+        &self,
+        config: &ParsedStructRenderConfig,
+    ) -> String {
+        let field = &self.fields[0];
+        let (inner_ty, _) = field
+            .rust_type
+            .render_dart_and_ffi_type(self.type_infos(), false);
+        let raw_class_name = format!(
+            "{dart_ffi}.Pointer<{ffigen_bind}.{RawIdent}>",
+            dart_ffi = DART_FFI,
+            ffigen_bind = FFI_GEN_BIND,
+            RawIdent = self.raw_ident
+        );
+        format!(
+            r###"{comment}
+{comment} // Dart representation of {ident} which is transparent and thus resolves to its inner type.
+{comment} typedef {ident} = {inner_ty};
+{comment}
+{comment} // Extension method `toDart` to resolve the inner value of {ident} from Rust
+{comment} extension Rid_ToDart_ExtOn{ident} on {raw_class_name} {{
+{comment}   {ident} toDart() {{
+{comment}      ridStoreLock();
+{comment}      final instance = {constructor_arg};
+{comment}      ridStoreUnlock();
+{comment}      return instance;
+{comment}   }}
+{comment} }}{wire_encode_fn}"###,
+            ident = self.ident,
+            wire_encode_fn = self.render_dart_wire_fns(config),
+            inner_ty = inner_ty,
+            raw_class_name = raw_class_name,
+            constructor_arg = field.render_constructor_arg(self.type_infos()),
+            comment = config.comment
+        )
+    }
+
     // -----------------
     // Args to Class constructor
     // -----------------
@@ -92,7 +299,7 @@ impl ParsedStruct {
     // -----------------
     /// Renders a Dart class for a specific Rust struct that can be instantiated by passing a pointer
     /// to a Rust struct instance.
-    /// It includes a private constructor and overrides for equality and toString.
+    /// It includes constructors and overrides for equality and toString.
     fn render_dart_class(
         &self,
         config: &ParsedStructRenderConfig,
@@ -100,11 +307,8 @@ impl ParsedStruct {
         class_name: &str,
     ) -> String {
         let field_declarations = self.render_field_declarations(config);
-        let constructor = self.render_private_constructor(
-            &config,
-            constructor_fields,
-            class_name,
-        );
+        let constructor =
+            self.render_constructors(config, constructor_fields, class_name);
         let equality_overrides =
             self.render_equality_overrides(config, class_name);
         let to_string_override =
@@ -163,19 +367,32 @@ impl ParsedStruct {
     // -----------------
     // Class Constructor
     // -----------------
-    fn render_private_constructor(
+    /// Renders the private constructor used by `toDart` and, except for the store, a public one
+    /// which allows creating instances in Dart, i.e. to pass them to Rust as part of a message.
+    fn render_constructors(
         &self,
         config: &ParsedStructRenderConfig,
         constructor_fields: &str,
         class_name: &str,
     ) -> String {
+        let public_constructor = if config.is_store {
+            "".to_string()
+        } else {
+            format!(
+                "{comment}   const {class_name}({constructor_fields});\n",
+                class_name = class_name,
+                comment = config.comment,
+                constructor_fields = constructor_fields,
+            )
+        };
         format!(
             r###"{comment}
 {comment}   const {class_name}._({constructor_fields});
-"###,
+{public_constructor}"###,
             class_name = class_name,
             comment = config.comment,
             constructor_fields = constructor_fields,
+            public_constructor = public_constructor,
         )
     }
 
@@ -201,182 +418,52 @@ impl ParsedStruct {
     }
 
     // -----------------
-    // Class Equality overrides
+    // Class Equality and toString overrides
     // -----------------
+    fn dart_class_fields(&self) -> Vec<DartClassField> {
+        self.fields
+            .iter()
+            .map(|x| DartClassField {
+                name: x.ident.to_string().to_mixed_case(),
+                is_collection: x.rust_type.is_dart_collection(),
+            })
+            .collect()
+    }
+
     fn render_equality_overrides(
         &self,
         config: &ParsedStructRenderConfig,
         class_name: &str,
     ) -> String {
         if config.include_equality {
+            let fields = self.dart_class_fields();
             format!(
                 "{}\n{}",
-                self.render_equals_operator(config, class_name),
-                self.render_hash_code(config)
+                render_dart_equals_operator(
+                    class_name,
+                    &fields,
+                    &config.comment
+                ),
+                render_dart_hash_code(&fields, &config.comment)
             )
         } else {
             "".to_string()
         }
     }
 
-    /// Renders hashCode override for the provided class.
-    ///
-    /// Example:
-    ///
-    /// ```dart
-    /// @override
-    /// int get hashCode {
-    ///   return
-    ///     id.hashCode ^
-    ///     title.hashCode ^
-    ///     completed.hashCode;
-    /// }
-    /// ```
-    fn render_hash_code(&self, config: &ParsedStructRenderConfig) -> String {
-        if self.fields.is_empty() {
-            "".to_string()
-        } else {
-            let field_xors = self
-                .fields
-                .iter()
-                .map(|x| {
-                    format!(
-                        "{comment}      {field}.hashCode",
-                        field = x.ident.to_string().to_mixed_case(),
-                        comment = config.comment
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(" ^\n");
-
-            format!(
-                r###"{comment}  @override
-{comment}  int get hashCode {{
-{comment}    return
-{field_xors};
-{comment}  }}"###,
-                field_xors = field_xors,
-                comment = config.comment,
-            )
-        }
-    }
-
-    /// Renders equals override for the provided class.
-    ///
-    /// Example:
-    ///
-    /// ```dart
-    /// @override
-    /// bool operator ==(Object other) {
-    ///   return identical(this, other) ||
-    ///     other is Todo &&
-    ///         runtimeType == other.runtimeType &&
-    ///         id == other.id &&
-    ///         title == other.title &&
-    ///         completed == other.completed;
-    /// }
-    /// ```
-    fn render_equals_operator(
-        &self,
-        config: &ParsedStructRenderConfig,
-        class_name: &str,
-    ) -> String {
-        if self.fields.is_empty() {
-            "".to_string()
-        } else {
-            let field_comparisons = self
-                .fields
-                .iter()
-                .map(|x| {
-                    format!(
-                        "{comment}          {field} == other.{field}",
-                        field = x.ident.to_string().to_mixed_case(),
-                        comment = config.comment
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(" &&\n");
-
-            format!(
-                r###"{comment}  @override
-{comment}  bool operator ==(Object other) {{
-{comment}    return identical(this, other) ||
-{comment}      other is {class_name} &&
-{field_comparisons};
-{comment}  }}"###,
-                field_comparisons = field_comparisons,
-                class_name = class_name,
-                comment = config.comment,
-            )
-        }
-    }
-
-    // -----------------
-    // Class toString override
-    // -----------------
-
-    /// Renders toString() override for Dart instance
-    ///
-    /// Example:
-    /// ```dart
-    /// @override
-    /// String toString() {
-    ///   return 'Todo{id: $id, title: $title, completed: $completed}';
-    /// }
-    /// ```
     fn render_to_string_override(
         &self,
         config: &ParsedStructRenderConfig,
         class_name: &str,
     ) -> String {
-        if !config.include_to_string || self.fields.is_empty() {
-            "".to_string()
-        } else {
-            let multi_line = self.fields.len() > 6;
-            let field_separator = if multi_line {
-                format!("\n{comment}   ", comment = config.comment)
-            } else {
-                ", ".to_string()
-            };
-            let quote = if multi_line { "'''" } else { "'" };
-            let fields = self
-                .fields
-                .iter()
-                .map(|x| {
-                    format!(
-                        "{field}: ${field}",
-                        field = x.ident.to_string().to_mixed_case()
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(&field_separator);
-
-            let pre_fields = if multi_line {
-                format!(" {{\n{comment}   ", comment = config.comment)
-            } else {
-                "{".to_string()
-            };
-            let post_fields = if multi_line {
-                format!(
-                    "\n{comment}{closing_brace}",
-                    comment = config.comment,
-                    closing_brace = "}}"
-                )
-            } else {
-                "}".to_string()
-            };
-            format!(
-                r###"{comment}  @override
-    {comment}  String toString() {{
-    {comment}    return {quote}{class_name}{pre_fields}{fields}{post_fields}{quote};
-    {comment}  }}"###,
-                class_name = class_name,
-                pre_fields = pre_fields,
-                post_fields = post_fields,
-                fields = fields,
-                quote = quote,
-                comment = config.comment,
+        if config.include_to_string {
+            render_dart_to_string_override(
+                class_name,
+                &self.dart_class_fields(),
+                &config.comment,
             )
+        } else {
+            "".to_string()
         }
     }
 }
@@ -36,11 +36,30 @@ impl RustType {
                 ret_ident = ret_ident,
                 res_ident = res_ident
             ),
-            K::Composite(Composite::Vec, inner_type, _) => format!(
+            K::Composite(
+                Composite::Vec | Composite::VecDeque,
+                inner_type,
+                _,
+            ) => format!(
                 "final {ret_ident} = {res_ident};",
                 ret_ident = ret_ident,
                 res_ident = res_ident
             ),
+            // Optional strings are converted and freed right away like strings
+            K::Composite(Composite::Option, Some(_), _)
+                if self
+                    .flattened_option_type()
+                    .is_some_and(|x| x.is_string_like()) =>
+            {
+                format!(
+                    r###"
+{comment}     final {ret_ident} = {res_ident}.address == 0x0 ? null : {res_ident}.toDartString();
+{comment}     if ({res_ident}.address != 0x0) {res_ident}.free();"###,
+                    ret_ident = ret_ident,
+                    res_ident = res_ident,
+                    comment = comment
+                )
+            }
             K::Composite(Composite::Option, inner_type, _) => {
                 match inner_type {
                     Some(ty) => {
@@ -58,14 +77,18 @@ impl RustType {
                     }
                 }
             }
-            K::Composite(Composite::HashMap, key_type, val_type) => {
-                // TODO(thlorenz): HashMap
-                abort!(
-                    self.rust_ident(),
-                    "TODO: RustType::render_dart_to_return_type K::Composite::HashMap<{:?}, {:?}>",
-                    key_type, val_type
-                )
-            }
+            K::Composite(
+                Composite::HashMap
+                | Composite::BTreeMap
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                _,
+                _,
+            ) => format!(
+                "final {ret_ident} = {res_ident};",
+                ret_ident = ret_ident,
+                res_ident = res_ident
+            ),
             K::Composite(kind, _, _) => {
                 abort!(
                     self.rust_ident(),
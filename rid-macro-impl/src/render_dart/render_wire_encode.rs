@@ -0,0 +1,316 @@
+use rid_common::DART_COLLECTION;
+
+use crate::{
+    attrs::Category,
+    common::abort,
+    parse::rust_type::{Composite, Primitive, RustType, TypeKind, Value},
+};
+
+/// Name of the Dart function which encodes a struct or data enum into the rid wire format, i.e.
+/// `_ridEncodePoint`.
+pub fn dart_wire_encode_fn_name(type_name: &str) -> String {
+    format!("_ridEncode{}", type_name)
+}
+
+/// Name of the Dart function which decodes a struct or data enum from the rid wire format, i.e.
+/// `_ridDecodePoint`.
+pub fn dart_wire_decode_fn_name(type_name: &str) -> String {
+    format!("_ridDecode{}", type_name)
+}
+
+/// Prefixes each line of the provided Dart code, i.e. with the comment the Dart code is wrapped
+/// in and its indentation.
+pub fn prefix_dart_lines(code: &str, prefix: &str) -> String {
+    code.lines()
+        .map(|line| format!("{}{}", prefix, line))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+impl RustType {
+    /// Returns `true` if values of this type can be encoded into the rid wire format, i.e. it
+    /// consists only of primitives, strings, custom types and collections thereof.
+    pub fn is_wire_encodable(&self) -> bool {
+        match &self.kind {
+            TypeKind::Primitive(_) | TypeKind::Value(Value::String) => true,
+            TypeKind::Value(Value::Custom(info, _)) => {
+                info.cat != Category::Prim
+            }
+            TypeKind::Composite(
+                Composite::Option
+                | Composite::Vec
+                | Composite::VecDeque
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                Some(inner),
+                _,
+            ) => inner.is_wire_encodable(),
+            TypeKind::Composite(
+                Composite::HashMap | Composite::BTreeMap,
+                Some(key),
+                Some(val),
+            ) => key.is_wire_encodable() && val.is_wire_encodable(),
+            _ => false,
+        }
+    }
+
+    /// Renders Dart statements which encode the value of `var` into the rid wire format via the
+    /// [RidWireWriter] `w`.
+    /// This is the counterpart of the `rid::_RidDecode` implementation of this type in Rust.
+    ///
+    /// `depth` ensures that variables introduced for nested types don't shadow each other.
+    ///
+    /// Example for `Vec<Option<Point>>`:
+    ///
+    /// ```dart
+    /// w.writeLen(points.length);
+    /// for (final item0 in points) {
+    ///   {
+    ///     final opt1 = item0;
+    ///     if (opt1 == null) {
+    ///       w.writeBool(false);
+    ///     } else {
+    ///       w.writeBool(true);
+    ///       _ridEncodePoint(w, opt1);
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    pub fn render_dart_wire_encode(&self, var: &str, depth: usize) -> String {
+        match &self.kind {
+            // -----------------
+            // Primitives
+            // -----------------
+            TypeKind::Primitive(prim) => {
+                let method = match prim {
+                    Primitive::U8 => "writeU8",
+                    Primitive::I8 => "writeI8",
+                    Primitive::U16 => "writeU16",
+                    Primitive::I16 => "writeI16",
+                    Primitive::U32 => "writeU32",
+                    Primitive::I32 => "writeI32",
+                    Primitive::U64 | Primitive::USize => "writeU64",
+                    Primitive::I64 => "writeI64",
+                    Primitive::F32 => "writeF32",
+                    Primitive::F64 => "writeF64",
+                    Primitive::Bool => "writeBool",
+                };
+                format!("w.{}({});", method, var)
+            }
+            // -----------------
+            // Strings
+            // -----------------
+            TypeKind::Value(Value::String) => {
+                format!("w.writeString({});", var)
+            }
+            // -----------------
+            // Custom Types
+            // -----------------
+            TypeKind::Value(Value::Custom(info, type_name)) => match info.cat {
+                Category::Enum => format!("w.writeI32({}.index);", var),
+                Category::Struct => format!(
+                    "{}(w, {});",
+                    dart_wire_encode_fn_name(type_name),
+                    var
+                ),
+                Category::Prim => self.abort_unsupported_wire_type(),
+            },
+            // -----------------
+            // Option
+            // -----------------
+            TypeKind::Composite(Composite::Option, Some(inner), _) => {
+                // Assigning to a local first allows Dart to promote it to non-nullable.
+                // The block scopes the local as multiple options may be encoded side by side.
+                let opt = format!("opt{}", depth);
+                format!(
+                    r###"{{
+  final {opt} = {var};
+  if ({opt} == null) {{
+    w.writeBool(false);
+  }} else {{
+    w.writeBool(true);
+{inner}
+  }}
+}}"###,
+                    opt = opt,
+                    var = var,
+                    inner = prefix_dart_lines(
+                        &inner.render_dart_wire_encode(&opt, depth + 1),
+                        "    "
+                    )
+                )
+            }
+            // -----------------
+            // Collections
+            // -----------------
+            TypeKind::Composite(
+                Composite::Vec
+                | Composite::VecDeque
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                Some(inner),
+                _,
+            ) => {
+                let item = format!("item{}", depth);
+                format!(
+                    r###"w.writeLen({var}.length);
+for (final {item} in {var}) {{
+{inner}
+}}"###,
+                    var = var,
+                    item = item,
+                    inner = prefix_dart_lines(
+                        &inner.render_dart_wire_encode(&item, depth + 1),
+                        "  "
+                    )
+                )
+            }
+            TypeKind::Composite(
+                Composite::HashMap | Composite::BTreeMap,
+                Some(key),
+                Some(val),
+            ) => {
+                let entry = format!("entry{}", depth);
+                format!(
+                    r###"w.writeLen({var}.length);
+for (final {entry} in {var}.entries) {{
+{key}
+{val}
+}}"###,
+                    var = var,
+                    entry = entry,
+                    key = prefix_dart_lines(
+                        &key.render_dart_wire_encode(
+                            &format!("{}.key", entry),
+                            depth + 1
+                        ),
+                        "  "
+                    ),
+                    val = prefix_dart_lines(
+                        &val.render_dart_wire_encode(
+                            &format!("{}.value", entry),
+                            depth + 1
+                        ),
+                        "  "
+                    )
+                )
+            }
+            // -----------------
+            // Unsupported
+            // -----------------
+            TypeKind::Value(Value::CString | Value::Str)
+            | TypeKind::Composite(_, _, _)
+            | TypeKind::Unit
+            | TypeKind::Unknown => self.abort_unsupported_wire_type(),
+        }
+    }
+
+    /// Renders a Dart expression which decodes a value of this type from the rid wire format via
+    /// the [RidWireReader] `r`.
+    /// This is the counterpart of the `rid::_RidEncode` implementation of this type in Rust.
+    ///
+    /// Example for `Vec<Option<Point>>`:
+    ///
+    /// ```dart
+    /// List.generate(r.readLen(), (_) => r.readBool() ? _ridDecodePoint(r) : null)
+    /// ```
+    pub fn render_dart_wire_decode(&self) -> String {
+        match &self.kind {
+            // -----------------
+            // Primitives
+            // -----------------
+            TypeKind::Primitive(prim) => {
+                let method = match prim {
+                    Primitive::U8 => "readU8",
+                    Primitive::I8 => "readI8",
+                    Primitive::U16 => "readU16",
+                    Primitive::I16 => "readI16",
+                    Primitive::U32 => "readU32",
+                    Primitive::I32 => "readI32",
+                    Primitive::U64 | Primitive::USize => "readU64",
+                    Primitive::I64 => "readI64",
+                    Primitive::F32 => "readF32",
+                    Primitive::F64 => "readF64",
+                    Primitive::Bool => "readBool",
+                };
+                format!("r.{}()", method)
+            }
+            // -----------------
+            // Strings
+            // -----------------
+            TypeKind::Value(Value::String) => "r.readString()".to_string(),
+            // -----------------
+            // Custom Types
+            // -----------------
+            TypeKind::Value(Value::Custom(info, type_name)) => match info.cat {
+                Category::Enum => format!("{}.values[r.readI32()]", type_name),
+                Category::Struct => {
+                    format!("{}(r)", dart_wire_decode_fn_name(type_name))
+                }
+                Category::Prim => self.abort_unsupported_wire_type(),
+            },
+            // -----------------
+            // Option
+            // -----------------
+            TypeKind::Composite(Composite::Option, Some(inner), _) => format!(
+                "(r.readBool() ? {} : null)",
+                inner.render_dart_wire_decode()
+            ),
+            // -----------------
+            // Collections
+            // -----------------
+            TypeKind::Composite(
+                Composite::Vec | Composite::VecDeque,
+                Some(inner),
+                _,
+            ) => format!(
+                "List.generate(r.readLen(), (_) => {})",
+                inner.render_dart_wire_decode()
+            ),
+            TypeKind::Composite(
+                set @ (Composite::HashSet | Composite::BTreeSet),
+                Some(inner),
+                _,
+            ) => format!(
+                "{dart_collection}.{set}.of(List.generate(r.readLen(), (_) => {inner}))",
+                dart_collection = DART_COLLECTION,
+                set = if set == &Composite::HashSet {
+                    "HashSet"
+                } else {
+                    "LinkedHashSet"
+                },
+                inner = inner.render_dart_wire_decode()
+            ),
+            TypeKind::Composite(
+                map @ (Composite::HashMap | Composite::BTreeMap),
+                Some(key),
+                Some(val),
+            ) => format!(
+                "{dart_collection}.{map}.fromEntries(List.generate(r.readLen(), (_) => MapEntry({key}, {val})))",
+                dart_collection = DART_COLLECTION,
+                map = if map == &Composite::HashMap {
+                    "HashMap"
+                } else {
+                    "LinkedHashMap"
+                },
+                key = key.render_dart_wire_decode(),
+                val = val.render_dart_wire_decode()
+            ),
+            // -----------------
+            // Unsupported
+            // -----------------
+            TypeKind::Value(Value::CString | Value::Str)
+            | TypeKind::Composite(_, _, _)
+            | TypeKind::Unit
+            | TypeKind::Unknown => self.abort_unsupported_wire_type(),
+        }
+    }
+
+    fn abort_unsupported_wire_type(&self) -> String {
+        abort!(
+            self.rust_ident(),
+            "[rid] type {} cannot be passed between Dart and Rust as part of a message or reply",
+            self.rust_ident()
+        )
+    }
+}
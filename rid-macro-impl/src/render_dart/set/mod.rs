@@ -0,0 +1,79 @@
+use rid_common::{DART_COLLECTION, RID_FFI, THROW_IF_PANICKED};
+
+use crate::{
+    accesses::{map_to_dart_string, resolved_dart_item_type_string, SetAccess},
+    attrs::TypeInfoMap,
+};
+
+const TEMPLATE_FIELD_ACCESS: &str =
+    std::include_str!("./set_field_access.dart");
+const TEMPLATE_METHOD_RETURN: &str =
+    std::include_str!("./set_method_return.dart");
+
+impl SetAccess {
+    pub fn render_dart_for_field_reference(
+        &self,
+        type_infos: &TypeInfoMap,
+        comment: &str,
+    ) -> String {
+        let pointer_set_type = self.set_type.render_dart_field_return_type();
+        let item_ffi_arg = self
+            .item_type
+            .render_dart_resolved_ffi_var(type_infos, "item");
+        let resolved_dart_item_type =
+            resolved_dart_item_type_string(&self.item_type, type_infos);
+        let map_to_dart = map_to_dart_string(
+            &self.item_type,
+            &resolved_dart_item_type,
+            type_infos,
+        );
+
+        TEMPLATE_FIELD_ACCESS
+            .replace("/// ", comment)
+            .replace("{set_type}", &self.set_type.ffi_type_name())
+            .replace("{dart_set_type}", self.dart_set_type())
+            .replace("{pointer_set_type}", &pointer_set_type)
+            .replace("{item_ffi_arg}", &item_ffi_arg)
+            .replace("{resolved_dart_item_type}", &resolved_dart_item_type)
+            .replace("{map_to_dart}", &map_to_dart)
+            // fn idents
+            .replace("{fn_len_ident}", &self.fn_len_ident.to_string())
+            .replace("{fn_contains_ident}", &self.fn_contains_ident.to_string())
+            .replace("{fn_items_ident}", &self.fn_items_ident.to_string())
+            // namespaces
+            .replace("{rid_ffi}", RID_FFI)
+            .replace("{throw_if_panicked}", THROW_IF_PANICKED)
+            .replace("{dart_collection}", DART_COLLECTION)
+    }
+
+    /// Sets returned from a method share the accessors rendered for field references and
+    /// only add methods to free them.
+    pub fn render_dart_return_from_method(
+        &self,
+        type_infos: &TypeInfoMap,
+        comment: &str,
+    ) -> String {
+        let pointer_set_type = self.set_type.render_dart_field_return_type();
+        let resolved_dart_item_type =
+            resolved_dart_item_type_string(&self.item_type, type_infos);
+
+        TEMPLATE_METHOD_RETURN
+            .replace("/// ", comment)
+            .replace("{set_type}", &self.set_type.ffi_type_name())
+            .replace("{dart_set_type}", self.dart_set_type())
+            .replace("{pointer_set_type}", &pointer_set_type)
+            .replace("{resolved_dart_item_type}", &resolved_dart_item_type)
+            .replace("{fn_free_ident}", &self.fn_free_ident.to_string())
+            .replace("{rid_ffi}", RID_FFI)
+            .replace("{dart_collection}", DART_COLLECTION)
+    }
+
+    /// A `BTreeSet` converts to a `LinkedHashSet` in order to preserve the order of its items.
+    fn dart_set_type(&self) -> &'static str {
+        if self.set_type.is_ordered() {
+            "LinkedHashSet"
+        } else {
+            "HashSet"
+        }
+    }
+}
@@ -0,0 +1,29 @@
+/// extension Rid_Set_ExtOn{set_type} on {pointer_set_type} {
+///   int get length {
+///     final len = {rid_ffi}.rid_export_{fn_len_ident}(this);
+///     {throw_if_panicked}('rid_export_{fn_len_ident}');
+///     return len;
+///   }
+///
+///   bool contains({resolved_dart_item_type} item) {
+///     final res = {rid_ffi}.rid_export_{fn_contains_ident}(this, {item_ffi_arg});
+///     {throw_if_panicked}('rid_export_{fn_contains_ident}');
+///     return res != 0;
+///   }
+///
+///   /// Copies the items of this set into a Dart [List&lt;{resolved_dart_item_type}&gt;].
+///   /// Items of a BTreeSet are provided in order.
+///   List<{resolved_dart_item_type}> items() {
+///     ridStoreLock();
+///     final items = {rid_ffi}.rid_export_{fn_items_ident}(this);
+///     {throw_if_panicked}('rid_export_{fn_items_ident}');
+///     final list = items.iter(){map_to_dart}.toList();
+///     items.dispose();
+///     ridStoreUnlock();
+///     return list;
+///   }
+///
+///   {dart_collection}.{dart_set_type}<{resolved_dart_item_type}> toDart() {
+///     return new {dart_collection}.{dart_set_type}<{resolved_dart_item_type}>.of(this.items());
+///   }
+/// }
@@ -0,0 +1,12 @@
+/// extension Rid_RidSet_ExtOn{set_type} on {pointer_set_type} {
+///   {dart_collection}.{dart_set_type}<{resolved_dart_item_type}> toDartAndDispose() {
+///     final set = this.toDart();
+///     this.dispose();
+///     return set;
+///   }
+///
+///   // Only call this on sets returned from exported functions by value, never on set fields.
+///   void dispose() {
+///     {rid_ffi}.{fn_free_ident}(this);
+///   }
+/// }
@@ -1,12 +1,13 @@
 use heck::{CamelCase, SnakeCase};
 use rid_common::{
     DART_COLLECTION, DART_FFI, FFI_GEN_BIND, RID_FFI, STRING_REF_ACCESS,
+    THROW_IF_PANICKED,
 };
 
 use crate::{
     accesses::{
-        map_to_dart_string, resolved_dart_item_type_string, RenderableAccess,
-        VecAccess,
+        item_to_dart_string, map_to_dart_string,
+        resolved_dart_item_type_string, RenderableAccess, VecAccess,
     },
     attrs::TypeInfoMap,
     parse::{dart_type::DartType, rust_type::RustType},
@@ -30,28 +31,44 @@ impl VecAccess {
 
         let resolved_dart_item_type =
             resolved_dart_item_type_string(&self.item_type, type_infos);
-        let map_to_dart =
-            map_to_dart_string(&self.item_type, &resolved_dart_item_type);
+        let map_to_dart = map_to_dart_string(
+            &self.item_type,
+            &resolved_dart_item_type,
+            type_infos,
+        );
+        let item_to_dart = item_to_dart_string(
+            &self.item_type,
+            &resolved_dart_item_type,
+            type_infos,
+            "raw",
+        );
 
-        let item_to_dart = if self.item_type.is_string_like() {
-            ".toDartString()".to_string()
+        let get_item = if self.item_type.is_option() {
+            format!(
+                "return raw.address == 0x0 ? null : raw{};",
+                self.option_item_to_dart()
+            )
+        } else if self.item_type.is_string_like() {
+            "return raw.toDartString();".to_string()
         } else {
-            "".to_string()
+            "return raw;".to_string()
         };
 
         TEMPLATE_FIELD_ACCESS
             .replace("/// ", comment)
+            .replace("{get_item}", &get_item)
             .replace("{vec_type}", &vec_type.to_string())
             .replace("{pointer_vec_type}", &pointer_vec_type)
             .replace("{resolved_dart_item_type}", &resolved_dart_item_type)
             .replace("{iterated_item_type}", &iterated_item_type)
-            .replace("{item_to_dart}", &item_to_dart)
             .replace("{map_to_dart}", &map_to_dart)
+            .replace("{item_to_dart}", &item_to_dart)
             .replace("{fn_len_ident}", &self.fn_len_ident.to_string())
             .replace("{fn_get_ident}", &self.fn_get_ident.to_string())
             .replace("{ffigen_bind}", FFI_GEN_BIND)
             .replace("{dart_ffi}", DART_FFI)
             .replace("{rid_ffi}", RID_FFI)
+            .replace("{throw_if_panicked}", THROW_IF_PANICKED)
             .replace("{dart_collection}", DART_COLLECTION)
     }
 
@@ -62,17 +79,30 @@ impl VecAccess {
     ) -> String {
         let resolved_dart_item_type =
             resolved_dart_item_type_string(&self.item_type, type_infos);
-        let map_to_dart =
-            map_to_dart_string(&self.item_type, &resolved_dart_item_type);
+        let map_to_dart = map_to_dart_string(
+            &self.item_type,
+            &resolved_dart_item_type,
+            type_infos,
+        );
 
-        let access_item_return = if self.item_type.is_string_like()
+        let access_item_return = if self.item_type.is_option() {
+            format!(
+                "return raw.address == 0x0 ? null : raw{};",
+                self.option_item_to_dart()
+            )
+        } else if self.item_type.is_string_like()
             && !self.item_type.reference.is_owned()
         {
             "return raw.toDartString();".to_string()
         } else {
             "return raw;".to_string()
         };
-        let dart_raw_item_type = &self.item_type.render_dart_pointer_type();
+        // Composite items are passed as pointers into the RidVec
+        let dart_raw_item_type = if self.item_type.is_composite() {
+            self.item_type.render_dart_field_return_type()
+        } else {
+            self.item_type.render_dart_pointer_type()
+        };
         TEMPLATE
             .replace("///", comment)
             .replace("{vec_type}", &self.vec_type_dart)
@@ -86,10 +116,16 @@ impl VecAccess {
             .replace("{ffigen_bind}", FFI_GEN_BIND)
             .replace("{dart_ffi}", DART_FFI)
             .replace("{rid_ffi}", RID_FFI)
+            .replace("{throw_if_panicked}", THROW_IF_PANICKED)
             .replace("{dart_collection}", DART_COLLECTION)
     }
 
-    fn map_to_dart_string(&self, dart_item_type: &str) -> String {
-        map_to_dart_string(&self.item_type, dart_item_type)
+    /// Optional strings are converted right away while all other optional items are
+    /// passed on as pointers.
+    fn option_item_to_dart(&self) -> &'static str {
+        match self.item_type.flattened_option_type() {
+            Some(ty) if ty.is_string_like() => ".toDartString()",
+            _ => "",
+        }
     }
 }
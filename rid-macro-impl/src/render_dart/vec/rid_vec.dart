@@ -5,6 +5,7 @@
 ///       throw AssertionError("Out of range access on List<{dart_raw_item_type}>[$idx] of length $len");
 ///     }
 ///     final raw = {rid_ffi}.{fn_get_ident}(this, idx);
+///     {throw_if_panicked}('{fn_get_ident}');
 ///     {access_item_return}
 ///   }
 ///
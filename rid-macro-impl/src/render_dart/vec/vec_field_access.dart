@@ -1,11 +1,18 @@
 /// extension Rid_Vec_ExtOn{vec_type} on {pointer_vec_type} {
-///   int get length => {rid_ffi}.{fn_len_ident}(this);
-///   {iterated_item_type} operator [](int idx) {
-///     final len = this.length;
+///   int get length {
+///     final len = {rid_ffi}.{fn_len_ident}(this);
+///     {throw_if_panicked}('{fn_len_ident}');
+///     return len;
+///   }
+///   {iterated_item_type} operator [](int idx) => _itemAt(idx, this.length);
+///
+///   {iterated_item_type} _itemAt(int idx, int len) {
 ///     if (!(0 <= idx && idx < len)) {
 ///       throw AssertionError("Out of range access on List<{resolved_dart_item_type}>[$idx] of length $len");
 ///     }
-///     return {rid_ffi}.{fn_get_ident}(this, idx){item_to_dart};
+///     final raw = {rid_ffi}.{fn_get_ident}(this, idx);
+///     {throw_if_panicked}('{fn_get_ident}');
+///     {get_item}
 ///   }
 ///   Rid_{vec_type}_Iterable iter() => Rid_{vec_type}_Iterable(this);
 ///
@@ -16,6 +23,13 @@
 ///     ridStoreUnlock();
 ///     return list;
 ///   }
+///
+///   /// Converts the item at [idx] into Dart, i.e. to read items on demand via a [RidList]
+///   /// Pass the [length] if it was read while the store stayed locked to not read it again.
+///   {resolved_dart_item_type} toDartAt(int idx, {int? length}) {
+///     final raw = _itemAt(idx, length ?? this.length);
+///     return {item_to_dart};
+///   }
 /// }
 /// 
 /// class Rid_{vec_type}_Iterator implements Iterator<{iterated_item_type}> {
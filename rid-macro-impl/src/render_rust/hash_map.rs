@@ -3,39 +3,33 @@ use std::collections::HashMap;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 
+use syn::Ident;
+
 use crate::{
-    accesses::{HashMapAccess, RenderedAccessRust},
-    common::tokens::resolve_hash_map_ptr,
-    parse::{rust_type::RustType, ParsedReference},
+    accesses::{AccessKind, HashMapAccess, RenderedAccessRust},
+    common::abort,
+    parse::{
+        rust_type::{Composite, RustType, TypeKind},
+        ParsedReference,
+    },
     render_common::PointerTypeAlias,
+    render_rust::{render_free, RenderedFree},
 };
 
 impl HashMapAccess {
     pub fn render_rust_field_access(&self) -> RenderedAccessRust {
         let ffi_prelude = &self.rust_ffi_prelude;
+        if self.key_type.is_composite() {
+            abort!(
+                self.key_type.rust_ident(),
+                "HashMap keys cannot be collections or Options"
+            )
+        }
         let key_ty = self.key_type.rust_ident();
-        let val_ty = self.val_type.rust_ident();
-        let arg = format_ident!("hash_map_arg");
-
-        let resolve_hash_map = resolve_hash_map_ptr(&arg, &key_ty, &val_ty);
+        let map_ty = self.hash_map_type.render_owned_rust_type();
 
-        // -----------------
-        // Determine #[rid::structs] and #[rid::enums]
-        // -----------------
-        let structs_attr =
-            match (self.key_type.is_struct(), self.val_type.is_struct()) {
-                (true, true) => quote! { #[rid::structs(#key_ty, #val_ty)] },
-                (true, false) => quote! { #[rid::structs(#key_ty)] },
-                (false, true) => quote! { #[rid::structs(#val_ty)] },
-                (false, false) => TokenStream::new(),
-            };
-        let enums_atrr =
-            match (self.key_type.is_enum(), self.val_type.is_enum()) {
-                (true, true) => quote! { #[rid::enums(#key_ty, #val_ty)] },
-                (true, false) => quote! { #[rid::enums(#key_ty)] },
-                (false, true) => quote! { #[rid::enums(#val_ty)] },
-                (false, false) => TokenStream::new(),
-            };
+        let custom_type_attrs =
+            render_custom_type_attrs(&[&self.key_type, &self.val_type]);
 
         // -----------------
         // HashMap::len()
@@ -43,9 +37,8 @@ impl HashMapAccess {
         let fn_len_ident = &self.fn_len_ident;
         let len_impl = quote_spanned! { fn_len_ident.span() =>
             #[rid::export]
-            #structs_attr
-            #enums_atrr
-            fn #fn_len_ident(map: &HashMap<#key_ty, #val_ty>) -> usize {
+            #custom_type_attrs
+            fn #fn_len_ident(map: &#map_ty) -> usize {
                 map.len()
             }
         };
@@ -57,15 +50,23 @@ impl HashMapAccess {
         let fn_get_ident_str_tokens: TokenStream =
             format!("\"{}\"", fn_get_ident).parse().unwrap();
 
-        // TODO(thlorenz): special case for String val types
-        // see: ./accesses/collection_item_access_tokens.rs
+        // Option values are flattened such that Dart receives `null` both if the key is
+        // missing and if the value is `None`
+        let mut get_value = quote! { map.get(&key) };
+        let mut get_val_type = &self.val_type;
+        while let TypeKind::Composite(Composite::Option, Some(ty), _) =
+            &get_val_type.kind
+        {
+            get_value = quote! { #get_value.and_then(|x| x.as_ref()) };
+            get_val_type = ty;
+        }
+        let get_val_ty = get_val_type.render_owned_rust_type();
 
         let get_impl = quote_spanned! { fn_get_ident.span() =>
             #[rid::export]
-            #structs_attr
-            #enums_atrr
-            fn #fn_get_ident(map: &HashMap<#key_ty, #val_ty>, key: #key_ty) -> Option<&#val_ty>  {
-                map.get(&key)
+            #custom_type_attrs
+            fn #fn_get_ident(map: &#map_ty, key: #key_ty) -> Option<&#get_val_ty>  {
+                #get_value
             }
         };
 
@@ -76,9 +77,8 @@ impl HashMapAccess {
 
         let contains_key_impl = quote_spanned! { fn_contains_key_ident.span() =>
             #[rid::export]
-            #structs_attr
-            #enums_atrr
-            fn #fn_contains_key_ident(map: &HashMap<#key_ty, #val_ty>, key: #key_ty) -> bool {
+            #custom_type_attrs
+            fn #fn_contains_key_ident(map: &#map_ty, key: #key_ty) -> bool {
                 map.contains_key(&key)
             }
         };
@@ -90,9 +90,8 @@ impl HashMapAccess {
 
         let keys_impl = quote_spanned! { fn_keys_ident.span() =>
             #[rid::export]
-            #structs_attr
-            #enums_atrr
-            fn #fn_keys_ident(map: &HashMap<#key_ty, #val_ty>) -> Vec<&#key_ty> {
+            #custom_type_attrs
+            fn #fn_keys_ident(map: &#map_ty) -> Vec<&#key_ty> {
                 map.keys().collect()
             }
         };
@@ -109,7 +108,69 @@ impl HashMapAccess {
         }
     }
 
+    /// HashMaps returned from a method are looked up via the accessors rendered for
+    /// [AccessKind::FieldReference], thus only the method to free them is rendered here.
     pub fn render_rust_method_return(&self) -> RenderedAccessRust {
-        todo!("HashMapAccess::render_rust_method_return")
+        let RenderedFree {
+            tokens,
+            type_alias: _,
+        } = render_free(
+            &self.hash_map_type,
+            &self.fn_free_ident,
+            &self.rust_ffi_prelude,
+            &AccessKind::MethodReturn,
+        );
+        RenderedAccessRust {
+            tokens,
+            type_aliases: HashMap::new(),
+        }
+    }
+}
+
+/// Renders the `#[rid::structs]` and `#[rid::enums]` attributes of accessors exported for
+/// collections of the provided types.
+pub fn render_custom_type_attrs(types: &[&RustType]) -> TokenStream {
+    let mut struct_idents = vec![];
+    let mut enum_idents = vec![];
+    for ty in types {
+        collect_custom_type_idents(ty, &mut struct_idents, &mut enum_idents);
+    }
+    let structs_attr = if struct_idents.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! { #[rid::structs(#(#struct_idents),*)] }
+    };
+    let enums_attr = if enum_idents.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! { #[rid::enums(#(#enum_idents),*)] }
+    };
+    quote! {
+        #structs_attr
+        #enums_attr
+    }
+}
+
+/// Collects the idents of all structs and enums that `ty` is made of, i.e. `Todo` for
+/// `Vec<Option<Todo>>`, in order to render the `#[rid::structs]` and `#[rid::enums]`
+/// attributes of the exported accessors.
+fn collect_custom_type_idents(
+    ty: &RustType,
+    struct_idents: &mut Vec<Ident>,
+    enum_idents: &mut Vec<Ident>,
+) {
+    match &ty.kind {
+        TypeKind::Composite(_, fst_ty, snd_ty) => {
+            for inner in fst_ty.iter().chain(snd_ty.iter()) {
+                collect_custom_type_idents(inner, struct_idents, enum_idents)
+            }
+        }
+        _ if ty.is_struct() && !struct_idents.contains(ty.rust_ident()) => {
+            struct_idents.push(ty.rust_ident().clone())
+        }
+        _ if ty.is_enum() && !enum_idents.contains(ty.rust_ident()) => {
+            enum_idents.push(ty.rust_ident().clone())
+        }
+        _ => {}
     }
 }
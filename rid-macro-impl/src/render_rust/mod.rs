@@ -1,7 +1,10 @@
 pub use ffi_prelude::*;
+pub use render_catch_panic::*;
+pub use render_composite_item::*;
 pub use render_debug_impl::*;
 pub use render_display_impl::*;
 pub use render_enum_resolution_impl::*;
+pub use render_export_error::*;
 pub use render_free::*;
 pub use render_function_export::*;
 pub use render_pointer_type::*;
@@ -15,8 +18,11 @@ pub use type_alias::*;
 
 mod ffi_prelude;
 pub(crate) mod hash_map;
+mod render_catch_panic;
+mod render_composite_item;
 mod render_debug_impl;
 mod render_display_impl;
+mod render_export_error;
 mod render_free;
 mod render_function_export;
 mod render_pointer_type;
@@ -26,12 +32,21 @@ mod render_return_type;
 mod render_rust_arg;
 mod render_rust_type;
 mod render_to_return_type;
+pub(crate) mod set;
 mod type_alias;
 pub(crate) mod vec;
 
 mod render_enum_resolution_impl;
+mod render_wire_decode_impl;
+mod render_wire_encode_impl;
 
+#[cfg(test)]
+pub mod render_catch_panic_test;
 #[cfg(test)]
 pub mod render_enum_resolution_impl_test;
 #[cfg(test)]
 pub mod render_function_export_test;
+#[cfg(test)]
+pub mod render_wire_decode_impl_test;
+#[cfg(test)]
+pub mod render_wire_encode_impl_test;
@@ -0,0 +1,94 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use rid_common::{PANIC_CLEAR, PANIC_MESSAGE};
+use syn::{parse_quote, Abi, Item, ItemFn, ItemMod, ReturnType, Type};
+
+/// Wraps the body of each `#[no_mangle] extern "C"` function found in the provided tokens,
+/// including the ones nested inside modules, with `rid::_catch_panic` so that a panic never
+/// unwinds into Dart.
+///
+/// Functions returning a reference return a raw pointer instead, which has the same ABI, so that
+/// a null pointer can be returned when they panic.
+///
+/// The functions Dart uses to look up the caught panic are left as is.
+/// Tokens that don't parse as a list of items are returned together with a compile error since
+/// the functions they include would not be guarded.
+pub fn render_catch_ffi_panics(tokens: TokenStream) -> TokenStream {
+    match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(mut file) => {
+            file.items.iter_mut().for_each(wrap_ffi_functions);
+            quote! { #file }
+        }
+        Err(err) => {
+            let full_err = format!(
+                "rid: failed to guard exported functions against panics: {}",
+                err
+            );
+            let compile_error =
+                syn::Error::new(err.span(), full_err).to_compile_error();
+            quote! { #tokens #compile_error }
+        }
+    }
+}
+
+fn wrap_ffi_functions(item: &mut Item) {
+    match item {
+        Item::Fn(item_fn) if is_ffi_function(item_fn) => {
+            let block = &item_fn.block;
+            let wrapped = match &item_fn.sig.output {
+                ReturnType::Type(_, ty) => match &**ty {
+                    Type::Reference(reference) => {
+                        let elem = &reference.elem;
+                        let ptr_ty: Type = match reference.mutability {
+                            Some(_) => parse_quote! { *mut #elem },
+                            None => parse_quote! { *const #elem },
+                        };
+                        let wrapped = parse_quote! {
+                            {
+                                rid::_catch_panic(move || {
+                                    let reference: #ty = #block;
+                                    reference as #ptr_ty
+                                })
+                            }
+                        };
+                        item_fn.sig.output = parse_quote! { -> #ptr_ty };
+                        wrapped
+                    }
+                    // Bodies may rely on the return type to coerce, i.e. a reference into a pointer
+                    _ => parse_quote! {
+                        { rid::_catch_panic(move || -> #ty #block) }
+                    },
+                },
+                ReturnType::Default => {
+                    parse_quote! { { rid::_catch_panic(move || #block) } }
+                }
+            };
+            *item_fn.block = wrapped;
+        }
+        Item::Mod(ItemMod {
+            content: Some((_, items)),
+            ..
+        }) => items.iter_mut().for_each(wrap_ffi_functions),
+        _ => {}
+    }
+}
+
+fn is_ffi_function(item_fn: &ItemFn) -> bool {
+    let is_extern_c = match &item_fn.sig.abi {
+        Some(Abi {
+            name: Some(name), ..
+        }) => name.value() == "C",
+        _ => false,
+    };
+    let is_no_mangle = item_fn
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("no_mangle"));
+    let ident = item_fn.sig.ident.to_string();
+
+    is_extern_c
+        && is_no_mangle
+        && !item_fn.block.stmts.is_empty()
+        && ident != PANIC_MESSAGE
+        && ident != PANIC_CLEAR
+}
@@ -0,0 +1,131 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::render_rust::render_catch_ffi_panics;
+
+/// Normalizes the spacing of the tokens, i.e. `move | |` vs `move ||`.
+fn normalize(tokens: TokenStream) -> String {
+    let file = syn::parse2::<syn::File>(tokens).unwrap();
+    quote! { #file }.to_string()
+}
+
+#[test]
+fn wraps_ffi_functions() {
+    let res = render_catch_ffi_panics(quote! {
+        #[no_mangle]
+        pub extern "C" fn rid_todo_id(ptr: *mut Todo) -> u32 {
+            let todo = unsafe { &*ptr };
+            todo.id
+        }
+        #[no_mangle]
+        pub extern "C" fn rid_todo_ctitle(ptr: *mut Todo) -> *const c_char {
+            let todo = unsafe { &*ptr };
+            unsafe { &*todo.ctitle.as_ptr() }
+        }
+        mod store {
+            #[no_mangle]
+            pub extern "C" fn rid_store_unlock() {
+                RidStoreAccess::instance().dart_unlock()
+            }
+        }
+    });
+    let expected = quote! {
+        #[no_mangle]
+        pub extern "C" fn rid_todo_id(ptr: *mut Todo) -> u32 {
+            rid::_catch_panic(move || -> u32 {
+                let todo = unsafe { &*ptr };
+                todo.id
+            })
+        }
+        #[no_mangle]
+        pub extern "C" fn rid_todo_ctitle(ptr: *mut Todo) -> *const c_char {
+            rid::_catch_panic(move || -> *const c_char {
+                let todo = unsafe { &*ptr };
+                unsafe { &*todo.ctitle.as_ptr() }
+            })
+        }
+        mod store {
+            #[no_mangle]
+            pub extern "C" fn rid_store_unlock() {
+                rid::_catch_panic(move || {
+                    RidStoreAccess::instance().dart_unlock()
+                })
+            }
+        }
+    };
+    assert_eq!(normalize(res), normalize(expected));
+}
+
+#[test]
+fn returns_pointers_instead_of_references() {
+    let res = render_catch_ffi_panics(quote! {
+        #[no_mangle]
+        pub extern "C" fn rid_todo_title(ptr: *mut Todo) -> &'static String {
+            let todo = unsafe { &*ptr };
+            &todo.title
+        }
+        #[no_mangle]
+        pub extern "C" fn rid_todo_tags_mut<'a>(ptr: *mut Todo) -> &'a mut Vec<String> {
+            let todo = unsafe { &mut *ptr };
+            &mut todo.tags
+        }
+    });
+    let expected = quote! {
+        #[no_mangle]
+        pub extern "C" fn rid_todo_title(ptr: *mut Todo) -> *const String {
+            rid::_catch_panic(move || {
+                let reference: &'static String = {
+                    let todo = unsafe { &*ptr };
+                    &todo.title
+                };
+                reference as *const String
+            })
+        }
+        #[no_mangle]
+        pub extern "C" fn rid_todo_tags_mut<'a>(ptr: *mut Todo) -> *mut Vec<String> {
+            rid::_catch_panic(move || {
+                let reference: &'a mut Vec<String> = {
+                    let todo = unsafe { &mut *ptr };
+                    &mut todo.tags
+                };
+                reference as *mut Vec<String>
+            })
+        }
+    };
+    assert_eq!(normalize(res), normalize(expected));
+}
+
+#[test]
+fn leaves_other_functions_as_is() {
+    let input = quote! {
+        pub extern "C" fn not_exported() -> u32 { 1 }
+        #[no_mangle]
+        pub fn not_extern() -> u32 { 1 }
+        #[no_mangle]
+        pub extern "C" fn _include_todo_field_wrappers() {}
+        #[no_mangle]
+        pub extern "C" fn rid_panic_message() -> *const ::std::os::raw::c_char {
+            rid::_panic_message()
+        }
+        impl Todo {
+            fn id(&self) -> u32 { self.id }
+        }
+    };
+    let res = render_catch_ffi_panics(input.clone());
+    assert_eq!(normalize(res), normalize(input));
+}
+
+#[test]
+fn reports_tokens_that_are_not_items() {
+    let res = render_catch_ffi_panics(quote! {
+        #[no_mangle]
+        pub extern "C" fn rid_todo_id(ptr: *mut Todo) -> u32 { 1 }
+        let id = 1;
+    });
+    let res = res.to_string();
+    assert!(res.contains("compile_error"), "renders compile error");
+    assert!(
+        res.contains("rid: failed to guard exported functions against panics"),
+        "explains error"
+    );
+}
@@ -0,0 +1,139 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+use crate::{
+    common::abort,
+    parse::rust_type::{Composite, RustType, TypeKind, Value},
+};
+
+/// Rendered conversion of a reference to a composite item, i.e. the item of a `Vec<Vec<u8>>`,
+/// into what is passed to Dart.
+pub struct RenderedCompositeItem {
+    /// Type returned to Dart, i.e. `*const Vec<u8>`
+    pub return_type: TokenStream,
+
+    /// Expression converting the item reference into the returned type
+    pub into_return: TokenStream,
+}
+
+impl RustType {
+    /// Renders how a reference to this composite type, obtained by evaluating `item_ref`, is
+    /// passed to Dart.
+    ///
+    /// - `Vec`s, `VecDeque`s, maps and sets are passed as pointers into memory owned by Rust
+    ///   and Dart uses the same accesses as for fields of that type to resolve their items
+    /// - `Option`s are flattened and passed as a pointer to the wrapped value which is null for
+    ///   `None`, see [RustType::render_option_ref_to_return]
+    pub fn render_composite_item_to_return(
+        &self,
+        item_ref: &TokenStream,
+    ) -> RenderedCompositeItem {
+        let ident = self.rust_ident();
+        match &self.kind {
+            TypeKind::Composite(
+                Composite::Vec
+                | Composite::VecDeque
+                | Composite::HashMap
+                | Composite::BTreeMap
+                | Composite::HashSet
+                | Composite::BTreeSet,
+                _,
+                _,
+            ) => {
+                let ty = self.render_owned_rust_type();
+                RenderedCompositeItem {
+                    return_type: quote_spanned! { ident.span() => *const #ty },
+                    into_return: quote_spanned! { ident.span() =>
+                        #item_ref as *const #ty
+                    },
+                }
+            }
+            TypeKind::Composite(Composite::Option, _, _) => {
+                self.render_option_to_return(&quote! { #item_ref.as_ref() })
+            }
+            _ => abort!(
+                ident,
+                "render_composite_item_to_return is only supported for collections and Option"
+            ),
+        }
+    }
+
+    /// Renders how `option_ref`, an `Option<&T>` where this type is `Option<T>`, is passed to
+    /// Dart.
+    /// Nested options are flattened, i.e. `Option<&Option<u8>>` is passed like `Option<&u8>`.
+    pub fn render_option_to_return(
+        &self,
+        option_ref: &TokenStream,
+    ) -> RenderedCompositeItem {
+        let mut option_ref = option_ref.clone();
+        let mut inner_type = match &self.kind {
+            TypeKind::Composite(Composite::Option, Some(ty), _) => ty.as_ref(),
+            _ => abort!(
+                self.rust_ident(),
+                "render_option_to_return expects an Option with inner type"
+            ),
+        };
+        while let TypeKind::Composite(Composite::Option, Some(ty), _) =
+            &inner_type.kind
+        {
+            option_ref = quote! { #option_ref.and_then(|x| x.as_ref()) };
+            inner_type = ty.as_ref();
+        }
+        inner_type.render_option_ref_to_return(&option_ref)
+    }
+
+    /// Renders how `option_ref`, an `Option<&T>` where `T` is this type, is passed to Dart.
+    ///
+    /// Strings are converted to a `CString` which Dart frees after reading it.
+    /// All other types are passed as a pointer to the wrapped value.
+    /// In both cases `None` is passed as a null pointer.
+    pub fn render_option_ref_to_return(
+        &self,
+        option_ref: &TokenStream,
+    ) -> RenderedCompositeItem {
+        let ident = self.rust_ident();
+        match &self.kind {
+            TypeKind::Value(Value::String) => RenderedCompositeItem {
+                return_type: quote_spanned! { ident.span() =>
+                    *const ::std::os::raw::c_char
+                },
+                into_return: quote_spanned! { ident.span() =>
+                    match #option_ref {
+                        Some(x) => ::std::ffi::CString::new(x.as_str())
+                            .expect("Invalid string encountered")
+                            .into_raw() as *const ::std::os::raw::c_char,
+                        None => ::std::ptr::null(),
+                    }
+                },
+            },
+            TypeKind::Value(Value::CString) => RenderedCompositeItem {
+                return_type: quote_spanned! { ident.span() =>
+                    *const ::std::os::raw::c_char
+                },
+                into_return: quote_spanned! { ident.span() =>
+                    match #option_ref {
+                        Some(x) => x.clone().into_raw() as *const ::std::os::raw::c_char,
+                        None => ::std::ptr::null(),
+                    }
+                },
+            },
+            TypeKind::Value(Value::Str) => {
+                abort!(ident, "Option<&str> cannot be passed to Dart, use Option<String> instead")
+            }
+            TypeKind::Primitive(_)
+            | TypeKind::Value(_)
+            | TypeKind::Composite(_, _, _) => {
+                let ty = self.render_owned_rust_type();
+                RenderedCompositeItem {
+                    return_type: quote_spanned! { ident.span() => *const #ty },
+                    into_return: quote_spanned! { ident.span() =>
+                        rid::_option_ref_to_pointer(#option_ref)
+                    },
+                }
+            }
+            TypeKind::Unit | TypeKind::Unknown => {
+                abort!(ident, "Option item type is not supported")
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote_spanned};
+use syn::Ident;
+
+use crate::{
+    attrs::Category,
+    parse::rust_type::{RustType, TypeKind, Value},
+};
+
+impl RustType {
+    /// Ident of the method resolving the error of this type returned by the last exported
+    /// function call, i.e. `rid_export_error_todoerror`.
+    pub fn export_error_access_ident(&self) -> Ident {
+        format_ident!(
+            "rid_export_error_{}",
+            self.rust_ident().to_string().to_lowercase()
+        )
+    }
+}
+
+/// Renders the method which Dart calls to resolve a custom error returned by the last exported
+/// function call.
+/// C-style enums are passed as their discriminant while structs are passed as a pointer.
+/// Enums with data carrying variants are passed as a pointer as well since they are included
+/// via `#[rid::structs]` just like structs.
+///
+/// ```rust
+/// fn rid_export_error_todoerror() -> i32 {
+///     let err = rid::_export_error_ref::<TodoError>();
+///     unsafe { err.as_ref() }
+///         .expect("rid_export_error_todoerror called without a TodoError error")
+///         ._rid_into_discriminant()
+/// }
+/// ```
+pub fn render_export_error_access(
+    err_type: &RustType,
+    ffi_prelude: &TokenStream,
+) -> TokenStream {
+    let err_ident = err_type.rust_ident();
+    let fn_ident = err_type.export_error_access_ident();
+    match &err_type.kind {
+        TypeKind::Value(Value::Custom(info, _)) => match info.cat {
+            Category::Enum => {
+                let expect_msg = format!(
+                    "{} called without a {} error",
+                    fn_ident, err_ident
+                );
+                quote_spanned! { err_ident.span() =>
+                    #ffi_prelude fn #fn_ident() -> i32 {
+                        let err = rid::_export_error_ref::<#err_ident>();
+                        unsafe { err.as_ref() }
+                            .expect(#expect_msg)
+                            ._rid_into_discriminant()
+                    }
+                }
+            }
+            Category::Struct => quote_spanned! { err_ident.span() =>
+                #ffi_prelude fn #fn_ident() -> *const #err_ident {
+                    rid::_export_error_ref::<#err_ident>()
+                }
+            },
+            Category::Prim => TokenStream::new(),
+        },
+        _ => TokenStream::new(),
+    }
+}
@@ -37,12 +37,21 @@ pub fn render_free(
         // TODO: in general we shouldn't free refs, but only owned values since the refs
         // are most likely to a model property which is still alive
         K::Value(val) => None,
-        K::Composite(Composite::Vec, _, _) => {
-            Some(quote_spanned! { arg_ident.span() =>  #arg_ident.free(); })
-        }
-        K::Composite(Composite::HashMap, _, _) => {
+        K::Composite(Composite::Vec | Composite::VecDeque, _, _) => {
             Some(quote_spanned! { arg_ident.span() =>  #arg_ident.free(); })
         }
+        K::Composite(
+            Composite::HashMap
+            | Composite::BTreeMap
+            | Composite::HashSet
+            | Composite::BTreeSet,
+            _,
+            _,
+        ) => Some(quote_spanned! { arg_ident.span() =>
+            if !#arg_ident.is_null() {
+                drop(unsafe { Box::from_raw(#arg_ident.cast_mut()) });
+            }
+        }),
         K::Composite(composite, _, _) => {
             todo!("render_free::Composite::{:?}", composite)
         }
@@ -5,7 +5,10 @@ use super::{
     ReceiverArg, RenderedReturnType,
 };
 use crate::{
-    accesses::{AccessKind, VecAccess},
+    accesses::{
+        field_collection_access, AccessKind, HashMapAccess, RenderableAccess,
+        SetAccess, VecAccess,
+    },
     attrs::Category,
     parse::{
         rust_type::{Composite, Primitive, RustType, TypeKind, Value},
@@ -27,6 +30,7 @@ pub struct RenderedFunctionExport {
     pub tokens: TokenStream,
     pub ptr_type_aliases: Vec<PointerTypeAlias>,
     pub vec_access: Option<VecAccess>,
+    pub collection_accesses: Vec<Box<dyn RenderableAccess>>,
 }
 
 pub fn render_function_export(
@@ -43,6 +47,7 @@ pub fn render_function_export(
         receiver,
         args,
         return_arg,
+        return_err,
         ..
     } = parsed_function;
 
@@ -97,6 +102,13 @@ pub fn render_function_export(
     let typed_arg_tokens = render_incoming_args(&fn_ident, &arg_idents);
 
     let fn_call = render_export_call(fn_ident, receiver_ident, &arg_idents);
+    let fn_call = quote! { #static_impl_call_tok#fn_call };
+    let fn_call = match return_err {
+        Some(_) => {
+            render_fallible_export_call(fn_ident, fn_call, &return_ident)
+        }
+        None => fn_call,
+    };
 
     let call_args_resolvers_tokens = arg_idents.iter().map(
         |RustArg {
@@ -124,13 +136,16 @@ pub fn render_function_export(
         fn #rid_export_ident(#receiver_arg #(#typed_arg_tokens)*) -> #ret_type {
             #receiver_arg_resolve
             #(#call_args_resolvers_tokens)*
-            let #return_ident = #static_impl_call_tok#fn_call;
+            let #return_ident = #fn_call;
             #ret_to_pointer
             #return_pointer_ident
         }
     };
 
-    let vec_access = if return_arg.is_vec() {
+    // Owned VecDeques are converted to a Vec and thus share its accesses.
+    let vec_access = if (return_arg.is_vec() || return_arg.is_vec_deque())
+        && return_arg.reference.is_owned()
+    {
         let inner_return_ty = return_arg.inner_composite_type().unwrap();
 
         let ret_ident = match &ret_alias {
@@ -141,6 +156,9 @@ pub fn render_function_export(
             None if inner_return_ty.is_enum() => {
                 format_ident!("i32")
             }
+            None if inner_return_ty.is_composite() => {
+                format_ident!("{}", inner_return_ty.ffi_type_name())
+            }
             None => return_arg.rust_ident().clone(),
         };
 
@@ -154,10 +172,36 @@ pub fn render_function_export(
         None
     };
 
+    // Returned maps and sets, borrowed Vecs and collections wrapped in an Option are looked up
+    // via the same accessors as fields of those types.
+    // Owned maps and sets additionally need to be freed once Dart is done with them.
+    let mut collection_accesses: Vec<Box<dyn RenderableAccess>> = vec![];
+    if vec_access.is_none() {
+        collection_accesses
+            .extend(field_collection_access(return_arg, &ffi_prelude));
+    }
+    if return_arg.is_map() && return_arg.reference.is_owned() {
+        collection_accesses.push(Box::new(HashMapAccess::new(
+            return_arg,
+            return_arg.rust_ident(),
+            AccessKind::MethodReturn,
+            &ffi_prelude,
+        )));
+    }
+    if return_arg.is_set() && return_arg.reference.is_owned() {
+        collection_accesses.push(Box::new(SetAccess::new(
+            return_arg,
+            return_arg.rust_ident(),
+            AccessKind::MethodReturn,
+            &ffi_prelude,
+        )));
+    }
+
     RenderedFunctionExport {
         tokens: fn_export,
         ptr_type_aliases,
         vec_access,
+        collection_accesses,
     }
 }
 
@@ -189,6 +233,30 @@ fn render_incoming_args(
 // -----------------
 // Calling exported Function
 // -----------------
+/// Unwraps the `Result` returned by a fallible function.
+/// Errors are stored for Dart to pick up and the `rid::_RidFfiDefault` value is returned instead
+/// which Dart discards.
+fn render_fallible_export_call(
+    fn_ident: &Ident,
+    fn_call: TokenStream,
+    return_ident: &Ident,
+) -> TokenStream {
+    quote_spanned! { fn_ident.span() =>
+        {
+            rid::_clear_export_error();
+            match #fn_call {
+                Ok(#return_ident) => #return_ident,
+                Err(err) => {
+                    use rid::{_DebugExportError, _DisplayExportError};
+                    let msg = (&rid::_ExportErrorMessage(&err))._rid_error_message();
+                    rid::_set_export_error(msg, err);
+                    return rid::_RidFfiDefault::_rid_ffi_default();
+                }
+            }
+        }
+    }
+}
+
 fn render_export_call(
     fn_ident: &Ident,
     receiver_ident: Option<Ident>,
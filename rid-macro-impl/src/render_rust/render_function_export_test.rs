@@ -210,6 +210,21 @@ mod no_args_prim_return {
         };
         assert_eq!(res.tokens.to_string(), expected.to_string());
     }
+    #[test]
+    fn return_f64() {
+        let res = render(quote! {
+            fn me() -> f64 {}
+        });
+
+        let expected = quote! {
+            fn rid_export_me() -> f64 {
+                let ret = me();
+                let ret_ptr = ret;
+                ret_ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+    }
 }
 
 // -----------------
@@ -260,6 +275,29 @@ mod no_args_composite_vec_return_full {
         assert_eq!(res.type_aliases, "");
     }
 
+    #[test]
+    fn return_vec_f32() {
+        let res = render_full(quote! {
+            fn me() -> Vec<f32> {}
+        });
+        let expected = quote! {
+            fn rid_export_me() -> rid::RidVec<f32> {
+                let ret = me();
+                let ret_ptr = rid::RidVec::from(ret);
+                ret_ptr
+            }
+            fn rid_free_ridvec_f32(arg: rid::RidVec<f32>) {
+                arg.free();
+            }
+            fn rid_get_item_ridvec_f32(vec: rid::RidVec<f32>, idx: usize) -> f32 {
+                let ptr = vec[idx];
+                ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+        assert_eq!(res.type_aliases, "");
+    }
+
     #[test]
     fn return_vec_struct_ref() {
         let res = render_full(quote! {
@@ -290,6 +328,31 @@ mod no_args_composite_vec_return_full {
         assert_eq!(res.tokens.to_string(), expected.to_string());
         assert_eq!(res.type_aliases, "Pointer_MyStruct");
     }
+
+    #[test]
+    fn return_vec_vec_u8() {
+        let res = render_full(quote! {
+            fn grid() -> Vec<Vec<u8>> {}
+        });
+        let expected = quote! {
+            fn rid_export_grid() -> rid::RidVec<Vec<u8> > {
+                let ret = grid();
+                let ret_ptr = rid::RidVec::from(ret);
+                ret_ptr
+            }
+            fn rid_free_ridvec_vec_u8(arg: rid::RidVec<Vec<u8> >) {
+                arg.free();
+            }
+            fn rid_get_item_ridvec_vec_u8(
+                vec: rid::RidVec<Vec<u8> >,
+                idx: usize
+            ) -> *const Vec<u8> {
+                (&vec[idx]) as *const Vec<u8>
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+        assert_eq!(res.type_aliases, "");
+    }
 }
 
 // -----------------
@@ -391,6 +454,56 @@ mod impl_instance_methods {
         assert_eq!(res.tokens.to_string(), expected.to_string());
         assert_eq!(res.type_aliases, "Pointer_Item, Pointer_Model");
     }
+
+    #[test]
+    fn no_args_non_mut_store_receiver() {
+        let res = render_impl(
+            quote! {
+                #[rid::export]
+                fn count(&self) -> u32 { self.count }
+            },
+            "Store",
+            false,
+        );
+        let expected = quote! {
+            fn rid_export_Store_count(ptr: Pointer_Store) -> u32 {
+                let receiver: &Store = unsafe {
+                    assert!(!ptr.is_null());
+                    let ptr = store::_resolve_dart_ptr(ptr as *const Store);
+                    ptr.as_ref().unwrap()
+                };
+                let ret = Store::count(receiver);
+                let ret_ptr = ret;
+                ret_ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn no_args_mut_store_receiver() {
+        let res = render_impl(
+            quote! {
+                #[rid::export]
+                fn next_id(&mut self) -> u32 { self.count += 1; self.count }
+            },
+            "Store",
+            false,
+        );
+        let expected = quote! {
+            fn rid_export_Store_next_id(ptr: PointerMut_Store) -> u32 {
+                let receiver: &mut Store = unsafe {
+                    assert!(!ptr.is_null());
+                    let ptr = store::_resolve_dart_ptr_mut(ptr as *mut Store);
+                    ptr.as_mut().unwrap()
+                };
+                let ret = Store::next_id(receiver);
+                let ret_ptr = ret;
+                ret_ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+    }
 }
 
 // -----------------
@@ -576,3 +689,194 @@ mod standalone_special_returns {
         assert_eq!(res.tokens.to_string(), expected.to_string());
     }
 }
+
+// -----------------
+// Result Returns
+// -----------------
+mod result_returns {
+    use super::*;
+
+    #[test]
+    fn returning_result_u32_string() {
+        let res = render(quote! {
+            fn checked_half(n: u32) -> Result<u32, String> {}
+        });
+
+        let expected = quote! {
+            fn rid_export_checked_half(arg0: u32) -> u32 {
+                let ret = {
+                    rid::_clear_export_error();
+                    match checked_half(arg0) {
+                        Ok(ret) => ret,
+                        Err(err) => {
+                            use rid::{_DebugExportError, _DisplayExportError};
+                            let msg = (&rid::_ExportErrorMessage(&err))._rid_error_message();
+                            rid::_set_export_error(msg, err);
+                            return rid::_RidFfiDefault::_rid_ffi_default();
+                        }
+                    }
+                };
+                let ret_ptr = ret;
+                ret_ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn instance_method_returning_result_u32_custom_enum() {
+        let res = render_impl(
+            quote! {
+                #[rid::export]
+                #[rid::enums(MyError)]
+                fn withdraw(&self, amount: u32) -> Result<u32, MyError> {}
+            },
+            "Model",
+            false,
+        );
+
+        let expected = quote! {
+            fn rid_export_Model_withdraw(ptr: Pointer_Model, arg0: u32) -> u32 {
+                let receiver: &Model = unsafe {
+                    assert!(!ptr.is_null());
+                    ptr.as_ref().unwrap()
+                };
+                let ret = {
+                    rid::_clear_export_error();
+                    match Model::withdraw(receiver, arg0) {
+                        Ok(ret) => ret,
+                        Err(err) => {
+                            use rid::{_DebugExportError, _DisplayExportError};
+                            let msg = (&rid::_ExportErrorMessage(&err))._rid_error_message();
+                            rid::_set_export_error(msg, err);
+                            return rid::_RidFfiDefault::_rid_ffi_default();
+                        }
+                    }
+                };
+                let ret_ptr = ret;
+                ret_ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+    }
+}
+
+mod hash_map_returns {
+    use super::*;
+
+    #[test]
+    fn returning_owned_hash_map_string_u32() {
+        let res = render(quote! {
+            fn counts() -> HashMap<String, u32> {}
+        });
+
+        let expected = quote! {
+            fn rid_export_counts() -> *const HashMap<String, u32> {
+                let ret = counts();
+                let ret_ptr = std::boxed::Box::into_raw(std::boxed::Box::new(ret));
+                ret_ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+
+        let keys: Vec<String> =
+            res.collection_accesses.iter().map(|x| x.key()).collect();
+        assert_eq!(keys, vec!["hash_map_string_u32", "ridhash_map_string_u32"]);
+
+        let expected_free = quote! {
+            fn rid_free_ridhash_map_string_u32(arg: *const HashMap<String, u32>) {
+                if !arg.is_null() {
+                    drop(unsafe { Box::from_raw(arg.cast_mut()) });
+                }
+            }
+        };
+        assert_eq!(
+            res.collection_accesses[1].render_rust().tokens.to_string(),
+            expected_free.to_string()
+        );
+    }
+
+    #[test]
+    fn instance_method_returning_hash_map_ref() {
+        let res = render_impl(
+            quote! {
+                #[rid::export]
+                fn scores(&self) -> &HashMap<String, u32> {}
+            },
+            "Model",
+            false,
+        );
+
+        let expected = quote! {
+            fn rid_export_Model_scores(ptr: Pointer_Model) -> *const HashMap<String, u32> {
+                let receiver: &Model = unsafe {
+                    assert!(!ptr.is_null());
+                    ptr.as_ref().unwrap()
+                };
+                let ret = Model::scores(receiver);
+                let ret_ptr = ret;
+                ret_ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+    }
+}
+
+mod std_collection_returns {
+    use super::*;
+
+    #[test]
+    fn returning_owned_btree_set_u32() {
+        let res = render(quote! {
+            fn tags() -> BTreeSet<u32> {}
+        });
+
+        let expected = quote! {
+            fn rid_export_tags() -> *const BTreeSet<u32> {
+                let ret = tags();
+                let ret_ptr = std::boxed::Box::into_raw(std::boxed::Box::new(ret));
+                ret_ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+
+        let keys: Vec<String> =
+            res.collection_accesses.iter().map(|x| x.key()).collect();
+        assert_eq!(keys, vec!["btree_set_u32", "ridbtree_set_u32"]);
+
+        let expected_free = quote! {
+            fn rid_free_ridbtree_set_u32(arg: *const BTreeSet<u32>) {
+                if !arg.is_null() {
+                    drop(unsafe { Box::from_raw(arg.cast_mut()) });
+                }
+            }
+        };
+        assert_eq!(
+            res.collection_accesses[1].render_rust().tokens.to_string(),
+            expected_free.to_string()
+        );
+    }
+
+    #[test]
+    fn returning_owned_vec_deque_u8() {
+        let res = render_full(quote! {
+            fn history() -> VecDeque<u8> {}
+        });
+        let expected = quote! {
+            fn rid_export_history() -> rid::RidVec<u8> {
+                let ret = history();
+                let ret = Vec::from(ret);
+                let ret_ptr = rid::RidVec::from(ret);
+                ret_ptr
+            }
+            fn rid_free_ridvec_u8(arg: rid::RidVec<u8>) {
+                arg.free();
+            }
+            fn rid_get_item_ridvec_u8(vec: rid::RidVec<u8>, idx: usize) -> u8 {
+                let ptr = vec[idx];
+                ptr
+            }
+        };
+        assert_eq!(res.tokens.to_string(), expected.to_string());
+    }
+}
@@ -66,6 +66,8 @@ impl Primitive {
             U64 => quote! { u64 },
             I64 => quote! { i64 },
             USize => quote! { usize },
+            F32 => quote! { f32 },
+            F64 => quote! { f64 },
             Bool => quote! { bool },
         }
     }
@@ -5,9 +5,10 @@ use syn::Ident;
 
 use crate::{
     attrs::TypeInfo,
+    common::tokens::resolve_store_ptr,
     parse::{
         rust_type::{RustType, RustTypeContext, TypeKind, Value},
-        ParsedReceiver,
+        ParsedReceiver, ParsedReference,
     },
     render_common::PointerTypeAlias,
 };
@@ -89,9 +90,15 @@ fn render_receiver_arg_resolve(
 ) -> TokenStream {
     let arg_type_toks = rust_type.render_rust_type().tokens;
     let as_ident = rust_type.reference.render_deref();
+    let resolve_store_ptr = resolve_store_ptr(
+        ptr_ident,
+        &type_info.key,
+        matches!(rust_type.reference, ParsedReference::RefMut(_)),
+    );
     quote_spanned! { type_info.key.span() =>
         let #arg_ident: #arg_type_toks = unsafe {
             assert!(!#ptr_ident.is_null());
+            #resolve_store_ptr
             #ptr_ident#as_ident.unwrap()
         };
     }
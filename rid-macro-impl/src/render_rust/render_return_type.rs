@@ -1,10 +1,14 @@
 use std::ops::Deref;
 
-use crate::{accesses::AccessKind, attrs::Category, common::{abort, missing_struct_enum_info}, parse::{
+use crate::{
+    accesses::AccessKind,
+    attrs::Category,
+    common::{abort, missing_struct_enum_info},
+    parse::{
         rust_type::{Composite, Primitive, RustType, TypeKind, Value},
         ParsedReference,
     },
-    render_common::PointerTypeAlias
+    render_common::PointerTypeAlias,
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
@@ -41,9 +45,15 @@ pub fn render_return_type(
         }
 
         // -----------------
-        // Composites Vec
+        // Composites Vec, VecDeque
         // -----------------
-        K::Composite(Composite::Vec, inner_ty, _) => match inner_ty {
+        // Borrowed Vecs are passed as a pointer and accessed like Vec fields
+        K::Composite(Composite::Vec | Composite::VecDeque, _, _) if !rust_type.reference.is_owned() => {
+            let vec_ty = rust_type.render_owned_rust_type();
+            quote_spanned! { rust_type.rust_ident().span() => *const #vec_ty }
+        }
+        // Owned VecDeques are converted to a Vec and passed like one
+        K::Composite(Composite::Vec | Composite::VecDeque, inner_ty, _) => match inner_ty {
             Some(ty) => {
                 let (alias, tokens) = render_vec_return_type(ty, access_kind);
                 type_alias = alias;
@@ -58,6 +68,9 @@ pub fn render_return_type(
         // Composites Option
         // -----------------
         K::Composite(Composite::Option, inner_ty, _) => match inner_ty {
+            Some(ty) if ty.is_option_passed_as_composite_item() => {
+                rust_type.render_option_to_return(&TokenStream::new()).return_type
+            }
             Some(ty) => {
                 let (alias, tokens) = render_option_return_type(ty, access_kind);
                 type_alias = alias;
@@ -69,10 +82,10 @@ pub fn render_return_type(
         },
         
         // -----------------
-        // Composites HashMap
+        // Composites HashMap, BTreeMap, HashSet, BTreeSet
         // -----------------
-        K::Composite(Composite::HashMap, key_ty, val_ty) => {
-            todo!("render_return_type::custom_composite HashMap<{:?}, {:?}>", key_ty, val_ty)
+        K::Composite(Composite::HashMap | Composite::BTreeMap | Composite::HashSet | Composite::BTreeSet, _, _) => {
+            render_map_or_set_return_type(rust_type)
         }
         K::Composite(composite, rust_type, _) => {
             todo!("render_return_type::custom_composite {:?}", composite)
@@ -114,10 +127,19 @@ fn render_primitive_return(prim: &Primitive, ty: &RustType) -> TokenStream {
         U64 => quote! { #ref_token u64 },
         I64 => quote! { #ref_token i64 },
         USize => quote! { #ref_token usize },
+        F32 => quote! { #ref_token f32 },
+        F64 => quote! { #ref_token f64 },
         Bool => quote! { #ref_token u8 },
     }
 }
 
+/// Maps and sets are passed to Dart as a pointer, whether they are borrowed or owned.
+/// Dart then uses the same accessors as for fields of that type to look up its entries.
+fn render_map_or_set_return_type(rust_type: &RustType) -> TokenStream {
+    let ty = rust_type.render_owned_rust_type();
+    quote_spanned! { rust_type.rust_ident().span() => *const #ty }
+}
+
 fn render_vec_return_type(
     inner_type: &RustType,
     access_kind: &AccessKind,
@@ -137,10 +159,18 @@ fn render_vec_return_type(
             let tokens = quote! { rid::RidVec<#val_tokens> };
             (alias, tokens)
         }
+        // Composite items are owned by the RidVec and passed to Dart as pointers into it
+        K::Composite(_, _, _) if inner_type.reference.is_owned() => {
+            let item_ty = inner_type.render_owned_rust_type();
+            let tokens = quote_spanned! { inner_type.rust_ident().span() =>
+                rid::RidVec<#item_ty>
+            };
+            (None, tokens)
+        }
         K::Composite(_, _, _) => {
             abort!(
                 inner_type.rust_ident(),
-                "todo!(render_vec_return_type::composite)"
+                "Returning a Vec of borrowed Vec, Option or HashMap items is not supported, return them owned instead"
             )
         }
         K::Unit => {
@@ -176,7 +206,7 @@ fn render_option_return_type(
         K::Composite(_, _, _) => {
             abort!(
                 inner_type.rust_ident(),
-                "todo!(render_option_return_type::composite)"
+                "Returning an owned Option of a Vec, Option or HashMap is not supported, return Option<&T> instead"
             )
         }
         K::Unit => {
@@ -200,17 +230,22 @@ fn render_value_return_type(
     use Value as V;
 
     match value {
-        V::CString | V::String | V::Str if !ty.is_collection_item() || ty.reference.is_owned() => {
+        V::CString | V::String | V::Str
+            if !ty.is_collection_item() || ty.reference.is_owned() =>
+        {
             (None, quote! { *const ::std::os::raw::c_char })
         }
 
         V::CString | V::String | V::Str => {
             let type_name = &ty.rust_ident().to_string();
-            let qualified_type_name = &ty.fully_qualified_rust_ident().to_string();
+            let qualified_type_name =
+                &ty.fully_qualified_rust_ident().to_string();
 
-            let (alias, ref_tok) = ty
-                .reference
-                .render_pointer(&type_name, &qualified_type_name, false);
+            let (alias, ref_tok) = ty.reference.render_pointer(
+                type_name,
+                qualified_type_name,
+                false,
+            );
             (alias, quote_spanned! { ty.rust_ident().span() => #ref_tok })
         }
         V::Custom(type_info, type_name) => match type_info.cat {
@@ -218,13 +253,26 @@ fn render_value_return_type(
                 (None, quote_spanned! { type_info.key.span() => i32 })
             }
             _ => {
-            let type_name = &ty.rust_ident().to_string();
-            let qualified_type_name = &ty.fully_qualified_rust_ident().to_string();
-                let (alias, ref_tok) = ty
-                    .reference
-                    .render_pointer(&type_name, &qualified_type_name, false);
+                let type_name = &ty.rust_ident().to_string();
+                let qualified_type_name =
+                    &ty.fully_qualified_rust_ident().to_string();
+                let (alias, ref_tok) = ty.reference.render_pointer(
+                    type_name,
+                    qualified_type_name,
+                    false,
+                );
                 (alias, quote_spanned! { type_info.key.span() => #ref_tok })
             }
         },
     }
 }
+
+impl RustType {
+    /// Returns `true` if an `Option` wrapping this type is passed to Dart like an `Option`
+    /// nested inside a collection, see [RustType::render_option_to_return].
+    /// This is the case for borrowed collections, options, enums and strings.
+    pub fn is_option_passed_as_composite_item(&self) -> bool {
+        !self.reference.is_owned()
+            && (self.is_composite() || self.is_enum() || self.is_string_like())
+    }
+}
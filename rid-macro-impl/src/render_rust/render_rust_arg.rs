@@ -4,7 +4,8 @@ use syn::Ident;
 
 use crate::{
     common::tokens::{
-        resolve_bool_from_u8, resolve_hash_map_ptr, resolve_string_ptr,
+        resolve_bool_from_u8, resolve_hash_map_ptr, resolve_set_ptr,
+        resolve_string_ptr, resolve_wire_arg,
     },
     parse::rust_type::{self, RustType, TypeKind},
     render_rust::render_rust_type,
@@ -82,24 +83,39 @@ impl RustArg {
                 }
             }
             // -----------------
-            // Composite HashMap
+            // Composite HashMap, BTreeMap
             // -----------------
-            Composite(rust_type::Composite::HashMap, key_ty, val_ty) => {
+            Composite(
+                rust_type::Composite::HashMap | rust_type::Composite::BTreeMap,
+                _,
+                _,
+            ) => {
                 let arg_ident = format_ident!("arg{}", slot);
-                let key_ty =
-                    key_ty.as_ref().expect("HashMap should have key type");
-                let val_ty =
-                    val_ty.as_ref().expect("HashMap should have val type");
-                let key_ty_ident = key_ty.rust_ident();
-                let val_ty_ident = val_ty.rust_ident();
+                let map_ty = ty.render_owned_rust_type();
                 let type_tokens = quote_spanned! { arg_ident.span() =>
-                    *const HashMap<#key_ty_ident, #val_ty_ident>
+                    *const #map_ty
                 };
-                let resolver_tokens = resolve_hash_map_ptr(
-                    &arg_ident,
-                    &key_ty_ident,
-                    &val_ty_ident,
-                );
+                let resolver_tokens = resolve_hash_map_ptr(&arg_ident, &map_ty);
+                RustArg {
+                    arg_ident,
+                    type_tokens,
+                    resolver_tokens,
+                }
+            }
+            // -----------------
+            // Composite HashSet, BTreeSet
+            // -----------------
+            Composite(
+                rust_type::Composite::HashSet | rust_type::Composite::BTreeSet,
+                _,
+                _,
+            ) => {
+                let arg_ident = format_ident!("arg{}", slot);
+                let set_ty = ty.render_owned_rust_type();
+                let type_tokens = quote_spanned! { arg_ident.span() =>
+                    *const #set_ty
+                };
+                let resolver_tokens = resolve_set_ptr(&arg_ident, &set_ty);
                 RustArg {
                     arg_ident,
                     type_tokens,
@@ -120,6 +136,20 @@ impl RustArg {
         }
     }
 
+    /// Arg which is passed from Dart encoded in the rid wire format, i.e. a struct or collection
+    /// that is part of a message, and decoded into the owned `ty`.
+    pub fn from_wire(ty: &RustType, slot: usize) -> Self {
+        let arg_ident = format_ident!("arg{}", slot);
+        let owned_ty = ty.render_owned_rust_type();
+        let type_tokens = quote_spanned! { arg_ident.span() => *const u8 };
+        let resolver_tokens = resolve_wire_arg(&arg_ident, &owned_ty);
+        RustArg {
+            arg_ident,
+            type_tokens,
+            resolver_tokens,
+        }
+    }
+
     pub fn render_typed_parameter(
         &self,
         span: Option<Span>,
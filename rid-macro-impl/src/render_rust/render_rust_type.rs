@@ -22,15 +22,30 @@ impl RustType {
         let tokens = match kind {
             K::Primitive(prim) => prim.render_rust_type(),
             K::Value(val) => val.render_rust_type(reference),
-            K::Composite(Composite::Vec, rust_type, _) => {
-                // similar to same case in ./render_return_type.rs
-                todo!("render_rust_type::custom_composite::vec")
-            }
-            K::Composite(Composite::Option, rust_type, _) => {
-                todo!("render_rust_type::custom_composite::option")
+            K::Composite(
+                Composite::Vec
+                | Composite::VecDeque
+                | Composite::HashSet
+                | Composite::BTreeSet
+                | Composite::Option,
+                Some(inner_type),
+                _,
+            ) => {
+                let ref_tok = reference.render();
+                let ident = self.rust_ident();
+                let inner_tok = inner_type.render_item_type();
+                quote! { #ref_tok #ident<#inner_tok> }
             }
-            K::Composite(Composite::HashMap, key_type, val_type) => {
-                todo!("render_rust_type::custom_composite::hashmap")
+            K::Composite(
+                Composite::HashMap | Composite::BTreeMap,
+                Some(key_type),
+                Some(val_type),
+            ) => {
+                let ref_tok = reference.render();
+                let ident = self.rust_ident();
+                let key_tok = key_type.render_item_type();
+                let val_tok = val_type.render_item_type();
+                quote! { #ref_tok #ident<#key_tok, #val_tok> }
             }
             K::Composite(composite, rust_type, _) => {
                 todo!("render_rust_type::custom_composite::{:?}", composite)
@@ -43,6 +58,32 @@ impl RustType {
 
         RenderedRustType { tokens }
     }
+
+    /// Renders the owned version of this type as it is declared in Rust, i.e. `Vec<bool>` for
+    /// `&Vec<bool>`.
+    pub fn render_owned_rust_type(&self) -> TokenStream {
+        RustType::new(
+            self.rust_ident().clone(),
+            self.kind.clone(),
+            ParsedReference::Owned,
+            self.context.clone(),
+        )
+        .render_item_type()
+    }
+
+    /// Renders the type of an item nested inside a composite, i.e. `u8` inside `Vec<u8>`.
+    /// Different from [RustType::render_rust_type] primitives are rendered as is since they
+    /// aren't passed across FFI by themselves.
+    fn render_item_type(&self) -> TokenStream {
+        match &self.kind {
+            TypeKind::Primitive(_) => {
+                let ref_tok = self.reference.render();
+                let ident = self.rust_ident();
+                quote_spanned! { ident.span() => #ref_tok #ident }
+            }
+            _ => self.render_rust_type().tokens,
+        }
+    }
 }
 
 impl Primitive {
@@ -59,6 +100,8 @@ impl Primitive {
             U64 => quote! { u64 },
             I64 => quote! { i64 },
             USize => quote! { usize },
+            F32 => quote! { f32 },
+            F64 => quote! { f64 },
             Bool => quote! { u8 },
         }
     }
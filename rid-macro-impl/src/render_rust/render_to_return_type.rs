@@ -7,7 +7,7 @@ use crate::{
     },
 };
 use proc_macro2::TokenStream;
-use quote::quote_spanned;
+use quote::{quote, quote_spanned};
 use syn::Ident;
 
 // -----------------
@@ -52,14 +52,30 @@ impl RustType {
             // -----------------
             // Composites
             // -----------------
+            K::Composite(Composite::Vec | Composite::VecDeque, _, _) if !self.reference.is_owned() => quote_spanned! { res_ident.span() =>
+                let #res_pointer = #res_ident;
+            },
             K::Composite(Composite::Vec, rust_type, _) => 
                 render_vec_to_return_type(res_ident, res_pointer, rust_type),
+            K::Composite(Composite::VecDeque, rust_type, _) => {
+                let to_return = render_vec_to_return_type(res_ident, res_pointer, rust_type);
+                quote_spanned! { res_ident.span() =>
+                    let #res_ident = Vec::from(#res_ident);
+                    #to_return
+                }
+            }
 
+            K::Composite(Composite::Option, Some(rust_type), _) if rust_type.is_option_passed_as_composite_item() => {
+                let into_return = self.render_option_to_return(&quote! { #res_ident }).into_return;
+                quote_spanned! { res_ident.span() =>
+                    let #res_pointer = #into_return;
+                }
+            }
             K::Composite(Composite::Option, rust_type, _) =>
                 render_option_to_return_type(res_ident, res_pointer, rust_type),
 
-            // TODO(thlorenz): HashMap
-            K::Composite(Composite::HashMap, key_type, val_type) =>  todo!("render_pointer::Composite::HashMap<{:?}, {:?}>", key_type, val_type),
+            K::Composite(Composite::HashMap | Composite::BTreeMap | Composite::HashSet | Composite::BTreeSet, _, _) =>
+                render_map_or_set_to_return_type(res_ident, res_pointer, &self.reference),
             K::Composite(composite, _, _) =>  todo!("render_pointer::Composite::{:?}", composite),
 
             // -----------------
@@ -135,6 +151,26 @@ impl Value {
     }
 }
 
+/// Owned maps and sets are boxed and Dart is responsible to free them once it is done.
+/// Borrowed ones are passed as is since Rust keeps owning them.
+fn render_map_or_set_to_return_type(
+    res_ident: &Ident,
+    res_pointer: &Ident,
+    reference: &ParsedReference,
+) -> TokenStream {
+    match reference {
+        ParsedReference::Owned => quote_spanned! { res_ident.span() =>
+            let #res_pointer =
+                std::boxed::Box::into_raw(std::boxed::Box::new(#res_ident));
+        },
+        ParsedReference::Ref(_) | ParsedReference::RefMut(_) => {
+            quote_spanned! { res_ident.span() =>
+                let #res_pointer = #res_ident;
+            }
+        }
+    }
+}
+
 fn render_vec_to_return_type(
     res_ident: &Ident,
     res_pointer: &Ident,
@@ -148,63 +184,82 @@ fn render_vec_to_return_type(
                 // -----------------
                 // Primitives
                 // -----------------
-                K::Primitive(_) if is_owned => quote_spanned! { res_ident.span() =>
-                    let #res_pointer = rid::RidVec::from(#res_ident);
-                },
+                K::Primitive(_) if is_owned => {
+                    quote_spanned! { res_ident.span() =>
+                        let #res_pointer = rid::RidVec::from(#res_ident);
+                    }
+                }
                 K::Primitive(_) => {
-                    let owned_ty = rust_type.to_owned().render_rust_type().tokens;
+                    let owned_ty =
+                        rust_type.to_owned().render_rust_type().tokens;
                     quote_spanned! { res_ident.span() =>
                         let #res_ident: Vec<#owned_ty> = #res_ident.into_iter().map(|x| *x).collect();
                         let #res_pointer = rid::RidVec::from(#res_ident);
                     }
-                },
+                }
                 // -----------------
                 // Values
                 // -----------------
                 K::Value(val) => {
                     use Value::*;
                     match val {
-                        CString if is_owned => todo!("render_vec_to_return_type::Value::CString owned"),
-                        String if is_owned => todo!("render_vec_to_return_type::Value::String owned"),
-                        Str if is_owned => todo!("render_vec_to_return_type::Value::Str owned"),
-                        CString | String| Str =>{ 
-                            let pointer_type = rust_type.render_pointer_type().tokens;
+                        CString if is_owned => todo!(
+                            "render_vec_to_return_type::Value::CString owned"
+                        ),
+                        String if is_owned => todo!(
+                            "render_vec_to_return_type::Value::String owned"
+                        ),
+                        Str if is_owned => {
+                            todo!("render_vec_to_return_type::Value::Str owned")
+                        }
+                        CString | String | Str => {
+                            let pointer_type =
+                                rust_type.render_pointer_type().tokens;
                             quote_spanned! { res_ident.span() =>
                                 let vec_with_pointers: Vec<#pointer_type> =
                                     #res_ident.into_iter().map(|x| &*x as #pointer_type).collect();
                                 let #res_pointer = rid::RidVec::from(vec_with_pointers);
                             }
-                        },
+                        }
                         Custom(ty, _) => match ty.cat {
-                            Category::Enum => quote_spanned! { res_ident.span() =>
-                                let #res_ident: Vec<i32> = #res_ident
-                                    .into_iter()
-                                    .map(|x| x._rid_into_discriminant())
-                                    .collect();
-                                let #res_pointer = rid::RidVec::from(#res_ident);
-                            },
+                            Category::Enum => {
+                                quote_spanned! { res_ident.span() =>
+                                    let #res_ident: Vec<i32> = #res_ident
+                                        .into_iter()
+                                        .map(|x| x._rid_into_discriminant())
+                                        .collect();
+                                    let #res_pointer = rid::RidVec::from(#res_ident);
+                                }
+                            }
                             Category::Struct => {
-                                let pointer_type = rust_type.render_pointer_type().tokens;
+                                let pointer_type =
+                                    rust_type.render_pointer_type().tokens;
                                 quote_spanned! { res_ident.span() =>
                                     let vec_with_pointers: Vec<#pointer_type> =
                                         #res_ident.into_iter().map(|x| &*x as #pointer_type).collect();
                                     let #res_pointer = rid::RidVec::from(vec_with_pointers);
                                 }
                             }
-                            Category::Prim => TokenStream::new()
+                            Category::Prim => TokenStream::new(),
                         },
                     }
                 }
                 // -----------------
                 // Composites
                 // -----------------
-                K::Composite(_, _, _) =>
-                    abort!(res_ident, "TODO render_vec_to_return_type::Composite"),
+                K::Composite(_, _, _) => quote_spanned! { res_ident.span() =>
+                    let #res_pointer = rid::RidVec::from(#res_ident);
+                },
                 // -----------------
                 // Invalids
                 // -----------------
-                K::Unit => abort!(res_ident, "Returning Vec<()> is not supported"),
-                K::Unknown => abort!(res_ident, "Cannot render_vec_to_return_type for unknown inner type"),
+                K::Unit => {
+                    abort!(res_ident, "Returning Vec<()> is not supported")
+                }
+                K::Unknown => abort!(
+                    res_ident,
+                    "Cannot render_vec_to_return_type for unknown inner type"
+                ),
             }
         }
         None => abort!(res_ident, "Vec inner type should be defined"),
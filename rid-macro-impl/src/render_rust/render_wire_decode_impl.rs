@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+use crate::parse::{ParsedEnum, ParsedStruct};
+
+impl ParsedStruct {
+    /// Renders an implementation of `rid::_RidDecode` which allows this struct to be decoded from
+    /// the rid wire format, i.e. when it is passed as part of a message from Dart.
+    ///
+    /// The fields are decoded in the order in which they are declared.
+    ///
+    /// ```rust
+    /// impl rid::_RidDecode for Point {
+    ///     fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+    ///         Self {
+    ///             x: rid::_RidDecode::_rid_decode(reader),
+    ///             y: rid::_RidDecode::_rid_decode(reader),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn render_wire_decode_impl(&self) -> TokenStream {
+        let ident = &self.ident;
+        let fields = self.fields.iter().map(|x| {
+            let member = &x.member;
+            quote! { #member: rid::_RidDecode::_rid_decode(reader), }
+        });
+
+        quote_spanned! { ident.span() =>
+            impl rid::_RidDecode for #ident {
+                fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                    Self { #(#fields)* }
+                }
+            }
+        }
+    }
+}
+
+impl ParsedEnum {
+    /// Renders an implementation of `rid::_RidDecode` which allows this enum to be decoded from
+    /// the rid wire format, i.e. when it is passed as part of a message from Dart.
+    ///
+    /// C-style enums are resolved from their `i32` discriminant while enums with data variants
+    /// read the `u32` index of the variant followed by its fields.
+    ///
+    /// ```rust
+    /// impl rid::_RidDecode for Shape {
+    ///     fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+    ///         match reader.read_variant("Shape", &[0, 1]) {
+    ///             0 => Self::Circle(rid::_RidDecode::_rid_decode(reader)),
+    ///             1 => Self::Empty,
+    ///             n => panic!("enum Shape does not include variant {}", n),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn render_wire_decode_impl(&self) -> TokenStream {
+        let ident = &self.ident;
+        let enum_name = ident.to_string();
+        let decode_tokens = if self.has_data_variants() {
+            let panic_quote = format!(
+                "enum {ident} does not include variant {{}}",
+                ident = ident
+            );
+            let variant_arms = self.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let discriminant = variant.discriminant as u32;
+                let decoded_fields =
+                    variant.fields.iter().map(|x| match &x.ident {
+                        Some(field_ident) => quote! {
+                            #field_ident: rid::_RidDecode::_rid_decode(reader),
+                        },
+                        None => {
+                            quote! { rid::_RidDecode::_rid_decode(reader), }
+                        }
+                    });
+                let construct = if variant.fields.is_empty() {
+                    quote! { Self::#variant_ident }
+                } else if variant.has_named_fields() {
+                    quote! { Self::#variant_ident { #(#decoded_fields)* } }
+                } else {
+                    quote! { Self::#variant_ident(#(#decoded_fields)*) }
+                };
+                quote! { #discriminant => #construct, }
+            });
+            let variants = self.variants.iter().map(|x| x.discriminant as u32);
+            quote! {
+                match reader.read_variant(#enum_name, &[#(#variants),*]) {
+                    #(#variant_arms)*
+                    n => panic!(#panic_quote, n),
+                }
+            }
+        } else {
+            let discriminants =
+                self.variants.iter().map(|x| x.discriminant as i32);
+            quote! {
+                Self::_rid_from_discriminant(
+                    reader.read_discriminant(#enum_name, &[#(#discriminants),*])
+                )
+            }
+        };
+
+        quote_spanned! { ident.span() =>
+            impl rid::_RidDecode for #ident {
+                fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                    #decode_tokens
+                }
+            }
+        }
+    }
+}
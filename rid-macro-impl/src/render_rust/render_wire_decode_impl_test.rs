@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Item;
+
+use crate::{
+    attrs::{EnumConfig, StructConfig},
+    parse::{ParsedEnum, ParsedStruct},
+};
+
+fn render(input: TokenStream) -> TokenStream {
+    let item = syn::parse2::<syn::Item>(input).unwrap();
+    match item {
+        Item::Struct(item) => {
+            ParsedStruct::new(&item, &item.ident, StructConfig::from(&item))
+                .render_wire_decode_impl()
+        }
+        Item::Enum(item) => ParsedEnum::from(&item, EnumConfig::from(&item))
+            .render_wire_decode_impl(),
+        _ => panic!(
+            "Unexpected item, we're trying to parse structs and enums here"
+        ),
+    }
+}
+
+mod structs {
+    use super::*;
+
+    #[test]
+    fn render_named_struct() {
+        let res = render(quote! {
+            struct Point { x: i32, y: i32 }
+        });
+        let expected = quote! {
+            impl rid::_RidDecode for Point {
+                fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                    Self {
+                        x: rid::_RidDecode::_rid_decode(reader),
+                        y: rid::_RidDecode::_rid_decode(reader),
+                    }
+                }
+            }
+        };
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn render_tuple_struct() {
+        let res = render(quote! {
+            struct Pair(u8, String);
+        });
+        let expected = quote! {
+            impl rid::_RidDecode for Pair {
+                fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                    Self {
+                        0: rid::_RidDecode::_rid_decode(reader),
+                        1: rid::_RidDecode::_rid_decode(reader),
+                    }
+                }
+            }
+        };
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+}
+
+mod enums {
+    use super::*;
+
+    #[test]
+    fn render_c_style_enum() {
+        let res = render(quote! {
+            enum Filter { All, Completed }
+        });
+        let expected = quote! {
+            impl rid::_RidDecode for Filter {
+                fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                    Self::_rid_from_discriminant(
+                        reader.read_discriminant("Filter", &[0i32, 1i32])
+                    )
+                }
+            }
+        };
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn render_data_enum() {
+        let res = render(quote! {
+            enum Shape {
+                Circle(f64),
+                Rect { width: u32, height: u32 },
+                Empty,
+            }
+        });
+        let expected = quote! {
+            impl rid::_RidDecode for Shape {
+                fn _rid_decode(reader: &mut rid::_RidWireReader) -> Self {
+                    match reader.read_variant("Shape", &[0u32, 1u32, 2u32]) {
+                        0u32 => Self::Circle(rid::_RidDecode::_rid_decode(reader),),
+                        1u32 => Self::Rect {
+                            width: rid::_RidDecode::_rid_decode(reader),
+                            height: rid::_RidDecode::_rid_decode(reader),
+                        },
+                        2u32 => Self::Empty,
+                        n => panic!("enum Shape does not include variant {}", n),
+                    }
+                }
+            }
+        };
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+}
@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+
+use crate::parse::{ParsedEnum, ParsedStruct};
+
+impl ParsedStruct {
+    /// Renders an implementation of `rid::_RidEncode` which allows this struct to be encoded into
+    /// the rid wire format, i.e. when it is posted to Dart as part of a reply.
+    ///
+    /// The fields are encoded in the order in which they are declared.
+    ///
+    /// ```rust
+    /// impl rid::_RidEncode for Point {
+    ///     fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+    ///         rid::_RidEncode::_rid_encode(&self.x, writer);
+    ///         rid::_RidEncode::_rid_encode(&self.y, writer);
+    ///     }
+    /// }
+    /// ```
+    pub fn render_wire_encode_impl(&self) -> TokenStream {
+        let ident = &self.ident;
+        let fields = self.fields.iter().map(|x| {
+            let member = &x.member;
+            quote! { rid::_RidEncode::_rid_encode(&self.#member, writer); }
+        });
+
+        quote_spanned! { ident.span() =>
+            impl rid::_RidEncode for #ident {
+                fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                    #(#fields)*
+                }
+            }
+        }
+    }
+}
+
+impl ParsedEnum {
+    /// Renders an implementation of `rid::_RidEncode` which allows this enum to be encoded into
+    /// the rid wire format, i.e. when it is posted to Dart as part of a reply.
+    ///
+    /// C-style enums are encoded as their `i32` discriminant while enums with data variants
+    /// write the `u32` index of the variant followed by its fields.
+    ///
+    /// ```rust
+    /// impl rid::_RidEncode for Shape {
+    ///     fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+    ///         match self {
+    ///             Self::Circle(field0) => {
+    ///                 writer.write_u32(0);
+    ///                 rid::_RidEncode::_rid_encode(field0, writer);
+    ///             }
+    ///             Self::Empty => {
+    ///                 writer.write_u32(1);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn render_wire_encode_impl(&self) -> TokenStream {
+        let ident = &self.ident;
+        let encode_tokens = if self.has_data_variants() {
+            let variant_arms = self.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let discriminant = variant.discriminant as u32;
+                let bindings: Vec<syn::Ident> = variant
+                    .fields
+                    .iter()
+                    .map(|x| match &x.ident {
+                        Some(field_ident) => field_ident.clone(),
+                        None => format_ident!("field{}", x.slot),
+                    })
+                    .collect();
+                let pattern = if variant.fields.is_empty() {
+                    quote! { Self::#variant_ident }
+                } else if variant.has_named_fields() {
+                    quote! { Self::#variant_ident { #(#bindings),* } }
+                } else {
+                    quote! { Self::#variant_ident(#(#bindings),*) }
+                };
+                quote! {
+                    #pattern => {
+                        writer.write_u32(#discriminant);
+                        #(rid::_RidEncode::_rid_encode(#bindings, writer);)*
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#variant_arms)*
+                }
+            }
+        } else {
+            quote! { writer.write_i32(self._rid_into_discriminant()) }
+        };
+
+        quote_spanned! { ident.span() =>
+            impl rid::_RidEncode for #ident {
+                fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                    #encode_tokens
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Item;
+
+use crate::{
+    attrs::{EnumConfig, StructConfig},
+    parse::{ParsedEnum, ParsedStruct},
+};
+
+fn render(input: TokenStream) -> TokenStream {
+    let item = syn::parse2::<syn::Item>(input).unwrap();
+    match item {
+        Item::Struct(item) => {
+            ParsedStruct::new(&item, &item.ident, StructConfig::from(&item))
+                .render_wire_encode_impl()
+        }
+        Item::Enum(item) => ParsedEnum::from(&item, EnumConfig::from(&item))
+            .render_wire_encode_impl(),
+        _ => panic!(
+            "Unexpected item, we're trying to parse structs and enums here"
+        ),
+    }
+}
+
+mod structs {
+    use super::*;
+
+    #[test]
+    fn render_named_struct() {
+        let res = render(quote! {
+            struct Point { x: i32, y: i32 }
+        });
+        let expected = quote! {
+            impl rid::_RidEncode for Point {
+                fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                    rid::_RidEncode::_rid_encode(&self.x, writer);
+                    rid::_RidEncode::_rid_encode(&self.y, writer);
+                }
+            }
+        };
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn render_tuple_struct() {
+        let res = render(quote! {
+            struct Pair(u8, String);
+        });
+        let expected = quote! {
+            impl rid::_RidEncode for Pair {
+                fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                    rid::_RidEncode::_rid_encode(&self.0, writer);
+                    rid::_RidEncode::_rid_encode(&self.1, writer);
+                }
+            }
+        };
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+}
+
+mod enums {
+    use super::*;
+
+    #[test]
+    fn render_c_style_enum() {
+        let res = render(quote! {
+            enum Color { Red, Green }
+        });
+        let expected = quote! {
+            impl rid::_RidEncode for Color {
+                fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                    writer.write_i32(self._rid_into_discriminant())
+                }
+            }
+        };
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn render_data_enum() {
+        let res = render(quote! {
+            enum Shape {
+                Circle(u32),
+                Rect { width: u32, height: u32 },
+                Empty,
+            }
+        });
+        let expected = quote! {
+            impl rid::_RidEncode for Shape {
+                fn _rid_encode(&self, writer: &mut rid::_RidWireWriter) {
+                    match self {
+                        Self::Circle(field0) => {
+                            writer.write_u32(0u32);
+                            rid::_RidEncode::_rid_encode(field0, writer);
+                        }
+                        Self::Rect { width, height } => {
+                            writer.write_u32(1u32);
+                            rid::_RidEncode::_rid_encode(width, writer);
+                            rid::_RidEncode::_rid_encode(height, writer);
+                        }
+                        Self::Empty => {
+                            writer.write_u32(2u32);
+                        }
+                    }
+                }
+            }
+        };
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+}
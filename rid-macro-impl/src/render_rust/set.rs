@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use quote::{quote, quote_spanned};
+
+use crate::{
+    accesses::{AccessKind, RenderedAccessRust, SetAccess},
+    common::abort,
+    render_rust::{
+        hash_map::render_custom_type_attrs, render_free, RenderedFree,
+    },
+};
+
+impl SetAccess {
+    pub fn render_rust_field_access(&self) -> RenderedAccessRust {
+        if self.item_type.is_composite() {
+            abort!(
+                self.item_type.rust_ident(),
+                "Set items cannot be collections or Options"
+            )
+        }
+        let item_ty = self.item_type.rust_ident();
+        let set_ty = self.set_type.render_owned_rust_type();
+
+        let custom_type_attrs = render_custom_type_attrs(&[&self.item_type]);
+
+        // -----------------
+        // Set::len()
+        // -----------------
+        let fn_len_ident = &self.fn_len_ident;
+        let len_impl = quote_spanned! { fn_len_ident.span() =>
+            #[rid::export]
+            #custom_type_attrs
+            fn #fn_len_ident(set: &#set_ty) -> usize {
+                set.len()
+            }
+        };
+
+        // -----------------
+        // Set::contains(&item)
+        // -----------------
+        let fn_contains_ident = &self.fn_contains_ident;
+        let contains_impl = quote_spanned! { fn_contains_ident.span() =>
+            #[rid::export]
+            #custom_type_attrs
+            fn #fn_contains_ident(set: &#set_ty, item: #item_ty) -> bool {
+                set.contains(&item)
+            }
+        };
+
+        // -----------------
+        // Set::iter()
+        // -----------------
+        // Items of a BTreeSet are returned in order.
+        let fn_items_ident = &self.fn_items_ident;
+        let items_impl = quote_spanned! { fn_items_ident.span() =>
+            #[rid::export]
+            #custom_type_attrs
+            fn #fn_items_ident(set: &#set_ty) -> Vec<&#item_ty> {
+                set.iter().collect()
+            }
+        };
+
+        let tokens = quote! {
+            #len_impl
+            #contains_impl
+            #items_impl
+        };
+        RenderedAccessRust {
+            tokens,
+            type_aliases: HashMap::new(),
+        }
+    }
+
+    /// Sets returned from a method are looked up via the accessors rendered for
+    /// [AccessKind::FieldReference], thus only the method to free them is rendered here.
+    pub fn render_rust_method_return(&self) -> RenderedAccessRust {
+        let RenderedFree {
+            tokens,
+            type_alias: _,
+        } = render_free(
+            &self.set_type,
+            &self.fn_free_ident,
+            &self.rust_ffi_prelude,
+            &AccessKind::MethodReturn,
+        );
+        RenderedAccessRust {
+            tokens,
+            type_aliases: HashMap::new(),
+        }
+    }
+}
@@ -13,7 +13,9 @@ use crate::{
     common::{abort, tokens::resolve_vec_ptr},
     parse::rust_type::RustType,
     render_common::PointerTypeAlias,
-    render_rust::{render_return_type, render_to_return_type},
+    render_rust::{
+        render_return_type, render_to_return_type, RenderedCompositeItem,
+    },
 };
 
 use super::{render_free, RenderedFree};
@@ -78,13 +80,14 @@ impl VecAccess {
     pub fn render_rust_field_access(&self) -> RenderedAccessRust {
         let ffi_prelude = &self.rust_ffi_prelude;
 
-        let item_ty = self.item_type.rust_ident();
-        let resolve_vec = resolve_vec_ptr(&item_ty);
+        let item_ty = self.item_type.render_owned_rust_type();
+        let vec_ty = self.vec_type.render_owned_rust_type();
+        let resolve_vec = resolve_vec_ptr(&vec_ty);
 
         let fn_len_ident = &self.fn_len_ident;
         let len_impl = quote_spanned! { fn_len_ident.span() =>
             #ffi_prelude
-            fn #fn_len_ident(ptr: *mut Vec<#item_ty>) -> usize {
+            fn #fn_len_ident(ptr: *mut #vec_ty) -> usize {
                 #resolve_vec.len()
             }
         };
@@ -94,7 +97,7 @@ impl VecAccess {
         let get_impl = if self.item_type.is_struct() {
             quote_spanned! { fn_get_ident.span() =>
                 #ffi_prelude
-                fn #fn_get_ident(ptr: *mut Vec<#item_ty>, idx: usize) -> *const #item_ty  {
+                fn #fn_get_ident(ptr: *mut #vec_ty, idx: usize) -> *const #item_ty  {
                     let item = #resolve_vec
                         .get(idx)
                         .expect(&format!("Failed to access {fn_get_ident}({idx})",
@@ -107,7 +110,7 @@ impl VecAccess {
         } else if self.item_type.is_enum() {
             quote_spanned! { fn_get_ident.span() =>
                 #ffi_prelude
-                fn #fn_get_ident(ptr: *mut Vec<#item_ty>, idx: usize) -> i32  {
+                fn #fn_get_ident(ptr: *mut #vec_ty, idx: usize) -> i32  {
                     let item = #resolve_vec
                         .get(idx)
                         .expect(&format!("Failed to access {fn_get_ident}({idx})",
@@ -135,7 +138,7 @@ impl VecAccess {
 
             quote_spanned! { fn_get_ident.span() =>
                 #ffi_prelude
-                fn #fn_get_ident(ptr: *mut Vec<#item_ty>, idx: usize) -> #return_ty  {
+                fn #fn_get_ident(ptr: *mut #vec_ty, idx: usize) -> #return_ty  {
                     let #res_ident = #resolve_vec
                         .get(idx)
                         .expect(&format!("Failed to access {fn_get_ident}({idx})",
@@ -149,7 +152,7 @@ impl VecAccess {
         } else if self.item_type.is_primitive() {
             quote_spanned! { fn_get_ident.span() =>
                 #ffi_prelude
-                fn #fn_get_ident(ptr: *mut Vec<#item_ty>, idx: usize) -> #item_ty  {
+                fn #fn_get_ident(ptr: *mut #vec_ty, idx: usize) -> #item_ty  {
                     let item = #resolve_vec
                         .get(idx)
                         .expect(&format!("Failed to access {fn_get_ident}({idx})",
@@ -159,8 +162,27 @@ impl VecAccess {
                     *item
                 }
             }
+        } else if self.item_type.is_composite() {
+            let RenderedCompositeItem {
+                return_type,
+                into_return,
+            } = self
+                .item_type
+                .render_composite_item_to_return(&quote! { item });
+            quote_spanned! { fn_get_ident.span() =>
+                #ffi_prelude
+                fn #fn_get_ident(ptr: *mut #vec_ty, idx: usize) -> #return_type  {
+                    let item = #resolve_vec
+                        .get(idx)
+                        .expect(&format!("Failed to access {fn_get_ident}({idx})",
+                            fn_get_ident = #fn_get_ident_str_tokens,
+                            idx = idx
+                        ));
+                    #into_return
+                }
+            }
         } else {
-            abort!(item_ty, "Vec types other than owned structs and primitives are not supported yet.");
+            abort!(item_ty, "Vec types other than owned structs, primitives and collections are not supported yet.");
         };
         let tokens = quote! {
             #len_impl
@@ -193,6 +215,24 @@ fn render_vec_access_item(
         ..
     } = render_return_type(outer_type, access_kind);
 
+    // Composite items are owned by the RidVec and thus passed as a reference into it
+    if item_type.is_composite() {
+        let RenderedCompositeItem {
+            return_type,
+            into_return,
+        } = item_type.render_composite_item_to_return(&quote! { (&vec[idx]) });
+        let tokens = quote_spanned! { fn_access_ident.span() =>
+            #ffi_prelude
+            fn #fn_access_ident(vec: #vec_arg_type, idx: usize) -> #return_type {
+                #into_return
+            }
+        };
+        return RenderedVecAccessItem {
+            tokens,
+            type_alias: None,
+        };
+    }
+
     let CollectionItemAccessTokens {
         item_return_type,
         into_return_type,
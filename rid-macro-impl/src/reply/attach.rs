@@ -17,18 +17,21 @@ use super::{
 pub fn rid_ffi_reply_impl(item: &Item, _: &[NestedMeta]) -> TokenStream {
     match item {
         Item::Enum(enum_item) => {
+            let enum_config = EnumConfig::from(enum_item);
             let reply_variants: Vec<ReplyVariant> = enum_item
                 .variants
                 .iter()
                 .enumerate()
-                .map(|(slot, x)| ReplyVariant::new(slot, x))
+                .map(|(slot, x)| {
+                    ReplyVariant::new(slot, x, &enum_config.type_infos)
+                })
                 .collect();
 
             let into_dart =
                 render_reply_into_dart(&enum_item.ident, &reply_variants);
-            let enum_config = EnumConfig::from(&enum_item);
             let parsed_enum = ParsedEnum::from(&enum_item, enum_config);
-            let reply_dart = render_reply_dart(&parsed_enum, "///");
+            let reply_dart =
+                render_reply_dart(&parsed_enum, &reply_variants, "///");
 
             let utils_module = utils_module_tokens();
             quote_spanned! { enum_item.ident.span() =>
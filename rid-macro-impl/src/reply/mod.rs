@@ -3,3 +3,6 @@ mod render_reply_into_dart;
 mod reply_variant;
 mod render_reply_dart;
 pub use attach::*;
+
+#[cfg(test)]
+mod reply_test;
@@ -3,15 +3,20 @@ use rid_common::{RID_DEBUG_REPLY, _RID_REPLY_CHANNEL};
 use syn::{punctuated::Punctuated, ItemEnum, Token, Variant};
 
 use crate::{
+    attrs::TypeInfoMap,
     common::prefixes::reply_class_name_for_enum,
     parse::{
         rust_type::{RustType, TypeKind, Value},
         ParsedEnum, ParsedReference,
     },
+    render_dart::RenderDartTypeOpts,
 };
 
+use super::reply_variant::ReplyVariant;
+
 pub fn render_reply_dart(
     parsed_enum: &ParsedEnum,
+    reply_variants: &[ReplyVariant],
     comment: &str,
 ) -> TokenStream {
     let rust_type = RustType::from_owned_enum(&parsed_enum.ident);
@@ -21,6 +26,36 @@ pub fn render_reply_dart(
     let class_name = reply_class_name_for_enum(&dart_enum_name);
     let posted_reply_type = reply_class_name_for_enum(&dart_enum_name);
 
+    let variant_classes = reply_variants
+        .iter()
+        .filter(|x| !x.fields.is_empty())
+        .map(|x| {
+            render_variant_class(
+                x,
+                &class_name,
+                &dart_enum_name,
+                parsed_enum.type_infos(),
+                comment,
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let decode_cases = reply_variants
+        .iter()
+        .filter(|x| !x.fields.is_empty())
+        .map(|x| render_decode_case(x, &class_name, &dart_enum_name, comment))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let is_error = render_is_type(
+        reply_variants.iter().filter(|x| x.is_error),
+        &dart_enum_name,
+    );
+    let is_progress = render_is_type(
+        reply_variants.iter().filter(|x| x.is_progress),
+        &dart_enum_name,
+    );
+
     let rendered_reply_class = format!(
         r###"
 {comment} ```dart
@@ -33,6 +68,12 @@ pub fn render_reply_dart(
 {comment}   {class_name}._(this.type, this.reqId, this.data);
 {comment} 
 {comment}   @override
+{comment}   bool get isError => {is_error};
+{comment} 
+{comment}   @override
+{comment}   bool get isProgress => {is_progress};
+{comment} 
+{comment}   @override
 {comment}   String toString() {{
 {comment}     return '''{class_name} {{
 {comment}   type:  ${{this.type.toString().substring('{enum}.'.length)}}
@@ -42,6 +83,7 @@ pub fn render_reply_dart(
 {comment} ''';
 {comment}   }}
 {comment} }}
+{variant_classes}
 {comment} 
 {comment} void Function({PostedReply})? _RID_DEBUG_REPLY = ({PostedReply} reply) {{
 {comment}   print('$reply');
@@ -53,16 +95,15 @@ pub fn render_reply_dart(
 {comment}   void set debugReply(void Function({PostedReply})? val) => _RID_DEBUG_REPLY = val;
 {comment} }}
 {comment}
-{comment} const int _TYPE_MASK= 0x000000000000ffff;
-{comment} const int _I64_MIN = -9223372036854775808;
-{comment} 
-{comment} {class_name} decode(int packed, String? data) {{
-{comment}   final ntype = packed & _TYPE_MASK;
-{comment}   final id = (packed - _I64_MIN) >> 16;
-{comment}   final reqId = id > 0 ? id : null;
-{comment} 
-{comment}   final type = {enum}.values[ntype];
-{comment}   return {class_name}._(type, reqId, data);
+{comment} {class_name} decode(RidFrame frame) {{
+{comment}   final type = {enum}.values[frame.variant];
+{comment}   final reqId = frame.reqId;
+{comment}   final r = frame.payload;
+{comment}   switch (type) {{
+{decode_cases}
+{comment}     default:
+{comment}       return {class_name}._(type, reqId, null);
+{comment}   }}
 {comment} }}
 {comment} 
 {comment} final RidReplyChannelInternal<{class_name}> _replyChannel = RidReplyChannelInternal.instance(_dl, decode, _isDebugMode);
@@ -77,6 +118,10 @@ pub fn render_reply_dart(
         comment = comment,
         enum = dart_enum_name,
         class_name = class_name,
+        variant_classes = variant_classes,
+        decode_cases = decode_cases,
+        is_error = is_error,
+        is_progress = is_progress,
     );
 
     format!(
@@ -92,3 +137,130 @@ pub fn render_reply_dart(
     .parse()
     .unwrap()
 }
+
+/// Renders the condition checking if the type of a reply is one of the provided variants, i.e.
+/// `type == Reply.Failed || type == Reply.Rejected`.
+fn render_is_type<'a>(
+    variants: impl Iterator<Item = &'a ReplyVariant>,
+    dart_enum_name: &str,
+) -> String {
+    let conditions = variants
+        .map(|x| format!("type == {}.{}", dart_enum_name, x.ident))
+        .collect::<Vec<String>>();
+    if conditions.is_empty() {
+        "false".to_string()
+    } else {
+        conditions.join(" || ")
+    }
+}
+
+/// Renders a subclass of the reply class which exposes the data of a reply variant as typed
+/// fields, i.e. `PostedReplyAddedTodo` for `AddedTodo(u64, Todo)`.
+fn render_variant_class(
+    variant: &ReplyVariant,
+    class_name: &str,
+    dart_enum_name: &str,
+    type_infos: &TypeInfoMap,
+    comment: &str,
+) -> String {
+    let variant_class_name = format!("{}{}", class_name, variant.ident);
+    let field_declarations = variant
+        .fields
+        .iter()
+        .map(|x| {
+            format!(
+                "{comment}   final {ty} {name};",
+                ty = x
+                    .rust_ty
+                    .render_dart_type(type_infos, RenderDartTypeOpts::plain()),
+                name = x.dart_name(),
+                comment = comment
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let constructor_fields = variant
+        .fields
+        .iter()
+        .map(|x| format!(", this.{}", x.dart_name()))
+        .collect::<Vec<String>>()
+        .join("");
+    // Single String data is also exposed via `data` as it was before replies supported typed fields
+    let data = if variant.has_string_data() {
+        variant.fields[0].dart_name()
+    } else {
+        "null".to_string()
+    };
+    let to_string_fields = variant
+        .fields
+        .iter()
+        .map(|x| {
+            format!(
+                "{comment}   {name}: ${{this.{name}}}\n",
+                name = x.dart_name(),
+                comment = comment
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+
+    format!(
+        r###"{comment}
+{comment} class {variant_class_name} extends {class_name} {{
+{field_declarations}
+{comment}
+{comment}   {variant_class_name}._({enum} type, int? reqId{constructor_fields})
+{comment}       : super._(type, reqId, {data});
+{comment}
+{comment}   @override
+{comment}   String toString() {{
+{comment}     return '''{variant_class_name} {{
+{comment}   type:  ${{this.type.toString().substring('{enum}.'.length)}}
+{comment}   reqId: $reqId
+{to_string_fields}{comment} }}
+{comment} ''';
+{comment}   }}
+{comment} }}"###,
+        variant_class_name = variant_class_name,
+        class_name = class_name,
+        enum = dart_enum_name,
+        field_declarations = field_declarations,
+        constructor_fields = constructor_fields,
+        data = data,
+        to_string_fields = to_string_fields,
+        comment = comment
+    )
+}
+
+/// Renders the case which decodes the data of a reply variant from the rid wire format.
+fn render_decode_case(
+    variant: &ReplyVariant,
+    class_name: &str,
+    dart_enum_name: &str,
+    comment: &str,
+) -> String {
+    let decode_fields = variant
+        .fields
+        .iter()
+        .map(|x| {
+            format!(
+                "{comment}           {decode},\n",
+                decode = x.rust_ty.render_dart_wire_decode(),
+                comment = comment
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    format!(
+        r###"{comment}     case {enum}.{variant}:
+{comment}       return {class_name}{variant}._(
+{comment}           type,
+{comment}           reqId,
+{decode_fields}{comment}       );"###,
+        enum = dart_enum_name,
+        variant = variant.ident,
+        class_name = class_name,
+        decode_fields = decode_fields,
+        comment = comment
+    )
+}
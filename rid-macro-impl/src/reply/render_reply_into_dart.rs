@@ -1,8 +1,12 @@
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote_spanned};
+use quote::{format_ident, quote, quote_spanned};
 
 use super::reply_variant::ReplyVariant;
 
+/// Renders the conversion of a reply into the data posted to Dart.
+///
+/// A reply is posted as a frame whose header holds the variant index and request id, followed by
+/// the data fields encoded into the rid wire format.
 pub fn render_reply_into_dart(
     enum_ident: &syn::Ident,
     variants: &[ReplyVariant],
@@ -13,10 +17,10 @@ pub fn render_reply_into_dart(
         impl rid::_allo_isolate::IntoDart for #enum_ident {
             fn into_dart(self) -> rid::_allo_isolate::ffi::DartCObject {
                 use #enum_ident::*;
-                let (base, data): (i64, String) = match self {
+                let __rid_frame: rid::_RidFrame = match self {
                     #(#variant_tokens)*
                 };
-                format!("{}{}{}", base, "^", data).into_dart()
+                rid::_allo_isolate::IntoDart::into_dart(__rid_frame)
             }
         }
     }
@@ -24,19 +28,54 @@ pub fn render_reply_into_dart(
 
 fn render_variant(variant: &ReplyVariant) -> TokenStream {
     let ident = &variant.ident;
-    let slot = variant.slot as i64;
-    match (variant.has_req_id, variant.has_data) {
-        (true, true) => quote_spanned! { ident.span() =>
-            #ident(id, s) => (rid::_encode_with_id(#slot, id), s.into()),
-        },
-        (true, false) => quote_spanned! { ident.span() =>
-            #ident(id) => (rid::_encode_with_id(#slot, id), "".into()),
-        },
-        (false, true) => quote_spanned! { ident.span() =>
-            #ident(s) => (rid::_encode_without_id(#slot), s.into()),
-        },
-        _ => quote_spanned! {ident.span() =>
-            #ident => (rid::_encode_without_id(#slot), "".into()),
-        },
+    let slot = variant.slot as u32;
+
+    let req_id_ident = if variant.is_named {
+        format_ident!("req_id")
+    } else {
+        format_ident!("__rid_req_id")
+    };
+    let req_id_binding = if variant.has_req_id {
+        Some(&req_id_ident)
+    } else {
+        None
+    };
+    let bindings: Vec<syn::Ident> = req_id_binding
+        .into_iter()
+        .cloned()
+        .chain(variant.fields.iter().map(|x| x.binding()))
+        .collect();
+
+    let pattern = if bindings.is_empty() {
+        quote! { #ident }
+    } else if variant.is_named {
+        quote! { #ident { #(#bindings),* } }
+    } else {
+        quote! { #ident(#(#bindings),*) }
+    };
+
+    let encode_fields = variant.fields.iter().map(|x| {
+        let binding = x.binding();
+        quote! { rid::_RidEncode::_rid_encode(&#binding, __rid_frame.writer()); }
+    });
+
+    let req_id = if variant.has_req_id {
+        quote! { Some(#req_id_ident) }
+    } else {
+        quote! { None }
+    };
+
+    if variant.fields.is_empty() {
+        quote_spanned! { ident.span() =>
+            #pattern => rid::_RidFrame::new(#slot, #req_id),
+        }
+    } else {
+        quote_spanned! { ident.span() =>
+            #pattern => {
+                let mut __rid_frame = rid::_RidFrame::new(#slot, #req_id);
+                #(#encode_fields)*
+                __rid_frame
+            }
+        }
     }
 }
@@ -0,0 +1,179 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ItemEnum;
+
+use crate::attrs::EnumConfig;
+
+use super::{
+    render_reply_into_dart::render_reply_into_dart, reply_variant::ReplyVariant,
+};
+
+fn parse(input: TokenStream) -> (ItemEnum, Vec<ReplyVariant>) {
+    let item = syn::parse2::<ItemEnum>(input).unwrap();
+    let enum_config = EnumConfig::from(&item);
+    let variants = item
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(slot, x)| ReplyVariant::new(slot, x, &enum_config.type_infos))
+        .collect();
+    (item, variants)
+}
+
+fn render(input: TokenStream) -> TokenStream {
+    let (item, variants) = parse(input);
+    render_reply_into_dart(&item.ident, &variants)
+}
+
+fn expected_into_dart(variant_tokens: TokenStream) -> TokenStream {
+    quote! {
+        impl rid::_allo_isolate::IntoDart for Reply {
+            fn into_dart(self) -> rid::_allo_isolate::ffi::DartCObject {
+                use Reply::*;
+                let __rid_frame: rid::_RidFrame = match self {
+                    #variant_tokens
+                };
+                rid::_allo_isolate::IntoDart::into_dart(__rid_frame)
+            }
+        }
+    }
+}
+
+mod reply_variants {
+    use super::*;
+
+    #[test]
+    fn string_and_req_id_variants() {
+        let (_, variants) = parse(quote! {
+            pub enum Reply {
+                Started,
+                Stopped(u64),
+                Logged(String),
+                Added(u64, String),
+            }
+        });
+        let shapes: Vec<(bool, usize, bool)> = variants
+            .iter()
+            .map(|x| (x.has_req_id, x.fields.len(), x.has_string_data()))
+            .collect();
+        assert_eq!(
+            shapes,
+            vec![
+                (false, 0, false),
+                (true, 0, false),
+                (false, 1, true),
+                (true, 1, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn typed_variants() {
+        let (_, variants) = parse(quote! {
+            #[rid::structs(Todo)]
+            #[rid::enums(Filter)]
+            pub enum Reply {
+                Loaded(u64, Vec<Todo>, Filter),
+                Counted { req_id: u64, total: usize, label: Option<String> },
+                Noticed { todo: Todo },
+            }
+        });
+        let fields: Vec<(bool, Vec<String>)> = variants
+            .iter()
+            .map(|x| {
+                (
+                    x.has_req_id,
+                    x.fields.iter().map(|x| x.dart_name()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                (true, vec!["field0".to_string(), "field1".to_string()]),
+                (true, vec!["total".to_string(), "label".to_string()]),
+                (false, vec!["todo".to_string()]),
+            ]
+        );
+        assert!(!variants[1].has_string_data(), "two fields are not data");
+    }
+
+    #[test]
+    fn error_and_progress_variants() {
+        let (_, variants) = parse(quote! {
+            #[rid::structs(Todo)]
+            pub enum Reply {
+                Added(u64, String),
+                #[rid(error)]
+                Failed(u64, String),
+                #[rid(error)]
+                InvalidTodo { req_id: u64, todo: Todo },
+                #[rid(progress)]
+                Importing(u64, u32),
+            }
+        });
+        let kinds: Vec<(String, bool, bool)> = variants
+            .iter()
+            .map(|x| (x.ident.to_string(), x.is_error, x.is_progress))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("Added".to_string(), false, false),
+                ("Failed".to_string(), true, false),
+                ("InvalidTodo".to_string(), true, false),
+                ("Importing".to_string(), false, true),
+            ]
+        );
+    }
+}
+
+mod render_into_dart {
+    use super::*;
+
+    #[test]
+    fn string_and_req_id_variants() {
+        let res = render(quote! {
+            pub enum Reply {
+                Started,
+                Stopped(u64),
+                Added(u64, String),
+            }
+        });
+        let expected = expected_into_dart(quote! {
+            Started => rid::_RidFrame::new(0u32, None),
+            Stopped(__rid_req_id) => rid::_RidFrame::new(1u32, Some(__rid_req_id)),
+            Added(__rid_req_id, field0) => {
+                let mut __rid_frame = rid::_RidFrame::new(2u32, Some(__rid_req_id));
+                rid::_RidEncode::_rid_encode(&field0, __rid_frame.writer());
+                __rid_frame
+            }
+        });
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn typed_variants() {
+        let res = render(quote! {
+            #[rid::structs(Todo)]
+            pub enum Reply {
+                Loaded(u64, Vec<Todo>, u8),
+                Counted { req_id: u64, total: usize },
+            }
+        });
+        let expected = expected_into_dart(quote! {
+            Loaded(__rid_req_id, field0, field1) => {
+                let mut __rid_frame = rid::_RidFrame::new(0u32, Some(__rid_req_id));
+                rid::_RidEncode::_rid_encode(&field0, __rid_frame.writer());
+                rid::_RidEncode::_rid_encode(&field1, __rid_frame.writer());
+                __rid_frame
+            }
+            Counted { req_id, total } => {
+                let mut __rid_frame = rid::_RidFrame::new(1u32, Some(req_id));
+                rid::_RidEncode::_rid_encode(&total, __rid_frame.writer());
+                __rid_frame
+            }
+        });
+        assert_eq!(res.to_string(), expected.to_string());
+    }
+}
@@ -1,76 +1,181 @@
-use syn::{Field, Variant};
+use heck::MixedCase;
+use quote::format_ident;
+use syn::{Field, Fields, Variant};
 
 use crate::{
+    attrs::{parse_rid_attrs, RidAttr, TypeInfoMap},
     common::abort,
-    parse::rust_type::{self, Primitive, RustType, Value},
+    parse::rust_type::{self, Primitive, RustType, TypeKind, Value},
 };
 
+/// Names of the fields of the generated Dart reply class which thus cannot be used as the names
+/// of reply fields.
+const RESERVED_DART_FIELD_NAMES: [&str; 3] = ["type", "reqId", "data"];
+
+/// Name of the field holding the request id for struct variants, i.e. `Loaded { req_id: u64 }`.
+const REQ_ID_FIELD: &str = "req_id";
+
 pub struct ReplyVariant {
     pub ident: syn::Ident,
     pub slot: usize,
     pub has_req_id: bool,
-    pub has_data: bool,
+    /// `true` for struct variants, i.e. `Loaded { req_id: u64, todos: Vec<Todo> }`
+    pub is_named: bool,
+    /// `true` if the variant is marked with `#[rid(error)]` in which case the Dart Future awaiting
+    /// the reply completes with a `RidReplyException`
+    pub is_error: bool,
+    /// `true` if the variant is marked with `#[rid(progress)]` in which case it is added to the
+    /// `progress` stream of the Dart `RidRequest` instead of completing it
+    pub is_progress: bool,
+    /// The fields carrying the data of the reply, not including the request id
+    pub fields: Vec<ReplyVariantField>,
 }
 
-// There are only four versions of a reply variant which simplifies parsing a lot.
+pub struct ReplyVariantField {
+    /// The name of the field for struct variants, `None` for tuple variants
+    pub ident: Option<syn::Ident>,
+    pub rust_ty: RustType,
+    /// The slot (starting with 0) of the field among the data fields of the reply
+    pub slot: usize,
+}
+
+// A reply variant optionally carries the id of the request it responds to, followed by any
+// number of data fields.
+//
 // No fields:                         Foo
 // With RequestId field:              Foo(u64)
-// With RequestId and data fields:    Foo(u64, String)
-// With data field:                   Foo(String)
+// With RequestId and data fields:    Foo(u64, String, Vec<Todo>)
+// With data fields:                  Foo(String)
+// As struct variant:                 Foo { req_id: u64, todo: Todo }
+//
+// For tuple variants the first field is the RequestId if it is a u64, for struct variants it is
+// the field named `req_id` which needs to be a u64.
+// Variants marked with `#[rid(error)]` signal that the request failed and variants marked with
+// `#[rid(progress)]` report progress before the final reply is posted. Both need a RequestId.
+// Data fields can be of any type that can be encoded into the rid wire format, i.e. primitives,
+// strings, #[rid::model] structs and enums as well as collections of those.
 impl ReplyVariant {
-    pub fn new(slot: usize, variant: &Variant) -> Self {
-        let field_vec: Vec<&Field> = variant.fields.iter().collect();
+    pub fn new(slot: usize, variant: &Variant, types: &TypeInfoMap) -> Self {
         let ident = variant.ident.clone();
-        if field_vec.is_empty() {
-            ReplyVariant {
-                ident,
-                slot,
-                has_req_id: false,
-                has_data: false,
-            }
-        } else if field_vec.len() == 1 {
-            if is_req_id_type(field_vec[0]) {
-                ReplyVariant {
-                    ident,
-                    slot,
-                    has_req_id: true,
-                    has_data: false,
-                }
-            } else if is_data_type(field_vec[0]) {
-                ReplyVariant {
-                    ident,
-                    slot,
-                    has_req_id: false,
-                    has_data: true,
-                }
-            } else {
-                abort!(
-                    ident,
-                    "For replies with a single field it needs to be a u64 or String, i.e. 'Started(u64) or Started(String)'"
-                )
-            }
-        } else if field_vec.len() == 2 {
-            if !is_req_id_type(field_vec[0]) {
-                abort!(
-                    ident,
-                    "For replies with two fields the first field needs to be a u64, i.e. 'Started(u64, String)'"
-                )
+        let is_named = matches!(variant.fields, Fields::Named(_));
+        let field_vec: Vec<&Field> = variant.fields.iter().collect();
+
+        let is_req_id_field =
+            |f: &Field| matches!(&f.ident, Some(x) if x == REQ_ID_FIELD);
+        let has_req_id = if is_named {
+            match field_vec.iter().find(|f| is_req_id_field(f)) {
+                Some(req_id) if is_req_id_type(req_id) => true,
+                Some(req_id) => abort!(
+                    req_id.ty,
+                    "The req_id field of a reply needs to be a u64, i.e. 'Started {{ req_id: u64, .. }}'"
+                ),
+                None => false,
             }
-            if !is_data_type(field_vec[1]) {
+        } else {
+            matches!(field_vec.first(), Some(x) if is_req_id_type(x))
+        };
+
+        let rid_attrs = parse_rid_attrs(&variant.attrs);
+        let is_error = rid_attrs.iter().any(RidAttr::has_error);
+        let is_progress = rid_attrs.iter().any(RidAttr::has_progress);
+        if is_error && !has_req_id {
+            abort!(
+                ident,
+                "[rid] error replies need to include the id of the failed request, i.e. '#[rid(error)] Failed(u64, String)'"
+            )
+        }
+        if is_progress && !has_req_id {
+            abort!(
+                ident,
+                "[rid] progress replies need to include the id of the request they report on, i.e. '#[rid(progress)] Importing(u64, u32)'"
+            )
+        }
+        if is_error && is_progress {
+            abort!(
+                ident,
+                "[rid] a reply cannot be both an error and a progress reply"
+            )
+        }
+
+        let data_fields: Vec<&Field> = if !has_req_id {
+            field_vec
+        } else if is_named {
+            field_vec
+                .into_iter()
+                .filter(|f| !is_req_id_field(f))
+                .collect()
+        } else {
+            field_vec.into_iter().skip(1).collect()
+        };
+        let fields = data_fields
+            .iter()
+            .enumerate()
+            .map(|(slot, f)| ReplyVariantField::new(f, slot, types))
+            .collect();
+
+        ReplyVariant {
+            ident,
+            slot,
+            has_req_id,
+            is_named,
+            is_error,
+            is_progress,
+            fields,
+        }
+    }
+
+    /// Returns `true` if the data of this reply consists of a single `String`.
+    /// That string is also exposed as the `data` of the generated Dart reply class.
+    pub fn has_string_data(&self) -> bool {
+        self.fields.len() == 1 && is_data_type(&self.fields[0].rust_ty)
+    }
+}
+
+impl ReplyVariantField {
+    fn new(f: &Field, slot: usize, types: &TypeInfoMap) -> Self {
+        if let Some(ident) = &f.ident {
+            let dart_name = ident.to_string().to_mixed_case();
+            if RESERVED_DART_FIELD_NAMES.contains(&dart_name.as_str()) {
                 abort!(
                     ident,
-                    "For reply with two fields the second field needs to be a String, i.e. 'Started(u64, String)'"
+                    "[rid] reply fields cannot be named '{}' as it is used by the generated Dart reply class",
+                    ident
                 )
             }
+        }
+        let rust_ty = match RustType::from_type(&f.ty, types) {
+            Some(x) => x,
+            None => abort!(f.ty, "invalid rust type"),
+        };
+        if rust_ty.kind == TypeKind::Unknown || !rust_ty.is_wire_encodable() {
+            abort!(
+                f.ty,
+                "[rid] reply fields need to be primitives, strings, #[rid::model] structs \
+                or enums as well as Vecs, Options and other collections of those.\n\
+                Custom types need to be declared via #[rid::structs(..)] or #[rid::enums(..)] on the reply enum."
+            )
+        }
+        Self {
+            ident: f.ident.clone(),
+            rust_ty,
+            slot,
+        }
+    }
 
-            ReplyVariant {
-                ident,
-                slot,
-                has_req_id: true,
-                has_data: true,
-            }
-        } else {
-            abort!(ident.span(), "Only specific forms of reply are valid, i.e. 'Started | Started(u64) | Started(String) | Started(u64, String)'")
+    /// Name of the field in the generated Dart reply class, i.e. the lower camel cased field name
+    /// for struct variants and `field0` for tuple variants.
+    pub fn dart_name(&self) -> String {
+        match &self.ident {
+            Some(ident) => ident.to_string().to_mixed_case(),
+            None => format!("field{}", self.slot),
+        }
+    }
+
+    /// Name of the variable the field is bound to when encoding the reply in Rust.
+    pub fn binding(&self) -> syn::Ident {
+        match &self.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field{}", self.slot),
         }
     }
 }
@@ -86,13 +191,6 @@ fn is_req_id_type(req_id: &Field) -> bool {
     }
 }
 
-fn is_data_type(data: &Field) -> bool {
-    let rust_type = RustType::from_plain_type(&data.ty);
-    match rust_type {
-        Some(RustType {
-            kind: rust_type::TypeKind::Value(v),
-            ..
-        }) if v == Value::String => true,
-        _ => false,
-    }
+fn is_data_type(rust_type: &RustType) -> bool {
+    matches!(&rust_type.kind, TypeKind::Value(Value::String))
 }
@@ -6,8 +6,8 @@ use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
 
 use rid_macro_impl::{
-    rid_display_impl, rid_export_impl, rid_ffi_model_impl, rid_ffi_reply_impl,
-    rid_message_impl,
+    render_catch_ffi_panics, rid_display_impl, rid_export_impl,
+    rid_ffi_model_impl, rid_ffi_reply_impl, rid_message_impl,
 };
 use syn::{self, parse_macro_input};
 
@@ -18,12 +18,23 @@ const RID_PRINT_REPLY: &str = "RID_PRINT_REPLY";
 // -----------------
 // #[rid::store]
 // -----------------
+/// Exposes the annotated `Store` struct to Dart.
+///
+/// For each field `x` setters `x_mut()` and `set_x(..)` are generated which mark that field as
+/// changed. The changed fields are posted to Dart after each update, surfaced via
+/// `Store.changes`, and used by `RidBuilder` to decide which widgets to rebuild.
+/// Fields modified without these setters are not marked. If an update mutably accessed the store
+/// without marking any field then all fields are reported as changed.
+///
+/// Use `#[rid::store(snapshot)]` to publish an immutable snapshot of the store after each update
+/// which Dart reads without blocking updates.
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn store(_attrs: TokenStream, input: TokenStream) -> TokenStream {
+pub fn store(attrs: TokenStream, input: TokenStream) -> TokenStream {
     let item = parse_macro_input!(input as syn::Item);
-    let item_and_impl = rid_ffi_model_impl(&item, true);
-    item_and_impl.into()
+    let args = parse_macro_input!(attrs as syn::AttributeArgs);
+    let item_and_impl = rid_ffi_model_impl(&item, &args, true);
+    render_catch_ffi_panics(item_and_impl).into()
 }
 
 // -----------------
@@ -31,15 +42,17 @@ pub fn store(_attrs: TokenStream, input: TokenStream) -> TokenStream {
 // -----------------
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn model(_attrs: TokenStream, input: TokenStream) -> TokenStream {
+pub fn model(attrs: TokenStream, input: TokenStream) -> TokenStream {
     let item = parse_macro_input!(input as syn::Item);
+    let args = parse_macro_input!(attrs as syn::AttributeArgs);
     if let Ok(_) = env::var(RID_PRINT_MODEL) {
         eprintln!("input: {:#?}", &item);
-        rid_ffi_model_impl(&item, false);
+        eprintln!("args: {:#?}", &args);
+        rid_ffi_model_impl(&item, &args, false);
         process::exit(0)
     } else {
-        let item_and_impl = rid_ffi_model_impl(&item, false);
-        item_and_impl.into()
+        let item_and_impl = rid_ffi_model_impl(&item, &args, false);
+        render_catch_ffi_panics(item_and_impl).into()
     }
 }
 
@@ -57,7 +70,11 @@ pub fn message(attrs: TokenStream, input: TokenStream) -> TokenStream {
         rid_message_impl(&item, &args, Default::default());
         process::exit(0)
     } else {
-        let exports = rid_message_impl(&item, &args, Default::default());
+        let exports = render_catch_ffi_panics(rid_message_impl(
+            &item,
+            &args,
+            Default::default(),
+        ));
         let q = quote! {
             #[repr(C)]
             #item
@@ -81,7 +98,7 @@ pub fn reply(attrs: TokenStream, input: TokenStream) -> TokenStream {
         rid_ffi_reply_impl(&item, &args);
         process::exit(0)
     } else {
-        let impls = rid_ffi_reply_impl(&item, &args);
+        let impls = render_catch_ffi_panics(rid_ffi_reply_impl(&item, &args));
         let q = quote! {
             #[repr(C)]
             #item
@@ -100,7 +117,11 @@ pub fn export(attrs: TokenStream, input: TokenStream) -> TokenStream {
     let item = parse_macro_input!(input as syn::Item);
     let args = parse_macro_input!(attrs as syn::AttributeArgs);
     // TODO: is there any way to avoid this clone and/or is cloning the input cheaper?
-    let exports = rid_export_impl(item.clone(), args, Default::default());
+    let exports = render_catch_ffi_panics(rid_export_impl(
+        item.clone(),
+        args,
+        Default::default(),
+    ));
     let q = quote! {
         #item
         #exports
@@ -142,5 +163,5 @@ pub fn rid_attr(_input: TokenStream) -> TokenStream {
 #[proc_macro_error]
 pub fn display(input: TokenStream) -> TokenStream {
     let item = parse_macro_input!(input as syn::DeriveInput);
-    rid_display_impl(&item, Default::default()).into()
+    render_catch_ffi_panics(rid_display_impl(&item, Default::default())).into()
 }
@@ -4,15 +4,28 @@
 extern crate rid_ffi;
 extern crate rid_macro;
 pub use rid_ffi::{
-    post, RidVec, _encode_with_id, _encode_without_id, _init_msg_isolate,
-    _init_reply_isolate, _option_ref_to_pointer, _post_message,
-    allo_isolate as _allo_isolate, error, log_debug, log_info, log_warn,
-    msg_info, msg_warn, severe,
+    _DebugExportError, _DisplayExportError, _ExportErrorMessage, _RidDecode,
+    _RidEncode, _RidFfiDefault, _RidFrame, _RidMessageType, _RidWireReader,
+    _RidWireWriter, _cancel_request, _catch_panic, _catch_request_panic,
+    _clear_export_error, _clear_panic, _decode_wire_arg, _encode_wire_result,
+    _enqueue_message, _export_error_message, _export_error_ref,
+    _free_wire_result, _init_msg_isolate, _init_reply_isolate,
+    _mark_store_field_changed, _option_ref_to_pointer, _panic_flag,
+    _panic_message, _post_message, _post_store_changes, _record_message,
+    _set_export_error, _set_store_snapshot, _start_store_changes,
+    allo_isolate as _allo_isolate, error, init_logger, is_recording, log_debug,
+    log_info, log_warn, message_queue_depth, msg_info, msg_warn, post,
+    record_store_snapshot, severe, start_recording, stop_recording,
+    CancelToken, RidLogger, RidVec, RID_RECORD_MESSAGES,
 };
+#[cfg(feature = "tracing")]
+pub use rid_ffi::{init_tracing, RidTracingLayer, RID_TRACE_VARIANT};
 pub use rid_macro::*;
 
 // -----------------
 // Modules
 // -----------------
+pub mod replay;
+pub mod testing;
 mod traits;
-pub use traits::RidStore;
+pub use traits::{_RidGlobalStore, RidStore};
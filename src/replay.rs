@@ -0,0 +1,225 @@
+//! Replays a message log recorded via `rid::start_recording` or the `RID_RECORD_MESSAGES`
+//! environment variable into a fresh store, i.e. to reproduce a bug reported by QA.
+//!
+//! Stores that derive `Debug` record their final state when recording stops, i.e. when Dart
+//! frees the store. Replaying verifies that the replayed store matches it as well as any
+//! snapshot recorded via `rid::record_store_snapshot`.
+//!
+//! ```ignore
+//! let store = rid::replay::RidReplay::new("todos.ridlog").run::<Store, Msg>()?;
+//! assert_eq!(store.todos.len(), 2);
+//! ```
+//!
+//! Tests can replay a log into the global store via [crate::testing::RidTestHarness::replay].
+//!
+//! An app can ship a replay binary, i.e. `src/bin/replay.rs`, via [run_from_args]:
+//!
+//! ```ignore
+//! fn main() {
+//!     rid::replay::run_from_args::<my_app::Store, my_app::Msg>();
+//! }
+//! ```
+use std::{
+    env, error, fmt, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::{_RidDecode, testing::lock_capture, RidStore};
+
+pub use rid_ffi::{read_message_log, RidLogEntry};
+
+/// Replays the messages of a message log in the order they were recorded.
+pub struct RidReplay {
+    path: PathBuf,
+    check_snapshots: bool,
+}
+
+impl RidReplay {
+    /// Prepares replaying the message log at the given path.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            check_snapshots: true,
+        }
+    }
+
+    /// When `true` the `Debug` output of the replayed store is compared to each recorded
+    /// snapshot and replaying fails on the first mismatch.
+    /// Snapshots are checked by default.
+    pub fn check_snapshots(mut self, check_snapshots: bool) -> Self {
+        self.check_snapshots = check_snapshots;
+        self
+    }
+
+    /// Creates a fresh store via [RidStore::create], applies each recorded message with the
+    /// request id Dart sent it with and returns the resulting store.
+    ///
+    /// Replies and messages posted by the store while replaying are dropped. The store is
+    /// updated on the current thread, thus a panicking update panics here as well.
+    pub fn run<TStore, TMsg>(&self) -> Result<TStore, RidReplayError>
+    where
+        TStore: RidStore<TMsg> + fmt::Debug,
+        TMsg: _RidDecode,
+    {
+        let entries = read_message_log(&self.path)?;
+
+        // Replies would be posted to Dart otherwise which isn't running
+        let _lock = lock_capture();
+        rid_ffi::_start_capture();
+        let mut store = TStore::create();
+        let result = self.apply(&mut store, entries);
+        rid_ffi::_stop_capture();
+        result.map(|_| store)
+    }
+
+    /// Applies the recorded messages to the given store while replies and messages are
+    /// captured, see [Self::run].
+    pub(crate) fn apply<TStore, TMsg>(
+        &self,
+        store: &mut TStore,
+        entries: Vec<RidLogEntry>,
+    ) -> Result<(), RidReplayError>
+    where
+        TStore: RidStore<TMsg> + fmt::Debug,
+        TMsg: _RidDecode,
+    {
+        let mut messages = 0;
+        for (idx, entry) in entries.into_iter().enumerate() {
+            match entry {
+                RidLogEntry::Message { req_id, .. } => {
+                    // Only message entries decode into a message
+                    let msg = match entry.decode_message::<TMsg>().unwrap() {
+                        Ok(msg) => msg,
+                        Err(err) => {
+                            return Err(RidReplayError::Corrupt {
+                                entry: idx,
+                                reason: err.to_string(),
+                            })
+                        }
+                    };
+                    store.update(req_id, msg);
+                    rid_ffi::_take_replies();
+                    rid_ffi::_take_messages();
+                    messages += 1;
+                }
+                RidLogEntry::Snapshot {
+                    store: expected, ..
+                } => {
+                    if !self.check_snapshots {
+                        continue;
+                    }
+                    let actual = format!("{:#?}", &*store);
+                    if actual != expected {
+                        return Err(RidReplayError::SnapshotMismatch {
+                            messages,
+                            expected,
+                            actual,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [RidReplay::run].
+#[derive(Debug)]
+pub enum RidReplayError {
+    /// The message log could not be read.
+    Io(io::Error),
+    /// A recorded message could not be decoded, i.e. because the log is corrupt or was recorded
+    /// by a different version of the app.
+    Corrupt {
+        /// Index of the entry holding the message
+        entry: usize,
+        reason: String,
+    },
+    /// The replayed store doesn't match a recorded snapshot.
+    SnapshotMismatch {
+        /// Number of messages that were applied before the snapshot was recorded
+        messages: usize,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl From<io::Error> for RidReplayError {
+    fn from(err: io::Error) -> Self {
+        RidReplayError::Io(err)
+    }
+}
+
+impl fmt::Display for RidReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RidReplayError::Io(err) => {
+                write!(f, "Failed to read message log: {}", err)
+            }
+            RidReplayError::Corrupt { entry, reason } => {
+                write!(
+                    f,
+                    "Failed to decode message of entry {}: {}",
+                    entry, reason
+                )
+            }
+            RidReplayError::SnapshotMismatch {
+                messages,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Store does not match the snapshot recorded after {} messages\n\
+                ---- Expected ----\n{}\n---- Actual ----\n{}",
+                messages, expected, actual
+            ),
+        }
+    }
+}
+
+impl error::Error for RidReplayError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            RidReplayError::Io(err) => Some(err),
+            RidReplayError::Corrupt { .. }
+            | RidReplayError::SnapshotMismatch { .. } => None,
+        }
+    }
+}
+
+/// Entry point of a replay binary which replays the log passed as the first argument and
+/// prints the resulting store.
+///
+/// ```text
+/// replay <message-log> [--skip-snapshots]
+/// ```
+///
+/// The recorded snapshots are verified unless `--skip-snapshots` is passed.
+/// Exits with `1` if replaying fails.
+pub fn run_from_args<TStore, TMsg>()
+where
+    TStore: RidStore<TMsg> + fmt::Debug,
+    TMsg: _RidDecode,
+{
+    let args: Vec<String> = env::args().skip(1).collect();
+    let check_snapshots = !args.iter().any(|x| x == "--skip-snapshots");
+    let path = match args.iter().find(|x| !x.starts_with("--")) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: replay <message-log> [--skip-snapshots]");
+            process::exit(1);
+        }
+    };
+
+    match RidReplay::new(path)
+        .check_snapshots(check_snapshots)
+        .run::<TStore, TMsg>()
+    {
+        Ok(store) => println!("{:#?}", store),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Harness to test a `#[rid::store]` from Rust without Dart.
+//!
+//! ```ignore
+//! #[test]
+//! fn adding_todo() {
+//!     let mut harness = rid::testing::RidTestHarness::<Store, Msg, Reply>::new();
+//!
+//!     let req_id = harness.send(Msg::AddTodo("Write tests".to_string()));
+//!     assert_eq!(harness.next_reply(), Reply::AddedTodo(req_id, "1".to_string()));
+//!     assert_eq!(harness.store(|store| store.todos.len()), 1);
+//! }
+//! ```
+use std::{
+    any::{type_name, Any},
+    fmt,
+    marker::PhantomData,
+    path::Path,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use crate::{
+    _RidDecode, _RidGlobalStore,
+    replay::{read_message_log, RidReplay, RidReplayError},
+    RidStore,
+};
+
+pub use rid_ffi::{_RidMessageType as RidMessageType, RidMessage};
+
+/// Default for how long [RidTestHarness::next_reply] and [RidTestHarness::next_message] wait.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+// All harnesses share the global store as well as the captured replies and messages. Therefore
+// tests using a harness, or replaying a message log, run one at a time even though cargo runs
+// tests in parallel.
+static HARNESS_LOCK: Mutex<()> = Mutex::new(());
+
+pub(crate) fn lock_capture() -> MutexGuard<'static, ()> {
+    // A failed test panics while holding the lock which must not fail the following tests
+    HARNESS_LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sends messages to the global store and captures the replies and messages it posts.
+///
+/// Creating a harness resets the store to the state returned by [RidStore::create] and starts
+/// capturing. Replies passed to `rid::post` and messages posted via rid's macros, i.e.
+/// `rid::log_info!`, or the `rid::RidLogger` are kept in the order they were posted until
+/// they are taken via the harness. Capturing stops once the harness is dropped.
+pub struct RidTestHarness<TStore, TMsg, TReply> {
+    next_req_id: u64,
+    timeout: Duration,
+    _lock: MutexGuard<'static, ()>,
+    _types: PhantomData<(TStore, TMsg, TReply)>,
+}
+
+impl<TStore, TMsg, TReply> Default for RidTestHarness<TStore, TMsg, TReply>
+where
+    TStore: RidStore<TMsg> + _RidGlobalStore,
+    TReply: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TStore, TMsg, TReply> RidTestHarness<TStore, TMsg, TReply>
+where
+    TStore: RidStore<TMsg> + _RidGlobalStore,
+    TReply: 'static,
+{
+    /// Resets the store and starts capturing replies and messages, waiting for the harness of
+    /// another test to be dropped first.
+    pub fn new() -> Self {
+        let lock = lock_capture();
+        *TStore::_write() = TStore::create();
+        rid_ffi::_start_capture();
+        Self {
+            next_req_id: 1,
+            timeout: DEFAULT_TIMEOUT,
+            _lock: lock,
+            _types: PhantomData,
+        }
+    }
+
+    /// Changes how long [Self::next_reply] and [Self::next_message] wait, defaults to
+    /// [DEFAULT_TIMEOUT].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Updates the store with the message as if Dart sent it and returns the id of the request
+    /// which replies to it include.
+    ///
+    /// The update runs on the current thread, even for messages of a `threaded` message enum.
+    /// Unlike for messages sent from Dart a panic is not caught and thus fails the test.
+    pub fn send(&mut self, msg: TMsg) -> u64 {
+        let req_id = self.next_req_id;
+        self.next_req_id += 1;
+        TStore::_write().update(req_id, msg);
+        req_id
+    }
+
+    /// Runs the callback with the store locked for writing, i.e. to assert on its state.
+    pub fn store<R>(&self, f: impl FnOnce(&mut TStore) -> R) -> R {
+        f(&mut TStore::_write())
+    }
+
+    /// Removes and returns all replies posted so far in the order they were posted.
+    pub fn replies(&mut self) -> Vec<TReply> {
+        rid_ffi::_take_replies()
+            .into_iter()
+            .map(downcast_reply)
+            .collect()
+    }
+
+    /// Removes and returns the reply posted first, waiting for one to be posted, i.e. from a
+    /// thread spawned by the update.
+    ///
+    /// Panics if no reply was posted within the timeout, see [Self::timeout].
+    pub fn next_reply(&mut self) -> TReply {
+        match rid_ffi::_wait_for_reply(self.timeout) {
+            Some(reply) => downcast_reply(reply),
+            None => panic!("No reply was posted within {:?}", self.timeout),
+        }
+    }
+
+    /// Removes and returns all messages posted so far in the order they were posted.
+    pub fn messages(&mut self) -> Vec<RidMessage> {
+        rid_ffi::_take_messages()
+    }
+
+    /// Removes and returns the message posted first, waiting for one to be posted.
+    ///
+    /// Panics if no message was posted within the timeout, see [Self::timeout].
+    pub fn next_message(&mut self) -> RidMessage {
+        match rid_ffi::_wait_for_message(self.timeout) {
+            Some(msg) => msg,
+            None => panic!("No message was posted within {:?}", self.timeout),
+        }
+    }
+}
+
+impl<TStore, TMsg, TReply> RidTestHarness<TStore, TMsg, TReply>
+where
+    TStore: RidStore<TMsg> + _RidGlobalStore + fmt::Debug,
+    TMsg: _RidDecode,
+    TReply: 'static,
+{
+    /// Resets the store and replays the message log at the given path into it, i.e. one QA
+    /// recorded while reproducing a bug, see [crate::replay].
+    ///
+    /// Fails if the replayed store does not match a recorded snapshot, including the final
+    /// state of the store recorded when recording stopped. Replies and messages posted while
+    /// replaying are dropped.
+    pub fn replay(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), RidReplayError> {
+        let entries = read_message_log(&path)?;
+        let mut store = TStore::_write();
+        *store = TStore::create();
+        RidReplay::new(path).apply(&mut *store, entries)
+    }
+}
+
+impl<TStore, TMsg, TReply> Drop for RidTestHarness<TStore, TMsg, TReply> {
+    fn drop(&mut self) {
+        // Runs before the lock is released
+        rid_ffi::_stop_capture();
+    }
+}
+
+fn downcast_reply<TReply: 'static>(reply: Box<dyn Any + Send>) -> TReply {
+    match reply.downcast::<TReply>() {
+        Ok(reply) => *reply,
+        Err(_) => panic!(
+            "Posted reply is not a '{}', make sure to provide the reply type of the store's messages",
+            type_name::<TReply>()
+        ),
+    }
+}
@@ -1,4 +1,24 @@
+use std::ops::DerefMut;
+
 pub trait RidStore<TMsg> {
     fn create() -> Self;
+    /// Applies the message to the store.
+    ///
+    /// Dart is notified which store fields changed once the update completes, see the `changes`
+    /// stream of the Dart `Store`. Fields are only reported individually when they are modified
+    /// via the setters of the generated `StoreFieldSetters` trait, i.e. `self.todos_mut()` or
+    /// `self.set_filter(filter)`. An update that modifies fields directly instead, i.e.
+    /// `self.todos.push(todo)`, reports all fields as changed unless it used a setter as well
+    /// in which case only the fields modified via setters are reported. Therefore each update
+    /// should modify either all or none of the fields it changes via setters.
     fn update(&mut self, req_id: u64, msg: TMsg);
 }
+
+/// Implemented by `#[rid::store]` to provide access to the global instance of the store, i.e.
+/// for the `rid::testing` harness.
+#[doc(hidden)]
+pub trait _RidGlobalStore: Sized + 'static {
+    type WriteGuard: DerefMut<Target = Self>;
+    /// Locks the store for writing, see the generated `store::write`.
+    fn _write() -> Self::WriteGuard;
+}
@@ -0,0 +1,40 @@
+import 'package:tests_apps/generated/rid_api.dart';
+
+// Compares converting the store into Dart state field by field, which takes one FFI call per
+// field and collection item, with converting it in a single FFI call via the rid wire format.
+//
+// Run via: make bench
+
+const TODOS = 5000;
+const RUNS = 20;
+
+int _averageMicros(void Function() convert) {
+  final stopwatch = Stopwatch()..start();
+  for (var i = 0; i < RUNS; i++) {
+    convert();
+  }
+  return stopwatch.elapsedMicroseconds ~/ RUNS;
+}
+
+Future<void> main() async {
+  rid.debugLock = null;
+  rid.debugReply = null;
+
+  final store = Store.instance;
+  for (var i = 0; i < TODOS; i++) {
+    await store.msgAddTodo('Todo $i');
+  }
+
+  // Warm up
+  store.raw.toDart();
+  store.raw.toDartEncoded();
+
+  final byField = _averageMicros(() => store.raw.toDart());
+  final encoded = _averageMicros(() => store.raw.toDartEncoded());
+
+  print('Converting a store with $TODOS todos, averaged over $RUNS runs');
+  print('  toDart()        one FFI call per field/item: ${byField}µs');
+  print('  toDartEncoded() single FFI call:             ${encoded}µs');
+
+  await store.dispose();
+}
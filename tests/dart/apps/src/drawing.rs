@@ -0,0 +1,156 @@
+#![allow(dead_code)]
+
+use std::collections::BTreeMap;
+
+use rid::RidStore;
+
+// -----------------
+// Store
+// -----------------
+#[rid::store]
+#[rid::structs(Point, Shape)]
+#[rid::enums(Color)]
+#[derive(Debug)]
+pub struct Store {
+    points: Vec<Point>,
+    shapes: Vec<Shape>,
+    labels: Vec<String>,
+    color: Color,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self {
+            points: vec![],
+            shapes: vec![],
+            labels: vec![],
+            color: Color::Black,
+        }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        use Msg::*;
+        match msg {
+            AddPoint(point) => {
+                self.points.push(point);
+                rid::post(Reply::AddedPoints(req_id));
+            }
+            AddPoints(points) => {
+                self.points.extend(points);
+                rid::post(Reply::AddedPoints(req_id));
+            }
+            MovePoint { index, to, label } => {
+                self.points[index as usize] = to;
+                if let Some(label) = label {
+                    self.labels.push(label);
+                }
+                rid::post(Reply::MovedPoint(req_id));
+            }
+            AddShape(shape) => {
+                self.shapes.push(shape);
+                rid::post(Reply::AddedShape(req_id));
+            }
+            SetLabels(labels) => {
+                self.labels = labels.unwrap_or_default();
+                rid::post(Reply::SetLabels(req_id));
+            }
+            SetColor(color) => {
+                self.color = color.unwrap_or(Color::Black);
+                rid::post(Reply::SetColor(req_id));
+            }
+            QueryPoints => {
+                rid::post(Reply::Points(req_id, self.points.clone()));
+            }
+            QueryShape(index) => {
+                let shape = self.shapes.get(index as usize).cloned();
+                rid::post(Reply::ShapeAt {
+                    req_id,
+                    index,
+                    shape,
+                });
+            }
+            QueryColor => {
+                rid::post(Reply::CurrentColor(req_id, self.color.clone()));
+            }
+            QueryLabelCounts => {
+                let mut counts = BTreeMap::new();
+                for label in &self.labels {
+                    *counts.entry(label.clone()).or_insert(0) += 1;
+                }
+                rid::post(Reply::LabelCounts(req_id, counts));
+            }
+        };
+    }
+}
+
+// -----------------
+// Models
+// -----------------
+#[rid::model]
+#[derive(Clone, Debug)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[rid::model]
+#[derive(Clone, Debug)]
+pub enum Shape {
+    Circle(u32),
+    Rect { width: u32, height: u32 },
+    Empty,
+}
+
+#[rid::model]
+#[derive(Clone, Debug)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+}
+
+// -----------------
+// Msg
+// -----------------
+#[rid::message(Reply)]
+#[rid::structs(Point, Shape)]
+#[rid::enums(Color)]
+#[derive(Debug)]
+pub enum Msg {
+    AddPoint(Point),
+    AddPoints(Vec<Point>),
+    MovePoint {
+        index: u32,
+        to: Point,
+        label: Option<String>,
+    },
+    AddShape(Shape),
+    SetLabels(Option<Vec<String>>),
+    SetColor(Option<Color>),
+    QueryPoints,
+    QueryShape(u32),
+    QueryColor,
+    QueryLabelCounts,
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[rid::structs(Point, Shape)]
+#[rid::enums(Color)]
+pub enum Reply {
+    AddedPoints(u64),
+    MovedPoint(u64),
+    AddedShape(u64),
+    SetLabels(u64),
+    SetColor(u64),
+    Points(u64, Vec<Point>),
+    ShapeAt {
+        req_id: u64,
+        index: u32,
+        shape: Option<Shape>,
+    },
+    CurrentColor(u64, Color),
+    LabelCounts(u64, BTreeMap<String, u32>),
+}
@@ -29,13 +29,13 @@ impl RidStore<Msg> for Store {
         use Msg::*;
         match msg {
             AddTodo(title) => {
-                self.last_added_id += 1;
+                *self.last_added_id_mut() += 1;
                 let todo = Todo {
                     id: self.last_added_id,
                     title,
                     completed: false,
                 };
-                self.todos.push(todo);
+                self.todos_mut().push(todo);
                 rid::post(Reply::AddedTodo(
                     req_id,
                     self.last_added_id.to_string(),
@@ -45,14 +45,9 @@ impl RidStore<Msg> for Store {
                 let mut enumerated = self.todos.iter().enumerate();
                 let idx = match enumerated.find(|(_, todo)| todo.id == id) {
                     Some((idx, _)) => idx,
-                    None => {
-                        return eprintln!(
-                            "Could not find Todo with id '{}'",
-                            id
-                        )
-                    }
+                    None => return rid::post(Reply::TodoNotFound(req_id, id)),
                 };
-                self.todos.remove(idx);
+                self.todos_mut().remove(idx);
                 rid::post(Reply::RemovedTodo(
                     req_id,
                     self.last_added_id.to_string(),
@@ -60,7 +55,7 @@ impl RidStore<Msg> for Store {
             }
 
             RemoveCompleted => {
-                self.todos.retain(|todo| !todo.completed);
+                self.todos_mut().retain(|todo| !todo.completed);
                 rid::post(Reply::RemovedCompleted(req_id));
             }
 
@@ -78,16 +73,18 @@ impl RidStore<Msg> for Store {
             }
 
             CompleteAll => {
-                self.todos.iter_mut().for_each(|x| x.completed = true);
+                self.todos_mut().iter_mut().for_each(|x| x.completed = true);
                 rid::post(Reply::CompletedAll(req_id));
             }
             RestartAll => {
-                self.todos.iter_mut().for_each(|x| x.completed = false);
+                self.todos_mut()
+                    .iter_mut()
+                    .for_each(|x| x.completed = false);
                 rid::post(Reply::RestartedAll(req_id));
             }
 
             SetFilter(filter) => {
-                self.filter = filter;
+                self.set_filter(filter);
                 rid::post(Reply::SetFilter(req_id));
             }
         };
@@ -97,7 +94,7 @@ impl RidStore<Msg> for Store {
 #[rid::export]
 impl Store {
     fn update_todo<F: FnOnce(&mut Todo)>(&mut self, id: u32, update: F) {
-        match self.todos.iter_mut().find(|x| x.id == id) {
+        match self.todos_mut().iter_mut().find(|x| x.id == id) {
             Some(todo) => update(todo),
             None => eprintln!("Could not find Todo with id '{}'", id),
         };
@@ -189,6 +186,7 @@ pub enum Msg {
 // Reply
 // -----------------
 #[rid::reply]
+#[derive(rid::Config)]
 pub enum Reply {
     AddedTodo(u64, String),
     RemovedTodo(u64, String),
@@ -201,4 +199,7 @@ pub enum Reply {
     RestartedAll(u64),
 
     SetFilter(u64),
+
+    #[rid(error)]
+    TodoNotFound(u64, u32),
 }
@@ -0,0 +1,87 @@
+import 'package:test/test.dart';
+import 'package:tests_apps/generated/rid_api.dart';
+
+void main() {
+  test('messages: struct, enum and collection fields', () async {
+    rid.debugLock = null;
+    rid.debugReply = null;
+
+    final store = Store.instance;
+
+    await store.msgAddPoint(Point(1, 2));
+    expect(store.points.length, 1);
+    expect(store.points[0].x, 1);
+    expect(store.points[0].y, 2);
+
+    await store.msgAddPoints([Point(-3, 4), Point(5, -6)]);
+    expect(store.points.map((p) => '(${p.x}, ${p.y})').join(', '),
+        '(1, 2), (-3, 4), (5, -6)');
+
+    await store.msgAddPoints([]);
+    expect(store.points.length, 3);
+
+    await store.msgMovePoint(index: 1, to: Point(7, 8));
+    expect(store.points[1].x, 7);
+    expect(store.points[1].y, 8);
+    expect(store.labels, []);
+
+    await store.msgMovePoint(to: Point(0, 0), index: 0, label: 'origin');
+    expect(store.points[0].x, 0);
+    expect(store.labels, ['origin']);
+
+    await store.msgAddShape(ShapeCircle(3));
+    await store.msgAddShape(ShapeRect(4, 5));
+    await store.msgAddShape(ShapeEmpty());
+    expect(store.shapes.length, 3);
+    expect(store.shapes[0], ShapeCircle(3));
+    expect(store.shapes[1], ShapeRect(4, 5));
+    expect(store.shapes[2], ShapeEmpty());
+
+    await store.msgSetLabels(['hello', 'wörld']);
+    expect(store.labels, ['hello', 'wörld']);
+
+    await store.msgSetLabels(null);
+    expect(store.labels, []);
+
+    await store.msgSetColor(Color.Green);
+    expect(store.color, Color.Green);
+
+    await store.msgSetColor(null);
+    expect(store.color, Color.Black);
+  });
+
+  test('replies: struct, enum and collection fields', () async {
+    rid.debugLock = null;
+    rid.debugReply = null;
+
+    final store = Store.instance;
+    await store.msgAddPoints([Point(1, 2), Point(-3, 4)]);
+    await store.msgSetLabels(['b', 'a', 'b']);
+    await store.msgSetColor(Color.Red);
+
+    final points = await store.msgQueryPoints() as PostedReplyPoints;
+    expect(points.type, Reply.Points);
+    expect(points.field0, store.points);
+    expect(points.field0.sublist(points.field0.length - 2),
+        [Point(1, 2), Point(-3, 4)]);
+    expect(points.data, null);
+
+    final shape = await store.msgQueryShape(1) as PostedReplyShapeAt;
+    expect(shape.index, 1);
+    expect(shape.shape, ShapeRect(4, 5));
+
+    final missing = await store.msgQueryShape(9) as PostedReplyShapeAt;
+    expect(missing.index, 9);
+    expect(missing.shape, null);
+
+    final color = await store.msgQueryColor() as PostedReplyCurrentColor;
+    expect(color.field0, Color.Red);
+
+    final counts =
+        await store.msgQueryLabelCounts() as PostedReplyLabelCounts;
+    expect(counts.field0, {'a': 1, 'b': 2});
+    expect(counts.field0.keys.toList(), ['a', 'b']);
+
+    store.dispose();
+  });
+}
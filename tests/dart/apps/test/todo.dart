@@ -50,6 +50,67 @@ void main() {
     // restarting non-existent todo does not crash
     await store.msgRestartTodo(5);
 
+    // removing non-existent todo completes with an error reply
+    try {
+      await store.msgRemoveTodo(5);
+      fail('should have thrown');
+    } on RidReplyException<PostedReply> catch (err) {
+      expect(err.message, 'msgRemoveTodo(5)');
+      expect(err.reply.type, Reply.TodoNotFound);
+      expect(err.reply.isError, true);
+      expect(err.reqId, err.reply.reqId);
+      expect((err.reply as PostedReplyTodoNotFound).field0, 5);
+    }
+    expect(store.todos.length, 1);
+
+    // the fields changed by each update are reported once the update completes
+    final changes = store.changes.take(2).toList();
+    await store.msgAddTodo("Adios");
+    await store.msgSetFilter(Filter.All);
+    expect(await changes, [
+      {StoreField.lastAddedId, StoreField.todos},
+      {StoreField.filter},
+    ]);
+
+    // converting the store in a single FFI call matches converting it field by field
+    final encoded = store.raw.toDartEncoded();
+    final byField = store.raw.toDart();
+    expect(encoded.lastAddedId, byField.lastAddedId);
+    expect(encoded.todos, byField.todos);
+    expect(encoded.filter, byField.filter);
+    expect(store.toDartState().todos, byField.todos);
+
+    // lazy lists read items on demand and match the converted todos
+    final lazy = store.todosList();
+    expect(lazy.length, 2);
+    expect(lazy.toList(), store.todos);
+    expect(() => lazy[2], throwsRangeError);
+    expect(() => lazy[-1], throwsRangeError);
+
+    final windowed = store.todosList(window: 1, maxCachedWindows: 1);
+    expect(windowed.length, 2);
+    expect(windowed[1], store.todos[1]);
+    expect(windowed.toList(), store.todos);
+    expect(() => windowed[2], throwsRangeError);
+    expect(() => windowed[-1], throwsRangeError);
+
+    await store.msgAddTodo("Hola");
+    expect(lazy.length, 3);
+    expect(windowed.length, 2);
+
+    // the length of a lazy list is read once while the store stays locked
+    ridStoreLock();
+    try {
+      expect(ridStoreLockScope(), isNotNull);
+      expect(lazy.toList(), store.todos);
+    } finally {
+      ridStoreUnlock();
+    }
+    expect(ridStoreLockScope(), isNull);
+    windowed.invalidate();
+    expect(windowed.length, 3);
+    expect(windowed.last, store.todos.last);
+
     store.dispose();
   });
 }
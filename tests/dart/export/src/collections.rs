@@ -0,0 +1,66 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+use rid::RidStore;
+
+#[rid::store]
+pub struct Store {
+    ids: Vec<u32>,
+    tags: BTreeSet<String>,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        let tags = ["work", "home"].iter().map(|x| x.to_string()).collect();
+        Self {
+            ids: vec![3, 1, 2, 3],
+            tags,
+        }
+    }
+
+    fn update(&mut self, _req_id: u64, _msg: Msg) {
+        unimplemented!()
+    }
+}
+
+#[rid::export]
+impl Store {
+    // owned
+    #[rid::export]
+    pub fn unique_ids(&self) -> HashSet<u32> {
+        self.ids.iter().cloned().collect()
+    }
+
+    #[rid::export]
+    pub fn sorted_ids(&self) -> BTreeSet<u32> {
+        self.ids.iter().cloned().collect()
+    }
+
+    #[rid::export]
+    pub fn id_counts(&self) -> BTreeMap<u32, u32> {
+        let mut counts = BTreeMap::new();
+        for id in &self.ids {
+            *counts.entry(*id).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    #[rid::export]
+    pub fn recent_ids(&self) -> VecDeque<u32> {
+        self.ids.iter().rev().cloned().collect()
+    }
+
+    // references
+    #[rid::export]
+    pub fn tags_ref(&self) -> &BTreeSet<String> {
+        &self.tags
+    }
+}
+
+#[rid::message(Reply)]
+pub enum Msg {
+    NotUsed,
+}
+#[rid::reply]
+pub enum Reply {
+    NotUsed,
+}
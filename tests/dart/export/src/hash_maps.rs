@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use rid::RidStore;
+
+#[rid::store]
+#[rid::structs(Todo)]
+pub struct Store {
+    todos: Vec<Todo>,
+    scores: HashMap<String, u32>,
+}
+
+#[rid::model]
+#[derive(Clone)]
+pub struct Todo {
+    id: u32,
+    tag: String,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        let todos = vec![
+            Todo {
+                id: 1,
+                tag: "home".to_string(),
+            },
+            Todo {
+                id: 2,
+                tag: "work".to_string(),
+            },
+            Todo {
+                id: 3,
+                tag: "home".to_string(),
+            },
+        ];
+        let mut scores = HashMap::new();
+        scores.insert("alice".to_string(), 10);
+        scores.insert("bob".to_string(), 20);
+        Self { todos, scores }
+    }
+
+    fn update(&mut self, _req_id: u64, _msg: Msg) {
+        unimplemented!()
+    }
+}
+
+#[rid::export]
+#[rid::structs(Todo)]
+impl Store {
+    // owned
+    #[rid::export]
+    pub fn todo_counts_by_tag(&self) -> HashMap<String, u32> {
+        let mut counts = HashMap::new();
+        for todo in &self.todos {
+            *counts.entry(todo.tag.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    #[rid::export]
+    pub fn todos_by_id(&self) -> HashMap<u32, Todo> {
+        self.todos.iter().map(|x| (x.id, x.clone())).collect()
+    }
+
+    // references
+    #[rid::export]
+    pub fn scores_ref(&self) -> &HashMap<String, u32> {
+        &self.scores
+    }
+}
+
+#[rid::message(Reply)]
+pub enum Msg {
+    NotUsed,
+}
+#[rid::reply]
+pub enum Reply {
+    NotUsed,
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use rid::RidStore;
+
+#[rid::store]
+pub struct Store {
+    grid: Vec<Vec<u8>>,
+    slots: Vec<Option<u32>>,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self {
+            grid: vec![vec![1, 2, 3], vec![4, 5, 6]],
+            slots: vec![Some(1), None, Some(3)],
+        }
+    }
+
+    fn update(&mut self, _req_id: u64, _msg: Msg) {
+        unimplemented!()
+    }
+}
+
+#[rid::export]
+impl Store {
+    // owned
+    #[rid::export]
+    pub fn transposed_grid(&self) -> Vec<Vec<u8>> {
+        let cols = self.grid.first().map_or(0, |row| row.len());
+        (0..cols)
+            .map(|col| self.grid.iter().map(|row| row[col]).collect())
+            .collect()
+    }
+
+    #[rid::export]
+    pub fn filled_slots(&self) -> Vec<Option<u32>> {
+        self.slots.iter().map(|x| x.or(Some(0))).collect()
+    }
+
+    #[rid::export]
+    pub fn rows_by_sum(&self) -> HashMap<String, Vec<u8>> {
+        self.grid
+            .iter()
+            .map(|row| {
+                let sum: u32 = row.iter().map(|x| *x as u32).sum();
+                (sum.to_string(), row.clone())
+            })
+            .collect()
+    }
+
+    // references
+    #[rid::export]
+    pub fn grid_ref(&self) -> &Vec<Vec<u8>> {
+        &self.grid
+    }
+
+    #[rid::export]
+    pub fn slots_ref(&self) -> &Vec<Option<u32>> {
+        &self.slots
+    }
+}
+
+#[rid::message(Reply)]
+pub enum Msg {
+    NotUsed,
+}
+#[rid::reply]
+pub enum Reply {
+    NotUsed,
+}
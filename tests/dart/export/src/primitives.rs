@@ -12,6 +12,9 @@ pub struct Store {
     m_signed: i16,
     l_signed: i32,
     xl_signed: i64,
+    // floats
+    price: f32,
+    ratio: f64,
     // bool
     ok: bool,
     not_ok: bool,
@@ -30,6 +33,9 @@ impl RidStore<Msg> for Store {
             l_signed: -100,
             xl_signed: -1000,
 
+            price: 1.5,
+            ratio: -0.25,
+
             ok: true,
             not_ok: false,
         }
@@ -91,6 +97,17 @@ impl Store {
         self.xl_signed
     }
 
+    // floats
+    #[rid::export]
+    pub fn price_owned(&self) -> f32 {
+        self.price
+    }
+
+    #[rid::export]
+    pub fn ratio_owned(&self) -> f64 {
+        self.ratio
+    }
+
     // bool
     #[rid::export]
     pub fn ok_owned(&self) -> bool {
@@ -147,6 +164,17 @@ impl Store {
         &self.xl_signed
     }
 
+    // floats
+    #[rid::export]
+    pub fn price_ref(&self) -> &f32 {
+        &self.price
+    }
+
+    #[rid::export]
+    pub fn ratio_ref(&self) -> &f64 {
+        &self.ratio
+    }
+
     // bool
     #[rid::export]
     pub fn ok_ref(&self) -> &bool {
@@ -209,6 +237,17 @@ pub fn fn_xl_signed_owned() -> i64 {
     -64
 }
 
+// floats
+#[rid::export]
+pub fn fn_price_owned() -> f32 {
+    3.5
+}
+
+#[rid::export]
+pub fn fn_ratio_owned() -> f64 {
+    -6.25
+}
+
 // bool
 #[rid::export]
 pub fn fn_ok_owned() -> bool {
@@ -0,0 +1,105 @@
+use rid::RidStore;
+
+#[rid::store]
+pub struct Store {
+    balance: u32,
+}
+
+#[rid::model]
+#[derive(Debug, PartialEq)]
+pub enum WithdrawError {
+    InsufficientFunds,
+    Frozen,
+}
+
+#[rid::model]
+pub struct ParseError {
+    line: u32,
+    reason: String,
+}
+
+#[rid::model]
+#[derive(Debug)]
+pub enum TransferError {
+    LimitExceeded { limit: u32 },
+    UnknownAccount(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self { balance: 100 }
+    }
+
+    fn update(&mut self, _req_id: u64, _msg: Msg) {
+        unimplemented!()
+    }
+}
+
+#[rid::export]
+#[rid::enums(WithdrawError)]
+#[rid::structs(ParseError, TransferError)]
+impl Store {
+    #[rid::export]
+    pub fn checked_half(&self, n: u32) -> Result<u32, String> {
+        if n % 2 == 0 {
+            Ok(n / 2)
+        } else {
+            Err(format!("{} is odd", n))
+        }
+    }
+
+    #[rid::export]
+    pub fn preview_withdraw(&self, amount: u32) -> Result<u32, WithdrawError> {
+        if amount == 0 {
+            Err(WithdrawError::Frozen)
+        } else if amount > self.balance {
+            Err(WithdrawError::InsufficientFunds)
+        } else {
+            Ok(self.balance - amount)
+        }
+    }
+
+    #[rid::export]
+    pub fn parse_greeting(&self, line: u32) -> Result<String, ParseError> {
+        if line == 1 {
+            Ok("hello".to_string())
+        } else {
+            Err(ParseError {
+                line,
+                reason: "expected a greeting".to_string(),
+            })
+        }
+    }
+
+    #[rid::export]
+    pub fn preview_transfer(
+        &self,
+        account: String,
+        amount: u32,
+    ) -> Result<u32, TransferError> {
+        if account != "savings" {
+            Err(TransferError::UnknownAccount(account))
+        } else if amount > self.balance {
+            Err(TransferError::LimitExceeded {
+                limit: self.balance,
+            })
+        } else {
+            Ok(self.balance - amount)
+        }
+    }
+}
+
+#[rid::message(Reply)]
+pub enum Msg {
+    NotUsed,
+}
+#[rid::reply]
+pub enum Reply {
+    NotUsed,
+}
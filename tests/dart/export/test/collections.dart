@@ -0,0 +1,42 @@
+import 'package:test/test.dart';
+
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  rid.debugLock = null;
+  final store = Store.instance;
+
+  test('export: HashSet<u32>', () {
+    final ids = store.uniqueIds();
+    expect(ids, {1, 2, 3}, reason: 'unique ids');
+  });
+
+  test('export: BTreeSet<u32>', () {
+    final ids = store.sortedIds();
+    expect(ids.toList(), [1, 2, 3], reason: 'sorted ids are ordered');
+  });
+
+  test('export: BTreeMap<u32, u32>', () {
+    final counts = store.idCounts();
+    expect(counts.keys.toList(), [1, 2, 3], reason: 'keys are ordered');
+    expect(counts[3], 2, reason: 'count of 3');
+  });
+
+  test('export: VecDeque<u32>', () {
+    expect(store.recentIds(), [3, 2, 1, 3], reason: 'recent ids');
+  });
+
+  test('export: &BTreeSet<String>', () {
+    expect(store.tagsRef().toList(), ['home', 'work'],
+        reason: 'tags are ordered');
+  });
+
+  test('export: raw BTreeSet returned by value is accessed lazily', () {
+    final ids = store.raw.sorted_ids();
+    expect(ids.length, 3, reason: 'ids len');
+    expect(ids.contains(2), true, reason: 'contains 2');
+    expect(ids.contains(4), false, reason: 'contains 4');
+    expect(ids.items(), [1, 2, 3], reason: 'items are ordered');
+    ids.dispose();
+  });
+}
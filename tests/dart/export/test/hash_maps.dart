@@ -0,0 +1,37 @@
+import 'package:test/test.dart';
+
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  rid.debugLock = null;
+  final store = Store.instance;
+
+  test('export: HashMap<String, u32>', () {
+    final counts = store.todoCountsByTag();
+    expect(counts.length, 2, reason: 'counts len');
+    expect(counts['home'], 2, reason: 'home count');
+    expect(counts['work'], 1, reason: 'work count');
+  });
+
+  test('export: HashMap<u32, Todo>', () {
+    final todos = store.todosById();
+    expect(todos.length, 3, reason: 'todos len');
+    expect(todos[2]!.tag, 'work', reason: 'todo tag');
+  });
+
+  test('export: &HashMap<String, u32>', () {
+    final scores = store.scoresRef();
+    expect(scores.length, 2, reason: 'scores len');
+    expect(scores['alice'], 10, reason: 'alice score');
+    expect(scores['bob'], 20, reason: 'bob score');
+  });
+
+  test('export: raw HashMap returned by value is accessed lazily', () {
+    final counts = store.raw.todo_counts_by_tag();
+    expect(counts.length, 2, reason: 'counts len');
+    expect(counts.contains('home'), true, reason: 'contains home');
+    expect(counts.get('home'), 2, reason: 'get home');
+    expect(counts.get('play'), null, reason: 'get missing');
+    counts.dispose();
+  });
+}
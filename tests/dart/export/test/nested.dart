@@ -0,0 +1,44 @@
+import 'package:test/test.dart';
+
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  rid.debugLock = null;
+  final store = Store.instance;
+
+  test('export: Vec<Vec<u8>>', () {
+    expect(store.transposedGrid(), [
+      [1, 4],
+      [2, 5],
+      [3, 6]
+    ], reason: 'transposed grid');
+  });
+
+  test('export: Vec<Option<u32>>', () {
+    expect(store.filledSlots(), [1, 0, 3], reason: 'filled slots');
+  });
+
+  test('export: HashMap<String, Vec<u8>>', () {
+    final rows = store.rowsBySum();
+    expect(rows.length, 2, reason: 'rows len');
+    expect(rows['6'], [1, 2, 3], reason: 'rows 6');
+    expect(rows['15'], [4, 5, 6], reason: 'rows 15');
+  });
+
+  test('export: &Vec<Vec<u8>>', () {
+    expect(store.gridRef(), [
+      [1, 2, 3],
+      [4, 5, 6]
+    ], reason: 'grid ref');
+  });
+
+  test('export: &Vec<Option<u32>>', () {
+    expect(store.slotsRef(), [1, null, 3], reason: 'slots ref');
+  });
+
+  test('export: raw Vec<Vec<u8>> items are accessed lazily', () {
+    final grid = store.raw.grid_ref();
+    expect(grid.length, 2, reason: 'raw grid len');
+    expect(grid[1][0], 4, reason: 'raw grid[1][0]');
+  });
+}
@@ -19,6 +19,10 @@ void main() {
     expect(store.lSignedOwned(), -100, reason: 'i32');
     expect(store.xlSignedOwned(), -1000, reason: 'i64');
 
+    // floats
+    expect(store.priceOwned(), 1.5, reason: 'f32');
+    expect(store.ratioOwned(), -0.25, reason: 'f64');
+
     // bool
     expect(store.okOwned(), true, reason: 'bool: true');
     expect(store.notOkOwned(), false, reason: 'bool: false');
@@ -38,6 +42,10 @@ void main() {
     expect(store.lSignedRef(), -100, reason: '&i32');
     expect(store.xlSignedRef(), -1000, reason: '&i64');
 
+    // floats
+    expect(store.priceRef(), 1.5, reason: '&f32');
+    expect(store.ratioRef(), -0.25, reason: '&f64');
+
     // bool
     expect(store.okRef(), true, reason: 'bool: true');
     expect(store.notOkRef(), false, reason: 'bool: false');
@@ -56,6 +64,10 @@ void main() {
     expect(rid_ffi.rid_export_fn_l_signed_owned(), -32, reason: 'i32');
     expect(rid_ffi.rid_export_fn_xl_signed_owned(), -64, reason: 'i64');
 
+    // floats
+    expect(rid_ffi.rid_export_fn_price_owned(), 3.5, reason: 'f32');
+    expect(rid_ffi.rid_export_fn_ratio_owned(), -6.25, reason: 'f64');
+
     // bool
     expect(rid_ffi.rid_export_fn_ok_owned(), 1, reason: 'bool: true');
     expect(rid_ffi.rid_export_fn_not_ok_owned(), 0, reason: 'bool: false');
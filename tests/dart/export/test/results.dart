@@ -0,0 +1,53 @@
+import 'package:test/test.dart';
+
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  rid.debugLock = null;
+  final store = Store.instance;
+
+  test('export: results with String errors', () {
+    expect(store.checkedHalf(4), 2, reason: 'ok value');
+    expect(
+        () => store.checkedHalf(3),
+        throwsA(isA<RidExportException>()
+            .having((e) => e.message, 'message', '3 is odd')));
+    expect(store.checkedHalf(8), 4, reason: 'ok value after error');
+  });
+
+  test('export: results with model enum errors', () {
+    expect(store.previewWithdraw(40), 60, reason: 'ok value');
+    expect(
+        () => store.previewWithdraw(200),
+        throwsA(isA<WithdrawErrorException>()
+            .having((e) => e.error, 'error', WithdrawError.InsufficientFunds)
+            .having((e) => e.message, 'message', 'InsufficientFunds')));
+    expect(
+        () => store.previewWithdraw(0),
+        throwsA(isA<WithdrawErrorException>()
+            .having((e) => e.error, 'error', WithdrawError.Frozen)));
+  });
+
+  test('export: results with model struct errors', () {
+    expect(store.parseGreeting(1), 'hello', reason: 'ok value');
+    expect(
+        () => store.parseGreeting(3),
+        throwsA(isA<ParseErrorException>()
+            .having((e) => e.message, 'message', 'line 3: expected a greeting')
+            .having((e) => e.error.line, 'error.line', 3)
+            .having((e) => e.error.reason, 'error.reason',
+                'expected a greeting')));
+  });
+
+  test('export: results with model data enum errors', () {
+    expect(store.previewTransfer('savings', 40), 60, reason: 'ok value');
+    expect(
+        () => store.previewTransfer('checking', 40),
+        throwsA(isA<TransferErrorException>().having((e) => e.error, 'error',
+            TransferErrorUnknownAccount('checking'))));
+    expect(
+        () => store.previewTransfer('savings', 200),
+        throwsA(isA<TransferErrorException>().having(
+            (e) => e.error, 'error', TransferErrorLimitExceeded(100))));
+  });
+}
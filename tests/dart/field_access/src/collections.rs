@@ -0,0 +1,61 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+use rid::RidStore;
+
+#[rid::store]
+#[rid::structs(Point)]
+pub struct Store {
+    history: VecDeque<u32>,
+    timeline: BTreeMap<u64, String>,
+    tags: HashSet<String>,
+    sorted_ids: BTreeSet<u32>,
+    visited: VecDeque<Point>,
+}
+
+#[rid::model]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        let mut history = VecDeque::new();
+        history.push_back(2);
+        history.push_back(3);
+        history.push_front(1);
+
+        let mut timeline = BTreeMap::new();
+        timeline.insert(30, "third".to_string());
+        timeline.insert(10, "first".to_string());
+        timeline.insert(20, "second".to_string());
+
+        let tags = ["rust", "dart"].iter().map(|x| x.to_string()).collect();
+        let sorted_ids = [3, 1, 2].iter().cloned().collect();
+
+        let mut visited = VecDeque::new();
+        visited.push_back(Point { x: 1, y: 1 });
+        visited.push_front(Point { x: 0, y: 0 });
+
+        Self {
+            history,
+            timeline,
+            tags,
+            sorted_ids,
+            visited,
+        }
+    }
+
+    fn update(&mut self, _req_id: u64, _msg: Msg) {
+        unimplemented!()
+    }
+}
+
+#[rid::message(Reply)]
+pub enum Msg {
+    NotUsed,
+}
+#[rid::reply]
+pub enum Reply {
+    NotUsed,
+}
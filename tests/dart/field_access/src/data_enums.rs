@@ -0,0 +1,75 @@
+use rid::RidStore;
+
+#[rid::store]
+#[rid::structs(Shape, Status)]
+pub struct Store {
+    shape: Shape,
+    other_shape: Shape,
+    status: Status,
+}
+
+#[rid::model]
+#[rid::structs(Point)]
+#[derive(Debug)]
+pub enum Shape {
+    Circle(f64),
+    Rect(Point, Point),
+    Empty,
+}
+
+#[rid::model]
+#[derive(Debug)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[rid::model]
+#[rid::enums(Stage)]
+pub enum Status {
+    Pending,
+    Failed {
+        reason: String,
+        code: u8,
+        retry: bool,
+    },
+    Running {
+        stage: Stage,
+    },
+}
+
+#[rid::model]
+pub enum Stage {
+    Start,
+    Finish,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self {
+            shape: Shape::Circle(1.5),
+            other_shape: Shape::Rect(
+                Point { x: 1, y: 2 },
+                Point { x: 3, y: 4 },
+            ),
+            status: Status::Failed {
+                reason: "timeout".to_string(),
+                code: 3,
+                retry: true,
+            },
+        }
+    }
+
+    fn update(&mut self, _req_id: u64, _msg: Msg) {
+        unimplemented!()
+    }
+}
+
+#[rid::message(Reply)]
+pub enum Msg {
+    NotUsed,
+}
+#[rid::reply]
+pub enum Reply {
+    NotUsed,
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use rid::RidStore;
+
+#[rid::store]
+#[rid::structs(Point)]
+pub struct Store {
+    grid: Vec<Vec<u8>>,
+    slots: Vec<Option<u32>>,
+    tags: Option<Vec<String>>,
+    no_tags: Option<Vec<String>>,
+    label: Option<String>,
+    origin: Option<Point>,
+    groups: HashMap<String, Vec<u32>>,
+    paths: Vec<Vec<Point>>,
+}
+
+#[rid::model]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        let mut groups = HashMap::new();
+        groups.insert("odd".to_string(), vec![1, 3, 5]);
+        groups.insert("even".to_string(), vec![2, 4]);
+
+        Self {
+            grid: vec![vec![1, 2, 3], vec![4, 5, 6]],
+            slots: vec![Some(1), None, Some(3)],
+            tags: Some(vec!["rust".to_string(), "dart".to_string()]),
+            no_tags: None,
+            label: Some("nested".to_string()),
+            origin: Some(Point { x: 0, y: 0 }),
+            groups,
+            paths: vec![
+                vec![Point { x: 0, y: 0 }, Point { x: 1, y: 1 }],
+                vec![Point { x: 2, y: 2 }],
+            ],
+        }
+    }
+
+    fn update(&mut self, _req_id: u64, _msg: Msg) {
+        unimplemented!()
+    }
+}
+
+#[rid::message(Reply)]
+pub enum Msg {
+    NotUsed,
+}
+#[rid::reply]
+pub enum Reply {
+    NotUsed,
+}
@@ -12,6 +12,14 @@ pub struct Store {
     m_signed: i16,
     l_signed: i32,
     xl_signed: i64,
+    // floats
+    price: f32,
+    ratio: f64,
+    // optional floats
+    discount: Option<f32>,
+    no_discount: Option<f32>,
+    weight: Option<f64>,
+    no_weight: Option<f64>,
     // bool
     ok: bool,
     not_ok: bool,
@@ -30,6 +38,14 @@ impl RidStore<Msg> for Store {
             l_signed: -100,
             xl_signed: -1000,
 
+            price: 1.5,
+            ratio: -0.25,
+
+            discount: Some(0.5),
+            no_discount: None,
+            weight: Some(-2.25),
+            no_weight: None,
+
             ok: true,
             not_ok: false,
         }
@@ -0,0 +1,44 @@
+use rid::RidStore;
+
+#[rid::store]
+#[rid::structs(TodoId, Point, Celsius)]
+pub struct Store {
+    id: TodoId,
+    point: Point,
+    temperature: Celsius,
+    temperatures: Vec<Celsius>,
+}
+
+#[rid::model]
+pub struct TodoId(u32);
+
+#[rid::model]
+#[derive(rid::Config)]
+pub struct Point(#[rid(name = "x")] i32, #[rid(name = "y")] i32);
+
+#[rid::model(transparent)]
+pub struct Celsius(f64);
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self {
+            id: TodoId(1),
+            point: Point(2, 3),
+            temperature: Celsius(21.5),
+            temperatures: vec![Celsius(-1.0), Celsius(30.5)],
+        }
+    }
+
+    fn update(&mut self, _req_id: u64, _msg: Msg) {
+        unimplemented!()
+    }
+}
+
+#[rid::message(Reply)]
+pub enum Msg {
+    NotUsed,
+}
+#[rid::reply]
+pub enum Reply {
+    NotUsed,
+}
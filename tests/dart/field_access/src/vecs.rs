@@ -8,6 +8,7 @@ use rid::RidStore;
 pub struct Store {
     todos: Vec<Todo>,
     u8s: Vec<u8>,
+    f64s: Vec<f64>,
     filters: Vec<Filter>,
     strings: Vec<String>,
     cstrings: Vec<CString>,
@@ -28,6 +29,7 @@ impl RidStore<Msg> for Store {
         Self {
             todos: vec![Todo { id: 1 }, Todo { id: 2 }],
             u8s: vec![3, 4],
+            f64s: vec![0.5, 1.25],
             filters: vec![Filter::Completed, Filter::All],
             strings: vec!["hello".to_string(), "world".to_string()],
             cstrings: vec![
@@ -0,0 +1,52 @@
+import 'package:test/test.dart';
+
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  final store = Store.instance;
+  rid.debugLock = null;
+
+  test('field_access: VecDeque<u32>', () {
+    expect(store.raw.history.length, 3, reason: 'raw history len');
+    expect(store.raw.history[0], 1, reason: 'raw history[0]');
+    expect(store.raw.history[2], 3, reason: 'raw history[2]');
+
+    expect(store.history, [1, 2, 3], reason: 'history');
+  });
+
+  test('field_access: VecDeque<Point>', () {
+    expect(store.raw.visited.length, 2, reason: 'raw visited len');
+    expect(store.raw.visited[1].x, 1, reason: 'raw visited[1].x');
+
+    expect(store.visited.map((p) => p.x).toList(), [0, 1], reason: 'visited');
+  });
+
+  test('field_access: BTreeMap<u64, String>', () {
+    expect(store.raw.timeline.length, 3, reason: 'raw timeline len');
+    expect(store.raw.timeline.contains(20), true, reason: 'contains 20');
+    expect(store.raw.timeline.get(10), 'first', reason: 'get 10');
+    expect(store.raw.timeline.get(40), null, reason: 'get missing');
+
+    expect(store.timeline.keys.toList(), [10, 20, 30],
+        reason: 'timeline keys are ordered');
+    expect(store.timeline.values.toList(), ['first', 'second', 'third'],
+        reason: 'timeline values are ordered');
+  });
+
+  test('field_access: HashSet<String>', () {
+    expect(store.raw.tags.length, 2, reason: 'raw tags len');
+    expect(store.raw.tags.contains('rust'), true, reason: 'contains rust');
+    expect(store.raw.tags.contains('go'), false, reason: 'contains go');
+
+    expect(store.tags, {'rust', 'dart'}, reason: 'tags');
+  });
+
+  test('field_access: BTreeSet<u32>', () {
+    expect(store.raw.sorted_ids.length, 3, reason: 'raw sorted_ids len');
+    expect(store.raw.sorted_ids.contains(2), true, reason: 'contains 2');
+    expect(store.raw.sorted_ids.items(), [1, 2, 3],
+        reason: 'raw sorted_ids items are ordered');
+
+    expect(store.sortedIds.toList(), [1, 2, 3], reason: 'sortedIds');
+  });
+}
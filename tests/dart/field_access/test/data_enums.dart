@@ -0,0 +1,38 @@
+import 'package:test/test.dart';
+
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  rid.debugLock = null;
+  test('field_access: data enums', () {
+    final store = Store.instance;
+
+    expect(store.raw.shape.discriminant, 0, reason: 'raw shape variant');
+    expect(store.raw.shape.circle_field0, 1.5, reason: 'raw shape field');
+    expect(store.shape, isA<ShapeCircle>(), reason: 'shape subclass');
+    expect((store.shape as ShapeCircle).field0, 1.5, reason: 'shape field');
+
+    final rect = store.otherShape as ShapeRect;
+    expect(rect.field0.x, 1, reason: 'rect field0 x');
+    expect(rect.field1.y, 4, reason: 'rect field1 y');
+
+    final status = store.status as StatusFailed;
+    expect(status.reason, 'timeout', reason: 'status reason');
+    expect(status.code, 3, reason: 'status code');
+    expect(status.retry, true, reason: 'status retry');
+  });
+
+  test('field_access: data enums equality and when', () {
+    final store = Store.instance;
+
+    expect(store.shape, store.shape, reason: 'equal variants');
+    expect(store.shape == store.otherShape, false, reason: 'different variants');
+
+    final description = store.shape.when(
+      circle: (c) => 'circle ${c.field0}',
+      rect: (r) => 'rect',
+      empty: (_) => 'empty',
+    );
+    expect(description, 'circle 1.5', reason: 'when resolves variant');
+  });
+}
@@ -0,0 +1,69 @@
+import 'package:test/test.dart';
+
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  final store = Store.instance;
+  rid.debugLock = null;
+
+  test('field_access: Vec<Vec<u8>>', () {
+    expect(store.raw.grid.length, 2, reason: 'raw grid len');
+    expect(store.raw.grid[0].length, 3, reason: 'raw grid[0] len');
+    expect(store.raw.grid[1][2], 6, reason: 'raw grid[1][2]');
+    expect(store.raw.grid.iter().first.iter().first, 1,
+        reason: 'raw grid iter first first');
+
+    expect(store.grid, [
+      [1, 2, 3],
+      [4, 5, 6]
+    ], reason: 'grid');
+  });
+
+  test('field_access: Vec<Option<u32>>', () {
+    expect(store.raw.slots.length, 3, reason: 'raw slots len');
+    expect(store.raw.slots[0]?.value, 1, reason: 'raw slots[0]');
+    expect(store.raw.slots[1], null, reason: 'raw slots[1]');
+
+    expect(store.slots, [1, null, 3], reason: 'slots');
+  });
+
+  test('field_access: Option<Vec<String>>', () {
+    expect(store.raw.tags?.length, 2, reason: 'raw tags len');
+    expect(store.raw.tags?[1], 'dart', reason: 'raw tags[1]');
+    expect(store.raw.no_tags, null, reason: 'raw no_tags');
+
+    expect(store.tags, ['rust', 'dart'], reason: 'tags');
+    expect(store.noTags, null, reason: 'noTags');
+  });
+
+  test('field_access: Option<String>', () {
+    expect(store.raw.label, 'nested', reason: 'raw label');
+    expect(store.label, 'nested', reason: 'label');
+  });
+
+  test('field_access: Option<struct>', () {
+    expect(store.raw.origin?.x, 0, reason: 'raw origin x');
+    expect(store.origin.toString(), 'Point{x: 0, y: 0}', reason: 'origin');
+  });
+
+  test('field_access: HashMap<String, Vec<u32>>', () {
+    expect(store.raw.groups.length, 2, reason: 'raw groups len');
+    expect(store.raw.groups.contains('odd'), true,
+        reason: 'raw groups contains odd');
+    expect(store.raw.groups.get('even'), [2, 4], reason: 'raw groups get even');
+    expect(store.raw.groups.get('none'), null, reason: 'raw groups get none');
+
+    final groups = store.groups;
+    expect(groups['odd'], [1, 3, 5], reason: 'groups odd');
+    expect(groups['even'], [2, 4], reason: 'groups even');
+  });
+
+  test('field_access: Vec<Vec<struct>>', () {
+    expect(store.raw.paths.length, 2, reason: 'raw paths len');
+    expect(store.raw.paths[0][1].x, 1, reason: 'raw paths[0][1].x');
+
+    final paths = store.paths;
+    expect(paths[0].length, 2, reason: 'paths[0] len');
+    expect(paths[1][0].toString(), 'Point{x: 2, y: 2}', reason: 'paths[1][0]');
+  });
+}
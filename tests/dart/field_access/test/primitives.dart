@@ -17,6 +17,16 @@ void main() {
     expect(store.lSigned, -100, reason: 'i32');
     expect(store.xlSigned, -1000, reason: 'i64');
 
+    // floats
+    expect(store.price, 1.5, reason: 'f32');
+    expect(store.ratio, -0.25, reason: 'f64');
+
+    // optional floats
+    expect(store.discount, 0.5, reason: 'Option<f32>: Some');
+    expect(store.noDiscount, null, reason: 'Option<f32>: None');
+    expect(store.weight, -2.25, reason: 'Option<f64>: Some');
+    expect(store.noWeight, null, reason: 'Option<f64>: None');
+
     // bool
     expect(store.ok, true, reason: 'bool: true');
     expect(store.notOk, false, reason: 'bool: false');
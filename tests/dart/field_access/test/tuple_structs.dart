@@ -0,0 +1,27 @@
+import 'package:test/test.dart';
+
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  rid.debugLock = null;
+  test('field_access: tuple structs', () {
+    final store = Store.instance;
+
+    expect(store.raw.id.field0, 1, reason: 'raw newtype field0');
+    expect(store.id.field0, 1, reason: 'newtype field0');
+
+    expect(store.raw.point.x, 2, reason: 'raw named tuple field x');
+    expect(store.point.x, 2, reason: 'named tuple field x');
+    expect(store.point.y, 3, reason: 'named tuple field y');
+  });
+
+  test('field_access: transparent tuple structs', () {
+    final store = Store.instance;
+
+    expect(store.raw.temperature.field0, 21.5, reason: 'raw transparent');
+    final Celsius temperature = store.temperature;
+    expect(temperature, 21.5, reason: 'transparent resolves to inner type');
+    expect(store.temperatures, [-1.0, 30.5],
+        reason: 'vec of transparent resolves to inner types');
+  });
+}
@@ -25,6 +25,15 @@ void main() {
     expect(store.u8s[0], 3, reason: 'u8s idx');
   });
 
+  test('field_access: Vec<f64>', () {
+    expect(store.raw.f64s.length, 2, reason: 'raw f64s len');
+    expect(store.raw.f64s.iter().first, 0.5, reason: 'raw f64s iter first');
+    expect(store.raw.f64s[1], 1.25, reason: 'raw f64s idx');
+
+    expect(store.f64s.length, 2, reason: 'f64s len');
+    expect(store.f64s[0], 0.5, reason: 'f64s idx');
+  });
+
   test('field_access: Vec<enum>', () {
     expect(store.raw.filters.length, 2, reason: 'raw filters len');
     expect(store.raw.filters.iter().first, 1, reason: 'raw filters iter first');
@@ -42,3 +42,29 @@ pub fn send_severe_error_message_without_details(id: u8) -> u8 {
     rid::severe!(&format!("Severe Error {} from Rust", id));
     0
 }
+
+#[rid::export]
+pub fn send_log_info_message_with_separator(id: u8) -> u8 {
+    rid::log_info!("Info^{}^from Rust", id);
+    0
+}
+
+#[rid::export]
+pub fn init_logger() -> u8 {
+    rid::RidLogger::new()
+        .max_level(log::LevelFilter::Debug)
+        .target("messaging")
+        .init()
+        .ok();
+    0
+}
+
+#[rid::export]
+pub fn send_log_record(id: u8) -> u8 {
+    log::warn!("Record {} from Rust", id);
+    // Filtered out by level and target respectively
+    log::trace!("Trace {} from Rust", id);
+    log::error!(target: "other", "Other {} from Rust", id);
+    log::debug!("Debug record {} from Rust", id);
+    0
+}
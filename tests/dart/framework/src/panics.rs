@@ -0,0 +1,71 @@
+use rid::RidStore;
+
+// -----------------
+// Store
+// -----------------
+#[rid::store]
+#[rid::structs(Shape)]
+#[derive(Debug)]
+pub struct Store {
+    count: u32,
+    shape: Shape,
+}
+
+#[rid::model]
+#[derive(Debug)]
+pub enum Shape {
+    Circle(u32),
+    Empty,
+}
+
+#[rid::export]
+impl Store {
+    #[rid::export]
+    pub fn half(&self, n: u32) -> u32 {
+        if n % 2 != 0 {
+            panic!("cannot halve {}", n);
+        }
+        n / 2
+    }
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self {
+            count: 0,
+            shape: Shape::Empty,
+        }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        match msg {
+            Msg::Inc => {
+                self.set_count(self.count + 1);
+                rid::post(Reply::Increased(req_id));
+            }
+            Msg::Panic => {
+                self.set_count(self.count + 1);
+                panic!("update failed");
+            }
+        }
+    }
+}
+
+// -----------------
+// Message
+// -----------------
+#[rid::message(Reply)]
+#[derive(Debug)]
+pub enum Msg {
+    Inc,
+    Panic,
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[derive(Clone, Debug)]
+pub enum Reply {
+    Increased(u64),
+}
@@ -0,0 +1,51 @@
+use std::{thread, time::Duration};
+
+use rid::RidStore;
+
+// -----------------
+// Store
+// -----------------
+#[rid::store(snapshot)]
+#[derive(Clone, Debug)]
+pub struct Store {
+    applied: Vec<u32>,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self { applied: vec![] }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        match msg {
+            Msg::Apply(n) => {
+                self.applied_mut().push(n);
+                rid::post(Reply::Applied(req_id, n.to_string()));
+            }
+            Msg::ApplySlowly(n, millis) => {
+                self.applied_mut().push(n);
+                thread::sleep(Duration::from_millis(millis));
+                rid::post(Reply::Applied(req_id, n.to_string()));
+            }
+        }
+    }
+}
+
+// -----------------
+// Message
+// -----------------
+#[rid::message(Reply, threaded)]
+#[derive(Debug)]
+pub enum Msg {
+    Apply(u32),
+    ApplySlowly(u32, u64),
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[derive(Clone, Debug)]
+pub enum Reply {
+    Applied(u64, String),
+}
@@ -0,0 +1,78 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use rid::RidStore;
+
+// -----------------
+// Store
+// -----------------
+#[rid::store]
+#[derive(Debug)]
+pub struct Store {
+    applied: Vec<u32>,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self { applied: vec![] }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        match msg {
+            Msg::Apply(n) => {
+                self.applied.push(n);
+                rid::post(Reply::Applied(req_id, n.to_string()));
+            }
+            Msg::ApplySlowly(n, millis) => {
+                thread::sleep(Duration::from_millis(millis));
+                self.applied.push(n);
+                rid::post(Reply::Applied(req_id, n.to_string()));
+            }
+            Msg::ApplyUnlessCancelled(n, millis) => {
+                let token = rid::CancelToken::new(req_id);
+                let started = Instant::now();
+                while started.elapsed() < Duration::from_millis(millis) {
+                    if token.is_cancelled() {
+                        return;
+                    }
+                    thread::sleep(Duration::from_millis(5));
+                }
+                self.applied.push(n);
+                rid::post(Reply::Applied(req_id, n.to_string()));
+            }
+            Msg::ApplyEach(ns) => {
+                for n in ns.iter() {
+                    self.applied.push(*n);
+                    rid::post(Reply::Applying(req_id, *n));
+                }
+                rid::post(Reply::AppliedEach(req_id, ns.len() as u32));
+            }
+        }
+    }
+}
+
+// -----------------
+// Message
+// -----------------
+#[rid::message(Reply, threaded)]
+#[derive(Debug)]
+pub enum Msg {
+    Apply(u32),
+    ApplySlowly(u32, u64),
+    ApplyUnlessCancelled(u32, u64),
+    ApplyEach(Vec<u32>),
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[derive(Clone, Debug, rid::Config)]
+pub enum Reply {
+    Applied(u64, String),
+    #[rid(progress)]
+    Applying(u64, u32),
+    AppliedEach(u64, u32),
+}
@@ -0,0 +1,25 @@
+use tracing::{debug, info_span, warn};
+
+#[rid::export]
+pub fn init_tracing() -> u8 {
+    rid::init_tracing().ok();
+    0
+}
+
+#[rid::export]
+pub fn load_todos(count: u32) -> u32 {
+    let span = info_span!("load_todos", count);
+    let _guard = span.enter();
+
+    let mut loaded = 0;
+    for id in 0..count {
+        info_span!("load_todo", id).in_scope(|| {
+            loaded += 1;
+        });
+    }
+    debug!(loaded, "loaded todos");
+    if loaded == 0 {
+        warn!("nothing to load");
+    }
+    loaded
+}
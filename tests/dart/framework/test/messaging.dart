@@ -48,4 +48,35 @@ void main() {
     expect(msg.toString(),
         'RidMessage{ type: RidMessageType.Severe, message: "Severe Error 2 from Rust" }');
   });
+
+  test('messaging: message containing former separator', () async {
+    rid_ffi.rid_export_send_log_info_message_with_separator(3);
+    RidMessage msg = await rid.messageChannel.stream.first;
+    expect(msg.toString(),
+        'RidMessage{ type: RidMessageType.LogInfo, message: "Info^3^from Rust" }');
+  });
+
+  test('messaging: records logged via the log crate', () async {
+    rid_ffi.rid_export_init_logger();
+    final msgs = rid.messageChannel.stream.take(2).toList();
+    final before = DateTime.now().subtract(const Duration(seconds: 1));
+    rid_ffi.rid_export_send_log_record(4);
+
+    final received = await msgs;
+    final warn = received[0];
+    expect(warn.type, RidMessageType.LogWarn);
+    expect(warn.message, 'Record 4 from Rust');
+    expect(warn.isLogRecord, true);
+    expect(warn.level, RidLogLevel.Warn);
+    expect(warn.target, 'messaging');
+    expect(warn.modulePath, 'messaging');
+    expect(warn.file, 'src/messaging.rs');
+    expect(warn.line, greaterThan(0));
+    expect(warn.timestamp!.isAfter(before), true);
+
+    final debug = received[1];
+    expect(debug.type, RidMessageType.LogDebug);
+    expect(debug.level, RidLogLevel.Debug);
+    expect(debug.message, 'Debug record 4 from Rust');
+  });
 }
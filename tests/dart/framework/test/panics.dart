@@ -0,0 +1,53 @@
+import 'package:test/test.dart';
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  test('panics: exported function throws and reports severe message',
+      () async {
+    final store = Store.instance;
+    expect(store.half(4), 2);
+
+    final msg = rid.messageChannel.stream.first;
+    expect(
+        () => store.half(3),
+        throwsA(isA<RidPanicException>()
+            .having((e) => e.message, 'message', 'cannot halve 3')
+            .having((e) => e.call, 'call', 'rid_export_Store_half')));
+    final severe = await msg;
+    expect(severe.type, RidMessageType.Severe);
+    expect(severe.message, 'Rust panicked: cannot halve 3');
+    expect(severe.details, contains('panics.rs'));
+
+    // the panic was cleared and the next call succeeds
+    expect(store.half(6), 3);
+  });
+
+  test('panics: message completes with exception and store stays usable',
+      () async {
+    final store = Store.instance;
+    await store.msgInc();
+    expect(store.count, 1);
+
+    await expectLater(
+        store.msgPanic(),
+        throwsA(isA<RidPanicException>()
+            .having((e) => e.message, 'message', 'update failed')
+            .having((e) => e.call, 'call', 'msgPanic()')));
+
+    // the store lock poisoned by the panic is still usable
+    expect(store.count, 2);
+    await store.msgInc();
+    expect(store.count, 3);
+  });
+
+  test('panics: accessing the field of another variant throws', () {
+    final store = Store.instance;
+    expect(
+        () => store.raw.shape.circle_field0,
+        throwsA(isA<RidPanicException>()
+            .having((e) => e.call, 'call', 'rid_shape_circle_field0')));
+
+    // the panic was cleared and the next access succeeds
+    expect(store.raw.shape.discriminant, 1);
+  });
+}
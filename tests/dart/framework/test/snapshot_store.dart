@@ -0,0 +1,56 @@
+import 'dart:io';
+import 'package:test/test.dart';
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  test('snapshot store: reads do not wait for updates in progress', () async {
+    rid.debugLock = null;
+    rid.debugReply = null;
+
+    final store = Store.instance;
+    await store.msgApply(1);
+    expect(store.applied, [1]);
+
+    final slow = store.msgApplySlowly(2, 500);
+    // Give the worker time to start the update which holds the write lock
+    await Future.delayed(const Duration(milliseconds: 100));
+
+    final stopwatch = Stopwatch()..start();
+    expect(store.applied, [1], reason: 'reads latest published snapshot');
+    expect(stopwatch.elapsedMilliseconds, lessThan(200));
+
+    await slow;
+    expect(store.applied, [1, 2]);
+    expect(store.toDartState().applied, [1, 2]);
+  });
+
+  test('snapshot store: runLocked pins a snapshot', () async {
+    final store = Store.instance;
+
+    final update = store.msgApply(3);
+    final applied = store.raw.runLocked((raw) {
+      final before = raw.applied.toDart();
+      // The update is applied on the worker thread while the snapshot is pinned
+      sleep(const Duration(milliseconds: 100));
+      return [before, raw.applied.toDart()];
+    });
+    expect(applied[0], applied[1], reason: 'snapshot did not change');
+
+    await update;
+    expect(store.applied, [1, 2, 3]);
+  });
+
+  test('snapshot store: raw reads resolve the pinned snapshot', () async {
+    final store = Store.instance;
+    final raw = store.raw;
+
+    expect(() => raw.applied, throwsA(isA<RidPanicException>()),
+        reason: 'store is not locked');
+
+    await store.msgApply(4);
+    final applied = raw.runLocked((_) => raw.applied.toDart());
+    expect(applied, [1, 2, 3, 4], reason: 'reads latest snapshot');
+
+    store.dispose();
+  });
+}
@@ -0,0 +1,51 @@
+import 'package:test/test.dart';
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  test('threaded messages: processed in order off the caller thread',
+      () async {
+    rid.debugLock = null;
+    rid.debugReply = null;
+
+    final store = Store.instance;
+    expect(store.msgQueueDepth, 0);
+
+    final slow = store.msgApplySlowly(1, 200);
+    final fast = [2, 3, 4].map((n) => store.msgApply(n)).toList();
+    expect(store.msgQueueDepth, greaterThan(0));
+
+    final replies = await Future.wait([slow, ...fast]);
+    expect(replies.map((r) => r.data).toList(), ['1', '2', '3', '4']);
+    expect(store.applied, [1, 2, 3, 4]);
+    expect(store.msgQueueDepth, 0);
+  });
+
+  test('threaded messages: progress replies before the final reply', () async {
+    final store = Store.instance;
+
+    final request = store.msgApplyEach([5, 6, 7]);
+    final progress = request.progress
+        .map((r) => (r as PostedReplyApplying).field0)
+        .toList();
+
+    final reply = await request;
+    expect(reply.type, Reply.AppliedEach);
+    expect(reply.isProgress, false);
+    expect((reply as PostedReplyAppliedEach).field0, 3);
+    expect(await progress, [5, 6, 7]);
+    expect(store.applied, [1, 2, 3, 4, 5, 6, 7]);
+  });
+
+  test('threaded messages: cancelling a request', () async {
+    final store = Store.instance;
+
+    final request = store.msgApplyUnlessCancelled(8, 5000);
+    await Future.delayed(const Duration(milliseconds: 50));
+    request.cancel();
+    await expectLater(request, throwsA(isA<CancelledException>()));
+
+    // Rust stopped working on the cancelled request and processes the next one right away
+    await store.msgApply(9).timeout(const Duration(seconds: 2));
+    expect(store.applied, [1, 2, 3, 4, 5, 6, 7, 9]);
+  });
+}
@@ -0,0 +1,67 @@
+import 'package:test/test.dart';
+import '../lib/generated/rid_api.dart';
+
+void main() {
+  test('tracing: spans and events are posted with fields and durations',
+      () async {
+    rid_ffi.rid_export_init_tracing();
+    final traces = rid.messageChannel.traces.take(5).toList();
+    expect(rid_ffi.rid_export_load_todos(1), 1);
+
+    final received = await traces;
+    expect(received.map((x) => x.kind).toList(), [
+      RidTraceKind.SpanEnter,
+      RidTraceKind.SpanEnter,
+      RidTraceKind.SpanExit,
+      RidTraceKind.Event,
+      RidTraceKind.SpanExit,
+    ]);
+
+    final enter = received[0];
+    expect(enter.name, 'load_todos');
+    expect(enter.target, 'tracing_spans');
+    expect(enter.level, RidLogLevel.Info);
+    expect(enter.fields, {'count': '1'});
+    expect(enter.parentId, null);
+    expect(enter.duration, null);
+
+    final child = received[1];
+    expect(child.name, 'load_todo');
+    expect(child.fields, {'id': '0'});
+    expect(child.parentId, enter.spanId);
+
+    final childExit = received[2];
+    expect(childExit.spanId, child.spanId);
+    expect(childExit.duration, isNotNull);
+
+    final event = received[3];
+    expect(event.level, RidLogLevel.Debug);
+    expect(event.message, 'loaded todos');
+    expect(event.fields['loaded'], '1');
+    expect(event.parentId, enter.spanId);
+    expect(event.file, 'src/tracing_spans.rs');
+
+    final exit = received[4];
+    expect(exit.spanId, enter.spanId);
+    expect(exit.duration! >= childExit.duration!, true);
+  });
+
+  test('tracing: traces are not posted as messages', () async {
+    final messages = <RidMessage>[];
+    final sub = rid.messageChannel.stream.listen(messages.add);
+    final trace = rid.messageChannel.traces.first;
+    rid_ffi.rid_export_load_todos(0);
+    await trace;
+    await sub.cancel();
+    expect(messages, isEmpty);
+  });
+
+  test('tracing: converting to timeline events', () async {
+    final sub = ridTracesToTimeline(rid.messageChannel.traces);
+    final done = rid.messageChannel.traces
+        .firstWhere((x) => x.kind == RidTraceKind.SpanExit && x.parentId == null);
+    rid_ffi.rid_export_load_todos(2);
+    await done;
+    await sub.cancel();
+  });
+}
@@ -0,0 +1,77 @@
+use rid::RidStore;
+
+// -----------------
+// Store
+// -----------------
+#[rid::store]
+#[rid::structs(Tags)]
+#[derive(Debug)]
+pub struct Store {
+    count: u32,
+    label: String,
+    untracked: u32,
+    tags: Tags,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self {
+            count: 0,
+            label: "".to_string(),
+            untracked: 0,
+            tags: Tags { names: vec![] },
+        }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        match msg {
+            Msg::Inc => {
+                *self.count_mut() += 1;
+                rid::post(Reply::Updated(req_id));
+            }
+            Msg::SetLabel(label) => {
+                self.set_label(label);
+                rid::post(Reply::Updated(req_id));
+            }
+            Msg::IncUntracked => {
+                // Not using the generated setter which marks the field as changed
+                self.untracked += 1;
+                rid::post(Reply::Updated(req_id));
+            }
+            Msg::AddTag(name) => {
+                self.tags_mut().names.push(name);
+                rid::post(Reply::Updated(req_id));
+            }
+        }
+    }
+}
+
+// -----------------
+// Tags Model
+// -----------------
+#[rid::model]
+#[derive(Debug)]
+pub struct Tags {
+    names: Vec<String>,
+}
+
+// -----------------
+// Message
+// -----------------
+#[rid::message(Reply)]
+#[derive(Debug)]
+pub enum Msg {
+    Inc,
+    SetLabel(String),
+    IncUntracked,
+    AddTag(String),
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[derive(Clone, Debug)]
+pub enum Reply {
+    Updated(u64),
+}
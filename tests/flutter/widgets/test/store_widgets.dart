@@ -0,0 +1,128 @@
+import 'package:flutter/widgets.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:plugin/generated/rid_api.dart';
+
+/// Sends the message via [send] and waits until the store changes it caused reached Dart.
+/// Messages are handled by Rust in real time and thus need to run outside the fake async zone
+/// of widget tests, the widgets rebuild once the test pumps a frame.
+Future<Set<StoreField>> update(
+    WidgetTester tester, Future<void> Function(Store store) send) async {
+  final changes = await tester.runAsync(() async {
+    final changes = Store.instance.changes.first;
+    await send(Store.instance);
+    return changes;
+  });
+  await tester.pump();
+  return changes!;
+}
+
+Widget text(String value) => Text(value, textDirection: TextDirection.ltr);
+
+void main() {
+  setUpAll(() {
+    rid.debugLock = null;
+    rid.debugReply = null;
+    // Initialize the reply channel outside the fake async zone of widget tests
+    rid.replyChannel;
+  });
+
+  testWidgets('RidBuilder: rebuilds when the selected value changed',
+      (WidgetTester tester) async {
+    int builds = 0;
+    await tester.pumpWidget(RidBuilder<int>(
+      select: (store) => store.count,
+      builder: (context, count) {
+        builds++;
+        return text('count: $count');
+      },
+    ));
+    final initial = Store.instance.count;
+    expect(find.text('count: $initial'), findsOneWidget);
+    expect(builds, 1);
+
+    final changes = await update(tester, (store) => store.msgInc());
+    expect(changes, {StoreField.count});
+    expect(find.text('count: ${initial + 1}'), findsOneWidget);
+    expect(builds, 2);
+  });
+
+  testWidgets('RidBuilder: does not rebuild when the selected value is equal',
+      (WidgetTester tester) async {
+    int builds = 0;
+    await tester.pumpWidget(RidBuilder<bool>(
+      select: (store) => store.count > 1000,
+      builder: (context, large) {
+        builds++;
+        return text('large: $large');
+      },
+    ));
+    expect(builds, 1);
+
+    await update(tester, (store) => store.msgInc());
+    expect(find.text('large: false'), findsOneWidget);
+    expect(builds, 1);
+  });
+
+  testWidgets('RidBuilder: only selects when one of the provided fields changed',
+      (WidgetTester tester) async {
+    int selects = 0;
+    await tester.pumpWidget(RidBuilder<int>(
+      select: (store) {
+        selects++;
+        return store.count;
+      },
+      fields: {StoreField.label},
+      builder: (context, count) => text('count: $count'),
+    ));
+    final initial = Store.instance.count;
+    expect(selects, 1);
+
+    await update(tester, (store) => store.msgInc());
+    expect(selects, 1, reason: 'count is not one of the fields');
+    expect(find.text('count: $initial'), findsOneWidget);
+
+    final changes =
+        await update(tester, (store) => store.msgSetLabel('label'));
+    expect(changes, {StoreField.label});
+    expect(selects, 2);
+    expect(find.text('count: ${initial + 1}'), findsOneWidget);
+  });
+
+  testWidgets(
+      'RidBuilder: does not rebuild when a selected class holding a list is equal',
+      (WidgetTester tester) async {
+    int builds = 0;
+    await tester.pumpWidget(RidBuilder<Tags>(
+      select: (store) => store.tags,
+      builder: (context, tags) {
+        builds++;
+        return text('tags: ${tags.names.join(',')}');
+      },
+    ));
+    expect(builds, 1);
+
+    final changes = await update(tester, (store) => store.msgInc());
+    expect(changes, {StoreField.count});
+    expect(builds, 1, reason: 'tags hold an equal list');
+
+    await update(tester, (store) => store.msgAddTag('urgent'));
+    expect(find.text('tags: urgent'), findsOneWidget);
+    expect(builds, 2);
+  });
+
+  testWidgets(
+      'RidBuilder: rebuilds when an update changed fields without their setters',
+      (WidgetTester tester) async {
+    await tester.pumpWidget(RidBuilder<int>(
+      select: (store) => store.untracked,
+      fields: {StoreField.untracked},
+      builder: (context, untracked) => text('untracked: $untracked'),
+    ));
+    expect(find.text('untracked: 0'), findsOneWidget);
+
+    final changes = await update(tester, (store) => store.msgIncUntracked());
+    expect(changes, StoreField.values.toSet(),
+        reason: 'all fields are reported as changed');
+    expect(find.text('untracked: 1'), findsOneWidget);
+  });
+}
@@ -0,0 +1,231 @@
+// The store module generated by `#[rid::store]` keeps the store inside `static mut`s
+#![allow(static_mut_refs)]
+
+use std::{env, ffi::CString, fs, path::PathBuf};
+
+use rid::{
+    replay::{read_message_log, RidLogEntry, RidReplay, RidReplayError},
+    testing::RidTestHarness,
+    RidStore,
+};
+
+// -----------------
+// Store
+// -----------------
+#[rid::store]
+#[derive(Debug)]
+pub struct Store {
+    todos: Vec<String>,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self { todos: vec![] }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        match msg {
+            Msg::AddTodo(title) => {
+                self.todos.push(title);
+                rid::post(Reply::AddedTodo(req_id));
+            }
+            Msg::RemoveTodo(idx) => {
+                self.todos.remove(idx as usize);
+                rid::post(Reply::RemovedTodo(req_id));
+            }
+            Msg::RenameTodo { idx, title } => {
+                self.todos[idx as usize] = title;
+                rid::post(Reply::RenamedTodo(req_id));
+            }
+        }
+    }
+}
+
+// -----------------
+// Message
+// -----------------
+#[rid::message(Reply)]
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AddTodo(String),
+    RemoveTodo(u32),
+    RenameTodo { idx: u32, title: String },
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[derive(Debug, PartialEq)]
+pub enum Reply {
+    AddedTodo(u64),
+    RemovedTodo(u64),
+    RenamedTodo(u64),
+}
+
+type Harness = RidTestHarness<Store, Msg, Reply>;
+
+fn log_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("rid_{}_{}.ridlog", name, std::process::id()))
+}
+
+fn dart_string(s: &str) -> *mut std::os::raw::c_char {
+    CString::new(s).unwrap().into_raw()
+}
+
+#[test]
+fn replays_messages_sent_from_dart() {
+    let path = log_path("replays_messages");
+    let mut harness = Harness::new();
+    rid::start_recording(&path).unwrap();
+
+    // Sending messages the way Dart does, via the generated FFI functions
+    __rid_msg_ffi::rid_msg_AddTodo(1, dart_string("write tests"));
+    __rid_msg_ffi::rid_msg_AddTodo(2, dart_string("run tests"));
+    rid::record_store_snapshot(&*store::read());
+    __rid_msg_ffi::rid_msg_RemoveTodo(3, 0);
+    __rid_msg_ffi::rid_msg_RenameTodo(4, 0, dart_string("fix tests"));
+    rid::record_store_snapshot(&*store::read());
+
+    rid::stop_recording();
+    assert_eq!(
+        harness.replies(),
+        vec![
+            Reply::AddedTodo(1),
+            Reply::AddedTodo(2),
+            Reply::RemovedTodo(3),
+            Reply::RenamedTodo(4)
+        ],
+        "replies"
+    );
+    drop(harness);
+
+    let entries = read_message_log(&path).unwrap();
+    let messages: Vec<(u64, Msg)> = entries
+        .iter()
+        .filter_map(|entry| match entry {
+            RidLogEntry::Message { req_id, .. } => {
+                Some((*req_id, entry.decode_message().unwrap().unwrap()))
+            }
+            RidLogEntry::Snapshot { .. } => None,
+        })
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            (1, Msg::AddTodo("write tests".to_string())),
+            (2, Msg::AddTodo("run tests".to_string())),
+            (3, Msg::RemoveTodo(0)),
+            (
+                4,
+                Msg::RenameTodo {
+                    idx: 0,
+                    title: "fix tests".to_string()
+                }
+            ),
+        ],
+        "recorded messages"
+    );
+    assert_eq!(entries.len(), 7, "messages and snapshots");
+    match entries.last().unwrap() {
+        RidLogEntry::Snapshot { store, .. } => {
+            assert!(store.contains("fix tests"), "final snapshot {}", store)
+        }
+        entry => panic!("not a snapshot {:?}", entry),
+    }
+
+    let store = RidReplay::new(&path).run::<Store, Msg>().unwrap();
+    assert_eq!(store.todos, vec!["fix tests".to_string()], "replayed store");
+
+    let mut harness = Harness::new();
+    harness.replay(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(harness.replies(), vec![], "replies are dropped");
+    harness.store(|store| {
+        assert_eq!(store.todos, vec!["fix tests".to_string()], "global store")
+    });
+}
+
+#[test]
+fn reports_snapshot_mismatch() {
+    let path = log_path("snapshot_mismatch");
+    let harness = Harness::new();
+    rid::start_recording(&path).unwrap();
+    rid::_record_message(1, &Msg::AddTodo("write tests".to_string()));
+    rid::record_store_snapshot(&Store {
+        todos: vec!["run tests".to_string()],
+    });
+    rid::stop_recording();
+    drop(harness);
+
+    let err = RidReplay::new(&path).run::<Store, Msg>().unwrap_err();
+    match err {
+        RidReplayError::SnapshotMismatch {
+            messages,
+            expected,
+            actual,
+        } => {
+            assert_eq!(messages, 1, "messages applied");
+            assert!(expected.contains("run tests"), "expected");
+            assert!(actual.contains("write tests"), "actual");
+        }
+        err => panic!("unexpected error {}", err),
+    }
+
+    let store = RidReplay::new(&path)
+        .check_snapshots(false)
+        .run::<Store, Msg>()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(
+        store.todos,
+        vec!["write tests".to_string()],
+        "ignores snapshots"
+    );
+}
+
+#[test]
+fn fails_to_replay_missing_log() {
+    let err = RidReplay::new(log_path("missing"))
+        .run::<Store, Msg>()
+        .unwrap_err();
+    assert!(matches!(err, RidReplayError::Io(_)), "io error");
+}
+
+#[test]
+fn fails_to_replay_corrupt_log() {
+    let path = log_path("corrupt");
+    let harness = Harness::new();
+    rid::start_recording(&path).unwrap();
+    rid::_record_message(1, &Msg::AddTodo("write tests".to_string()));
+    // Truncated AddTodo message which only holds the variant index
+    rid::_record_message(2, &0u32);
+    rid::stop_recording();
+    drop(harness);
+
+    let err = RidReplay::new(&path).run::<Store, Msg>().unwrap_err();
+    match err {
+        RidReplayError::Corrupt { entry, reason } => {
+            assert_eq!(entry, 1, "entry");
+            assert_eq!(
+                reason, "tried to read 4 bytes at offset 4 of 4 bytes",
+                "reason"
+            );
+        }
+        err => panic!("unexpected error {}", err),
+    }
+
+    let harness = Harness::new();
+    rid::start_recording(&path).unwrap();
+    rid::_record_message(1, &7u32);
+    rid::stop_recording();
+    drop(harness);
+
+    let err = RidReplay::new(&path).run::<Store, Msg>().unwrap_err();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Failed to decode message of entry 0: enum Msg does not include variant 7",
+        "invalid variant"
+    );
+}
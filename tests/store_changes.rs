@@ -0,0 +1,79 @@
+// The store module generated by `#[rid::store]` keeps the store inside `static mut`s
+#![allow(static_mut_refs)]
+// The generated string accessors format their `expect` messages
+#![allow(clippy::expect_fun_call, clippy::useless_format)]
+
+use rid::{testing::RidTestHarness, RidStore};
+
+// -----------------
+// Store
+// -----------------
+// Defines methods named like the generated setters which must not clash with them.
+#[rid::store]
+#[derive(Debug)]
+pub struct Store {
+    count: u32,
+    title: String,
+}
+
+impl Store {
+    pub fn set_title(&mut self, title: &str) {
+        self.title = title.trim().to_string();
+    }
+
+    pub fn count_mut(&mut self) -> &mut u32 {
+        &mut self.count
+    }
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self {
+            count: 0,
+            title: String::new(),
+        }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        match msg {
+            Msg::Rename(title) => {
+                // Inherent methods take precedence over the generated setters
+                Store::set_title(self, &title);
+                StoreFieldSetters::set_count(self, self.count + 1);
+                rid::post(Reply::Renamed(req_id));
+            }
+        }
+    }
+}
+
+// -----------------
+// Message
+// -----------------
+#[rid::message(Reply)]
+#[derive(Debug)]
+pub enum Msg {
+    Rename(String),
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[derive(Debug, PartialEq)]
+pub enum Reply {
+    Renamed(u64),
+}
+
+#[test]
+fn generated_setters_do_not_clash_with_store_methods() {
+    let mut harness = RidTestHarness::<Store, Msg, Reply>::new();
+    let req_id = harness.send(Msg::Rename("  renamed ".to_string()));
+    assert_eq!(harness.next_reply(), Reply::Renamed(req_id), "reply");
+
+    harness.store(|store| {
+        assert_eq!(store.title, "renamed", "title set via store method");
+        assert_eq!(store.count, 1, "count set via generated setter");
+        *store.count_mut() += 1;
+        assert_eq!(store.count, 2, "count set via store method");
+    });
+}
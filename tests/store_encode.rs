@@ -0,0 +1,85 @@
+// The store module generated by `#[rid::store]` keeps the store inside `static mut`s
+#![allow(static_mut_refs)]
+// The generated string accessors format their `expect` messages
+#![allow(clippy::expect_fun_call, clippy::useless_format)]
+
+use rid::{_RidEncode, _RidWireReader, _RidWireWriter, RidStore};
+
+// -----------------
+// Store
+// -----------------
+#[rid::store]
+#[rid::structs(Todo)]
+#[derive(Debug)]
+pub struct Store {
+    todos: Vec<Todo>,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self { todos: vec![] }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        match msg {
+            Msg::AddTodo(title) => {
+                self.todos.push(Todo {
+                    id: self.todos.len() as u32,
+                    title,
+                    cache: vec![],
+                });
+                rid::post(Reply::AddedTodo(req_id));
+            }
+        }
+    }
+}
+
+// -----------------
+// Todo Model
+// -----------------
+// Skipped fields prevent decoding a Todo, but it still needs to be encoded as part of the store
+// state.
+#[rid::model]
+#[derive(Debug, rid::Config)]
+pub struct Todo {
+    id: u32,
+    title: String,
+    #[rid(skip)]
+    #[allow(dead_code)]
+    cache: Vec<u8>,
+}
+
+// -----------------
+// Message
+// -----------------
+#[rid::message(Reply)]
+#[derive(Debug)]
+pub enum Msg {
+    AddTodo(String),
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[derive(Debug, PartialEq)]
+pub enum Reply {
+    AddedTodo(u64),
+}
+
+#[test]
+fn encodes_included_fields_of_model_with_skipped_field() {
+    let todo = Todo {
+        id: 1,
+        title: "write tests".to_string(),
+        cache: vec![1, 2, 3],
+    };
+    let mut writer = _RidWireWriter::new();
+    todo._rid_encode(&mut writer);
+    let bytes = writer.into_bytes();
+
+    let mut reader = _RidWireReader::new(&bytes);
+    assert_eq!(reader.read_u32(), 1, "id");
+    assert_eq!(reader.read_string(), "write tests", "title");
+    assert_eq!(reader.remaining(), 0, "skipped cache");
+}
@@ -0,0 +1,157 @@
+// The store module generated by `#[rid::store]` keeps the store inside `static mut`s
+#![allow(static_mut_refs)]
+
+use std::{thread, time::Duration};
+
+use rid::{
+    testing::{RidMessage, RidMessageType, RidTestHarness},
+    RidStore,
+};
+
+// -----------------
+// Store
+// -----------------
+#[rid::store]
+#[derive(Debug)]
+pub struct Store {
+    todos: Vec<String>,
+}
+
+impl RidStore<Msg> for Store {
+    fn create() -> Self {
+        Self { todos: vec![] }
+    }
+
+    fn update(&mut self, req_id: u64, msg: Msg) {
+        match msg {
+            Msg::AddTodo(title) => {
+                rid::log_info!("Adding '{}'", title);
+                self.todos.push(title);
+                rid::post(Reply::AddedTodo(req_id, self.todos.len() as u32));
+            }
+            Msg::RemoveTodo(idx) => {
+                if (idx as usize) < self.todos.len() {
+                    self.todos.remove(idx as usize);
+                    rid::post(Reply::RemovedTodo(req_id));
+                } else {
+                    rid::error!("Cannot remove todo", idx);
+                }
+            }
+            Msg::Sync => {
+                thread::spawn(move || {
+                    thread::sleep(Duration::from_millis(20));
+                    rid::post(Reply::Synced(req_id));
+                });
+            }
+        }
+    }
+}
+
+// -----------------
+// Message
+// -----------------
+#[rid::message(Reply)]
+#[derive(Debug)]
+pub enum Msg {
+    AddTodo(String),
+    RemoveTodo(u32),
+    Sync,
+}
+
+// -----------------
+// Reply
+// -----------------
+#[rid::reply]
+#[derive(Debug, PartialEq)]
+pub enum Reply {
+    AddedTodo(u64, u32),
+    RemovedTodo(u64),
+    Synced(u64),
+}
+
+type Harness = RidTestHarness<Store, Msg, Reply>;
+
+#[test]
+fn replies_in_order() {
+    let mut harness = Harness::new();
+    let first = harness.send(Msg::AddTodo("write tests".to_string()));
+    let second = harness.send(Msg::AddTodo("run tests".to_string()));
+    let third = harness.send(Msg::RemoveTodo(0));
+
+    assert_eq!(
+        harness.replies(),
+        vec![
+            Reply::AddedTodo(first, 1),
+            Reply::AddedTodo(second, 2),
+            Reply::RemovedTodo(third),
+        ],
+        "replies"
+    );
+    assert!(harness.replies().is_empty(), "replies were taken");
+    assert_eq!(
+        harness.store(|store| store.todos.clone()),
+        vec!["run tests".to_string()],
+        "store"
+    );
+}
+
+#[test]
+fn messages_in_order() {
+    let mut harness = Harness::new();
+    harness.send(Msg::AddTodo("write tests".to_string()));
+    harness.send(Msg::RemoveTodo(1));
+
+    assert_eq!(
+        harness.messages(),
+        vec![
+            RidMessage {
+                msg_type: RidMessageType::LogInfo,
+                message: "Adding 'write tests'".to_string(),
+                details: None,
+            },
+            RidMessage {
+                msg_type: RidMessageType::Error,
+                message: "\"Cannot remove todo\"".to_string(),
+                details: Some("1".to_string()),
+            },
+        ],
+        "messages"
+    );
+    assert_eq!(harness.replies().len(), 1, "only added todo");
+}
+
+#[test]
+fn waits_for_reply_posted_from_other_thread() {
+    let mut harness = Harness::new().timeout(Duration::from_secs(5));
+    let req_id = harness.send(Msg::Sync);
+    assert!(harness.replies().is_empty(), "not replied yet");
+    assert_eq!(harness.next_reply(), Reply::Synced(req_id), "reply");
+}
+
+#[test]
+fn resets_store() {
+    let mut harness = Harness::new();
+    harness.send(Msg::AddTodo("write tests".to_string()));
+    drop(harness);
+
+    let mut harness = Harness::new();
+    assert!(
+        harness.store(|store| store.todos.is_empty()),
+        "store was reset"
+    );
+    let req_id = harness.send(Msg::AddTodo("run tests".to_string()));
+    assert_eq!(harness.next_reply(), Reply::AddedTodo(req_id, 1), "reply");
+    assert_eq!(
+        harness.next_message().message,
+        "Adding 'run tests'",
+        "message"
+    );
+}
+
+#[test]
+#[should_panic(expected = "No reply was posted within")]
+fn next_reply_times_out() {
+    let mut harness = Harness::new().timeout(Duration::from_millis(10));
+    harness.send(Msg::RemoveTodo(0));
+    harness.next_reply();
+}